@@ -61,6 +61,7 @@ pub enum JsTruncationStrategy {
   LongestFirst,
   OnlyFirst,
   OnlySecond,
+  Proportional,
 }
 
 impl From<JsTruncationStrategy> for tokenizers::TruncationStrategy {
@@ -69,6 +70,7 @@ impl From<JsTruncationStrategy> for tokenizers::TruncationStrategy {
       JsTruncationStrategy::LongestFirst => tokenizers::TruncationStrategy::LongestFirst,
       JsTruncationStrategy::OnlyFirst => tokenizers::TruncationStrategy::OnlyFirst,
       JsTruncationStrategy::OnlySecond => tokenizers::TruncationStrategy::OnlySecond,
+      JsTruncationStrategy::Proportional => tokenizers::TruncationStrategy::Proportional,
     }
   }
 }
@@ -135,6 +135,16 @@ impl tk::Model for Model {
       .get_trainer()
       .into()
   }
+
+  fn prune_vocab(&mut self, keep: &dyn Fn(u32) -> bool) -> tk::Result<HashMap<u32, u32>> {
+    self
+      .model
+      .as_ref()
+      .ok_or("Uninitialized Model")?
+      .write()
+      .unwrap()
+      .prune_vocab(keep)
+  }
 }
 
 #[derive(Default)]
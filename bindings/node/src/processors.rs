@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 extern crate tokenizers as tk;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
 use tk::processors::PostProcessorWrapper;
@@ -26,6 +27,16 @@ impl tk::PostProcessor for Processor {
       .added_tokens(is_pair)
   }
 
+  fn added_tokens_multi(&self, n_sequences: usize) -> tk::Result<usize> {
+    self
+      .processor
+      .as_ref()
+      .expect("Uninitialized PostProcessor")
+      .read()
+      .unwrap()
+      .added_tokens_multi(n_sequences)
+  }
+
   fn process_encodings(
     &self,
     encodings: Vec<Encoding>,
@@ -39,6 +50,26 @@ impl tk::PostProcessor for Processor {
       .unwrap()
       .process_encodings(encodings, add_special_tokens)
   }
+
+  fn get_special_ids(&self) -> Vec<u32> {
+    self
+      .processor
+      .as_ref()
+      .expect("Uninitialized PostProcessor")
+      .read()
+      .unwrap()
+      .get_special_ids()
+  }
+
+  fn remap_ids(&mut self, ids: &HashMap<u32, u32>) {
+    self
+      .processor
+      .as_ref()
+      .expect("Uninitialized PostProcessor")
+      .write()
+      .unwrap()
+      .remap_ids(ids)
+  }
 }
 
 #[napi]
@@ -796,7 +796,15 @@ class Tokenizer:
         """
         pass
 
-    def enable_truncation(self, max_length, stride=0, strategy="longest_first", direction="right"):
+    def enable_truncation(
+        self,
+        max_length,
+        stride=0,
+        strategy="longest_first",
+        direction="right",
+        boundary="token",
+        boundary_pattern=None,
+    ):
         """
         Enable truncation
 
@@ -809,11 +817,18 @@ class Tokenizer:
                 sequence
 
             strategy (:obj:`str`, `optional`, defaults to :obj:`longest_first`):
-                The strategy used to truncation. Can be one of ``longest_first``, ``only_first`` or
-                ``only_second``.
+                The strategy used to truncation. Can be one of ``longest_first``, ``only_first``,
+                ``only_second`` or ``proportional``.
 
             direction (:obj:`str`, defaults to :obj:`right`):
                 Truncate direction
+
+            boundary (:obj:`str`, `optional`, defaults to :obj:`token`):
+                Where the sequences can be cut. Can be one of ``token``, ``word`` or ``sentence``,
+                or ``regex`` along with a ``boundary_pattern``
+
+            boundary_pattern (:obj:`str`, `optional`):
+                A regex whose matches end the spans that can be cut, used instead of ``boundary``
         """
         pass
 
@@ -2,13 +2,17 @@ from .. import normalizers
 
 
 Normalizer = normalizers.Normalizer
+AsciiFold = normalizers.AsciiFold
 BertNormalizer = normalizers.BertNormalizer
 NFD = normalizers.NFD
 NFKD = normalizers.NFKD
 NFC = normalizers.NFC
 NFKC = normalizers.NFKC
 Sequence = normalizers.Sequence
+LocaleLowercase = normalizers.LocaleLowercase
 Lowercase = normalizers.Lowercase
+CaseFold = normalizers.CaseFold
+ConfusableSkeleton = normalizers.ConfusableSkeleton
 Prepend = normalizers.Prepend
 Strip = normalizers.Strip
 StripAccents = normalizers.StripAccents
@@ -40,6 +40,59 @@ class Normalizer:
         """
         pass
 
+class AsciiFold(Normalizer):
+    """
+    AsciiFold Normalizer
+
+    Folds the characters to their closest ASCII equivalent, like :obj:`è` to :obj:`e` or
+    :obj:`ß` to :obj:`ss`, and removes the combining marks
+
+    Args:
+        fallback (:obj:`str`, `optional`, defaults to :obj:`"keep"`):
+            What to do with the characters that can't be folded: :obj:`"keep"`, :obj:`"drop"` or
+            :obj:`"replace"` them with the :obj:`replacement`
+
+        replacement (:obj:`str`, `optional`, defaults to :obj:`"?"`):
+            The replacement of the characters that can't be folded, with the :obj:`"replace"`
+            fallback
+    """
+    def __init__(self, fallback="keep", replacement="?"):
+        pass
+
+    def normalize(self, normalized):
+        """
+        Normalize a :class:`~tokenizers.NormalizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.NormalizedString` to
+        keep track of the alignment information. If you just want to see the result
+        of the normalization on a raw string, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize_str`
+
+        Args:
+            normalized (:class:`~tokenizers.NormalizedString`):
+                The normalized string on which to apply this
+                :class:`~tokenizers.normalizers.Normalizer`
+        """
+        pass
+
+    def normalize_str(self, sequence):
+        """
+        Normalize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.normalizers.Normalizer` but it does not keep track of the alignment
+        information. If you need to get/convert offsets, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to normalize
+
+        Returns:
+            :obj:`str`: A string after normalization
+        """
+        pass
+
 class BertNormalizer(Normalizer):
     """
     BertNormalizer
@@ -99,6 +152,153 @@ class BertNormalizer(Normalizer):
         """
         pass
 
+class CaseFold(Normalizer):
+    """
+    CaseFold Normalizer
+
+    Case folds the input, which is better suited than lowercasing for caseless matching
+
+    Args:
+        mode (:obj:`str`, `optional`, defaults to :obj:`"full"`):
+            One of :obj:`"full"`, :obj:`"simple"` where each character stays a single one, or
+            :obj:`"nfkc_casefold"` to also apply NFKC and remove the default ignorable characters
+    """
+    def __init__(self, mode="full"):
+        pass
+
+    def normalize(self, normalized):
+        """
+        Normalize a :class:`~tokenizers.NormalizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.NormalizedString` to
+        keep track of the alignment information. If you just want to see the result
+        of the normalization on a raw string, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize_str`
+
+        Args:
+            normalized (:class:`~tokenizers.NormalizedString`):
+                The normalized string on which to apply this
+                :class:`~tokenizers.normalizers.Normalizer`
+        """
+        pass
+
+    def normalize_str(self, sequence):
+        """
+        Normalize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.normalizers.Normalizer` but it does not keep track of the alignment
+        information. If you need to get/convert offsets, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to normalize
+
+        Returns:
+            :obj:`str`: A string after normalization
+        """
+        pass
+class ConfusableSkeleton(Normalizer):
+    """
+    ConfusableSkeleton Normalizer
+
+    Replaces the text by its Unicode TR39 confusable skeleton, so that lookalike texts, like
+    :obj:`"pаypаl"` written with a Cyrillic :obj:`а`, become the same. The invisible default
+    ignorable characters are removed.
+
+    All the confusables of Unicode 15.0.0 are covered, including those whose prototype is not
+    Latin, like the Cyrillic :obj:`П` replaced by the Greek :obj:`Π`. Like in the TR39, the
+    compatibility forms like the fullwidth :obj:`ｍ` are kept, a NFKC normalizer can be added first.
+
+    Args:
+        keep_joiners (:obj:`bool`, `optional`, defaults to :obj:`False`):
+            Whether to keep the zero width joiner and non-joiner, needed by some scripts
+    """
+    def __init__(self, keep_joiners=False):
+        pass
+
+    def normalize(self, normalized):
+        """
+        Normalize a :class:`~tokenizers.NormalizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.NormalizedString` to
+        keep track of the alignment information. If you just want to see the result
+        of the normalization on a raw string, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize_str`
+
+        Args:
+            normalized (:class:`~tokenizers.NormalizedString`):
+                The normalized string on which to apply this
+                :class:`~tokenizers.normalizers.Normalizer`
+        """
+        pass
+
+    def normalize_str(self, sequence):
+        """
+        Normalize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.normalizers.Normalizer` but it does not keep track of the alignment
+        information. If you need to get/convert offsets, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to normalize
+
+        Returns:
+            :obj:`str`: A string after normalization
+        """
+        pass
+class LocaleLowercase(Normalizer):
+    """
+    LocaleLowercase Normalizer
+
+    Lowercases following the rules of a language, where :obj:`I` becomes the dotless :obj:`ı`
+
+    Args:
+        locale (:obj:`str`):
+            The language whose lowercasing rules to follow, one of :obj:`"turkish"` or
+            :obj:`"azeri"`
+    """
+    def __init__(self, locale):
+        pass
+
+    def normalize(self, normalized):
+        """
+        Normalize a :class:`~tokenizers.NormalizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.NormalizedString` to
+        keep track of the alignment information. If you just want to see the result
+        of the normalization on a raw string, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize_str`
+
+        Args:
+            normalized (:class:`~tokenizers.NormalizedString`):
+                The normalized string on which to apply this
+                :class:`~tokenizers.normalizers.Normalizer`
+        """
+        pass
+
+    def normalize_str(self, sequence):
+        """
+        Normalize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.normalizers.Normalizer` but it does not keep track of the alignment
+        information. If you need to get/convert offsets, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to normalize
+
+        Returns:
+            :obj:`str`: A string after normalization
+        """
+        pass
+
 class Lowercase(Normalizer):
     """
     Lowercase Normalizer
@@ -5,6 +5,7 @@ PreTokenizer = pre_tokenizers.PreTokenizer
 BertPreTokenizer = pre_tokenizers.BertPreTokenizer
 ByteLevel = pre_tokenizers.ByteLevel
 CharDelimiterSplit = pre_tokenizers.CharDelimiterSplit
+DictionarySegmenter = pre_tokenizers.DictionarySegmenter
 Digits = pre_tokenizers.Digits
 Metaspace = pre_tokenizers.Metaspace
 Punctuation = pre_tokenizers.Punctuation
@@ -204,6 +204,79 @@ class CharDelimiterSplit(PreTokenizer):
         """
         pass
 
+class DictionarySegmenter(PreTokenizer):
+    """
+    This pre-tokenizer splits the text of the scripts that don't use spaces between words
+    (Thai, Lao, Khmer, Myanmar, Chinese and Japanese) into the words of a dictionary. The rest
+    of the text is left as it is.
+
+    Args:
+        words (:obj:`List[Tuple[str, Optional[int]]]`):
+            The words of the dictionary, each with an optional frequency
+
+        mode (:obj:`str`, `optional`, defaults to :obj:`"maximal_matching"`):
+            How to choose between the possible segmentations. Choices: "maximal_matching" to
+            use as few words as possible, "unigram_cost" to use the most frequent words.
+    """
+    def __init__(self, words, mode="maximal_matching"):
+        pass
+
+    @staticmethod
+    def from_file(path, mode="maximal_matching"):
+        """
+        Instantiate a DictionarySegmenter from the given file
+
+        The file has a word per line, optionally followed by a tab and its frequency.
+
+        Args:
+            path (:obj:`str`):
+                The path to the words file
+
+            mode (:obj:`str`, `optional`, defaults to :obj:`"maximal_matching"`):
+                How to choose between the possible segmentations
+
+        Returns:
+            :class:`~tokenizers.pre_tokenizers.DictionarySegmenter`: An instance loaded from file
+        """
+        pass
+
+    def pre_tokenize(self, pretok):
+        """
+        Pre-tokenize a :class:`~tokenizers.PyPreTokenizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.PreTokenizedString` to
+        keep track of the pre-tokenization, and leverage the capabilities of the
+        :class:`~tokenizers.PreTokenizedString`. If you just want to see the result of
+        the pre-tokenization of a raw string, you can use
+        :meth:`~tokenizers.pre_tokenizers.PreTokenizer.pre_tokenize_str`
+
+        Args:
+            pretok (:class:`~tokenizers.PreTokenizedString):
+                The pre-tokenized string on which to apply this
+                :class:`~tokenizers.pre_tokenizers.PreTokenizer`
+        """
+        pass
+
+    def pre_tokenize_str(self, sequence):
+        """
+        Pre tokenize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.pre_tokenizers.PreTokenizer` but it does not keep track of the
+        alignment, nor does it provide all the capabilities of the
+        :class:`~tokenizers.PreTokenizedString`. If you need some of these, you can use
+        :meth:`~tokenizers.pre_tokenizers.PreTokenizer.pre_tokenize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to pre-tokeize
+
+        Returns:
+            :obj:`List[Tuple[str, Offsets]]`:
+                A list of tuple with the pre-tokenized parts and their offsets
+        """
+        pass
+
 class Digits(PreTokenizer):
     """
     This pre-tokenizer simply splits using the digits in separate tokens
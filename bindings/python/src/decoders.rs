@@ -58,6 +58,7 @@ impl PyDecoder {
                 DecoderWrapper::Sequence(_) => {
                     Py::new(py, (PySequenceDecoder {}, base))?.into_py(py)
                 }
+                DecoderWrapper::ByteDecoder(_) => Py::new(py, base)?.into_py(py),
             },
         })
     }
@@ -39,6 +39,7 @@ impl PyModel {
             ModelWrapper::WordPiece(_) => Py::new(py, (PyWordPiece {}, base))?.into_py(py),
             ModelWrapper::WordLevel(_) => Py::new(py, (PyWordLevel {}, base))?.into_py(py),
             ModelWrapper::Unigram(_) => Py::new(py, (PyUnigram {}, base))?.into_py(py),
+            ModelWrapper::ByteModel(_) => Py::new(py, base)?.into_py(py),
         })
     }
 }
@@ -73,6 +74,10 @@ impl Model for PyModel {
     fn get_trainer(&self) -> Self::Trainer {
         self.model.read().unwrap().get_trainer().into()
     }
+
+    fn prune_vocab(&mut self, keep: &dyn Fn(u32) -> bool) -> tk::Result<HashMap<u32, u32>> {
+        self.model.write().unwrap().prune_vocab(keep)
+    }
 }
 
 impl<I> From<I> for PyModel
@@ -592,12 +597,11 @@ impl PyWordPiece {
 
     #[setter]
     fn set_continuing_subword_prefix(self_: PyRef<Self>, continuing_subword_prefix: String) {
-        setter!(
-            self_,
-            WordPiece,
-            continuing_subword_prefix,
-            continuing_subword_prefix
-        );
+        let super_ = self_.as_ref();
+        let mut model = super_.model.write().unwrap();
+        if let ModelWrapper::WordPiece(ref mut wp) = *model {
+            wp.set_continuing_subword_prefix(continuing_subword_prefix);
+        }
     }
 
     #[getter]
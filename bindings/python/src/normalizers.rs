@@ -9,8 +9,9 @@ use crate::utils::{PyNormalizedString, PyNormalizedStringRefMut, PyPattern};
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use tk::normalizers::{
-    BertNormalizer, Lowercase, Nmt, NormalizerWrapper, Precompiled, Prepend, Replace, Strip,
-    StripAccents, NFC, NFD, NFKC, NFKD,
+    AsciiFold, AsciiFoldFallback, BertNormalizer, CaseFold, CaseFoldMode, CaseLocale,
+    ConfusableSkeleton, LocaleLowercase, Lowercase, Nmt, NormalizerWrapper, Precompiled, Prepend,
+    Replace, Strip, StripAccents, NFC, NFD, NFKC, NFKD,
 };
 use tk::{NormalizedString, Normalizer};
 use tokenizers as tk;
@@ -80,11 +81,23 @@ impl PyNormalizer {
                     NormalizerWrapper::Lowercase(_) => {
                         Py::new(py, (PyLowercase {}, base))?.into_py(py)
                     }
+                    NormalizerWrapper::LocaleLowercase(_) => {
+                        Py::new(py, (PyLocaleLowercase {}, base))?.into_py(py)
+                    }
                     NormalizerWrapper::Precompiled(_) => {
                         Py::new(py, (PyPrecompiled {}, base))?.into_py(py)
                     }
                     NormalizerWrapper::Replace(_) => Py::new(py, (PyReplace {}, base))?.into_py(py),
                     NormalizerWrapper::Nmt(_) => Py::new(py, (PyNmt {}, base))?.into_py(py),
+                    NormalizerWrapper::CaseFold(_) => {
+                        Py::new(py, (PyCaseFold {}, base))?.into_py(py)
+                    }
+                    NormalizerWrapper::AsciiFold(_) => {
+                        Py::new(py, (PyAsciiFold {}, base))?.into_py(py)
+                    }
+                    NormalizerWrapper::ConfusableSkeleton(_) => {
+                        Py::new(py, (PyConfusableSkeleton {}, base))?.into_py(py)
+                    }
                 },
             },
         })
@@ -369,6 +382,25 @@ impl PySequence {
     }
 }
 
+fn locale_from_string(string: String) -> PyResult<CaseLocale> {
+    match string.as_str() {
+        "turkish" => Ok(CaseLocale::Turkish),
+        "azeri" => Ok(CaseLocale::Azeri),
+        _ => Err(exceptions::PyValueError::new_err(format!(
+            "{} is an unknown locale, should be one of ['turkish', 'azeri']",
+            string
+        ))),
+    }
+}
+
+fn locale_to_string(locale: CaseLocale) -> String {
+    match locale {
+        CaseLocale::Turkish => "turkish",
+        CaseLocale::Azeri => "azeri",
+    }
+    .into()
+}
+
 /// Lowercase Normalizer
 #[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name = "Lowercase")]
 pub struct PyLowercase {}
@@ -381,6 +413,212 @@ impl PyLowercase {
     }
 }
 
+/// LocaleLowercase Normalizer
+///
+/// Lowercases following the rules of a language, where :obj:`I` becomes the dotless :obj:`ı`
+///
+/// Args:
+///     locale (:obj:`str`):
+///         The language whose lowercasing rules to follow, one of :obj:`"turkish"` or
+///         :obj:`"azeri"`
+#[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name = "LocaleLowercase")]
+pub struct PyLocaleLowercase {}
+#[pymethods]
+impl PyLocaleLowercase {
+    #[getter]
+    fn get_locale(self_: PyRef<Self>) -> String {
+        locale_to_string(getter!(self_, LocaleLowercase, locale))
+    }
+
+    #[setter]
+    fn set_locale(self_: PyRef<Self>, locale: String) -> PyResult<()> {
+        let locale = locale_from_string(locale)?;
+        setter!(self_, LocaleLowercase, locale, locale);
+        Ok(())
+    }
+
+    #[new]
+    #[pyo3(text_signature = "(self, locale)")]
+    fn new(locale: String) -> PyResult<(Self, PyNormalizer)> {
+        let locale = locale_from_string(locale)?;
+        Ok((PyLocaleLowercase {}, LocaleLowercase::new(locale).into()))
+    }
+}
+
+fn case_fold_mode_from_string(string: String) -> PyResult<CaseFoldMode> {
+    match string.as_str() {
+        "full" => Ok(CaseFoldMode::Full),
+        "simple" => Ok(CaseFoldMode::Simple),
+        "nfkc_casefold" => Ok(CaseFoldMode::NfkcCasefold),
+        _ => Err(exceptions::PyValueError::new_err(format!(
+            "{} is an unknown mode, should be one of ['full', 'simple', 'nfkc_casefold']",
+            string
+        ))),
+    }
+}
+
+/// CaseFold Normalizer
+///
+/// Case folds the input, which is better suited than lowercasing for caseless matching
+///
+/// Args:
+///     mode (:obj:`str`, `optional`, defaults to :obj:`"full"`):
+///         One of :obj:`"full"`, :obj:`"simple"` where each character stays a single one, or
+///         :obj:`"nfkc_casefold"` to also apply NFKC and remove the default ignorable characters
+#[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name = "CaseFold")]
+pub struct PyCaseFold {}
+#[pymethods]
+impl PyCaseFold {
+    #[getter]
+    fn get_mode(self_: PyRef<Self>) -> String {
+        match getter!(self_, CaseFold, mode) {
+            CaseFoldMode::Full => "full",
+            CaseFoldMode::Simple => "simple",
+            CaseFoldMode::NfkcCasefold => "nfkc_casefold",
+        }
+        .into()
+    }
+
+    #[setter]
+    fn set_mode(self_: PyRef<Self>, mode: String) -> PyResult<()> {
+        let mode = case_fold_mode_from_string(mode)?;
+        setter!(self_, CaseFold, mode, mode);
+        Ok(())
+    }
+
+    #[new]
+    #[pyo3(signature = (mode = String::from("full")), text_signature = "(self, mode=\"full\")")]
+    fn new(mode: String) -> PyResult<(Self, PyNormalizer)> {
+        let mode = case_fold_mode_from_string(mode)?;
+        Ok((PyCaseFold {}, CaseFold::new(mode).into()))
+    }
+}
+
+fn ascii_fold_fallback(fallback: String, replacement: String) -> PyResult<AsciiFoldFallback> {
+    match fallback.as_str() {
+        "keep" => Ok(AsciiFoldFallback::Keep),
+        "drop" => Ok(AsciiFoldFallback::Drop),
+        "replace" => Ok(AsciiFoldFallback::Replace(replacement)),
+        _ => Err(exceptions::PyValueError::new_err(format!(
+            "{} is an unknown fallback, should be one of ['keep', 'drop', 'replace']",
+            fallback
+        ))),
+    }
+}
+
+/// AsciiFold Normalizer
+///
+/// Folds the characters to their closest ASCII equivalent, like :obj:`è` to :obj:`e` or
+/// :obj:`ß` to :obj:`ss`, and removes the combining marks
+///
+/// Args:
+///     fallback (:obj:`str`, `optional`, defaults to :obj:`"keep"`):
+///         What to do with the characters that can't be folded: :obj:`"keep"`, :obj:`"drop"` or
+///         :obj:`"replace"` them with the :obj:`replacement`
+///
+///     replacement (:obj:`str`, `optional`, defaults to :obj:`"?"`):
+///         The replacement of the characters that can't be folded, with the :obj:`"replace"`
+///         fallback
+#[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name = "AsciiFold")]
+pub struct PyAsciiFold {}
+#[pymethods]
+impl PyAsciiFold {
+    #[getter]
+    fn get_fallback(self_: PyRef<Self>) -> String {
+        match getter!(self_, AsciiFold, fallback) {
+            AsciiFoldFallback::Keep => "keep",
+            AsciiFoldFallback::Drop => "drop",
+            AsciiFoldFallback::Replace(_) => "replace",
+        }
+        .into()
+    }
+
+    #[setter]
+    fn set_fallback(self_: PyRef<Self>, fallback: String) -> PyResult<()> {
+        // The current replacement is kept when there is one
+        let replacement = match getter!(self_, AsciiFold, fallback) {
+            AsciiFoldFallback::Replace(replacement) => replacement,
+            _ => String::from("?"),
+        };
+        let fallback = ascii_fold_fallback(fallback, replacement)?;
+        setter!(self_, AsciiFold, fallback, fallback);
+        Ok(())
+    }
+
+    #[getter]
+    fn get_replacement(self_: PyRef<Self>) -> Option<String> {
+        match getter!(self_, AsciiFold, fallback) {
+            AsciiFoldFallback::Replace(replacement) => Some(replacement),
+            _ => None,
+        }
+    }
+
+    #[setter]
+    fn set_replacement(self_: PyRef<Self>, replacement: String) -> PyResult<()> {
+        match getter!(self_, AsciiFold, fallback) {
+            AsciiFoldFallback::Replace(_) => {
+                setter!(
+                    self_,
+                    AsciiFold,
+                    fallback,
+                    AsciiFoldFallback::Replace(replacement)
+                );
+                Ok(())
+            }
+            _ => Err(exceptions::PyValueError::new_err(
+                "The replacement is only used with the \"replace\" fallback",
+            )),
+        }
+    }
+
+    #[new]
+    #[pyo3(
+        signature = (fallback = String::from("keep"), replacement = String::from("?")),
+        text_signature = "(self, fallback=\"keep\", replacement=\"?\")"
+    )]
+    fn new(fallback: String, replacement: String) -> PyResult<(Self, PyNormalizer)> {
+        let fallback = ascii_fold_fallback(fallback, replacement)?;
+        Ok((PyAsciiFold {}, AsciiFold::new(fallback).into()))
+    }
+}
+
+/// ConfusableSkeleton Normalizer
+///
+/// Replaces the text by its Unicode TR39 confusable skeleton, so that lookalike texts, like
+/// :obj:`"pаypаl"` written with a Cyrillic :obj:`а`, become the same. The invisible default
+/// ignorable characters are removed.
+///
+/// All the confusables of Unicode 15.0.0 are covered, including those whose prototype is not
+/// Latin, like the Cyrillic :obj:`П` replaced by the Greek :obj:`Π`. Like in the TR39, the
+/// compatibility forms like the fullwidth :obj:`ｍ` are kept, a NFKC normalizer can be added first.
+///
+/// Args:
+///     keep_joiners (:obj:`bool`, `optional`, defaults to :obj:`False`):
+///         Whether to keep the zero width joiner and non-joiner, needed by some scripts
+#[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name = "ConfusableSkeleton")]
+pub struct PyConfusableSkeleton {}
+#[pymethods]
+impl PyConfusableSkeleton {
+    #[getter]
+    fn get_keep_joiners(self_: PyRef<Self>) -> bool {
+        getter!(self_, ConfusableSkeleton, keep_joiners)
+    }
+
+    #[setter]
+    fn set_keep_joiners(self_: PyRef<Self>, keep_joiners: bool) {
+        setter!(self_, ConfusableSkeleton, keep_joiners, keep_joiners)
+    }
+
+    #[new]
+    #[pyo3(signature = (keep_joiners = false), text_signature = "(self, keep_joiners=False)")]
+    fn new(keep_joiners: bool) -> (Self, PyNormalizer) {
+        (
+            PyConfusableSkeleton {},
+            ConfusableSkeleton::new(keep_joiners).into(),
+        )
+    }
+}
+
 /// Strip normalizer
 #[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name = "Strip")]
 pub struct PyStrip {}
@@ -644,6 +882,10 @@ pub fn normalizers(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyNFKC>()?;
     m.add_class::<PySequence>()?;
     m.add_class::<PyLowercase>()?;
+    m.add_class::<PyLocaleLowercase>()?;
+    m.add_class::<PyCaseFold>()?;
+    m.add_class::<PyAsciiFold>()?;
+    m.add_class::<PyConfusableSkeleton>()?;
     m.add_class::<PyStrip>()?;
     m.add_class::<PyStripAccents>()?;
     m.add_class::<PyPrepend>()?;
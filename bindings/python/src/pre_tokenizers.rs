@@ -10,6 +10,7 @@ use tk::normalizer::SplitDelimiterBehavior;
 use tk::pre_tokenizers::bert::BertPreTokenizer;
 use tk::pre_tokenizers::byte_level::ByteLevel;
 use tk::pre_tokenizers::delimiter::CharDelimiterSplit;
+use tk::pre_tokenizers::dictionary::{DictionarySegmenter, SegmentationMode};
 use tk::pre_tokenizers::digits::Digits;
 use tk::pre_tokenizers::metaspace::{Metaspace, PrependScheme};
 use tk::pre_tokenizers::punctuation::Punctuation;
@@ -89,6 +90,9 @@ impl PyPreTokenizer {
                         PreTokenizerWrapper::UnicodeScripts(_) => {
                             Py::new(py, (PyUnicodeScripts {}, base))?.into_py(py)
                         }
+                        PreTokenizerWrapper::DictionarySegmenter(_) => {
+                            Py::new(py, (PyDictionarySegmenter {}, base))?.into_py(py)
+                        }
                     },
                 }
             }
@@ -587,6 +591,89 @@ impl PyUnicodeScripts {
     }
 }
 
+fn segmentation_mode_from_string(mode: &str) -> PyResult<SegmentationMode> {
+    match mode {
+        "maximal_matching" => Ok(SegmentationMode::MaximalMatching),
+        "unigram_cost" => Ok(SegmentationMode::UnigramCost),
+        _ => Err(exceptions::PyValueError::new_err(format!(
+            "{} is an unknown variant, should be one of ['maximal_matching', 'unigram_cost']",
+            mode
+        ))),
+    }
+}
+
+/// This pre-tokenizer splits the text of the scripts that don't use spaces between words
+/// (Thai, Lao, Khmer, Myanmar, Chinese and Japanese) into the words of a dictionary. The rest
+/// of the text is left as it is.
+///
+/// Args:
+///     words (:obj:`List[Tuple[str, Optional[int]]]`):
+///         The words of the dictionary, each with an optional frequency
+///
+///     mode (:obj:`str`, `optional`, defaults to :obj:`"maximal_matching"`):
+///         How to choose between the possible segmentations. Choices: "maximal_matching" to
+///         use as few words as possible, "unigram_cost" to use the most frequent words.
+#[pyclass(extends=PyPreTokenizer, module = "tokenizers.pre_tokenizers", name = "DictionarySegmenter")]
+pub struct PyDictionarySegmenter {}
+#[pymethods]
+impl PyDictionarySegmenter {
+    #[getter]
+    fn get_words(self_: PyRef<Self>) -> Vec<(String, Option<u64>)> {
+        getter!(self_, DictionarySegmenter, words().to_vec())
+    }
+
+    #[getter]
+    fn get_mode(self_: PyRef<Self>) -> String {
+        match getter!(self_, DictionarySegmenter, mode()) {
+            SegmentationMode::MaximalMatching => "maximal_matching",
+            SegmentationMode::UnigramCost => "unigram_cost",
+        }
+        .to_string()
+    }
+
+    #[new]
+    #[pyo3(signature = (words, mode = "maximal_matching"), text_signature = "(self, words, mode=\"maximal_matching\")")]
+    fn new(words: Vec<(String, Option<u64>)>, mode: &str) -> PyResult<(Self, PyPreTokenizer)> {
+        let mode = segmentation_mode_from_string(mode)?;
+        Ok((
+            PyDictionarySegmenter {},
+            DictionarySegmenter::new(words, mode).into(),
+        ))
+    }
+
+    /// Instantiate a DictionarySegmenter from the given file
+    ///
+    /// The file has a word per line, optionally followed by a tab and its frequency.
+    ///
+    /// Args:
+    ///     path (:obj:`str`):
+    ///         The path to the words file
+    ///
+    ///     mode (:obj:`str`, `optional`, defaults to :obj:`"maximal_matching"`):
+    ///         How to choose between the possible segmentations
+    ///
+    /// Returns:
+    ///     :class:`~tokenizers.pre_tokenizers.DictionarySegmenter`: An instance loaded from file
+    #[classmethod]
+    #[pyo3(signature = (path, mode = "maximal_matching"))]
+    #[pyo3(text_signature = "(path, mode=\"maximal_matching\")")]
+    fn from_file(
+        _cls: &Bound<'_, PyType>,
+        py: Python,
+        path: &str,
+        mode: &str,
+    ) -> PyResult<Py<Self>> {
+        let mode = segmentation_mode_from_string(mode)?;
+        let segmenter = DictionarySegmenter::from_file(path, mode).map_err(|e| {
+            exceptions::PyException::new_err(format!(
+                "Error while reading DictionarySegmenter file: {}",
+                e
+            ))
+        })?;
+        Py::new(py, (PyDictionarySegmenter {}, segmenter.into()))
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct CustomPreTokenizer {
     inner: PyObject,
@@ -738,6 +825,7 @@ pub fn pre_tokenizers(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PySequence>()?;
     m.add_class::<PyDigits>()?;
     m.add_class::<PyUnicodeScripts>()?;
+    m.add_class::<PyDictionarySegmenter>()?;
     Ok(())
 }
 
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::sync::Arc;
 
@@ -50,6 +51,7 @@ impl PyPostProcessor {
                 Py::new(py, (PyTemplateProcessing {}, base))?.into_py(py)
             }
             PostProcessorWrapper::Sequence(_) => Py::new(py, (PySequence {}, base))?.into_py(py),
+            PostProcessorWrapper::ChatTemplate(_) => Py::new(py, base)?.into_py(py),
         })
     }
 }
@@ -59,6 +61,10 @@ impl PostProcessor for PyPostProcessor {
         self.processor.added_tokens(is_pair)
     }
 
+    fn added_tokens_multi(&self, n_sequences: usize) -> tk::Result<usize> {
+        self.processor.added_tokens_multi(n_sequences)
+    }
+
     fn process_encodings(
         &self,
         encodings: Vec<Encoding>,
@@ -67,6 +73,22 @@ impl PostProcessor for PyPostProcessor {
         self.processor
             .process_encodings(encodings, add_special_tokens)
     }
+
+    fn process_chat(
+        &self,
+        messages: Vec<(String, Encoding)>,
+        add_special_tokens: bool,
+    ) -> tk::Result<tk::ChatEncoding> {
+        self.processor.process_chat(messages, add_special_tokens)
+    }
+
+    fn get_special_ids(&self) -> Vec<u32> {
+        self.processor.get_special_ids()
+    }
+
+    fn remap_ids(&mut self, ids: &HashMap<u32, u32>) {
+        Arc::make_mut(&mut self.processor).remap_ids(ids)
+    }
 }
 
 #[pymethods]
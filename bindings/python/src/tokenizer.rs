@@ -9,8 +9,8 @@ use pyo3::prelude::*;
 use pyo3::types::*;
 use tk::models::bpe::BPE;
 use tk::tokenizer::{
-    Model, PaddingDirection, PaddingParams, PaddingStrategy, PostProcessor, TokenizerImpl,
-    TruncationDirection, TruncationParams, TruncationStrategy,
+    BoundaryRegex, Model, PaddingDirection, PaddingParams, PaddingStrategy, PostProcessor,
+    TokenizerImpl, TruncationBoundary, TruncationDirection, TruncationParams, TruncationStrategy,
 };
 use tk::utils::iter::ResultShunt;
 use tokenizers as tk;
@@ -703,14 +703,21 @@ impl PyTokenizer {
     ///         sequence
     ///
     ///     strategy (:obj:`str`, `optional`, defaults to :obj:`longest_first`):
-    ///         The strategy used to truncation. Can be one of ``longest_first``, ``only_first`` or
-    ///         ``only_second``.
+    ///         The strategy used to truncation. Can be one of ``longest_first``, ``only_first``,
+    ///         ``only_second`` or ``proportional``.
     ///
     ///     direction (:obj:`str`, defaults to :obj:`right`):
     ///         Truncate direction
+    ///
+    ///     boundary (:obj:`str`, `optional`, defaults to :obj:`token`):
+    ///         Where the sequences can be cut. Can be one of ``token``, ``word`` or ``sentence``,
+    ///         or ``regex`` along with a ``boundary_pattern``
+    ///
+    ///     boundary_pattern (:obj:`str`, `optional`):
+    ///         A regex whose matches end the spans that can be cut, used instead of ``boundary``
     #[pyo3(signature = (max_length, **kwargs))]
     #[pyo3(
-        text_signature = "(self, max_length, stride=0, strategy='longest_first', direction='right')"
+        text_signature = "(self, max_length, stride=0, strategy='longest_first', direction='right', boundary='token', boundary_pattern=None)"
     )]
     fn enable_truncation(
         &mut self,
@@ -733,9 +740,10 @@ impl PyTokenizer {
                             "longest_first" => Ok(TruncationStrategy::LongestFirst),
                             "only_first" => Ok(TruncationStrategy::OnlyFirst),
                             "only_second" => Ok(TruncationStrategy::OnlySecond),
+                            "proportional" => Ok(TruncationStrategy::Proportional),
                             _ => Err(PyError(format!(
-                                "Unknown `strategy`: `{}`. Use \
-                                 one of `longest_first`, `only_first`, or `only_second`",
+                                "Unknown `strategy`: `{}`. Use one of \
+                                 `longest_first`, `only_first`, `only_second`, or `proportional`",
                                 value
                             ))
                             .into_pyerr::<exceptions::PyValueError>()),
@@ -754,6 +762,41 @@ impl PyTokenizer {
                             .into_pyerr::<exceptions::PyValueError>()),
                         }?
                     }
+                    "boundary" => {
+                        let value: &str = value.extract()?;
+                        params.boundary = match value {
+                            "token" => Ok(TruncationBoundary::Token),
+                            "word" => Ok(TruncationBoundary::Word),
+                            "sentence" => Ok(TruncationBoundary::Sentence),
+                            // Set from `boundary_pattern`, as returned by `truncation`
+                            "regex"
+                                if kwargs
+                                    .get_item("boundary_pattern")?
+                                    .map_or(false, |pattern| !pattern.is_none()) =>
+                            {
+                                Ok(params.boundary)
+                            }
+                            "regex" => Err(PyError(
+                                "The `regex` boundary needs a `boundary_pattern`".into(),
+                            )
+                            .into_pyerr::<exceptions::PyValueError>()),
+                            _ => Err(PyError(format!(
+                                "Unknown `boundary`: `{}`. Use \
+                                 one of `token`, `word`, `sentence` or `regex`.",
+                                value
+                            ))
+                            .into_pyerr::<exceptions::PyValueError>()),
+                        }?
+                    }
+                    "boundary_pattern" => {
+                        if let Some(pattern) = value.extract::<Option<String>>()? {
+                            params.boundary = TruncationBoundary::Regex(
+                                BoundaryRegex::new(pattern).map_err(|e| {
+                                    PyError(e.to_string()).into_pyerr::<exceptions::PyValueError>()
+                                })?,
+                            );
+                        }
+                    }
                     _ => println!("Ignored unknown kwarg option {}", key),
                 }
             }
@@ -789,6 +832,10 @@ impl PyTokenizer {
             dict.set_item("stride", params.stride)?;
             dict.set_item("strategy", params.strategy.as_ref())?;
             dict.set_item("direction", params.direction.as_ref())?;
+            dict.set_item("boundary", params.boundary.as_ref())?;
+            if let TruncationBoundary::Regex(pattern) = &params.boundary {
+                dict.set_item("boundary_pattern", pattern.as_str())?;
+            }
 
             Ok(Some(dict))
         })
@@ -39,6 +39,7 @@ impl PyTrainer {
             TrainerWrapper::UnigramTrainer(_) => {
                 Py::new(py, (PyUnigramTrainer {}, base))?.into_py(py)
             }
+            TrainerWrapper::ByteModelTrainer(_) => Py::new(py, base)?.into_py(py),
         })
     }
 }
@@ -3,7 +3,7 @@ import pickle
 import pytest
 
 from tokenizers import NormalizedString
-from tokenizers.normalizers import BertNormalizer, Lowercase, Normalizer, Sequence, Strip, Prepend
+from tokenizers.normalizers import AsciiFold, BertNormalizer, Lowercase, Normalizer, Sequence, Strip, Prepend
 
 
 class TestBertNormalizer:
@@ -140,6 +140,39 @@ class TestPrepend:
         assert normalizer.prepend == "-"
 
 
+class TestAsciiFold:
+    def test_instantiate(self):
+        assert isinstance(AsciiFold(), Normalizer)
+        assert isinstance(AsciiFold(), AsciiFold)
+        assert isinstance(pickle.loads(pickle.dumps(AsciiFold())), AsciiFold)
+
+    def test_fold(self):
+        normalizer = AsciiFold(fallback="replace")
+
+        output = normalizer.normalize_str("Straße 日本")
+        assert output == "Strasse ??"
+
+    def test_can_modify(self):
+        normalizer = AsciiFold()
+
+        assert normalizer.fallback == "keep"
+        assert normalizer.replacement == None
+        with pytest.raises(ValueError):
+            normalizer.replacement = "_"
+
+        # Modify these
+        normalizer.fallback = "replace"
+        assert normalizer.fallback == "replace"
+        assert normalizer.replacement == "?"
+        normalizer.replacement = "_"
+        assert normalizer.replacement == "_"
+        assert normalizer.normalize_str("日本") == "__"
+        normalizer.fallback = "drop"
+        assert normalizer.fallback == "drop"
+        with pytest.raises(ValueError):
+            normalizer.fallback = "unknown"
+
+
 class TestCustomNormalizer:
     class BadCustomNormalizer:
         def normalize(self, normalized, wrong):
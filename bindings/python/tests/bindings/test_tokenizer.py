@@ -325,6 +325,17 @@ class TestTokenizer:
         output = tokenizer.encode("my name is john", "pair")
         assert output.tokens == ["john", "pair"]
 
+        # The params of a regex boundary can be given back too
+        tokenizer.enable_truncation(2, boundary_pattern=" ")
+        trunc = tokenizer.truncation
+        assert trunc["boundary"] == "regex"
+        assert trunc["boundary_pattern"] == " "
+        tokenizer.enable_truncation(**trunc)
+        assert tokenizer.truncation == trunc
+
+        with pytest.raises(ValueError, match="boundary_pattern"):
+            tokenizer.enable_truncation(2, boundary="regex")
+
     def test_padding(self):
         tokenizer = Tokenizer(BPE())
         tokenizer.add_tokens(["my", "name", "is", "john", "pair"])
@@ -0,0 +1,257 @@
+from argparse import ArgumentParser
+import unicodedata
+
+UNICODE_VERSION = "14.0.0"
+
+# The blocks of combining diacritical marks, whose nonspacing marks are removed
+COMBINING_MARKS_BLOCKS = [
+    (0x0300, 0x036F),
+    (0x1AB0, 0x1AFF),
+    (0x1DC0, 0x1DFF),
+    (0x20D0, 0x20FF),
+    (0xFE20, 0xFE2F),
+]
+
+# The letters and punctuation without an ASCII decomposition, also used to fold the decompositions
+# of other chars. They take precedence over the decompositions.
+MANUAL_FOLDING = {
+    0x00A1: "!",  # INVERTED EXCLAMATION MARK
+    0x00A6: "|",  # BROKEN BAR
+    0x00A9: "(C)",  # COPYRIGHT SIGN
+    0x00AB: '"',  # LEFT-POINTING DOUBLE ANGLE QUOTATION MARK
+    0x00AC: "-",  # NOT SIGN
+    0x00AE: "(R)",  # REGISTERED SIGN
+    0x00B0: "o",  # DEGREE SIGN
+    0x00B1: "+/-",  # PLUS-MINUS SIGN
+    0x00B7: ".",  # MIDDLE DOT
+    0x00BB: '"',  # RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK
+    0x00BF: "?",  # INVERTED QUESTION MARK
+    0x00C6: "AE",  # LATIN CAPITAL LETTER AE
+    0x00D0: "D",  # LATIN CAPITAL LETTER ETH
+    0x00D7: "x",  # MULTIPLICATION SIGN
+    0x00D8: "O",  # LATIN CAPITAL LETTER O WITH STROKE
+    0x00DE: "TH",  # LATIN CAPITAL LETTER THORN
+    0x00DF: "ss",  # LATIN SMALL LETTER SHARP S
+    0x00E6: "ae",  # LATIN SMALL LETTER AE
+    0x00F0: "d",  # LATIN SMALL LETTER ETH
+    0x00F7: "/",  # DIVISION SIGN
+    0x00F8: "o",  # LATIN SMALL LETTER O WITH STROKE
+    0x00FE: "th",  # LATIN SMALL LETTER THORN
+    0x0110: "D",  # LATIN CAPITAL LETTER D WITH STROKE
+    0x0111: "d",  # LATIN SMALL LETTER D WITH STROKE
+    0x0126: "H",  # LATIN CAPITAL LETTER H WITH STROKE
+    0x0127: "h",  # LATIN SMALL LETTER H WITH STROKE
+    0x0131: "i",  # LATIN SMALL LETTER DOTLESS I
+    0x0138: "q",  # LATIN SMALL LETTER KRA
+    0x0141: "L",  # LATIN CAPITAL LETTER L WITH STROKE
+    0x0142: "l",  # LATIN SMALL LETTER L WITH STROKE
+    0x0152: "OE",  # LATIN CAPITAL LIGATURE OE
+    0x0153: "oe",  # LATIN SMALL LIGATURE OE
+    0x0166: "T",  # LATIN CAPITAL LETTER T WITH STROKE
+    0x0167: "t",  # LATIN SMALL LETTER T WITH STROKE
+    0x0180: "b",  # LATIN SMALL LETTER B WITH STROKE
+    0x0181: "B",  # LATIN CAPITAL LETTER B WITH HOOK
+    0x0182: "B",  # LATIN CAPITAL LETTER B WITH TOPBAR
+    0x0183: "b",  # LATIN SMALL LETTER B WITH TOPBAR
+    0x0186: "O",  # LATIN CAPITAL LETTER OPEN O
+    0x0187: "C",  # LATIN CAPITAL LETTER C WITH HOOK
+    0x0188: "c",  # LATIN SMALL LETTER C WITH HOOK
+    0x0189: "D",  # LATIN CAPITAL LETTER AFRICAN D
+    0x018A: "D",  # LATIN CAPITAL LETTER D WITH HOOK
+    0x018B: "D",  # LATIN CAPITAL LETTER D WITH TOPBAR
+    0x018C: "d",  # LATIN SMALL LETTER D WITH TOPBAR
+    0x018E: "E",  # LATIN CAPITAL LETTER REVERSED E
+    0x0190: "E",  # LATIN CAPITAL LETTER OPEN E
+    0x0191: "F",  # LATIN CAPITAL LETTER F WITH HOOK
+    0x0192: "f",  # LATIN SMALL LETTER F WITH HOOK
+    0x0193: "G",  # LATIN CAPITAL LETTER G WITH HOOK
+    0x0197: "I",  # LATIN CAPITAL LETTER I WITH STROKE
+    0x0198: "K",  # LATIN CAPITAL LETTER K WITH HOOK
+    0x0199: "k",  # LATIN SMALL LETTER K WITH HOOK
+    0x019A: "l",  # LATIN SMALL LETTER L WITH BAR
+    0x019D: "N",  # LATIN CAPITAL LETTER N WITH LEFT HOOK
+    0x019E: "n",  # LATIN SMALL LETTER N WITH LONG RIGHT LEG
+    0x019F: "O",  # LATIN CAPITAL LETTER O WITH MIDDLE TILDE
+    0x01A4: "P",  # LATIN CAPITAL LETTER P WITH HOOK
+    0x01A5: "p",  # LATIN SMALL LETTER P WITH HOOK
+    0x01AB: "t",  # LATIN SMALL LETTER T WITH PALATAL HOOK
+    0x01AC: "T",  # LATIN CAPITAL LETTER T WITH HOOK
+    0x01AD: "t",  # LATIN SMALL LETTER T WITH HOOK
+    0x01AE: "T",  # LATIN CAPITAL LETTER T WITH RETROFLEX HOOK
+    0x01B2: "V",  # LATIN CAPITAL LETTER V WITH HOOK
+    0x01B3: "Y",  # LATIN CAPITAL LETTER Y WITH HOOK
+    0x01B4: "y",  # LATIN SMALL LETTER Y WITH HOOK
+    0x01B5: "Z",  # LATIN CAPITAL LETTER Z WITH STROKE
+    0x01B6: "z",  # LATIN SMALL LETTER Z WITH STROKE
+    0x0224: "Z",  # LATIN CAPITAL LETTER Z WITH HOOK
+    0x0225: "z",  # LATIN SMALL LETTER Z WITH HOOK
+    0x0234: "l",  # LATIN SMALL LETTER L WITH CURL
+    0x0235: "n",  # LATIN SMALL LETTER N WITH CURL
+    0x0236: "t",  # LATIN SMALL LETTER T WITH CURL
+    0x0237: "j",  # LATIN SMALL LETTER DOTLESS J
+    0x0238: "db",  # LATIN SMALL LETTER DB DIGRAPH
+    0x0239: "qp",  # LATIN SMALL LETTER QP DIGRAPH
+    0x023A: "A",  # LATIN CAPITAL LETTER A WITH STROKE
+    0x023B: "C",  # LATIN CAPITAL LETTER C WITH STROKE
+    0x023C: "c",  # LATIN SMALL LETTER C WITH STROKE
+    0x023D: "L",  # LATIN CAPITAL LETTER L WITH BAR
+    0x023E: "T",  # LATIN CAPITAL LETTER T WITH DIAGONAL STROKE
+    0x023F: "s",  # LATIN SMALL LETTER S WITH SWASH TAIL
+    0x0240: "z",  # LATIN SMALL LETTER Z WITH SWASH TAIL
+    0x0243: "B",  # LATIN CAPITAL LETTER B WITH STROKE
+    0x0244: "U",  # LATIN CAPITAL LETTER U BAR
+    0x0246: "E",  # LATIN CAPITAL LETTER E WITH STROKE
+    0x0247: "e",  # LATIN SMALL LETTER E WITH STROKE
+    0x0248: "J",  # LATIN CAPITAL LETTER J WITH STROKE
+    0x0249: "j",  # LATIN SMALL LETTER J WITH STROKE
+    0x024A: "Q",  # LATIN CAPITAL LETTER SMALL Q WITH HOOK TAIL
+    0x024B: "q",  # LATIN SMALL LETTER Q WITH HOOK TAIL
+    0x024C: "R",  # LATIN CAPITAL LETTER R WITH STROKE
+    0x024D: "r",  # LATIN SMALL LETTER R WITH STROKE
+    0x024E: "Y",  # LATIN CAPITAL LETTER Y WITH STROKE
+    0x024F: "y",  # LATIN SMALL LETTER Y WITH STROKE
+    0x0253: "b",  # LATIN SMALL LETTER B WITH HOOK
+    0x0255: "c",  # LATIN SMALL LETTER C WITH CURL
+    0x0256: "d",  # LATIN SMALL LETTER D WITH TAIL
+    0x0257: "d",  # LATIN SMALL LETTER D WITH HOOK
+    0x025B: "e",  # LATIN SMALL LETTER OPEN E
+    0x025F: "j",  # LATIN SMALL LETTER DOTLESS J WITH STROKE
+    0x0260: "g",  # LATIN SMALL LETTER G WITH HOOK
+    0x0261: "g",  # LATIN SMALL LETTER SCRIPT G
+    0x0262: "G",  # LATIN LETTER SMALL CAPITAL G
+    0x0266: "h",  # LATIN SMALL LETTER H WITH HOOK
+    0x0267: "h",  # LATIN SMALL LETTER HENG WITH HOOK
+    0x0268: "i",  # LATIN SMALL LETTER I WITH STROKE
+    0x026A: "I",  # LATIN LETTER SMALL CAPITAL I
+    0x026B: "l",  # LATIN SMALL LETTER L WITH MIDDLE TILDE
+    0x026C: "l",  # LATIN SMALL LETTER L WITH BELT
+    0x026D: "l",  # LATIN SMALL LETTER L WITH RETROFLEX HOOK
+    0x0271: "m",  # LATIN SMALL LETTER M WITH HOOK
+    0x0272: "n",  # LATIN SMALL LETTER N WITH LEFT HOOK
+    0x0273: "n",  # LATIN SMALL LETTER N WITH RETROFLEX HOOK
+    0x0274: "N",  # LATIN LETTER SMALL CAPITAL N
+    0x0276: "OE",  # LATIN LETTER SMALL CAPITAL OE
+    0x027C: "r",  # LATIN SMALL LETTER R WITH LONG LEG
+    0x027D: "r",  # LATIN SMALL LETTER R WITH TAIL
+    0x027E: "r",  # LATIN SMALL LETTER R WITH FISHHOOK
+    0x0280: "R",  # LATIN LETTER SMALL CAPITAL R
+    0x0282: "s",  # LATIN SMALL LETTER S WITH HOOK
+    0x0288: "t",  # LATIN SMALL LETTER T WITH RETROFLEX HOOK
+    0x0289: "u",  # LATIN SMALL LETTER U BAR
+    0x028B: "v",  # LATIN SMALL LETTER V WITH HOOK
+    0x028F: "Y",  # LATIN LETTER SMALL CAPITAL Y
+    0x0290: "z",  # LATIN SMALL LETTER Z WITH RETROFLEX HOOK
+    0x0291: "z",  # LATIN SMALL LETTER Z WITH CURL
+    0x0299: "B",  # LATIN LETTER SMALL CAPITAL B
+    0x029B: "G",  # LATIN LETTER SMALL CAPITAL G WITH HOOK
+    0x029C: "H",  # LATIN LETTER SMALL CAPITAL H
+    0x029D: "j",  # LATIN SMALL LETTER J WITH CROSSED-TAIL
+    0x029F: "L",  # LATIN LETTER SMALL CAPITAL L
+    0x02A0: "q",  # LATIN SMALL LETTER Q WITH HOOK
+    0x02B9: "'",  # MODIFIER LETTER PRIME
+    0x02BB: "'",  # MODIFIER LETTER TURNED COMMA
+    0x02BC: "'",  # MODIFIER LETTER APOSTROPHE
+    0x02BD: "'",  # MODIFIER LETTER REVERSED COMMA
+    0x02C8: "'",  # MODIFIER LETTER VERTICAL LINE
+    0x1E9E: "SS",  # LATIN CAPITAL LETTER SHARP S
+    0x2010: "-",  # HYPHEN
+    0x2012: "-",  # FIGURE DASH
+    0x2013: "-",  # EN DASH
+    0x2014: "-",  # EM DASH
+    0x2015: "-",  # HORIZONTAL BAR
+    0x2018: "'",  # LEFT SINGLE QUOTATION MARK
+    0x2019: "'",  # RIGHT SINGLE QUOTATION MARK
+    0x201A: "'",  # SINGLE LOW-9 QUOTATION MARK
+    0x201B: "'",  # SINGLE HIGH-REVERSED-9 QUOTATION MARK
+    0x201C: '"',  # LEFT DOUBLE QUOTATION MARK
+    0x201D: '"',  # RIGHT DOUBLE QUOTATION MARK
+    0x201E: '"',  # DOUBLE LOW-9 QUOTATION MARK
+    0x201F: '"',  # DOUBLE HIGH-REVERSED-9 QUOTATION MARK
+    0x2022: "*",  # BULLET
+    0x2027: ".",  # HYPHENATION POINT
+    0x2030: "%.",  # PER MILLE SIGN
+    0x2032: "'",  # PRIME
+    0x2033: '"',  # DOUBLE PRIME
+    0x2035: "'",  # REVERSED PRIME
+    0x2036: '"',  # REVERSED DOUBLE PRIME
+    0x2039: "'",  # SINGLE LEFT-POINTING ANGLE QUOTATION MARK
+    0x203A: "'",  # SINGLE RIGHT-POINTING ANGLE QUOTATION MARK
+    0x2043: "-",  # HYPHEN BULLET
+    0x2044: "/",  # FRACTION SLASH
+    0x2212: "-",  # MINUS SIGN
+    0x2215: "/",  # DIVISION SLASH
+}
+
+
+def is_combining_mark(c):
+    return unicodedata.category(c) == "Mn" and any(start <= ord(c) <= end for start, end in COMBINING_MARKS_BLOCKS)
+
+
+def fold(c):
+    """The ASCII folding of `c`, or None if it doesn't have one"""
+    if ord(c) in MANUAL_FOLDING:
+        return MANUAL_FOLDING[ord(c)]
+    if is_combining_mark(c):
+        return ""
+    decomposition = unicodedata.normalize("NFKD", c)
+    folded = "".join(MANUAL_FOLDING.get(ord(d), d) for d in decomposition if not is_combining_mark(d))
+    if folded and all(ord(f) < 0x80 for f in folded):
+        return folded
+    return None
+
+
+def escape_char(c):
+    return f"\\u{{{ord(c):X}}}"
+
+
+def escape_str(s):
+    escaped = []
+    for c in s:
+        if not " " <= c <= "~":
+            escaped.append(escape_char(c))
+        elif c in '"\\':
+            escaped.append("\\" + c)
+        else:
+            escaped.append(c)
+    return "".join(escaped)
+
+
+def render():
+    lines = [
+        "// Generated by scripts/generate_ascii_fold_table.py from the NFKD decompositions of Unicode",
+        f"// {UNICODE_VERSION}, without the combining marks, and a list of letters and punctuation without",
+        "// an ASCII decomposition. Do not edit.",
+        "",
+        "/// The ASCII folding of the chars that have one, sorted by char. The combining marks fold to",
+        "/// an empty string.",
+        "pub(super) static ASCII_FOLDING: &[(char, &str)] = &[",
+    ]
+    for code in range(0x80, 0x110000):
+        folded = fold(chr(code))
+        if folded is not None:
+            lines.append(f"    ('{escape_char(chr(code))}', \"{escape_str(folded)}\"),")
+    lines.append("];")
+    return "\n".join(lines) + "\n"
+
+
+def main():
+    parser = ArgumentParser("ASCII folding table generator")
+    parser.add_argument(
+        "--output",
+        "-o",
+        type=str,
+        default="src/normalizers/ascii_fold_table.rs",
+        help="Where to write the generated table",
+    )
+    args = parser.parse_args()
+
+    if unicodedata.unidata_version != UNICODE_VERSION:
+        raise RuntimeError(
+            f"This Python uses Unicode {unicodedata.unidata_version}, the table needs Unicode {UNICODE_VERSION}"
+        )
+    with open(args.output, "w", encoding="utf-8") as f:
+        f.write(render())
+
+
+if __name__ == "__main__":
+    main()
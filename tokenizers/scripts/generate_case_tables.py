@@ -0,0 +1,82 @@
+from argparse import ArgumentParser
+from urllib.request import urlopen
+
+UNICODE_VERSION = "14.0.0"
+CASE_FOLDING_URL = f"https://www.unicode.org/Public/{UNICODE_VERSION}/ucd/CaseFolding.txt"
+
+
+def read_case_folding(path):
+    """Reads the mappings of CaseFolding.txt, as (char, status, folded) tuples"""
+    if path is None:
+        with urlopen(CASE_FOLDING_URL) as response:
+            lines = response.read().decode("utf-8").splitlines()
+    else:
+        with open(path, encoding="utf-8") as f:
+            lines = f.read().splitlines()
+
+    mappings = []
+    for line in lines:
+        line = line.split("#", 1)[0].strip()
+        if not line:
+            continue
+        code, status, mapping = [field.strip() for field in line.split(";")[:3]]
+        folded = "".join(chr(int(c, 16)) for c in mapping.split())
+        mappings.append((chr(int(code, 16)), status, folded))
+    return mappings
+
+
+def escape_char(c):
+    return f"\\u{{{ord(c):X}}}"
+
+
+def escape_str(s):
+    return "".join(c if " " <= c <= "~" and c not in '"\\' else escape_char(c) for c in s)
+
+
+def render(mappings):
+    full = sorted((c, folded) for c, status, folded in mappings if status in ("C", "F"))
+    simple = sorted((c, folded) for c, status, folded in mappings if status in ("C", "S"))
+
+    lines = [
+        "// Generated by scripts/generate_case_tables.py from CaseFolding.txt of Unicode "
+        f"{UNICODE_VERSION}, do not edit.",
+        "",
+        "/// The full case folding (statuses C and F) of the chars that change, sorted by char",
+        "pub(super) static FULL_CASE_FOLDING: &[(char, &str)] = &[",
+    ]
+    lines += [f"    ('{escape_char(c)}', \"{escape_str(folded)}\")," for c, folded in full]
+    lines += [
+        "];",
+        "",
+        "/// The simple case folding (statuses C and S) of the chars that change, sorted by char",
+        "pub(super) static SIMPLE_CASE_FOLDING: &[(char, char)] = &[",
+    ]
+    lines += [f"    ('{escape_char(c)}', '{escape_char(folded)}')," for c, folded in simple]
+    lines += ["];"]
+    return "\n".join(lines) + "\n"
+
+
+def main():
+    parser = ArgumentParser("Case folding tables generator")
+    parser.add_argument(
+        "--input",
+        "-i",
+        type=str,
+        default=None,
+        help=f"Path to CaseFolding.txt, downloaded from unicode.org (version {UNICODE_VERSION}) if missing",
+    )
+    parser.add_argument(
+        "--output",
+        "-o",
+        type=str,
+        default="src/normalizers/case_tables.rs",
+        help="Where to write the generated tables",
+    )
+    args = parser.parse_args()
+
+    with open(args.output, "w", encoding="utf-8") as f:
+        f.write(render(read_case_folding(args.input)))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,155 @@
+from argparse import ArgumentParser
+from urllib.request import urlopen
+import ctypes
+import unicodedata
+
+UNICODE_VERSION = "15.0.0"
+CONFUSABLES_URL = f"https://www.unicode.org/Public/security/{UNICODE_VERSION}/confusables.txt"
+
+
+def read_confusables(path):
+    """Reads the mappings of confusables.txt, as (char, prototype) tuples"""
+    if path is None:
+        with urlopen(CONFUSABLES_URL) as response:
+            lines = response.read().decode("utf-8-sig").splitlines()
+    else:
+        with open(path, encoding="utf-8-sig") as f:
+            lines = f.read().splitlines()
+
+    mappings = []
+    for line in lines:
+        line = line.split("#", 1)[0].strip()
+        if not line:
+            continue
+        source, target = [field.strip() for field in line.split(";")[:2]]
+        prototype = "".join(chr(int(c, 16)) for c in target.split())
+        mappings.append((chr(int(source, 16)), prototype))
+    return mappings
+
+
+def read_icu_confusables(icu_version):
+    """Reads the mappings compiled into the spoof checker of the ICU libraries, as (char,
+    prototype) tuples. ICU builds them from confusables.txt, so that they can be used when
+    unicode.org cannot be reached."""
+    icuuc = ctypes.CDLL(f"libicuuc.so.{icu_version}")
+    icui18n = ctypes.CDLL(f"libicui18n.so.{icu_version}")
+
+    version = (ctypes.c_uint8 * 4)()
+    getattr(icuuc, f"u_getUnicodeVersion_{icu_version}")(version)
+    if ".".join(str(v) for v in version[:3]) != UNICODE_VERSION:
+        raise RuntimeError(f"ICU {icu_version} uses Unicode {list(version)}, not {UNICODE_VERSION}")
+
+    status = ctypes.c_int(0)
+    open_checker = getattr(icui18n, f"uspoof_open_{icu_version}")
+    open_checker.restype = ctypes.c_void_p
+    open_checker.argtypes = [ctypes.POINTER(ctypes.c_int)]
+    checker = open_checker(ctypes.byref(status))
+    get_skeleton = getattr(icui18n, f"uspoof_getSkeleton_{icu_version}")
+    get_skeleton.restype = ctypes.c_int32
+    get_skeleton.argtypes = [
+        ctypes.c_void_p,
+        ctypes.c_uint32,
+        ctypes.c_char_p,
+        ctypes.c_int32,
+        ctypes.c_char_p,
+        ctypes.c_int32,
+        ctypes.POINTER(ctypes.c_int),
+    ]
+
+    mappings = []
+    for code in range(0x110000):
+        if 0xD800 <= code <= 0xDFFF:
+            continue
+        c = chr(code)
+        source = c.encode("utf-16-le")
+        buffer = ctypes.create_string_buffer(256)
+        length = get_skeleton(checker, 0, source, len(source) // 2, buffer, 128, ctypes.byref(status))
+        if status.value > 0:
+            raise RuntimeError(f"ICU failed to get the skeleton of {c!r}: error {status.value}")
+        # The skeleton of a single char is the NFD of its prototype, or its own NFD
+        prototype = buffer.raw[: 2 * length].decode("utf-16-le")
+        if prototype != unicodedata.normalize("NFD", c):
+            mappings.append((c, prototype))
+    return mappings
+
+
+def skeleton_table(mappings):
+    """The prototype of each char that can be reached after the NFD, sorted by char. The
+    prototypes are resolved until none of their chars gets mapped again."""
+    if unicodedata.unidata_version != UNICODE_VERSION:
+        raise RuntimeError(
+            f"The NFD needs Unicode {UNICODE_VERSION}, Python has {unicodedata.unidata_version}"
+        )
+    prototypes = {c: prototype for c, prototype in mappings}
+
+    def resolve(prototype, seen):
+        chars = unicodedata.normalize("NFD", prototype)
+        if not any(c in prototypes for c in chars):
+            return chars
+        if chars in seen:
+            raise RuntimeError(f"The prototype {chars!r} maps to itself")
+        return resolve("".join(prototypes.get(c, c) for c in chars), seen | {chars})
+
+    return sorted(
+        (c, resolve(prototype, set()))
+        for c, prototype in prototypes.items()
+        # The chars changed by the NFD are never looked up
+        if unicodedata.normalize("NFD", c) == c
+    )
+
+
+def escape_char(c):
+    return f"\\u{{{ord(c):X}}}"
+
+
+def escape_str(s):
+    return "".join(c if " " <= c <= "~" and c not in '"\\' else escape_char(c) for c in s)
+
+
+def render(table, source):
+    lines = [
+        f"// Generated by scripts/generate_confusables_table.py from {source} (Unicode "
+        f"{UNICODE_VERSION}), do not edit.",
+        "",
+        "/// The prototype of each confusable char, sorted by char",
+        "pub(super) static CONFUSABLES: &[(char, &str)] = &[",
+    ]
+    lines += [f"    ('{escape_char(c)}', \"{escape_str(prototype)}\")," for c, prototype in table]
+    lines += ["];"]
+    return "\n".join(lines) + "\n"
+
+
+def main():
+    parser = ArgumentParser("Confusables table generator")
+    parser.add_argument(
+        "--input",
+        "-i",
+        type=str,
+        default=None,
+        help=f"Path to confusables.txt, downloaded from unicode.org (version {UNICODE_VERSION}) if missing",
+    )
+    parser.add_argument(
+        "--icu",
+        type=str,
+        default=None,
+        help="Major version of the ICU libraries whose spoof checker data to read instead of confusables.txt",
+    )
+    parser.add_argument(
+        "--output",
+        "-o",
+        type=str,
+        default="src/normalizers/confusables_table.rs",
+        help="Where to write the generated table",
+    )
+    args = parser.parse_args()
+
+    if args.icu is None:
+        mappings, source = read_confusables(args.input), "confusables.txt"
+    else:
+        mappings, source = read_icu_confusables(args.icu), f"the confusables of ICU {args.icu}"
+    with open(args.output, "w", encoding="utf-8") as f:
+        f.write(render(skeleton_table(mappings), source))
+
+
+if __name__ == "__main__":
+    main()
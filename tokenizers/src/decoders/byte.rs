@@ -0,0 +1,135 @@
+use crate::models::byte::token_to_byte;
+use crate::tokenizer::{Decoder, Result};
+use monostate::MustBe;
+
+use serde::{Deserialize, Serialize};
+
+/// How to handle the byte sequences that are not valid UTF-8
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvalidUtf8 {
+    /// Replace each invalid sequence with `�`
+    #[default]
+    Replace,
+    /// Skip the invalid sequences
+    Ignore,
+    /// Return an error
+    Strict,
+}
+
+#[derive(Deserialize, Clone, Debug, Serialize, Default)]
+/// Decoder matching the `ByteModel`. It converts the tokens representing bytes back to
+/// these bytes, and assembles them into text. Any other token (like the special tokens)
+/// is kept as is. The added tokens never reach it when decoding ids with a `Tokenizer`, so
+/// that an added token like `é` is not mistaken for a byte.
+#[non_exhaustive]
+pub struct ByteDecoder {
+    #[serde(rename = "type")]
+    type_: MustBe!("ByteDecoder"),
+    #[serde(default)]
+    pub invalid_utf8: InvalidUtf8,
+}
+
+impl ByteDecoder {
+    pub fn new(invalid_utf8: InvalidUtf8) -> Self {
+        Self {
+            type_: MustBe!("ByteDecoder"),
+            invalid_utf8,
+        }
+    }
+
+    fn bytes_to_string(&self, mut bytes: &[u8]) -> Result<String> {
+        match self.invalid_utf8 {
+            InvalidUtf8::Replace => Ok(String::from_utf8_lossy(bytes).into_owned()),
+            InvalidUtf8::Strict => Ok(std::str::from_utf8(bytes)?.to_owned()),
+            InvalidUtf8::Ignore => {
+                let mut string = String::with_capacity(bytes.len());
+                loop {
+                    match std::str::from_utf8(bytes) {
+                        Ok(valid) => {
+                            string.push_str(valid);
+                            return Ok(string);
+                        }
+                        Err(e) => {
+                            let (valid, invalid) = bytes.split_at(e.valid_up_to());
+                            string.push_str(std::str::from_utf8(valid)?);
+                            bytes = &invalid[e.error_len().unwrap_or(invalid.len())..];
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Decoder for ByteDecoder {
+    fn decode_chain(&self, tokens: Vec<String>) -> Result<Vec<String>> {
+        let mut new_tokens: Vec<String> = vec![];
+        let mut bytes: Vec<u8> = vec![];
+
+        for token in tokens {
+            if let Some(byte) = token_to_byte(&token) {
+                bytes.push(byte);
+            } else {
+                if !bytes.is_empty() {
+                    new_tokens.push(self.bytes_to_string(&bytes)?);
+                    bytes.clear();
+                }
+                new_tokens.push(token);
+            }
+        }
+        if !bytes.is_empty() {
+            new_tokens.push(self.bytes_to_string(&bytes)?);
+        }
+
+        Ok(new_tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(bytes: &[u8]) -> Vec<String> {
+        bytes.iter().map(|b| char::from(*b).to_string()).collect()
+    }
+
+    #[test]
+    fn decode() {
+        let decoder = ByteDecoder::default();
+        let mut input = tokens("Hey ".as_bytes());
+        input.push("</s>".into());
+        input.extend(tokens("€!".as_bytes()));
+        assert_eq!(
+            decoder.decode_chain(input).unwrap(),
+            vec!["Hey ", "</s>", "€!"]
+        );
+    }
+
+    #[test]
+    fn decode_invalid_utf8() {
+        // A truncated `€`, and a lone continuation byte
+        let input = tokens(b"a\xE2\x82b\x80c");
+
+        let decoder = ByteDecoder::new(InvalidUtf8::Replace);
+        assert_eq!(decoder.decode_chain(input.clone()).unwrap(), vec!["a�b�c"]);
+
+        let decoder = ByteDecoder::new(InvalidUtf8::Ignore);
+        assert_eq!(decoder.decode_chain(input.clone()).unwrap(), vec!["abc"]);
+
+        let decoder = ByteDecoder::new(InvalidUtf8::Strict);
+        assert!(decoder.decode_chain(input).is_err());
+    }
+
+    #[test]
+    fn serialization() {
+        let decoder = ByteDecoder::new(InvalidUtf8::Ignore);
+        let serialized = serde_json::to_string(&decoder).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"type":"ByteDecoder","invalid_utf8":"ignore"}"#
+        );
+        let decoder: ByteDecoder = serde_json::from_str(r#"{"type":"ByteDecoder"}"#).unwrap();
+        assert_eq!(decoder.invalid_utf8, InvalidUtf8::Replace);
+    }
+}
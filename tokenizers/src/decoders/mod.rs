@@ -1,4 +1,5 @@
 pub mod bpe;
+pub mod byte;
 pub mod byte_fallback;
 pub mod ctc;
 pub mod fuse;
@@ -13,6 +14,7 @@ pub use super::pre_tokenizers::metaspace;
 use serde::{Deserialize, Serialize};
 
 use crate::decoders::bpe::BPEDecoder;
+use crate::decoders::byte::ByteDecoder;
 use crate::decoders::byte_fallback::ByteFallback;
 use crate::decoders::ctc::CTC;
 use crate::decoders::fuse::Fuse;
@@ -37,6 +39,7 @@ pub enum DecoderWrapper {
     Fuse(Fuse),
     Strip(Strip),
     ByteFallback(ByteFallback),
+    ByteDecoder(ByteDecoder),
 }
 
 impl Decoder for DecoderWrapper {
@@ -52,6 +55,7 @@ impl Decoder for DecoderWrapper {
             Self::ByteFallback(bf) => bf.decode_chain(tokens),
             Self::Strip(bf) => bf.decode_chain(tokens),
             Self::Fuse(bf) => bf.decode_chain(tokens),
+            Self::ByteDecoder(bd) => bd.decode_chain(tokens),
         }
     }
 }
@@ -59,6 +63,7 @@ impl Decoder for DecoderWrapper {
 impl_enum_from!(BPEDecoder, DecoderWrapper, BPE);
 impl_enum_from!(ByteLevel, DecoderWrapper, ByteLevel);
 impl_enum_from!(ByteFallback, DecoderWrapper, ByteFallback);
+impl_enum_from!(ByteDecoder, DecoderWrapper, ByteDecoder);
 impl_enum_from!(Fuse, DecoderWrapper, Fuse);
 impl_enum_from!(Strip, DecoderWrapper, Strip);
 impl_enum_from!(Metaspace, DecoderWrapper, Metaspace);
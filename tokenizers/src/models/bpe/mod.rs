@@ -2,7 +2,9 @@
 use std::{iter, mem};
 
 mod model;
+mod out_of_core;
 mod serialization;
+mod tiktoken;
 pub mod trainer;
 mod word;
 
@@ -33,6 +35,15 @@ pub enum Error {
     /// Dropout not between 0 and 1.
     #[error("Dropout should be between 0 and 1")]
     InvalidDropout,
+    /// When the tiktoken rank file is in the wrong format. This error holds the line
+    /// number of the line that caused the error.
+    #[error("Tiktoken rank file invalid at line {0}")]
+    BadTiktokenRanks(usize),
+    /// When continuing the training of a model whose tokens are not formed the same way
+    #[error(
+        "The trainer's continuing_subword_prefix and end_of_word_suffix must match the model's"
+    )]
+    IncompatibleModel,
 }
 
 /// Provides access to the `FirstLastIterator` to any Iterator
@@ -78,5 +89,7 @@ where
 
 // Re-export
 pub use model::*;
+pub use out_of_core::OutOfCore;
+pub use tiktoken::*;
 pub use trainer::*;
 use word::*;
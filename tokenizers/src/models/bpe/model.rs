@@ -1,11 +1,21 @@
-use super::{super::OrderedVocabIter, trainer::BpeTrainer, Error, Pair, Word};
+use super::{
+    super::{
+        compact_ids,
+        merge::{merge_vocabs, VocabMergeError, VocabMergeReport},
+        OrderedVocabIter,
+    },
+    trainer::BpeTrainer,
+    Error, Pair, Word,
+};
 use crate::tokenizer::{Model, Result, Token};
-use crate::utils::cache::{Cache, DEFAULT_CACHE_CAPACITY};
+use crate::utils::cache::{Cache, CacheStats, DEFAULT_CACHE_CAPACITY};
 use crate::utils::iter::ResultShunt;
+use crate::utils::rng::sample_rng;
+use rand::thread_rng;
 use serde_json::Value;
 use std::borrow::Cow;
 use std::{
-    collections::HashMap,
+    collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
     fs::File,
     io::prelude::*,
     io::{BufRead, BufReader},
@@ -19,16 +29,19 @@ pub type Merges = Vec<(String, String)>;
 
 struct Config {
     files: Option<(String, String)>,
+    tiktoken_file: Option<String>,
     vocab: Vocab,
     merges: Merges,
     cache_capacity: usize,
     dropout: Option<f32>,
+    dropout_seed: Option<u64>,
     unk_token: Option<String>,
     continuing_subword_prefix: Option<String>,
     end_of_word_suffix: Option<String>,
     fuse_unk: bool,
     byte_fallback: bool,
     ignore_merges: bool,
+    whole_word_offsets: bool,
 }
 
 /// A `BpeBuilder` can be used to create a `BPE` model with a custom configuration.
@@ -41,16 +54,19 @@ impl Default for BpeBuilder {
         Self {
             config: Config {
                 files: None,
+                tiktoken_file: None,
                 vocab: HashMap::new(),
                 merges: vec![],
                 cache_capacity: DEFAULT_CACHE_CAPACITY,
                 dropout: None,
+                dropout_seed: None,
                 unk_token: None,
                 continuing_subword_prefix: None,
                 end_of_word_suffix: None,
                 fuse_unk: false,
                 byte_fallback: false,
                 ignore_merges: false,
+                whole_word_offsets: false,
             },
         }
     }
@@ -69,6 +85,13 @@ impl BpeBuilder {
         self
     }
 
+    /// Set the input tiktoken rank file.
+    #[must_use]
+    pub fn tiktoken_file(mut self, ranks: String) -> Self {
+        self.config.tiktoken_file = Some(ranks);
+        self
+    }
+
     /// Set the vocab (token -> ID) and merges mappings.
     #[must_use]
     pub fn vocab_and_merges(mut self, vocab: Vocab, merges: Merges) -> Self {
@@ -91,6 +114,13 @@ impl BpeBuilder {
         self
     }
 
+    /// Seed the dropout, to make it reproducible.
+    #[must_use]
+    pub fn dropout_seed(mut self, seed: u64) -> Self {
+        self.config.dropout_seed = Some(seed);
+        self
+    }
+
     /// Set the `UNK` token for the vocab.
     #[must_use]
     pub fn unk_token(mut self, unk_token: String) -> Self {
@@ -132,6 +162,13 @@ impl BpeBuilder {
         self
     }
 
+    /// Set the `whole_word_offsets` option.
+    #[must_use]
+    pub fn whole_word_offsets(mut self, whole_word_offsets: bool) -> Self {
+        self.config.whole_word_offsets = whole_word_offsets;
+        self
+    }
+
     /// Returns a `BPE` model that uses the `BpeBuilder`'s configuration.
     pub fn build(mut self) -> Result<BPE> {
         // Validate dropout.
@@ -147,6 +184,11 @@ impl BpeBuilder {
             self.config.vocab = v;
             self.config.merges = m;
         }
+        if let Some(ranks) = self.config.tiktoken_file {
+            let (v, m) = BPE::read_tiktoken_file(&ranks)?;
+            self.config.vocab = v;
+            self.config.merges = m;
+        }
 
         let vocab_r = self
             .config
@@ -193,12 +235,14 @@ impl BpeBuilder {
             merges: merge_map,
             cache,
             dropout: self.config.dropout,
+            dropout_seed: self.config.dropout_seed,
             unk_token: self.config.unk_token,
             continuing_subword_prefix: self.config.continuing_subword_prefix,
             end_of_word_suffix: self.config.end_of_word_suffix,
             fuse_unk: self.config.fuse_unk,
             byte_fallback: self.config.byte_fallback,
             ignore_merges: self.config.ignore_merges,
+            whole_word_offsets: self.config.whole_word_offsets,
         })
     }
 }
@@ -217,6 +261,13 @@ pub struct BPE {
     /// Dropout probability for merges. 0 = no dropout is the default. At 1.0, tokenization will
     /// perform no merges, so the result will just be characters.
     pub dropout: Option<f32>,
+    /// When set, the dropout is reproducible: the merges dropped for a word only depend on the
+    /// seed, the word, its position in its input and the index of this input in its batch.
+    /// Encoding the same inputs thus always drops the same merges, whatever the threads
+    /// encoding them, while the same word gets different segmentations at different positions.
+    /// The same merges are then dropped at each epoch of a training: change the seed at each
+    /// epoch, like `seed + epoch`, to drop new ones.
+    pub dropout_seed: Option<u64>,
     /// The unknown token to be used when we encounter an unknown char
     pub unk_token: Option<String>,
     /// An optional prefix to use on any subword that exist only behind another one
@@ -230,12 +281,16 @@ pub struct BPE {
     pub byte_fallback: bool,
     /// Whether or not to direct output words if they are part of the vocab.
     pub ignore_merges: bool,
+    /// Whether the words output directly with `ignore_merges` get their offsets in the word,
+    /// instead of `(0, 0)`. Set when loading a tiktoken rank file.
+    pub whole_word_offsets: bool,
 }
 
 impl std::fmt::Debug for BPE {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
         fmt.debug_struct("BPE")
             .field("dropout", &self.dropout)
+            .field("dropout_seed", &self.dropout_seed)
             .field("unk_token", &self.unk_token)
             .field("continuing_subword_prefix", &self.continuing_subword_prefix)
             .field("end_of_word_suffix", &self.end_of_word_suffix)
@@ -244,6 +299,7 @@ impl std::fmt::Debug for BPE {
             .field("vocab", &self.vocab.len())
             .field("merges", &self.merges.len())
             .field("ignore_merges", &self.ignore_merges)
+            .field("whole_word_offsets", &self.whole_word_offsets)
             .finish()
     }
 }
@@ -265,12 +321,14 @@ impl Clone for BPE {
             merges: self.merges.clone(),
             cache: fresh_cache,
             dropout: self.dropout,
+            dropout_seed: self.dropout_seed,
             unk_token: self.unk_token.clone(),
             continuing_subword_prefix: self.continuing_subword_prefix.clone(),
             end_of_word_suffix: self.end_of_word_suffix.clone(),
             fuse_unk: self.fuse_unk,
             byte_fallback: self.byte_fallback,
             ignore_merges: self.ignore_merges,
+            whole_word_offsets: self.whole_word_offsets,
         }
     }
 }
@@ -354,10 +412,82 @@ impl BPE {
         }
     }
 
+    /// The number of words that the cache can contain, 0 when it is disabled.
+    pub fn get_cache_capacity(&self) -> usize {
+        self.cache.as_ref().map_or(0, |cache| cache.capacity)
+    }
+
+    /// Get the hit/miss statistics of the cache.
+    pub fn cache_stats(&self) -> CacheStats {
+        self.cache
+            .as_ref()
+            .map(|cache| cache.stats())
+            .unwrap_or_default()
+    }
+
     pub fn get_vocab(&self) -> Vocab {
         self.vocab.clone()
     }
 
+    /// Merge the vocabulary of the other `BPE` into this one, as explained in
+    /// [`ModelWrapper::merge_vocab`](crate::models::ModelWrapper::merge_vocab). The new tokens
+    /// are appended after the existing ids, and the new merges after the existing ones, so
+    /// they only apply once the existing merges have been applied. The other options are the
+    /// ones of this model.
+    pub fn merge_vocab(
+        &self,
+        other: &BPE,
+        reserved: &HashMap<u32, String>,
+    ) -> Result<(BPE, VocabMergeReport)> {
+        if self.continuing_subword_prefix != other.continuing_subword_prefix {
+            return Err(Box::new(VocabMergeError::IncompatibleOption(
+                "continuing_subword_prefix",
+            )));
+        }
+        if self.end_of_word_suffix != other.end_of_word_suffix {
+            return Err(Box::new(VocabMergeError::IncompatibleOption(
+                "end_of_word_suffix",
+            )));
+        }
+
+        let (vocab, report) = merge_vocabs(
+            &self.vocab,
+            reserved,
+            other
+                .vocab_r
+                .iter()
+                .map(|(id, token)| (*id, token.as_str())),
+        );
+        let mut merges = self.merges.clone();
+        let mut rank = merges.values().map(|(rank, _)| rank + 1).max().unwrap_or(0);
+        let mut other_merges = other.merges.iter().collect::<Vec<_>>();
+        other_merges.sort_unstable_by_key(|(_, (rank, _))| *rank);
+        let vocab_r: HashMap<u32, String> = vocab
+            .iter()
+            .map(|(token, id)| (*id, token.clone()))
+            .collect();
+        for ((a, b), (_, new_id)) in other_merges {
+            let (pair, new_id) = ((report.ids[a], report.ids[b]), report.ids[new_id]);
+            // The reserved ids are not part of the merged vocabulary
+            if ![pair.0, pair.1, new_id]
+                .iter()
+                .all(|id| vocab_r.contains_key(id))
+            {
+                continue;
+            }
+            if let Entry::Vacant(entry) = merges.entry(pair) {
+                entry.insert((rank, new_id));
+                rank += 1;
+            }
+        }
+
+        let mut bpe = self.clone();
+        bpe.vocab_r = vocab_r;
+        bpe.vocab = vocab;
+        bpe.merges = merges;
+        Ok((bpe, report))
+    }
+
     pub fn get_unk_token(&self) -> &Option<String> {
         &self.unk_token
     }
@@ -367,6 +497,18 @@ impl BPE {
     }
 
     fn merge_word(&self, w: &str) -> Result<Word> {
+        let mut word = self.split_word(w)?;
+        match (self.dropout, self.dropout_seed) {
+            (Some(dropout), Some(seed)) => {
+                word.merge_all(&self.merges, Some(dropout), &mut sample_rng(seed, w))
+            }
+            (dropout, _) => word.merge_all(&self.merges, dropout, &mut thread_rng()),
+        }
+        Ok(word)
+    }
+
+    /// Split the given word into its initial symbols, before any merge is applied.
+    fn split_word(&self, w: &str) -> Result<Word> {
         let mut indices = w.char_indices().map(|(idx, _)| idx).peekable();
         let mut word = Word::with_capacity(w.len());
         let mut unk: Option<(u32, usize)> = None;
@@ -448,8 +590,6 @@ impl BPE {
             word.add(unk_id, unk_len);
         }
 
-        word.merge_all(&self.merges, self.dropout);
-
         Ok(word)
     }
 
@@ -459,13 +599,23 @@ impl BPE {
             .map(move |(id, offsets)| Token::new(id, self.vocab_r[&id].clone(), offsets))
     }
 
+    /// The token of a word output directly with `ignore_merges`
+    fn whole_word_token(&self, id: u32, sequence: &str) -> Token {
+        let offsets = if self.whole_word_offsets {
+            (0, sequence.len())
+        } else {
+            (0, 0)
+        };
+        Token::new(id, sequence.to_owned(), offsets)
+    }
+
     fn tokenize_with_cache(&self, sequence: &str) -> Result<Vec<Token>> {
         if let Some(ref hit) = self.cache.as_ref().and_then(|c| c.get(sequence)) {
             return Ok(self.word_to_tokens(hit).collect());
         }
         if self.ignore_merges {
             if let Some(id) = self.vocab.get(sequence) {
-                return Ok(vec![Token::new(*id, sequence.to_string().clone(), (0, 0))]);
+                return Ok(vec![self.whole_word_token(*id, sequence)]);
             }
         }
         let word = self.merge_word(sequence)?;
@@ -501,6 +651,86 @@ impl Model for BPE {
         }
     }
 
+    /// With a seeded dropout, the same merges are dropped as when tokenizing the sequence at
+    /// the same position, so the count matches the encoding of the same input. Without a seed,
+    /// the count is only the one of a sampled segmentation.
+    fn count_tokens(&self, sequence: &str) -> Result<usize> {
+        if sequence.is_empty() {
+            return Ok(0);
+        }
+        if self.dropout.is_some() {
+            return Ok(self.merge_word(sequence)?.get_chars_iter().count());
+        }
+
+        if let Some(ref hit) = self.cache.as_ref().and_then(|c| c.get(sequence)) {
+            return Ok(hit.get_chars_iter().count());
+        }
+        if self.ignore_merges && self.vocab.contains_key(sequence) {
+            return Ok(1);
+        }
+        let word = self.merge_word(sequence)?;
+        let count = word.get_chars_iter().count();
+        if let Some(ref cache) = self.cache {
+            cache.set(sequence.to_owned(), word);
+        }
+        Ok(count)
+    }
+
+    /// The best segmentation of a `BPE` is the one obtained by applying all the merges, and
+    /// the other ones are obtained by skipping some of them: each segmentation leads to those
+    /// skipping one more of the merge ranks it applied, from the highest to the lowest rank.
+    /// The score of a segmentation is minus the number of merge ranks skipped to obtain it,
+    /// `0.0` for the best one. The dropout is never applied, and with `ignore_merges`, a word
+    /// that is part of the vocabulary keeps its only segmentation.
+    fn tokenize_nbest(&self, sequence: &str, n: usize) -> Result<Vec<(Vec<Token>, f64)>> {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        if sequence.is_empty() {
+            return Ok(vec![(vec![], 0.0)]);
+        }
+        if self.ignore_merges {
+            if let Some(id) = self.vocab.get(sequence) {
+                return Ok(vec![(vec![self.whole_word_token(*id, sequence)], 0.0)]);
+            }
+        }
+
+        let word = self.split_word(sequence)?;
+        // Visit the sets of skipped ranks breadth first, so from the highest to the lowest
+        // score, and give up after a number of visits bounded by the length of the word
+        let max_visits = n.saturating_mul(word.get_chars_iter().count().max(1));
+        let mut queue = VecDeque::from([vec![]]);
+        let mut visited = HashSet::from([vec![]]);
+        let mut segmentations = HashSet::new();
+        let mut nbest = vec![];
+        let mut visits = 0;
+        while let Some(skipped) = queue.pop_front() {
+            if nbest.len() == n || visits == max_visits {
+                break;
+            }
+            visits += 1;
+
+            let mut merged = word.clone();
+            let mut applied = merged.merge_all_except(&self.merges, &skipped);
+            if segmentations.insert(merged.get_chars()) {
+                let tokens = self.word_to_tokens(&merged).collect();
+                nbest.push((tokens, -(skipped.len() as f64)));
+            }
+
+            applied.sort_unstable_by(|a, b| b.cmp(a));
+            applied.dedup();
+            for rank in applied {
+                let mut next = skipped.clone();
+                next.push(rank);
+                next.sort_unstable();
+                if visited.insert(next.clone()) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        Ok(nbest)
+    }
+
     fn token_to_id(&self, token: &str) -> Option<u32> {
         self.vocab.get(token).copied()
     }
@@ -556,11 +786,68 @@ impl Model for BPE {
     fn get_trainer(&self) -> BpeTrainer {
         BpeTrainer::default()
     }
+
+    /// The parts of the merges producing a kept token are kept too, so that it can still be
+    /// produced, along with the unknown token and the bytes used as fallback. The merges
+    /// producing a removed token are removed.
+    fn prune_vocab(&mut self, keep: &dyn Fn(u32) -> bool) -> Result<HashMap<u32, u32>> {
+        let mut kept: HashSet<u32> = self
+            .vocab_r
+            .keys()
+            .copied()
+            .filter(|id| keep(*id))
+            .collect();
+        kept.extend(self.unk_token.as_ref().and_then(|unk| self.vocab.get(unk)));
+        if self.byte_fallback {
+            kept.extend((0..=255u8).filter_map(|b| self.vocab.get(&format!("<{:#04X}>", b))));
+        }
+
+        let mut parts: HashMap<u32, Vec<Pair>> = HashMap::new();
+        for (pair, (_, new_id)) in &self.merges {
+            parts.entry(*new_id).or_default().push(*pair);
+        }
+        let mut to_visit = kept.iter().copied().collect::<Vec<_>>();
+        while let Some(id) = to_visit.pop() {
+            for (a, b) in parts.get(&id).into_iter().flatten() {
+                for part in [*a, *b] {
+                    if kept.insert(part) {
+                        to_visit.push(part);
+                    }
+                }
+            }
+        }
+
+        let ids = compact_ids(kept);
+        let mut merges = self
+            .merges
+            .drain()
+            .filter(|(_, (_, new_id))| ids.contains_key(new_id))
+            .collect::<Vec<_>>();
+        merges.sort_unstable_by_key(|(_, (rank, _))| *rank);
+        self.merges = merges
+            .into_iter()
+            .enumerate()
+            .map(|(rank, ((a, b), (_, new_id)))| ((ids[&a], ids[&b]), (rank as u32, ids[&new_id])))
+            .collect();
+        self.vocab_r = self
+            .vocab_r
+            .drain()
+            .filter_map(|(id, token)| Some((*ids.get(&id)?, token)))
+            .collect();
+        self.vocab = self
+            .vocab_r
+            .iter()
+            .map(|(id, token)| (token.clone(), *id))
+            .collect();
+        self.clear_cache();
+        Ok(ids)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::rng::with_sample_stream;
     use tempfile::NamedTempFile;
 
     #[test]
@@ -709,6 +996,237 @@ mod tests {
         assert!(!tokens.is_empty() && tokens.len() <= 9);
     }
 
+    #[test]
+    fn test_tokenize_with_dropout_seed() {
+        let vocab: Vocab = [
+            ("a".into(), 0),
+            ("b".into(), 1),
+            ("ab".into(), 2),
+            ("abab".into(), 3),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        let merges: Merges = vec![
+            ("a".to_string(), "b".to_string()),
+            ("ab".to_string(), "ab".to_string()),
+        ];
+        let mut bpe = BPE::builder()
+            .vocab_and_merges(vocab, merges)
+            .dropout(0.5)
+            .dropout_seed(1)
+            .build()
+            .unwrap();
+
+        let words = (1..50).map(|n| "ab".repeat(n)).collect::<Vec<_>>();
+        let tokenize = |bpe: &BPE, words: &mut dyn Iterator<Item = &String>| {
+            words
+                .map(|word| (word.clone(), bpe.tokenize(word).unwrap()))
+                .collect::<HashMap<_, _>>()
+        };
+
+        // The segmentations are reproduced, by the model or a clone
+        let first = tokenize(&bpe, &mut words.iter());
+        assert_eq!(first, tokenize(&bpe, &mut words.iter()));
+        assert_eq!(first, tokenize(&bpe.clone(), &mut words.iter()));
+
+        // The same word gets new segmentations at each of its positions in an input
+        let segmentations = with_sample_stream(0, || {
+            (0..16)
+                .map(|_| bpe.tokenize(&words[8]).unwrap())
+                .collect::<Vec<_>>()
+        });
+        assert!(segmentations
+            .iter()
+            .any(|tokens| *tokens != segmentations[0]));
+
+        bpe.dropout_seed = Some(2);
+        assert_ne!(first, tokenize(&bpe, &mut words.iter()));
+    }
+
+    #[test]
+    fn test_encode_batch_with_dropout_seed() {
+        use crate::Tokenizer;
+
+        let vocab: Vocab = [
+            ("a".into(), 0),
+            ("b".into(), 1),
+            ("ab".into(), 2),
+            ("abab".into(), 3),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        let merges: Merges = vec![
+            ("a".to_string(), "b".to_string()),
+            ("ab".to_string(), "ab".to_string()),
+        ];
+        let bpe = BPE::builder()
+            .vocab_and_merges(vocab, merges)
+            .dropout(0.5)
+            .dropout_seed(42)
+            .build()
+            .unwrap();
+        let tokenizer = Tokenizer::new(bpe);
+
+        // The segmentations neither depend on the threads encoding the batch, nor on the
+        // previous batches, while the same inputs still get different segmentations
+        let inputs = vec!["abababab"; 200];
+        let encode_batch = |num_threads| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap()
+                .install(|| tokenizer.encode_batch(inputs.clone(), false).unwrap())
+        };
+        let first = encode_batch(1);
+        assert_eq!(first, encode_batch(8));
+        assert_eq!(first, encode_batch(1));
+        assert_eq!(first[0], tokenizer.encode(inputs[0], false).unwrap());
+        assert!(first.iter().any(|encoding| *encoding != first[0]));
+    }
+
+    #[test]
+    fn test_count_tokens_with_dropout_seed() {
+        use crate::pre_tokenizers::whitespace::Whitespace;
+        use crate::Tokenizer;
+
+        let vocab: Vocab = [
+            ("a".into(), 0),
+            ("b".into(), 1),
+            ("ab".into(), 2),
+            ("abab".into(), 3),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        let merges: Merges = vec![
+            ("a".to_string(), "b".to_string()),
+            ("ab".to_string(), "ab".to_string()),
+        ];
+        let bpe = BPE::builder()
+            .vocab_and_merges(vocab, merges)
+            .dropout(0.5)
+            .dropout_seed(42)
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(bpe);
+        tokenizer.with_pre_tokenizer(Whitespace {});
+
+        // The counts match the seeded encodings, and counting doesn't change them
+        let inputs = (1..50)
+            .map(|n| vec!["ab".repeat(n); 4].join(" "))
+            .collect::<Vec<_>>();
+        let encodings = tokenizer.encode_batch(inputs.clone(), false).unwrap();
+        assert_eq!(
+            tokenizer.count_tokens_batch(inputs.clone(), false).unwrap(),
+            encodings.iter().map(|e| e.len()).collect::<Vec<_>>()
+        );
+        for input in &inputs {
+            let encoding = tokenizer.encode(input.as_str(), false).unwrap();
+            assert_eq!(
+                tokenizer.count_tokens(input.as_str(), false).unwrap(),
+                encoding.len()
+            );
+            assert_eq!(tokenizer.encode(input.as_str(), false).unwrap(), encoding);
+        }
+    }
+
+    #[test]
+    fn test_cache() {
+        let vocab: Vocab = [("a".into(), 0), ("b".into(), 1), ("ab".into(), 2)]
+            .iter()
+            .cloned()
+            .collect();
+        let merges: Merges = vec![("a".to_string(), "b".to_string())];
+        let bpe = BPE::builder()
+            .vocab_and_merges(vocab, merges)
+            .cache_capacity(2)
+            .build()
+            .unwrap();
+
+        // Words keep being cached once the cache is full, evicting the older ones
+        for word in &["ab", "ba", "ab", "aab", "bab", "aab"] {
+            bpe.tokenize(word).unwrap();
+        }
+        let stats = bpe.cache_stats();
+        assert_eq!((stats.hits, stats.misses), (2, 4));
+        assert_eq!((stats.len, stats.capacity), (2, 2));
+
+        // The capacity is kept when serializing
+        let serialized = serde_json::to_string(&bpe).unwrap();
+        assert!(serialized.contains("\"cache_capacity\":2"));
+        let deserialized: BPE = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.get_cache_capacity(), 2);
+        assert!(!serde_json::to_string(&BPE::default())
+            .unwrap()
+            .contains("cache_capacity"));
+    }
+
+    #[test]
+    fn test_tokenize_nbest() {
+        let vocab: Vocab = [
+            ("a".into(), 0),
+            ("b".into(), 1),
+            ("c".into(), 2),
+            ("ab".into(), 3),
+            ("bc".into(), 4),
+            ("abc".into(), 5),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        let merges: Merges = vec![
+            ("a".to_string(), "b".to_string()),
+            ("ab".to_string(), "c".to_string()),
+            ("b".to_string(), "c".to_string()),
+        ];
+        let mut bpe = BPE::builder()
+            .vocab_and_merges(vocab, merges)
+            .build()
+            .unwrap();
+
+        let segment = |bpe: &BPE, n| {
+            bpe.tokenize_nbest("abc", n)
+                .unwrap()
+                .into_iter()
+                .map(|(tokens, score)| {
+                    let tokens = tokens.into_iter().map(|t| t.value).collect::<Vec<_>>();
+                    (tokens, score)
+                })
+                .collect::<Vec<_>>()
+        };
+        // Each segmentation skips one more of the merges applied by a better one
+        let nbest = vec![
+            (vec!["abc".to_string()], 0.0),
+            (vec!["ab".to_string(), "c".to_string()], -1.0),
+            (vec!["a".to_string(), "bc".to_string()], -1.0),
+            (
+                vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                -2.0,
+            ),
+        ];
+        assert_eq!(segment(&bpe, 10), nbest);
+        assert_eq!(segment(&bpe, 2), nbest[..2]);
+        assert_eq!(
+            bpe.tokenize_nbest("abc", 2).unwrap()[1].0,
+            vec![
+                Token::new(3, "ab".into(), (0, 2)),
+                Token::new(2, "c".into(), (2, 3))
+            ]
+        );
+        assert!(bpe.tokenize_nbest("abc", 0).unwrap().is_empty());
+        assert_eq!(bpe.tokenize_nbest("", 2).unwrap(), vec![(vec![], 0.0)]);
+
+        // The dropout is never applied
+        bpe.dropout = Some(1.0);
+        assert_eq!(segment(&bpe, 10), nbest);
+
+        // A word of the vocab keeps its only segmentation when ignoring the merges
+        bpe.ignore_merges = true;
+        assert_eq!(segment(&bpe, 10), nbest[..1]);
+    }
+
     #[test]
     // Ensure `BPE::from_file` works as expected.
     fn test_bpe_from_file() {
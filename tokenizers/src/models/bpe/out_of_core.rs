@@ -0,0 +1,618 @@
+//! Disk-backed storage used by the `BpeTrainer` to train on corpora with more distinct words
+//! than what fits in memory.
+
+use super::trainer::WordStore;
+use super::{Pair, Word};
+use crate::parallelism::*;
+use crate::tokenizer::Result;
+use crate::utils::progress::ProgressBar;
+use memmap2::MmapMut;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::convert::TryInto;
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tempfile::TempDir;
+
+/// Configuration of the out-of-core training mode of the `BpeTrainer`.
+///
+/// Word counts are spilled to sorted shards whenever more than `max_words_in_memory` distinct
+/// words have been accumulated. These shards are then merged before training, and the merges
+/// are computed over a memory-mapped file of symbols instead of in-memory words. The positions
+/// of the pairs in these words are kept on disk too, and only read back when merging their
+/// pair. The training still keeps in memory the count and the span of each word (24 bytes per
+/// word) along with the count of each distinct pair, but neither the words nor the positions of
+/// their pairs.
+///
+/// Every file is written in a temporary directory created in `directory`, unique to each
+/// trainer, and removed once the training is done (for the intermediate files) or once the
+/// trainer is dropped or fed again (for the shards).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutOfCore {
+    /// The directory in which the temporary directories of the trainers are created
+    pub directory: PathBuf,
+    /// The maximum number of distinct words to accumulate in memory before spilling a shard
+    pub max_words_in_memory: usize,
+    /// The maximum number of pair positions to accumulate in memory, while counting the pairs,
+    /// before spilling them to disk
+    pub max_pairs_in_memory: usize,
+    /// Words seen less than this number of times in the whole corpus are ignored
+    pub min_word_count: u64,
+}
+
+impl OutOfCore {
+    pub fn new<P: Into<PathBuf>>(directory: P) -> Self {
+        Self {
+            directory: directory.into(),
+            max_words_in_memory: 1_000_000,
+            max_pairs_in_memory: 10_000_000,
+            min_word_count: 0,
+        }
+    }
+
+    /// Create a new temporary directory, with the given prefix, in `directory`
+    pub(super) fn temp_dir(&self, prefix: &str) -> Result<TempDir> {
+        std::fs::create_dir_all(&self.directory)?;
+        Ok(tempfile::Builder::new()
+            .prefix(prefix)
+            .tempdir_in(&self.directory)?)
+    }
+}
+
+/// The file of merged words, in the temporary directory of a training
+pub(super) fn words_path(directory: &Path) -> PathBuf {
+    directory.join("words.bin")
+}
+
+/// The shards spilled while feeding a `BpeTrainer`, in their own temporary directory. Clones of
+/// the trainer share them, and the directory is removed once the last of them is dropped.
+#[derive(Debug, Clone)]
+pub(super) struct Shards {
+    directory: Arc<TempDir>,
+    paths: Vec<PathBuf>,
+}
+
+impl Shards {
+    pub(super) fn new(out_of_core: &OutOfCore) -> Result<Self> {
+        Ok(Self {
+            directory: Arc::new(out_of_core.temp_dir("bpe-shards-")?),
+            paths: vec![],
+        })
+    }
+
+    pub(super) fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Write the given words to a new shard
+    pub(super) fn spill(&mut self, words: HashMap<String, u64>) -> Result<()> {
+        let path = self
+            .directory
+            .path()
+            .join(format!("shard-{}.bin", self.paths.len()));
+        write_shard(&path, words)?;
+        self.paths.push(path);
+        Ok(())
+    }
+}
+
+impl PartialEq for Shards {
+    fn eq(&self, other: &Self) -> bool {
+        self.paths == other.paths
+    }
+}
+
+impl Eq for Shards {}
+
+/// Write a shard containing the given word counts, sorted by word.
+///
+/// Each record is made of the count (`u64`), the byte length of the word (`u32`), both in
+/// little-endian, followed by the word itself.
+fn write_shard(path: &Path, words: HashMap<String, u64>) -> Result<()> {
+    let mut words = words.into_iter().collect::<Vec<_>>();
+    words.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    for (word, count) in words {
+        write_record(&mut writer, &word, count)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn write_record<W: Write>(writer: &mut W, word: &str, count: u64) -> Result<()> {
+    writer.write_all(&count.to_le_bytes())?;
+    writer.write_all(&(word.len() as u32).to_le_bytes())?;
+    writer.write_all(word.as_bytes())?;
+    Ok(())
+}
+
+/// Reads the records of a shard, in order
+struct ShardReader {
+    reader: BufReader<File>,
+}
+
+impl ShardReader {
+    fn open(path: &Path) -> Result<Self> {
+        Ok(Self {
+            reader: BufReader::new(File::open(path)?),
+        })
+    }
+
+    fn next_record(&mut self) -> Result<Option<(String, u64)>> {
+        let mut count = [0u8; 8];
+        match self.reader.read_exact(&mut count) {
+            Ok(()) => {}
+            // The end of the file between two records is the end of the shard
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        }
+        let mut len = [0u8; 4];
+        self.reader.read_exact(&mut len)?;
+        let mut word = vec![0; u32::from_le_bytes(len) as usize];
+        self.reader.read_exact(&mut word)?;
+
+        Ok(Some((String::from_utf8(word)?, u64::from_le_bytes(count))))
+    }
+}
+
+/// Writes the merged words, keeping track of the alphabet
+struct WordsWriter {
+    writer: BufWriter<File>,
+    min_count: u64,
+    alphabet: HashMap<char, usize>,
+    len: usize,
+}
+
+impl WordsWriter {
+    fn push(&mut self, word: &str, count: u64) -> Result<()> {
+        if count < self.min_count {
+            return Ok(());
+        }
+        for c in word.chars() {
+            *self.alphabet.entry(c).or_insert(0) += count as usize;
+        }
+        self.len += 1;
+        write_record(&mut self.writer, word, count)
+    }
+}
+
+/// Merge the given sorted shards into a single sorted file of words at `output`, summing the
+/// counts of the words found in multiple shards, and ignoring those seen less than `min_count`
+/// times. Returns the count of each char in the kept words, along with the number of words.
+pub(super) fn merge_shards(
+    shards: &[PathBuf],
+    min_count: u64,
+    output: &Path,
+) -> Result<(HashMap<char, usize>, usize)> {
+    let mut readers = shards
+        .iter()
+        .map(|shard| ShardReader::open(shard))
+        .collect::<Result<Vec<_>>>()?;
+    let mut heap = BinaryHeap::with_capacity(readers.len());
+    for (i, reader) in readers.iter_mut().enumerate() {
+        if let Some((word, count)) = reader.next_record()? {
+            heap.push(Reverse((word, i, count)));
+        }
+    }
+
+    let mut writer = WordsWriter {
+        writer: BufWriter::new(File::create(output)?),
+        min_count,
+        alphabet: HashMap::new(),
+        len: 0,
+    };
+    let mut current: Option<(String, u64)> = None;
+    while let Some(Reverse((word, i, count))) = heap.pop() {
+        if let Some((next, next_count)) = readers[i].next_record()? {
+            heap.push(Reverse((next, i, next_count)));
+        }
+        match &mut current {
+            Some((current_word, current_count)) if *current_word == word => {
+                *current_count += count;
+            }
+            _ => {
+                if let Some((word, count)) = current.replace((word, count)) {
+                    writer.push(&word, count)?;
+                }
+            }
+        }
+    }
+    if let Some((word, count)) = current {
+        writer.push(&word, count)?;
+    }
+    writer.writer.flush()?;
+
+    Ok((writer.alphabet, writer.len))
+}
+
+/// A list of positions stored in `PairPositions`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Positions {
+    /// The index of the first position in the file
+    start: u64,
+    /// The number of positions
+    len: u64,
+}
+
+/// The positions of the pairs, as lists of word indices (`u64` in little-endian) appended to a
+/// single file. Each list is written once, and only read back to merge its pair.
+struct PairPositions {
+    writer: BufWriter<File>,
+    reader: File,
+    len: u64,
+}
+
+impl PairPositions {
+    fn create(path: &Path) -> Result<Self> {
+        let writer = BufWriter::new(File::create(path)?);
+        Ok(Self {
+            writer,
+            reader: File::open(path)?,
+            len: 0,
+        })
+    }
+
+    /// Append a position to the list being written, which starts at `self.len` when starting
+    /// a new list
+    fn push(&mut self, i: u64) -> Result<()> {
+        self.writer.write_all(&i.to_le_bytes())?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Write the given positions as a new list
+    fn store<I: IntoIterator<Item = usize>>(&mut self, pos: I) -> Result<Positions> {
+        let start = self.len;
+        for i in pos {
+            self.push(i as u64)?;
+        }
+        Ok(Positions {
+            start,
+            len: self.len - start,
+        })
+    }
+
+    fn read(&mut self, pos: Positions) -> Result<HashSet<usize>> {
+        self.writer.flush()?;
+        self.reader.seek(SeekFrom::Start(pos.start * 8))?;
+        let mut bytes = vec![0; pos.len as usize * 8];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(bytes
+            .chunks_exact(8)
+            .map(|b| u64::from_le_bytes(b.try_into().unwrap()) as usize)
+            .collect())
+    }
+}
+
+/// Sorts the occurrences of the pairs with their word index, buffering at most `capacity` of
+/// them in memory before spilling them to a sorted run on disk.
+struct PairRuns<'a> {
+    directory: &'a Path,
+    capacity: usize,
+    buffer: Vec<(Pair, u64)>,
+    runs: Vec<PathBuf>,
+}
+
+impl<'a> PairRuns<'a> {
+    fn new(directory: &'a Path, capacity: usize) -> Self {
+        Self {
+            directory,
+            capacity: capacity.max(1),
+            buffer: vec![],
+            runs: vec![],
+        }
+    }
+
+    fn push(&mut self, pair: Pair, i: u64) -> Result<()> {
+        self.buffer.push((pair, i));
+        if self.buffer.len() >= self.capacity {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    /// Each record of a run is made of both ids of the pair (`u32`) and of the word index
+    /// (`u64`), all in little-endian
+    fn spill(&mut self) -> Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        self.buffer.sort_unstable();
+        self.buffer.dedup();
+
+        let path = self
+            .directory
+            .join(format!("pairs-{}.bin", self.runs.len()));
+        let mut writer = BufWriter::new(File::create(&path)?);
+        for ((a, b), i) in self.buffer.drain(..) {
+            writer.write_all(&a.to_le_bytes())?;
+            writer.write_all(&b.to_le_bytes())?;
+            writer.write_all(&i.to_le_bytes())?;
+        }
+        writer.flush()?;
+        self.runs.push(path);
+        Ok(())
+    }
+
+    /// Merge the runs, writing the positions of each pair in `positions`, and removing the
+    /// runs once done. Returns the positions of each pair, in order.
+    fn finish(mut self, positions: &mut PairPositions) -> Result<Vec<(Pair, Positions)>> {
+        self.spill()?;
+
+        let mut readers = self
+            .runs
+            .iter()
+            .map(|run| Ok(BufReader::new(File::open(run)?)))
+            .collect::<Result<Vec<_>>>()?;
+        let mut heap = BinaryHeap::with_capacity(readers.len());
+        for (r, reader) in readers.iter_mut().enumerate() {
+            if let Some(record) = read_pair_record(reader)? {
+                heap.push(Reverse((record, r)));
+            }
+        }
+
+        let mut pairs: Vec<(Pair, Positions)> = vec![];
+        let mut last: Option<(Pair, u64)> = None;
+        while let Some(Reverse(((pair, i), r))) = heap.pop() {
+            if let Some(record) = read_pair_record(&mut readers[r])? {
+                heap.push(Reverse((record, r)));
+            }
+            // The same occurrence may have been spilled in several runs
+            if last == Some((pair, i)) {
+                continue;
+            }
+            if last.map(|(p, _)| p) != Some(pair) {
+                pairs.push((
+                    pair,
+                    Positions {
+                        start: positions.len,
+                        len: 0,
+                    },
+                ));
+            }
+            positions.push(i)?;
+            pairs.last_mut().unwrap().1.len += 1;
+            last = Some((pair, i));
+        }
+
+        drop(readers);
+        for run in &self.runs {
+            std::fs::remove_file(run)?;
+        }
+        Ok(pairs)
+    }
+}
+
+fn read_pair_record<R: Read>(reader: &mut R) -> Result<Option<(Pair, u64)>> {
+    let mut record = [0u8; 16];
+    match reader.read_exact(&mut record) {
+        Ok(()) => {}
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    }
+    let a = u32::from_le_bytes(record[0..4].try_into().unwrap());
+    let b = u32::from_le_bytes(record[4..8].try_into().unwrap());
+    let i = u64::from_le_bytes(record[8..16].try_into().unwrap());
+    Ok(Some(((a, b), i)))
+}
+
+/// The words being merged, stored as a memory-mapped file of `u32` symbols in little-endian.
+/// Merging only ever shrinks the words, so they are updated in place.
+pub(super) struct MmapWords {
+    mmap: MmapMut,
+    /// The index of the first symbol of each word, along with its current number of symbols
+    spans: Vec<(usize, usize)>,
+    /// The number of chars of the tokens produced by merges, used to respect the
+    /// `max_token_length`. Any other token is a single char.
+    token_lens: HashMap<u32, usize>,
+    /// The directory in which the runs of pairs are spilled while counting them
+    directory: PathBuf,
+    max_pairs_in_memory: usize,
+    positions: PairPositions,
+}
+
+impl MmapWords {
+    /// Tokenize each word of the sorted file of words in `directory`, storing their symbols in
+    /// a new file next to it. Returns the mapped words along with their counts.
+    pub(super) fn build<F>(
+        directory: &Path,
+        max_pairs_in_memory: usize,
+        mut tokenize: F,
+    ) -> Result<(Self, Vec<u64>)>
+    where
+        F: FnMut(&str) -> Word,
+    {
+        let mut reader = ShardReader::open(&words_path(directory))?;
+        // The file is also read, once mapped
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(directory.join("symbols.bin"))?;
+        let mut writer = BufWriter::new(file);
+        let mut spans = vec![];
+        let mut counts = vec![];
+        let mut token_lens = HashMap::new();
+        let mut start = 0;
+        while let Some((word, count)) = reader.next_record()? {
+            let word = tokenize(&word);
+            let mut len = 0;
+            for (c, (begin, end)) in word.get_chars_iter().zip(word.get_offsets_iter()) {
+                // Words may already contain merged tokens when continuing a training
+                if end - begin != 1 {
+                    token_lens.insert(c, end - begin);
+                }
+                writer.write_all(&c.to_le_bytes())?;
+                len += 1;
+            }
+            spans.push((start, len));
+            counts.push(count);
+            start += len;
+        }
+        let file = writer.into_inner().map_err(|e| e.into_error())?;
+
+        // Safety: the file has just been created by us, and is not modified elsewhere
+        let mmap = unsafe { MmapMut::map_mut(&file)? };
+        Ok((
+            Self {
+                mmap,
+                spans,
+                token_lens,
+                directory: directory.to_owned(),
+                max_pairs_in_memory,
+                positions: PairPositions::create(&directory.join("positions.bin"))?,
+            },
+            counts,
+        ))
+    }
+
+    fn token_len(&self, c: u32) -> usize {
+        self.token_lens.get(&c).copied().unwrap_or(1)
+    }
+
+    fn word(&self, i: usize) -> Word {
+        let (start, len) = self.spans[i];
+        let mut word = Word::with_capacity(len);
+        for c in self.mmap[start * 4..(start + len) * 4]
+            .chunks_exact(4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        {
+            word.add(c, self.token_len(c));
+        }
+        word
+    }
+}
+
+impl WordStore for MmapWords {
+    type Positions = Positions;
+
+    fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    fn count_pairs(
+        &mut self,
+        counts: &[u64],
+        p: &Option<ProgressBar>,
+    ) -> Result<(HashMap<Pair, i64>, Vec<(Pair, Positions)>)> {
+        let mut pair_counts = HashMap::new();
+        let mut runs = PairRuns::new(&self.directory, self.max_pairs_in_memory);
+        for (i, count) in counts.iter().enumerate() {
+            for window in self.word(i).get_chars().windows(2) {
+                let cur_pair: Pair = (window[0], window[1]);
+                *pair_counts.entry(cur_pair).or_insert(0) += *count as i64;
+                runs.push(cur_pair, i as u64)?;
+            }
+
+            if let Some(p) = &p {
+                p.inc(1);
+            }
+        }
+
+        let positions = runs.finish(&mut self.positions)?;
+        Ok((pair_counts, positions))
+    }
+
+    fn store(&mut self, pos: HashSet<usize>) -> Result<Positions> {
+        let mut pos = pos.into_iter().collect::<Vec<_>>();
+        pos.sort_unstable();
+        self.positions.store(pos)
+    }
+
+    fn merge(
+        &mut self,
+        pos: Positions,
+        pair: Pair,
+        new_id: u32,
+        max_length: usize,
+    ) -> Result<Vec<((Pair, i32), usize)>> {
+        let pos = self.positions.read(pos)?;
+        let new_len = self.token_len(pair.0) + self.token_len(pair.1);
+        self.token_lens.insert(new_id, new_len);
+
+        let merged = pos
+            .maybe_par_iter()
+            .map(|&i| {
+                let mut word = self.word(i);
+                let changes = word.merge(pair.0, pair.1, new_id, max_length);
+                (i, word.get_chars(), changes)
+            })
+            .collect::<Vec<_>>();
+
+        let mut all_changes = vec![];
+        for (i, chars, changes) in merged {
+            let start = self.spans[i].0;
+            for (j, c) in chars.iter().enumerate() {
+                self.mmap[(start + j) * 4..(start + j + 1) * 4].copy_from_slice(&c.to_le_bytes());
+            }
+            self.spans[i].1 = chars.len();
+            all_changes.extend(changes.into_iter().map(|c| (c, i)));
+        }
+        Ok(all_changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pair_runs_bounded() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut positions = PairPositions::create(&directory.path().join("positions.bin")).unwrap();
+        let mut runs = PairRuns::new(directory.path(), 4);
+
+        let mut expected: HashMap<Pair, HashSet<usize>> = HashMap::new();
+        for i in (0..50u64).rev() {
+            for pair in [(1, 2), (2, 3), ((i % 3) as u32, 1), (1, 2)] {
+                runs.push(pair, i).unwrap();
+                expected.entry(pair).or_default().insert(i as usize);
+                // Never more than `capacity` positions are kept in memory
+                assert!(runs.buffer.len() < 4);
+            }
+        }
+        assert_eq!(runs.runs.len(), 50);
+
+        let pairs = runs.finish(&mut positions).unwrap();
+        assert_eq!(
+            pairs.iter().map(|(pair, _)| *pair).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 1), (1, 2), (2, 1), (2, 3)]
+        );
+        for (pair, pos) in pairs {
+            assert_eq!(positions.read(pos).unwrap(), expected[&pair]);
+        }
+        // The runs are removed once merged
+        assert_eq!(std::fs::read_dir(directory.path()).unwrap().count(), 1);
+
+        let stored = positions.store(vec![4, 2]).unwrap();
+        assert_eq!(
+            positions.read(stored).unwrap(),
+            [2, 4].iter().copied().collect()
+        );
+    }
+
+    #[test]
+    fn count_pairs_above_i32() {
+        let directory = tempfile::tempdir().unwrap();
+        let count = u64::from(u32::MAX);
+        let words = vec![("ab".to_owned(), count), ("ba".to_owned(), 1)];
+        write_shard(&words_path(directory.path()), words.into_iter().collect()).unwrap();
+
+        let (mut words, counts) = MmapWords::build(directory.path(), 8, |w| {
+            let mut word = Word::new();
+            for c in w.chars() {
+                word.add(c as u32, 1);
+            }
+            word
+        })
+        .unwrap();
+        let (pair_counts, _) = words.count_pairs(&counts, &None).unwrap();
+        assert_eq!(pair_counts[&('a' as u32, 'b' as u32)], count as i64);
+        assert_eq!(pair_counts[&('b' as u32, 'a' as u32)], 1);
+    }
+}
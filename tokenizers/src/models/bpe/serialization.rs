@@ -1,4 +1,5 @@
 use super::{super::OrderedVocabIter, convert_merges_to_hashmap, BpeBuilder, Pair, BPE};
+use crate::utils::cache::DEFAULT_CACHE_CAPACITY;
 use serde::{
     de::{Error, MapAccess, Visitor},
     ser::SerializeStruct,
@@ -16,12 +17,28 @@ impl Serialize for BPE {
         // Start by small fields
         model.serialize_field("type", "BPE")?;
         model.serialize_field("dropout", &self.dropout)?;
+        if let Some(seed) = self.dropout_seed {
+            model.serialize_field("dropout_seed", &seed)?;
+        } else {
+            model.skip_field("dropout_seed")?;
+        }
         model.serialize_field("unk_token", &self.unk_token)?;
         model.serialize_field("continuing_subword_prefix", &self.continuing_subword_prefix)?;
         model.serialize_field("end_of_word_suffix", &self.end_of_word_suffix)?;
         model.serialize_field("fuse_unk", &self.fuse_unk)?;
         model.serialize_field("byte_fallback", &self.byte_fallback)?;
         model.serialize_field("ignore_merges", &self.ignore_merges)?;
+        if self.whole_word_offsets {
+            model.serialize_field("whole_word_offsets", &self.whole_word_offsets)?;
+        } else {
+            model.skip_field("whole_word_offsets")?;
+        }
+        // The capacity of the cache is only kept when it was changed
+        if self.get_cache_capacity() != DEFAULT_CACHE_CAPACITY {
+            model.serialize_field("cache_capacity", &self.get_cache_capacity())?;
+        } else {
+            model.skip_field("cache_capacity")?;
+        }
 
         // Then the large ones
         let mut merges: Vec<(&Pair, &u32)> = self
@@ -53,12 +70,15 @@ impl<'de> Deserialize<'de> for BPE {
             &[
                 "type",
                 "dropout",
+                "dropout_seed",
                 "unk_token",
                 "continuing_subword_prefix",
                 "end_of_word_suffix",
                 "fuse_unk",
                 "byte_fallback",
                 "ignore_merges",
+                "whole_word_offsets",
+                "cache_capacity",
                 "vocab",
                 "merges",
             ],
@@ -89,6 +109,11 @@ impl<'de> Visitor<'de> for BPEVisitor {
                         builder = builder.dropout(dropout);
                     }
                 }
+                "dropout_seed" => {
+                    if let Some(seed) = map.next_value()? {
+                        builder = builder.dropout_seed(seed);
+                    }
+                }
                 "unk_token" => {
                     if let Some(unk) = map.next_value()? {
                         builder = builder.unk_token(unk);
@@ -119,6 +144,16 @@ impl<'de> Visitor<'de> for BPEVisitor {
                         builder = builder.ignore_merges(suffix);
                     }
                 }
+                "whole_word_offsets" => {
+                    if let Some(whole_word_offsets) = map.next_value()? {
+                        builder = builder.whole_word_offsets(whole_word_offsets);
+                    }
+                }
+                "cache_capacity" => {
+                    if let Some(capacity) = map.next_value()? {
+                        builder = builder.cache_capacity(capacity);
+                    }
+                }
                 "vocab" => vocab = Some(map.next_value()?),
                 "merges" => merges = Some(map.next_value()?),
                 "type" => match map.next_value()? {
@@ -188,4 +223,24 @@ mod test {
         let bpe_string = r#"{"type":"BPE","dropout":null,"unk_token":"<unk>","continuing_subword_prefix":null,"end_of_word_suffix":null,"fuse_unk":false,"byte_fallback":false,"vocab":{"<unk>":0,"a":1,"b":2},"merges":[]}"#;
         assert_eq!(serde_json::from_str::<BPE>(bpe_string).unwrap(), bpe);
     }
+
+    #[test]
+    fn test_serialization_dropout_seed() {
+        let vocab: Vocab = [("<unk>".into(), 0), ("a".into(), 1), ("b".into(), 2)]
+            .iter()
+            .cloned()
+            .collect();
+        let bpe = BpeBuilder::default()
+            .vocab_and_merges(vocab, vec![])
+            .unk_token("<unk>".to_string())
+            .dropout(0.1)
+            .dropout_seed(42)
+            .build()
+            .unwrap();
+
+        let bpe_string = r#"{"type":"BPE","dropout":0.1,"dropout_seed":42,"unk_token":"<unk>","continuing_subword_prefix":null,"end_of_word_suffix":null,"fuse_unk":false,"byte_fallback":false,"ignore_merges":false,"vocab":{"<unk>":0,"a":1,"b":2},"merges":[]}"#;
+        assert_eq!(serde_json::to_string(&bpe).unwrap(), bpe_string);
+        let reconstructed = serde_json::from_str::<BPE>(bpe_string).unwrap();
+        assert_eq!(reconstructed.dropout_seed, Some(42));
+    }
 }
@@ -0,0 +1,246 @@
+use super::{BpeBuilder, Error, Merges, Vocab, BPE};
+use crate::pre_tokenizers::byte_level::{ByteLevel, BYTES_CHAR};
+use crate::pre_tokenizers::sequence::Sequence;
+use crate::pre_tokenizers::split::{Split, SplitPattern};
+use crate::pre_tokenizers::PreTokenizerWrapper;
+use crate::tokenizer::{Result, SplitDelimiterBehavior};
+use crate::utils::iter::ResultShunt;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+/// The encodings released with [tiktoken](https://github.com/openai/tiktoken), used to
+/// retrieve the pre-tokenization that goes along with their rank files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TiktokenEncoding {
+    /// `r50k_base`, used by GPT-2 and GPT-3
+    R50kBase,
+    /// `p50k_base`, used by Codex
+    P50kBase,
+    /// `cl100k_base`, used by GPT-3.5 and GPT-4
+    Cl100kBase,
+    /// `o200k_base`, used by GPT-4o
+    O200kBase,
+}
+
+impl TiktokenEncoding {
+    /// The regex used by tiktoken to split the input before applying the merges.
+    pub fn pattern(&self) -> &'static str {
+        match self {
+            Self::R50kBase | Self::P50kBase => {
+                r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+(?!\S)|\s+"
+            }
+            Self::Cl100kBase => {
+                r"(?i:'s|'t|'re|'ve|'m|'ll|'d)|[^\r\n\p{L}\p{N}]?\p{L}+|\p{N}{1,3}| ?[^\s\p{L}\p{N}]+[\r\n]*|\s*[\r\n]+|\s+(?!\S)|\s+"
+            }
+            Self::O200kBase => concat!(
+                r"[^\r\n\p{L}\p{N}]?[\p{Lu}\p{Lt}\p{Lm}\p{Lo}\p{M}]*[\p{Ll}\p{Lm}\p{Lo}\p{M}]+(?i:'s|'t|'re|'ve|'m|'ll|'d)?",
+                r"|[^\r\n\p{L}\p{N}]?[\p{Lu}\p{Lt}\p{Lm}\p{Lo}\p{M}]+[\p{Ll}\p{Lm}\p{Lo}\p{M}]*(?i:'s|'t|'re|'ve|'m|'ll|'d)?",
+                r"|\p{N}{1,3}| ?[^\s\p{L}\p{N}]+[\r\n/]*|\s*[\r\n]+|\s+(?!\S)|\s+"
+            ),
+        }
+    }
+
+    /// Build the pre-tokenizer matching this encoding: a `Split` using its regex, followed
+    /// by a `ByteLevel` that only takes care of the byte to unicode mapping.
+    pub fn pre_tokenizer(&self) -> Result<PreTokenizerWrapper> {
+        let split = Split::new(
+            SplitPattern::Regex(self.pattern().to_owned()),
+            SplitDelimiterBehavior::Isolated,
+            false,
+        )?;
+        Ok(Sequence::new(vec![
+            split.into(),
+            ByteLevel::new(false, true, false).into(),
+        ])
+        .into())
+    }
+}
+
+/// Converts the lines of a tiktoken rank file, with the format "{base64_token} {rank}", into
+/// the vocab and merges expected by the BPE struct. Tokens are mapped to their `ByteLevel`
+/// representation, and the merges are rebuilt from the ranks.
+///
+/// Tiktoken merges any two adjacent parts whose concatenation has the lowest rank, so each way
+/// of splitting a token in two other tokens gives a merge, ranked along with this token.
+pub(crate) fn convert_tiktoken_ranks<I: Iterator<Item = String>>(
+    iter: I,
+) -> Result<(Vocab, Merges)> {
+    let mut ranks: HashMap<Vec<u8>, u32> = HashMap::new();
+    for (i, line) in iter.enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let parts = line.split(' ').collect::<Vec<_>>();
+        if parts.len() != 2 {
+            return Err(Error::BadTiktokenRanks(i + 1).into());
+        }
+        let token = STANDARD
+            .decode(parts[0])
+            .map_err(|_| Error::BadTiktokenRanks(i + 1))?;
+        let rank = parts[1]
+            .parse::<u32>()
+            .map_err(|_| Error::BadTiktokenRanks(i + 1))?;
+        ranks.insert(token, rank);
+    }
+
+    let mut tokens = ranks.iter().collect::<Vec<_>>();
+    tokens.sort_unstable_by_key(|(_, rank)| **rank);
+
+    let mut merges = vec![];
+    for (token, rank) in &tokens {
+        if token.len() < 2 {
+            continue;
+        }
+        let splits = (1..token.len())
+            .filter(|i| ranks.contains_key(&token[..*i]) && ranks.contains_key(&token[*i..]))
+            .map(|i| (to_byte_level(&token[..i]), to_byte_level(&token[i..])))
+            .collect::<Vec<_>>();
+        if splits.is_empty() {
+            warn!(
+                "Token `{}` with rank {} can't be reached by merging other tokens",
+                to_byte_level(token),
+                rank
+            );
+        }
+        merges.extend(splits);
+    }
+
+    let vocab = tokens
+        .into_iter()
+        .map(|(token, rank)| (to_byte_level(token), *rank))
+        .collect();
+
+    Ok((vocab, merges))
+}
+
+fn to_byte_level(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| BYTES_CHAR[b]).collect()
+}
+
+impl BPE {
+    /// Initialize a BpeBuilder model from a tiktoken rank file. Just like with tiktoken,
+    /// any word already part of the vocabulary is kept as is, without applying the merges, and
+    /// with the offsets of the whole word.
+    pub fn from_tiktoken_file(ranks: &str) -> BpeBuilder {
+        Self::builder()
+            .tiktoken_file(ranks.to_owned())
+            .ignore_merges(true)
+            .whole_word_offsets(true)
+    }
+
+    /// Read the given tiktoken rank file to extract the vocab and merges
+    pub fn read_tiktoken_file(ranks: &str) -> Result<(Vocab, Merges)> {
+        let ranks_file = File::open(ranks)?;
+        let ranks_file = BufReader::new(ranks_file);
+        ResultShunt::process(ranks_file.lines(), |iter| convert_tiktoken_ranks(iter))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoders::DecoderWrapper;
+    use crate::models::ModelWrapper;
+    use crate::tokenizer::{NormalizerWrapper, PostProcessorWrapper, Tokenizer, TokenizerImpl};
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn ranks_file() -> NamedTempFile {
+        let ranks: &[&[u8]] = &[
+            b"a", b"b", b"c", b" ", b"!", b"ab", b"abc", b" abc", b"bc", b"cab",
+        ];
+        let mut file = NamedTempFile::new().unwrap();
+        for (rank, token) in ranks.iter().enumerate() {
+            writeln!(file, "{} {}", STANDARD.encode(token), rank).unwrap();
+        }
+        file
+    }
+
+    #[test]
+    fn rebuild_merges() {
+        let file = ranks_file();
+        let (vocab, merges) = BPE::read_tiktoken_file(file.path().to_str().unwrap()).unwrap();
+
+        assert_eq!(vocab.len(), 10);
+        assert_eq!(vocab["Ġabc"], 7);
+        assert_eq!(
+            merges,
+            vec![
+                ("a".to_string(), "b".to_string()),
+                ("a".to_string(), "bc".to_string()),
+                ("ab".to_string(), "c".to_string()),
+                ("Ġ".to_string(), "abc".to_string()),
+                ("b".to_string(), "c".to_string()),
+                ("c".to_string(), "ab".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn unreachable_token() {
+        // No two tokens make up "abc", so only looking it up in the vocab can produce it
+        let ranks: &[&[u8]] = &[b"a", b"b", b"c", b" ", b"abc"];
+        let mut file = NamedTempFile::new().unwrap();
+        for (rank, token) in ranks.iter().enumerate() {
+            writeln!(file, "{} {}", STANDARD.encode(token), rank).unwrap();
+        }
+        let bpe = BPE::from_tiktoken_file(file.path().to_str().unwrap())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(bpe);
+        tokenizer.with_pre_tokenizer(TiktokenEncoding::Cl100kBase.pre_tokenizer().unwrap());
+
+        let encoding = tokenizer.encode("abc cab", false).unwrap();
+        assert_eq!(encoding.get_tokens(), &["abc", "Ġ", "c", "a", "b"]);
+        assert_eq!(
+            encoding.get_offsets(),
+            &[(0, 3), (3, 4), (4, 5), (5, 6), (6, 7)]
+        );
+    }
+
+    #[test]
+    fn bad_ranks() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "YQ== 0").unwrap();
+        writeln!(file, "YWI=").unwrap();
+        match BPE::read_tiktoken_file(file.path().to_str().unwrap()) {
+            Err(err) => assert_eq!(err.to_string(), "Tiktoken rank file invalid at line 2"),
+            Ok(_) => panic!("Expected an error"),
+        }
+    }
+
+    #[test]
+    fn tiktoken_tokenizer_roundtrip() {
+        let file = ranks_file();
+        let bpe = BPE::from_tiktoken_file(file.path().to_str().unwrap())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(bpe);
+        tokenizer.with_pre_tokenizer(TiktokenEncoding::Cl100kBase.pre_tokenizer().unwrap());
+        tokenizer.with_decoder(ByteLevel::default());
+
+        let encoding = tokenizer.encode("cabc abc!", false).unwrap();
+        assert_eq!(encoding.get_tokens(), &["c", "abc", "Ġabc", "!"]);
+        assert_eq!(encoding.get_offsets(), &[(0, 1), (1, 4), (4, 8), (8, 9)]);
+        assert_eq!(
+            tokenizer.decode(encoding.get_ids(), false).unwrap(),
+            "cabc abc!"
+        );
+
+        let serialized = tokenizer.to_string(false).unwrap();
+        let deserialized: TokenizerImpl<
+            ModelWrapper,
+            NormalizerWrapper,
+            PreTokenizerWrapper,
+            PostProcessorWrapper,
+            DecoderWrapper,
+        > = serialized.parse().unwrap();
+        let deserialized_encoding = deserialized.encode("cabc abc!", false).unwrap();
+        assert_eq!(deserialized_encoding.get_ids(), encoding.get_ids());
+        assert_eq!(deserialized_encoding.get_offsets(), encoding.get_offsets());
+    }
+}
@@ -1,30 +1,36 @@
 #![allow(clippy::map_entry)]
 
-use super::{Pair, WithFirstLastIterator, Word, BPE};
+use super::out_of_core::{merge_shards, words_path, MmapWords, OutOfCore, Shards};
+use super::{Error, MergeMap, Pair, WithFirstLastIterator, Word, BPE};
 use crate::parallelism::*;
 use crate::tokenizer::{AddedToken, Result, Trainer};
 use crate::utils::progress::{ProgressBar, ProgressStyle};
+use rand::thread_rng;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap, HashSet};
 
-#[derive(Debug, Eq)]
-struct Merge {
+/// The number of sequences processed at once when feeding the trainer in out-of-core mode
+const OUT_OF_CORE_FEED_CHUNK_SIZE: usize = 1024;
+
+#[derive(Debug)]
+struct Merge<P> {
     pair: Pair,
     count: u64,
-    pos: HashSet<usize>,
+    pos: P,
 }
-impl PartialEq for Merge {
+impl<P> PartialEq for Merge<P> {
     fn eq(&self, other: &Self) -> bool {
         self.count == other.count && self.pair == other.pair
     }
 }
-impl PartialOrd for Merge {
+impl<P> Eq for Merge<P> {}
+impl<P> PartialOrd for Merge<P> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
-impl Ord for Merge {
+impl<P> Ord for Merge<P> {
     fn cmp(&self, other: &Self) -> Ordering {
         if self.count != other.count {
             self.count.cmp(&other.count)
@@ -45,6 +51,8 @@ struct Config {
     continuing_subword_prefix: Option<String>,
     end_of_word_suffix: Option<String>,
     max_token_length: Option<usize>,
+    out_of_core: Option<OutOfCore>,
+    continue_training: bool,
 }
 
 /// A `BpeTrainerBuilder` can be used to create a `BpeTrainer` with a custom
@@ -66,6 +74,8 @@ impl Default for BpeTrainerBuilder {
                 continuing_subword_prefix: None,
                 end_of_word_suffix: None,
                 max_token_length: None,
+                out_of_core: None,
+                continue_training: false,
             },
         }
     }
@@ -139,6 +149,20 @@ impl BpeTrainerBuilder {
         self
     }
 
+    /// Set the out-of-core configuration, to train on disk instead of in memory
+    #[must_use]
+    pub fn out_of_core(mut self, out_of_core: OutOfCore) -> Self {
+        self.config.out_of_core = Some(out_of_core);
+        self
+    }
+
+    /// Set whether to continue the training of the model, instead of starting from scratch
+    #[must_use]
+    pub fn continue_training(mut self, continue_training: bool) -> Self {
+        self.config.continue_training = continue_training;
+        self
+    }
+
     /// Constructs the final BpeTrainer
     pub fn build(self) -> BpeTrainer {
         BpeTrainer {
@@ -151,7 +175,10 @@ impl BpeTrainerBuilder {
             continuing_subword_prefix: self.config.continuing_subword_prefix,
             end_of_word_suffix: self.config.end_of_word_suffix,
             max_token_length: self.config.max_token_length,
+            out_of_core: self.config.out_of_core,
+            continue_training: self.config.continue_training,
             words: HashMap::new(),
+            shards: None,
         }
     }
 }
@@ -194,8 +221,19 @@ pub struct BpeTrainer {
     pub end_of_word_suffix: Option<String>,
     /// An optional parameter to limit the max length of any single token
     pub max_token_length: Option<usize>,
+    /// An optional configuration to accumulate the words on disk, and compute the merges over
+    /// a memory-mapped representation of them, to train on larger corpora
+    #[serde(default)]
+    pub out_of_core: Option<OutOfCore>,
+    /// Whether to extend the vocab and merges of the trained model instead of replacing them.
+    /// Existing merges are applied to the words first, and new merges are learned on top of
+    /// them until reaching `vocab_size`, keeping every existing id and merge rank
+    #[serde(default)]
+    pub continue_training: bool,
 
     words: HashMap<String, u64>,
+    #[serde(skip)]
+    shards: Option<Shards>,
 }
 
 impl Default for BpeTrainer {
@@ -250,6 +288,32 @@ impl BpeTrainer {
         }
     }
 
+    /// Get the initial vocabulary and merges, from the model if we continue its training
+    fn initial_vocab(&self, model: &BPE) -> Result<(HashMap<String, u32>, Vec<String>, MergeMap)> {
+        if !self.continue_training {
+            return Ok((
+                HashMap::with_capacity(self.vocab_size),
+                Vec::with_capacity(self.vocab_size),
+                MergeMap::new(),
+            ));
+        }
+
+        // The existing tokens must be formed the same way as the new ones
+        if model.continuing_subword_prefix != self.continuing_subword_prefix
+            || model.end_of_word_suffix != self.end_of_word_suffix
+        {
+            return Err(Error::IncompatibleModel.into());
+        }
+
+        let len = model.vocab.values().max().map_or(0, |id| *id as usize + 1);
+        let mut id_to_word = Vec::with_capacity(self.vocab_size.max(len));
+        id_to_word.resize(len, String::new());
+        for (token, id) in &model.vocab {
+            id_to_word[*id as usize] = token.to_owned();
+        }
+        Ok((model.vocab.clone(), id_to_word, model.merges.clone()))
+    }
+
     /// Add the provided special tokens to the initial vocabulary
     fn add_special_tokens(&self, w2id: &mut HashMap<String, u32>, id2w: &mut Vec<String>) {
         for token in &self.special_tokens {
@@ -260,14 +324,8 @@ impl BpeTrainer {
         }
     }
 
-    /// Compute the initial alphabet and limit it if relevant
-    fn compute_alphabet(
-        &self,
-        wc: &HashMap<String, u64>,
-        w2id: &mut HashMap<String, u32>,
-        id2w: &mut Vec<String>,
-    ) {
-        // Compute the alphabet from seen words
+    /// Count the chars in the given words
+    fn count_chars(wc: &HashMap<String, u64>) -> HashMap<char, usize> {
         let mut alphabet: HashMap<char, usize> = HashMap::new();
         for (word, count) in wc {
             for c in word.chars() {
@@ -277,7 +335,17 @@ impl BpeTrainer {
                     .or_insert(*count as usize);
             }
         }
+        alphabet
+    }
 
+    /// Compute the initial alphabet from the count of chars in seen words, and limit it if
+    /// relevant
+    fn compute_alphabet(
+        &self,
+        mut alphabet: HashMap<char, usize>,
+        w2id: &mut HashMap<String, u32>,
+        id2w: &mut Vec<String>,
+    ) {
         // Also include anything from the provided initial alphabet
         for c in &self.initial_alphabet {
             alphabet
@@ -319,49 +387,67 @@ impl BpeTrainer {
         });
     }
 
+    /// Tokenize a word and add subwords to the vocabulary when relevant, then apply the
+    /// existing merges
+    fn tokenize_word(
+        &self,
+        word: &str,
+        w2id: &mut HashMap<String, u32>,
+        id2w: &mut Vec<String>,
+        merges: &MergeMap,
+    ) -> Word {
+        let mut current_word = Word::new();
+        for (is_first, is_last, c) in word.chars().with_first_and_last() {
+            let mut s = c.to_string();
+            if w2id.contains_key(&s) {
+                // Found the initial char in the authorized alphabet
+
+                // Add the `continuing_subword_prefix` if relevant
+                if !is_first {
+                    if let Some(prefix) = &self.continuing_subword_prefix {
+                        s = format!("{}{}", prefix, s);
+                    }
+                }
+                // Add the `end_of_word_suffix` if relevant
+                if is_last {
+                    if let Some(suffix) = &self.end_of_word_suffix {
+                        s = format!("{}{}", s, suffix);
+                    }
+                }
+
+                // Insert the new formed string if necessary
+                if !w2id.contains_key(&s) {
+                    id2w.push(s.clone());
+                    w2id.insert(s.clone(), (id2w.len() - 1) as u32);
+                }
+                current_word.add(w2id[&s], 1); // We do not care about the len here
+            }
+        }
+        if !merges.is_empty() {
+            current_word.merge_all(merges, None, &mut thread_rng());
+        }
+        current_word
+    }
+
     /// Tokenize words and add subwords to the vocabulary when relevant
     fn tokenize_words(
         &self,
         wc: &HashMap<String, u64>,
         w2id: &mut HashMap<String, u32>,
         id2w: &mut Vec<String>,
+        merges: &MergeMap,
         p: &Option<ProgressBar>,
     ) -> (Vec<Word>, Vec<u64>) {
         let mut words: Vec<Word> = Vec::with_capacity(wc.len());
         let mut counts: Vec<u64> = Vec::with_capacity(wc.len());
 
+        // Words are tokenized in order, so that new subwords always get the same ids
+        let mut wc = wc.iter().collect::<Vec<_>>();
+        wc.sort_unstable_by(|a, b| a.0.cmp(b.0));
         for (word, count) in wc {
-            let mut current_word = Word::new();
+            words.push(self.tokenize_word(word, w2id, id2w, merges));
             counts.push(*count);
 
-            for (is_first, is_last, c) in word.chars().with_first_and_last() {
-                let mut s = c.to_string();
-                if w2id.contains_key(&s) {
-                    // Found the initial char in the authorized alphabet
-
-                    // Add the `continuing_subword_prefix` if relevant
-                    if !is_first {
-                        if let Some(prefix) = &self.continuing_subword_prefix {
-                            s = format!("{}{}", prefix, s);
-                        }
-                    }
-                    // Add the `end_of_word_suffix` if relevant
-                    if is_last {
-                        if let Some(suffix) = &self.end_of_word_suffix {
-                            s = format!("{}{}", s, suffix);
-                        }
-                    }
-
-                    // Insert the new formed string if necessary
-                    if !w2id.contains_key(&s) {
-                        id2w.push(s.clone());
-                        w2id.insert(s.clone(), (id2w.len() - 1) as u32);
-                    }
-                    current_word.add(w2id[&s], 1); // We do not care about the len here
-                }
-            }
-            words.push(current_word);
-
             if let Some(p) = p {
                 p.inc(1);
             }
@@ -370,73 +456,121 @@ impl BpeTrainer {
         (words, counts)
     }
 
-    fn count_pairs(
+    pub fn do_train(
         &self,
-        words: &[Word],
-        counts: &[u64],
-        p: &Option<ProgressBar>,
-    ) -> (HashMap<Pair, i32>, HashMap<Pair, HashSet<usize>>) {
-        words
-            .maybe_par_iter()
-            .enumerate()
-            .map(|(i, word)| {
-                let mut pair_counts = HashMap::new();
-                let mut where_to_update: HashMap<Pair, HashSet<usize>> = HashMap::new();
+        word_counts: &HashMap<String, u64>,
+        model: &mut BPE,
+    ) -> Result<Vec<AddedToken>> {
+        let (mut word_to_id, mut id_to_word, existing_merges) = self.initial_vocab(model)?;
 
-                for window in word.get_chars().windows(2) {
-                    let cur_pair: Pair = (window[0], window[1]);
+        let progress = self.setup_progress();
 
-                    // Initialize pair_counts and where_to_update for this pair if we just saw it
-                    if !pair_counts.contains_key(&cur_pair) {
-                        pair_counts.insert(cur_pair, 0);
-                    }
+        //
+        // 1. Add all special tokens to the vocabulary
+        //
+        self.add_special_tokens(&mut word_to_id, &mut id_to_word);
 
-                    // Then update counts
-                    let count = counts[i];
-                    where_to_update
-                        .entry(cur_pair)
-                        .and_modify(|h| {
-                            h.insert(i);
-                        })
-                        .or_insert_with(|| {
-                            let mut h = HashSet::new();
-                            h.insert(i);
-                            h
-                        });
-                    *pair_counts.get_mut(&cur_pair).unwrap() += count as i32;
-                }
+        //
+        // 2. Compute the initial alphabet
+        //
+        self.compute_alphabet(
+            Self::count_chars(word_counts),
+            &mut word_to_id,
+            &mut id_to_word,
+        );
 
-                if let Some(p) = &p {
-                    p.inc(1);
-                }
+        //
+        // 3. Tokenize words
+        //
+        self.update_progress(&progress, word_counts.len(), "Tokenize words");
+        let (mut words, counts) = self.tokenize_words(
+            word_counts,
+            &mut word_to_id,
+            &mut id_to_word,
+            &existing_merges,
+            &progress,
+        );
+        self.finalize_progress(&progress, words.len());
 
-                (pair_counts, where_to_update)
-            })
-            .reduce(
-                || (HashMap::new(), HashMap::new()),
-                |(mut pair_counts, mut where_to_update), (pc, wtu)| {
-                    for (k, v) in pc {
-                        pair_counts.entry(k).and_modify(|c| *c += v).or_insert(v);
-                    }
-                    for (k, v) in wtu {
-                        where_to_update
-                            .entry(k)
-                            .and_modify(|set| *set = set.union(&v).copied().collect())
-                            .or_insert(v);
+        self.compute_merges(
+            &mut words,
+            &counts,
+            word_to_id,
+            id_to_word,
+            existing_merges,
+            &progress,
+            model,
+        )
+    }
+
+    /// Count the words in chunks of sequences, spilling them to sorted shards on disk whenever
+    /// there are too many of them to keep in memory
+    fn feed_out_of_core<I, S, F>(
+        &mut self,
+        out_of_core: &OutOfCore,
+        iterator: I,
+        process: F,
+    ) -> Result<()>
+    where
+        I: Iterator<Item = S> + Send,
+        S: AsRef<str> + Send,
+        F: Fn(&str) -> Result<Vec<String>> + Sync,
+    {
+        let mut shards = Shards::new(out_of_core)?;
+        let mut iterator = iterator;
+        let mut words: HashMap<String, u64> = HashMap::new();
+        loop {
+            let sequences = iterator
+                .by_ref()
+                .take(OUT_OF_CORE_FEED_CHUNK_SIZE)
+                .collect::<Vec<_>>();
+            if sequences.is_empty() {
+                break;
+            }
+
+            let chunk_words: Result<HashMap<String, u64>> = sequences
+                .into_maybe_par_iter()
+                .map(|sequence| {
+                    let mut map = HashMap::new();
+                    for word in process(sequence.as_ref())? {
+                        map.entry(word).and_modify(|c| *c += 1).or_insert(1);
                     }
-                    (pair_counts, where_to_update)
-                },
-            )
+                    Ok(map)
+                })
+                .reduce(
+                    || Ok(HashMap::new()),
+                    |acc, ws| {
+                        let mut acc = acc?;
+                        for (k, v) in ws? {
+                            acc.entry(k).and_modify(|c| *c += v).or_insert(v);
+                        }
+                        Ok(acc)
+                    },
+                );
+            for (k, v) in chunk_words? {
+                words.entry(k).and_modify(|c| *c += v).or_insert(v);
+            }
+
+            if words.len() > out_of_core.max_words_in_memory {
+                shards.spill(std::mem::take(&mut words))?;
+            }
+        }
+        if !words.is_empty() {
+            shards.spill(words)?;
+        }
+
+        // Feeding replaces anything previously fed
+        self.shards = Some(shards);
+        Ok(())
     }
 
-    pub fn do_train(
+    /// Train on the shards spilled while feeding, with the given out-of-core configuration
+    fn do_train_out_of_core(
         &self,
-        word_counts: &HashMap<String, u64>,
+        out_of_core: &OutOfCore,
         model: &mut BPE,
     ) -> Result<Vec<AddedToken>> {
-        let mut word_to_id: HashMap<String, u32> = HashMap::with_capacity(self.vocab_size);
-        let mut id_to_word: Vec<String> = Vec::with_capacity(self.vocab_size);
-        let max_token_length: usize = self.max_token_length.unwrap_or(usize::MAX);
+        let (mut word_to_id, mut id_to_word, existing_merges) = self.initial_vocab(model)?;
 
         let progress = self.setup_progress();
 
@@ -446,26 +580,70 @@ impl BpeTrainer {
         self.add_special_tokens(&mut word_to_id, &mut id_to_word);
 
         //
-        // 2. Compute the initial alphabet
+        // 2. Merge the shards, and compute the initial alphabet
         //
-        self.compute_alphabet(word_counts, &mut word_to_id, &mut id_to_word);
+        // The intermediate files are removed with this directory, whatever happens
+        let directory = out_of_core.temp_dir("bpe-train-")?;
+        let words_path = words_path(directory.path());
+        let shards = self
+            .shards
+            .as_ref()
+            .map_or(&[][..], |shards| shards.paths());
+        let (alphabet, n_words) = merge_shards(shards, out_of_core.min_word_count, &words_path)?;
+        self.compute_alphabet(alphabet, &mut word_to_id, &mut id_to_word);
 
         //
-        // 3. Tokenize words
+        // 3. Tokenize words, in the same order as in memory
         //
-        self.update_progress(&progress, word_counts.len(), "Tokenize words");
-        let (words, counts) =
-            self.tokenize_words(word_counts, &mut word_to_id, &mut id_to_word, &progress);
-        self.finalize_progress(&progress, words.len());
+        self.update_progress(&progress, n_words, "Tokenize words");
+        let (mut words, counts) =
+            MmapWords::build(directory.path(), out_of_core.max_pairs_in_memory, |word| {
+                if let Some(p) = &progress {
+                    p.inc(1);
+                }
+                self.tokenize_word(word, &mut word_to_id, &mut id_to_word, &existing_merges)
+            })?;
+        std::fs::remove_file(&words_path)?;
+        self.finalize_progress(&progress, n_words);
+
+        let special_tokens = self.compute_merges(
+            &mut words,
+            &counts,
+            word_to_id,
+            id_to_word,
+            existing_merges,
+            &progress,
+            model,
+        );
+        // The files must be unmapped before removing them
+        drop(words);
+        directory.close()?;
+        special_tokens
+    }
+
+    /// Compute the merges over the tokenized words, and transfer the resulting vocab to the
+    /// model
+    #[allow(clippy::too_many_arguments)]
+    fn compute_merges<W: WordStore>(
+        &self,
+        words: &mut W,
+        counts: &[u64],
+        mut word_to_id: HashMap<String, u32>,
+        mut id_to_word: Vec<String>,
+        existing_merges: MergeMap,
+        progress: &Option<ProgressBar>,
+        model: &mut BPE,
+    ) -> Result<Vec<AddedToken>> {
+        let max_token_length: usize = self.max_token_length.unwrap_or(usize::MAX);
 
         //
         // 4. Count pairs in words
         //
-        self.update_progress(&progress, words.len(), "Count pairs");
-        let (mut pair_counts, mut where_to_update) = self.count_pairs(&words, &counts, &progress);
+        self.update_progress(progress, words.len(), "Count pairs");
+        let (mut pair_counts, positions) = words.count_pairs(counts, progress)?;
         // Insert them in the queue
         let mut queue = BinaryHeap::with_capacity(pair_counts.len());
-        where_to_update.drain().for_each(|(pair, pos)| {
+        positions.into_iter().for_each(|(pair, pos)| {
             let count = pair_counts[&pair];
             if count > 0 {
                 queue.push(Merge {
@@ -475,12 +653,12 @@ impl BpeTrainer {
                 });
             }
         });
-        self.finalize_progress(&progress, words.len());
+        self.finalize_progress(progress, words.len());
 
         //
         // 5. Do merges
         //
-        self.update_progress(&progress, self.vocab_size, "Compute merges");
+        self.update_progress(progress, self.vocab_size, "Compute merges");
         let mut merges: Vec<(Pair, u32)> = vec![];
         loop {
             // Stop as soon as we have a big enough vocabulary
@@ -530,27 +708,12 @@ impl BpeTrainer {
             merges.push((top.pair, new_token_id));
 
             // Merge the new pair in every words
-            let changes = top
-                .pos
-                .maybe_par_iter()
-                .flat_map(|&i| {
-                    let word = &words[i] as *const _ as *mut Word;
-                    // We can merge each of these words in parallel here because each position
-                    // can be there only once (HashSet). So this is safe.
-                    unsafe {
-                        // let word: &mut Word = &mut (*word);
-                        (*word)
-                            .merge(top.pair.0, top.pair.1, new_token_id, max_token_length)
-                            .into_iter()
-                            .map(|c| (c, i))
-                            .collect::<Vec<_>>()
-                    }
-                })
-                .collect::<Vec<_>>();
+            let changes = words.merge(top.pos, top.pair, new_token_id, max_token_length)?;
 
             // Introduce new formed pairs
+            let mut where_to_update: HashMap<Pair, HashSet<usize>> = HashMap::new();
             for ((pair, change), iw) in changes {
-                let count = change * counts[iw] as i32;
+                let count = i64::from(change) * counts[iw] as i64;
                 pair_counts
                     .entry(pair)
                     .and_modify(|c| *c += count)
@@ -568,22 +731,22 @@ impl BpeTrainer {
                         });
                 }
             }
-            where_to_update.drain().for_each(|(pair, pos)| {
+            for (pair, pos) in where_to_update {
                 let count = pair_counts[&pair];
                 if count > 0 {
                     queue.push(Merge {
                         pair,
                         count: count as u64,
-                        pos,
+                        pos: words.store(pos)?,
                     });
                 }
-            });
+            }
 
-            if let Some(p) = &progress {
+            if let Some(p) = progress {
                 p.inc(1);
             }
         }
-        self.finalize_progress(&progress, merges.len());
+        self.finalize_progress(progress, merges.len());
 
         // Transfer new vocab & options to model
         model.vocab = word_to_id;
@@ -592,11 +755,24 @@ impl BpeTrainer {
             .iter()
             .map(|(key, val)| (*val, key.to_owned()))
             .collect();
-        model.merges = merges
+        // New merges are ranked after the existing ones, which we keep as they are
+        let offset = existing_merges
+            .values()
+            .map(|(rank, _)| rank + 1)
+            .max()
+            .unwrap_or(0);
+        let merges = merges
             .into_iter()
-            .enumerate()
-            .map(|(i, (pair, new_token_id))| (pair, (i as u32, new_token_id)))
-            .collect();
+            .filter(|(pair, _)| !existing_merges.contains_key(pair))
+            .collect::<Vec<_>>();
+        model.merges = existing_merges;
+        model.merges.extend(
+            merges
+                .into_iter()
+                .enumerate()
+                .map(|(i, (pair, new_token_id))| (pair, (offset + i as u32, new_token_id))),
+        );
+        model.clear_cache();
 
         if let Some(prefix) = &self.continuing_subword_prefix {
             model.continuing_subword_prefix = Some(prefix.to_owned());
@@ -613,12 +789,139 @@ impl BpeTrainer {
     }
 }
 
+/// The words being merged by the `BpeTrainer`
+pub(super) trait WordStore {
+    /// The positions of a pair, as the indices of the words containing it
+    type Positions;
+
+    /// The number of words
+    fn len(&self) -> usize;
+    /// Count the pairs in the words, weighted by the given counts of the words. Returns the
+    /// count of each pair, along with its positions.
+    #[allow(clippy::type_complexity)]
+    fn count_pairs(
+        &mut self,
+        counts: &[u64],
+        p: &Option<ProgressBar>,
+    ) -> Result<(HashMap<Pair, i64>, Vec<(Pair, Self::Positions)>)>;
+    /// Store the given positions of a pair
+    fn store(&mut self, pos: HashSet<usize>) -> Result<Self::Positions>;
+    /// Merge the given pair in each word at the given positions. Returns the changes to the pair
+    /// counts, along with the index of the word they come from.
+    fn merge(
+        &mut self,
+        pos: Self::Positions,
+        pair: Pair,
+        new_id: u32,
+        max_length: usize,
+    ) -> Result<Vec<((Pair, i32), usize)>>;
+}
+
+impl WordStore for Vec<Word> {
+    type Positions = HashSet<usize>;
+
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn count_pairs(
+        &mut self,
+        counts: &[u64],
+        p: &Option<ProgressBar>,
+    ) -> Result<(HashMap<Pair, i64>, Vec<(Pair, HashSet<usize>)>)> {
+        let words: &Self = self;
+        let (pair_counts, where_to_update) = (0..words.len())
+            .into_maybe_par_iter()
+            .map(|i| {
+                let mut pair_counts = HashMap::new();
+                let mut where_to_update: HashMap<Pair, HashSet<usize>> = HashMap::new();
+
+                for window in words[i].get_chars().windows(2) {
+                    let cur_pair: Pair = (window[0], window[1]);
+
+                    // Initialize pair_counts and where_to_update for this pair if we just saw it
+                    if !pair_counts.contains_key(&cur_pair) {
+                        pair_counts.insert(cur_pair, 0);
+                    }
+
+                    // Then update counts
+                    let count = counts[i];
+                    where_to_update
+                        .entry(cur_pair)
+                        .and_modify(|h| {
+                            h.insert(i);
+                        })
+                        .or_insert_with(|| {
+                            let mut h = HashSet::new();
+                            h.insert(i);
+                            h
+                        });
+                    *pair_counts.get_mut(&cur_pair).unwrap() += count as i64;
+                }
+
+                if let Some(p) = &p {
+                    p.inc(1);
+                }
+
+                (pair_counts, where_to_update)
+            })
+            .reduce(
+                || (HashMap::new(), HashMap::new()),
+                |(mut pair_counts, mut where_to_update), (pc, wtu)| {
+                    for (k, v) in pc {
+                        pair_counts.entry(k).and_modify(|c| *c += v).or_insert(v);
+                    }
+                    for (k, v) in wtu {
+                        where_to_update
+                            .entry(k)
+                            .and_modify(|set| *set = set.union(&v).copied().collect())
+                            .or_insert(v);
+                    }
+                    (pair_counts, where_to_update)
+                },
+            );
+        Ok((pair_counts, where_to_update.into_iter().collect()))
+    }
+
+    fn store(&mut self, pos: HashSet<usize>) -> Result<HashSet<usize>> {
+        Ok(pos)
+    }
+
+    fn merge(
+        &mut self,
+        pos: HashSet<usize>,
+        pair: Pair,
+        new_id: u32,
+        max_length: usize,
+    ) -> Result<Vec<((Pair, i32), usize)>> {
+        let words: &Self = self;
+        Ok(pos
+            .maybe_par_iter()
+            .flat_map(|&i| {
+                let word = &words[i] as *const _ as *mut Word;
+                // We can merge each of these words in parallel here because each position
+                // can be there only once (HashSet). So this is safe.
+                unsafe {
+                    (*word)
+                        .merge(pair.0, pair.1, new_id, max_length)
+                        .into_iter()
+                        .map(|c| (c, i))
+                        .collect::<Vec<_>>()
+                }
+            })
+            .collect())
+    }
+}
+
 impl Trainer for BpeTrainer {
     type Model = BPE;
 
     /// Train a BPE model
     fn train(&self, model: &mut BPE) -> Result<Vec<AddedToken>> {
-        self.do_train(&self.words, model)
+        match &self.out_of_core {
+            Some(out_of_core) => self.do_train_out_of_core(out_of_core, model),
+            None => self.do_train(&self.words, model),
+        }
     }
 
     /// Whether we should show progress
@@ -632,6 +935,10 @@ impl Trainer for BpeTrainer {
         S: AsRef<str> + Send,
         F: Fn(&str) -> Result<Vec<String>> + Sync,
     {
+        if let Some(out_of_core) = self.out_of_core.clone() {
+            return self.feed_out_of_core(&out_of_core, iterator, process);
+        }
+
         let words: Result<HashMap<String, u64>> = iterator
             .maybe_par_bridge()
             .map(|sequence| {
@@ -660,7 +967,8 @@ impl Trainer for BpeTrainer {
 
 #[cfg(test)]
 mod tests {
-    use super::{BpeTrainer, Pair, BPE};
+    use super::{BpeTrainer, OutOfCore, Pair, BPE};
+    use crate::tokenizer::Trainer;
     use std::collections::HashMap;
 
     #[test]
@@ -847,4 +1155,214 @@ mod tests {
         .collect();
         assert_eq!(trained_vocab, expected_vocab)
     }
+
+    #[test]
+    fn test_train_large_counts() {
+        // The pair counts do not overflow past `i32::MAX`
+        let word_counts: HashMap<String, u64> =
+            vec![("ab".into(), 3_000_000_000), ("cd".into(), 5)]
+                .into_iter()
+                .collect();
+        let trainer = BpeTrainer::builder()
+            .show_progress(false)
+            .vocab_size(5)
+            .build();
+        let mut model = BPE::default();
+        trainer.do_train(&word_counts, &mut model).unwrap();
+        assert_eq!(model.merges.len(), 1);
+        assert!(model.vocab.contains_key("ab"));
+    }
+
+    #[test]
+    fn test_train_out_of_core() {
+        let sequences = [
+            "roses are red voilets are blue",
+            "BERT is big and so is GPT-2",
+            "the red roses and the blue voilets are big",
+            "so big and so red",
+            "is it GPT-2 or BERT",
+        ];
+        let feed = |trainer: &mut BpeTrainer| {
+            trainer
+                .feed(sequences.iter(), |s| {
+                    Ok(s.split(' ').map(|w| w.to_owned()).collect())
+                })
+                .unwrap();
+        };
+        let builder = || {
+            BpeTrainer::builder()
+                .show_progress(false)
+                .vocab_size(60)
+                .max_token_length(Some(4))
+                .continuing_subword_prefix("##".into())
+                .end_of_word_suffix("</w>".into())
+        };
+
+        let mut trainer = builder().build();
+        feed(&mut trainer);
+        let mut model = BPE::default();
+        trainer.train(&mut model).unwrap();
+
+        let directory = tempfile::tempdir().unwrap();
+        let mut out_of_core = OutOfCore::new(directory.path());
+        out_of_core.max_words_in_memory = 3;
+        // Spill the positions of the pairs every few words
+        out_of_core.max_pairs_in_memory = 8;
+        let mut trainer = builder().out_of_core(out_of_core.clone()).build();
+        feed(&mut trainer);
+        // A shard was spilled for each chunk of sequences with too many words
+        assert_eq!(trainer.shards.as_ref().unwrap().paths().len(), 1);
+
+        // Another trainer using the same directory does not collide with the first one
+        let mut other = builder().out_of_core(out_of_core).build();
+        feed(&mut other);
+        let mut other_model = BPE::default();
+        other.train(&mut other_model).unwrap();
+        drop(other);
+
+        let mut out_of_core_model = BPE::default();
+        trainer.train(&mut out_of_core_model).unwrap();
+
+        assert_eq!(out_of_core_model.vocab, model.vocab);
+        assert_eq!(out_of_core_model.merges, model.merges);
+        assert_eq!(other_model.vocab, model.vocab);
+        assert_eq!(other_model.merges, model.merges);
+
+        // Only the shards are left once trained, and nothing once the trainer is dropped
+        let entries = || std::fs::read_dir(directory.path()).unwrap().count();
+        assert_eq!(entries(), 1);
+        drop(trainer);
+        assert_eq!(entries(), 0);
+    }
+
+    #[test]
+    fn test_train_out_of_core_shards() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut out_of_core = OutOfCore::new(directory.path());
+        out_of_core.max_words_in_memory = 2;
+        out_of_core.min_word_count = 2;
+        let mut trainer = BpeTrainer::builder()
+            .show_progress(false)
+            .out_of_core(out_of_core)
+            .build();
+
+        // Each shard contains the words of a single chunk, so we feed enough sequences to
+        // spill several of them, with words spread across shards
+        let sequences = (0..3000)
+            .map(|i| format!("hug pug{} hugs", i % 3000 / 1000))
+            .collect::<Vec<_>>();
+        trainer
+            .feed(sequences.iter(), |s| {
+                Ok(s.split(' ').map(|w| w.to_owned()).collect())
+            })
+            .unwrap();
+        assert_eq!(trainer.shards.as_ref().unwrap().paths().len(), 3);
+
+        let mut model = BPE::default();
+        trainer.train(&mut model).unwrap();
+
+        let word_counts: HashMap<String, u64> = [
+            ("hug".into(), 3000),
+            ("hugs".into(), 3000),
+            ("pug0".into(), 1000),
+            ("pug1".into(), 1000),
+            ("pug2".into(), 1000),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        let trainer = BpeTrainer::builder().show_progress(false).build();
+        let mut expected = BPE::default();
+        trainer.do_train(&word_counts, &mut expected).unwrap();
+        assert_eq!(model.vocab, expected.vocab);
+        assert_eq!(model.merges, expected.merges);
+
+        // Words seen less than `min_word_count` times in the whole corpus are pruned
+        let directory = tempfile::tempdir().unwrap();
+        let mut out_of_core = OutOfCore::new(directory.path());
+        out_of_core.max_words_in_memory = 2;
+        out_of_core.min_word_count = 2;
+        let mut trainer = BpeTrainer::builder()
+            .show_progress(false)
+            .out_of_core(out_of_core)
+            .build();
+        trainer
+            .feed(["hug hugs", "hug pug"].iter(), |s| {
+                Ok(s.split(' ').map(|w| w.to_owned()).collect())
+            })
+            .unwrap();
+        let mut model = BPE::default();
+        trainer.train(&mut model).unwrap();
+        assert!(model.vocab.contains_key("h"));
+        assert!(!model.vocab.contains_key("p"));
+        assert!(!model.vocab.contains_key("s"));
+    }
+
+    #[test]
+    fn test_train_continuation() {
+        let word_counts: HashMap<String, u64> = [("hug".into(), 10), ("hugs".into(), 5)]
+            .iter()
+            .cloned()
+            .collect();
+        let trainer = BpeTrainer::builder()
+            .show_progress(false)
+            .vocab_size(6)
+            .build();
+        let mut model = BPE::default();
+        trainer.do_train(&word_counts, &mut model).unwrap();
+        let base = model.clone();
+        assert_eq!(base.vocab.len(), 6);
+
+        let domain_counts: HashMap<String, u64> =
+            [("hugs".into(), 10), ("pug".into(), 8), ("pugs".into(), 8)]
+                .iter()
+                .cloned()
+                .collect();
+        let trainer = BpeTrainer::builder()
+            .show_progress(false)
+            .vocab_size(10)
+            .continue_training(true)
+            .build();
+        trainer.do_train(&domain_counts, &mut model).unwrap();
+
+        // Every existing token and merge is kept as is
+        assert_eq!(model.vocab.len(), 10);
+        for (token, id) in &base.vocab {
+            assert_eq!(model.vocab[token], *id);
+        }
+        for (pair, rank_and_id) in &base.merges {
+            assert_eq!(model.merges[pair], *rank_and_id);
+        }
+        // New merges are learned on top of the existing ones, and appended
+        let mut new_merges = model
+            .merges
+            .iter()
+            .filter(|(pair, _)| !base.merges.contains_key(pair))
+            .map(|(pair, (rank, id))| {
+                (
+                    *rank,
+                    model.vocab_r[&pair.0].clone(),
+                    model.vocab_r[&pair.1].clone(),
+                    model.vocab_r[id].clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        new_merges.sort();
+        assert_eq!(
+            new_merges,
+            vec![
+                (2, "u".into(), "g".into(), "ug".into()),
+                (3, "p".into(), "ug".into(), "pug".into()),
+                (4, "hug".into(), "s".into(), "hugs".into()),
+            ]
+        );
+
+        // The tokens of the model being extended must be formed the same way
+        let trainer = BpeTrainer::builder()
+            .show_progress(false)
+            .continuing_subword_prefix("##".into())
+            .continue_training(true)
+            .build();
+        assert!(trainer.do_train(&domain_counts, &mut model).is_err());
+    }
 }
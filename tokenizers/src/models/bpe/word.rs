@@ -158,7 +158,35 @@ impl Word {
         changes
     }
 
-    pub(super) fn merge_all(&mut self, merges: &HashMap<Pair, (u32, u32)>, dropout: Option<f32>) {
+    pub(super) fn merge_all<R: Rng + ?Sized>(
+        &mut self,
+        merges: &HashMap<Pair, (u32, u32)>,
+        dropout: Option<f32>,
+        rng: &mut R,
+    ) {
+        self.apply_merges(merges, &[], dropout, rng, None);
+    }
+
+    /// Apply all the merges, except those whose rank is in `skipped`, and return the ranks of
+    /// the merges that got applied.
+    pub(super) fn merge_all_except(
+        &mut self,
+        merges: &HashMap<Pair, (u32, u32)>,
+        skipped: &[u32],
+    ) -> Vec<u32> {
+        let mut applied = vec![];
+        self.apply_merges(merges, skipped, None, &mut thread_rng(), Some(&mut applied));
+        applied
+    }
+
+    fn apply_merges<R: Rng + ?Sized>(
+        &mut self,
+        merges: &HashMap<Pair, (u32, u32)>,
+        skipped: &[u32],
+        dropout: Option<f32>,
+        rng: &mut R,
+        mut applied: Option<&mut Vec<u32>>,
+    ) {
         let mut queue = BinaryHeap::with_capacity(self.symbols.len());
         let mut skip = Vec::with_capacity(queue.len());
 
@@ -177,10 +205,10 @@ impl Word {
         );
 
         while let Some(top) = queue.pop() {
-            if dropout
-                .map(|d| thread_rng().gen::<f32>() < d)
-                .unwrap_or(false)
-            {
+            if skipped.contains(&top.rank) {
+                continue;
+            }
+            if dropout.map(|d| rng.gen::<f32>() < d).unwrap_or(false) {
                 skip.push(top);
             } else {
                 // Re-insert the skipped elements
@@ -208,6 +236,9 @@ impl Word {
 
                 // Otherwise, let's merge
                 self.symbols[top.pos].merge_with(&right, top.new_id);
+                if let Some(applied) = applied.as_mut() {
+                    applied.push(top.rank);
+                }
                 // Tag the right part as removed
                 self.symbols[next_pos].len = 0;
 
@@ -0,0 +1,225 @@
+//! A vocabulary-free model, mapping each byte of the input to its own id, as used by
+//! [ByT5](https://arxiv.org/abs/2105.13626).
+
+use crate::tokenizer::{Model, Result, Token};
+use crate::utils::macro_rules_attribute;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+
+mod trainer;
+pub use trainer::*;
+
+/// The number of ids used by the bytes
+const N_BYTES: u32 = 256;
+
+/// The token representing the given byte: the char with the same code point.
+fn byte_to_token(byte: u8) -> String {
+    char::from(byte).to_string()
+}
+
+/// The byte represented by the given token, if it is a single char with a code point below 256.
+pub(crate) fn token_to_byte(token: &str) -> Option<u8> {
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => u8::try_from(c).ok(),
+        _ => None,
+    }
+}
+
+/// A `ByteModel` tokenizes any input into its UTF-8 bytes, without any vocabulary. The
+/// first ids are reserved for the special tokens, and each byte `b` then gets the id
+/// `special_tokens.len() + b`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[macro_rules_attribute(impl_serde_type!)]
+pub struct ByteModel {
+    /// The tokens using the first ids, before the bytes
+    pub special_tokens: Vec<String>,
+}
+
+impl Default for ByteModel {
+    /// The special tokens used by ByT5
+    fn default() -> Self {
+        Self::new(vec!["<pad>".into(), "</s>".into(), "<unk>".into()])
+    }
+}
+
+impl ByteModel {
+    pub fn new(special_tokens: Vec<String>) -> Self {
+        Self { special_tokens }
+    }
+
+    fn offset(&self) -> u32 {
+        self.special_tokens.len() as u32
+    }
+}
+
+impl Model for ByteModel {
+    type Trainer = ByteModelTrainer;
+
+    fn tokenize(&self, sequence: &str) -> Result<Vec<Token>> {
+        Ok(sequence
+            .bytes()
+            .enumerate()
+            .map(|(i, byte)| {
+                Token::new(self.offset() + byte as u32, byte_to_token(byte), (i, i + 1))
+            })
+            .collect())
+    }
+
+    fn count_tokens(&self, sequence: &str) -> Result<usize> {
+        Ok(sequence.len())
+    }
+
+    /// Only the special tokens have an id: the tokens of the bytes, like `é` for the byte
+    /// `0xE9`, are only produced by `tokenize`, so that an added token like `é` never gets the id
+    /// of a byte, which would then be decoded as this token.
+    fn token_to_id(&self, token: &str) -> Option<u32> {
+        self.special_tokens
+            .iter()
+            .position(|t| t == token)
+            .map(|id| id as u32)
+    }
+
+    fn id_to_token(&self, id: u32) -> Option<String> {
+        if id < self.offset() {
+            Some(self.special_tokens[id as usize].clone())
+        } else if id < self.offset() + N_BYTES {
+            Some(byte_to_token((id - self.offset()) as u8))
+        } else {
+            None
+        }
+    }
+
+    fn get_vocab(&self) -> HashMap<String, u32> {
+        (0..self.get_vocab_size() as u32)
+            .filter_map(|id| self.id_to_token(id).map(|token| (token, id)))
+            .collect()
+    }
+
+    fn get_vocab_size(&self) -> usize {
+        (self.offset() + N_BYTES) as usize
+    }
+
+    fn save(&self, folder: &Path, name: Option<&str>) -> Result<Vec<PathBuf>> {
+        let name = match name {
+            Some(name) => format!("{}-byte.json", name),
+            None => "byte.json".to_string(),
+        };
+        let mut fullpath = PathBuf::new();
+        fullpath.push(folder);
+        fullpath.push(name);
+        let string = serde_json::to_string_pretty(self)?;
+        std::fs::write(&fullpath, string)?;
+        Ok(vec![fullpath])
+    }
+
+    fn get_trainer(&self) -> Self::Trainer {
+        ByteModelTrainer::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize() {
+        let model = ByteModel::default();
+        assert_eq!(
+            model.tokenize("aé").unwrap(),
+            vec![
+                Token::new(100, "a".into(), (0, 1)),
+                Token::new(198, "Ã".into(), (1, 2)),
+                Token::new(172, "©".into(), (2, 3)),
+            ]
+        );
+        assert_eq!(model.token_to_id("</s>"), Some(1));
+        assert_eq!(model.token_to_id("a"), None);
+        assert_eq!(model.token_to_id("ab"), None);
+        assert_eq!(model.token_to_id("€"), None);
+        assert_eq!(model.id_to_token(2), Some("<unk>".into()));
+        assert_eq!(model.id_to_token(258), Some("ÿ".into()));
+        assert_eq!(model.id_to_token(259), None);
+        assert_eq!(model.get_vocab_size(), 259);
+        assert_eq!(model.get_vocab().len(), 259);
+    }
+
+    #[test]
+    fn serialization() {
+        let model = ByteModel::new(vec!["<pad>".into()]);
+        let serialized = serde_json::to_string(&model).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"type":"ByteModel","special_tokens":["<pad>"]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<ByteModel>(&serialized).unwrap(),
+            model
+        );
+    }
+
+    #[test]
+    fn tokenizer_roundtrip() {
+        use crate::decoders::byte::ByteDecoder;
+        use crate::decoders::DecoderWrapper;
+        use crate::models::ModelWrapper;
+        use crate::normalizers::NormalizerWrapper;
+        use crate::pre_tokenizers::PreTokenizerWrapper;
+        use crate::processors::PostProcessorWrapper;
+        use crate::{AddedToken, Tokenizer, TokenizerImpl};
+
+        let mut tokenizer = Tokenizer::new(ByteModel::default());
+        tokenizer.with_decoder(ByteDecoder::default());
+        tokenizer.add_special_tokens(&[AddedToken::from("</s>", true)]);
+
+        // Each byte points to the whole char it belongs to
+        let encoding = tokenizer.encode("aé</s>", false).unwrap();
+        assert_eq!(encoding.get_ids(), &[100, 198, 172, 1]);
+        assert_eq!(encoding.get_offsets(), &[(0, 1), (1, 3), (1, 3), (3, 7)]);
+        let encoding = tokenizer.encode_char_offsets("aé</s>", false).unwrap();
+        assert_eq!(encoding.get_offsets(), &[(0, 1), (1, 2), (1, 2), (2, 6)]);
+
+        assert_eq!(tokenizer.decode(encoding.get_ids(), true).unwrap(), "aé");
+        assert_eq!(tokenizer.decode(&[100, 198], true).unwrap(), "a�");
+
+        let serialized = tokenizer.to_string(false).unwrap();
+        let deserialized: TokenizerImpl<
+            ModelWrapper,
+            NormalizerWrapper,
+            PreTokenizerWrapper,
+            PostProcessorWrapper,
+            DecoderWrapper,
+        > = serialized.parse().unwrap();
+        assert!(matches!(
+            deserialized.get_model(),
+            ModelWrapper::ByteModel(_)
+        ));
+        assert_eq!(
+            deserialized.decode(encoding.get_ids(), false).unwrap(),
+            "aé</s>"
+        );
+    }
+
+    #[test]
+    fn latin1_added_tokens() {
+        use crate::decoders::byte::ByteDecoder;
+        use crate::{AddedToken, Tokenizer};
+
+        let mut tokenizer = Tokenizer::new(ByteModel::default());
+        tokenizer.with_decoder(ByteDecoder::default());
+        tokenizer.add_tokens(&[AddedToken::from("é", false), AddedToken::from("£", false)]);
+        assert_eq!(tokenizer.token_to_id("é"), Some(259));
+        assert_eq!(tokenizer.token_to_id("£"), Some(260));
+
+        // `阿` is made of the bytes `0xE9 0x98 0xBF`, and `¢` of `0xC2 0xA2`
+        let text = "é阿£¢";
+        let encoding = tokenizer.encode(text, false).unwrap();
+        assert_eq!(
+            encoding.get_ids(),
+            &[259, 0xE9 + 3, 0x98 + 3, 0xBF + 3, 260, 0xC2 + 3, 0xA2 + 3]
+        );
+        assert_eq!(tokenizer.decode(encoding.get_ids(), false).unwrap(), text);
+    }
+}
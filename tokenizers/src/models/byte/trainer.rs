@@ -0,0 +1,34 @@
+use super::ByteModel;
+use crate::{AddedToken, Result, Trainer};
+use serde::{Deserialize, Serialize};
+
+/// A `ByteModel` has no vocabulary to learn, so training it does nothing besides making
+/// sure its special tokens get added to the tokenizer.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ByteModelTrainer {}
+
+impl Trainer for ByteModelTrainer {
+    type Model = ByteModel;
+
+    fn should_show_progress(&self) -> bool {
+        false
+    }
+
+    fn train(&self, model: &mut ByteModel) -> Result<Vec<AddedToken>> {
+        Ok(model
+            .special_tokens
+            .iter()
+            .map(|token| AddedToken::from(token.clone(), true))
+            .collect())
+    }
+
+    fn feed<I, S, F>(&mut self, _iterator: I, _process: F) -> Result<()>
+    where
+        I: Iterator<Item = S> + Send,
+        S: AsRef<str> + Send,
+        F: Fn(&str) -> Result<Vec<String>> + Sync,
+    {
+        Ok(())
+    }
+}
@@ -0,0 +1,277 @@
+//! Merge of the vocabularies of two models of the same type.
+//!
+//! The tokens of the base model keep their id, and the tokens of the other model that are not
+//! part of it are appended after the last id in use. The [`VocabMergeReport`] gives the id of
+//! each token of the other model in the merged one.
+use super::ModelWrapper;
+use crate::tokenizer::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(thiserror::Error, Debug)]
+pub enum VocabMergeError {
+    #[error("Cannot merge the vocabularies of a {0} and a {1}")]
+    IncompatibleModels(&'static str, &'static str),
+    #[error("Cannot merge the vocabularies of models using different `{0}`")]
+    IncompatibleOption(&'static str),
+    #[error("Cannot merge a vocabulary without any token for the id {0}")]
+    MissingId(u32),
+}
+
+/// A token being part of both vocabularies, with different ids. The id of the base
+/// vocabulary is the one kept.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VocabConflict {
+    pub token: String,
+    pub base_id: u32,
+    pub other_id: u32,
+}
+
+/// What happened to the tokens of the other vocabulary during a merge
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct VocabMergeReport {
+    /// The id of each token of the other vocabulary, in the merged one
+    pub ids: HashMap<u32, u32>,
+    /// The tokens that were not part of the base vocabulary, along with their new id
+    pub added: Vec<(String, u32)>,
+    pub conflicts: Vec<VocabConflict>,
+}
+
+impl VocabMergeReport {
+    /// Register the id of a token of the other vocabulary, and whether it was added
+    pub(crate) fn register(&mut self, token: &str, other_id: u32, id: u32, added: bool) {
+        self.ids.insert(other_id, id);
+        if added {
+            self.added.push((token.to_owned(), id));
+        } else if id != other_id {
+            self.conflicts.push(VocabConflict {
+                token: token.to_owned(),
+                base_id: id,
+                other_id,
+            });
+        }
+    }
+}
+
+/// Add the tokens of the other vocabulary to the base one, in the order of their ids, after the
+/// last id in use. The reserved tokens are left out of the merged vocabulary, but their ids are
+/// never given to the new tokens, and the tokens of the other vocabulary matching them get them.
+pub(crate) fn merge_vocabs<'a, I>(
+    base: &HashMap<String, u32>,
+    reserved: &HashMap<u32, String>,
+    other: I,
+) -> (HashMap<String, u32>, VocabMergeReport)
+where
+    I: IntoIterator<Item = (u32, &'a str)>,
+{
+    let mut other = other.into_iter().collect::<Vec<_>>();
+    other.sort_unstable_by_key(|(id, _)| *id);
+
+    let reserved_ids = reserved
+        .iter()
+        .map(|(id, token)| (token.as_str(), *id))
+        .collect::<HashMap<_, _>>();
+    let mut vocab = base.clone();
+    let mut report = VocabMergeReport::default();
+    let mut next_id = vocab
+        .values()
+        .chain(reserved.keys())
+        .max()
+        .map_or(0, |id| id + 1);
+    for (other_id, token) in other {
+        match vocab.get(token).or_else(|| reserved_ids.get(token)) {
+            Some(id) => report.register(token, other_id, *id, false),
+            None => {
+                vocab.insert(token.to_owned(), next_id);
+                report.register(token, other_id, next_id, true);
+                next_id += 1;
+            }
+        }
+    }
+    (vocab, report)
+}
+
+impl ModelWrapper {
+    /// Merge the vocabulary of the other model, of the same type, into this one. The ids in
+    /// `reserved` are used outside of this model, like by added tokens: the merged vocabulary
+    /// doesn't contain their tokens, but its new tokens don't reuse them.
+    pub fn merge_vocab(
+        &self,
+        other: &ModelWrapper,
+        reserved: &HashMap<u32, String>,
+    ) -> Result<(ModelWrapper, VocabMergeReport)> {
+        match (self, other) {
+            (Self::BPE(base), Self::BPE(other)) => base
+                .merge_vocab(other, reserved)
+                .map(|(model, report)| (model.into(), report)),
+            (Self::WordPiece(base), Self::WordPiece(other)) => base
+                .merge_vocab(other, reserved)
+                .map(|(model, report)| (model.into(), report)),
+            (Self::Unigram(base), Self::Unigram(other)) => base
+                .merge_vocab(other, reserved)
+                .map(|(model, report)| (model.into(), report)),
+            _ => Err(Box::new(VocabMergeError::IncompatibleModels(
+                self.type_name(),
+                other.type_name(),
+            ))),
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::BPE(_) => "BPE",
+            Self::WordPiece(_) => "WordPiece",
+            Self::WordLevel(_) => "WordLevel",
+            Self::Unigram(_) => "Unigram",
+            Self::ByteModel(_) => "ByteModel",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{bpe::BPE, unigram::Unigram, wordpiece::WordPiece};
+    use crate::tokenizer::{AddedToken, Model, Tokenizer};
+
+    #[test]
+    fn merge_vocabs_report() {
+        let base = [("a", 0), ("b", 1), ("c", 3)]
+            .iter()
+            .map(|(token, id)| (token.to_string(), *id))
+            .collect();
+        let reserved = [(4, "[CLS]".to_string())].iter().cloned().collect();
+        let (vocab, report) = merge_vocabs(&base, &reserved, vec![(0, "a"), (2, "d"), (1, "c")]);
+        assert!(!vocab.contains_key("[CLS]"));
+        assert_eq!(vocab["d"], 5);
+        assert_eq!(
+            report.ids,
+            [(0, 0), (1, 3), (2, 5)].iter().copied().collect()
+        );
+        assert_eq!(report.added, vec![("d".to_string(), 5)]);
+        assert_eq!(
+            report.conflicts,
+            vec![VocabConflict {
+                token: "c".into(),
+                base_id: 3,
+                other_id: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn merge_bpe_tokenizers() {
+        let bpe = |tokens: &[&str], merges: &[(&str, &str)]| {
+            let vocab = tokens
+                .iter()
+                .enumerate()
+                .map(|(id, token)| (token.to_string(), id as u32))
+                .collect();
+            let merges = merges
+                .iter()
+                .map(|(a, b)| (a.to_string(), b.to_string()))
+                .collect();
+            BPE::builder()
+                .vocab_and_merges(vocab, merges)
+                .build()
+                .unwrap()
+        };
+        let mut base = Tokenizer::new(bpe(&["a", "b", "ab"], &[("a", "b")]));
+        base.add_special_tokens(&[AddedToken::from("[CLS]", true)]);
+        let mut other = Tokenizer::new(bpe(
+            &["c", "b", "a", "cb", "cba"],
+            &[("c", "b"), ("cb", "a")],
+        ));
+        other.add_special_tokens(&[AddedToken::from("[SEP]", true)]);
+        other.add_tokens(&[AddedToken::from("ab", false)]);
+
+        let (merged, report) = base.merge_vocab(&other).unwrap();
+        for (token, id) in base.get_vocab(true) {
+            assert_eq!(merged.token_to_id(&token), Some(id));
+        }
+        assert_eq!(merged.token_to_id("[CLS]"), Some(3));
+        // The added tokens are not part of the model, but their ids are never reused
+        assert_eq!(merged.get_model().token_to_id("[CLS]"), None);
+        assert_eq!(
+            report.added,
+            vec![
+                ("c".to_string(), 4),
+                ("cb".to_string(), 5),
+                ("cba".to_string(), 6),
+                ("[SEP]".to_string(), 7)
+            ]
+        );
+        assert_eq!(report.ids[&0], 4);
+        assert_eq!(report.conflicts.len(), 2);
+        assert!(report.ids.values().all(|id| *id < 8));
+        let encoding = merged.encode("ab cba", false).unwrap();
+        assert_eq!(encoding.get_ids(), &[2, 6]);
+        let mut merged = merged;
+        merged.add_tokens(&[AddedToken::from("new", false)]);
+        assert_eq!(merged.token_to_id("new"), Some(8));
+
+        let wordpiece = Tokenizer::new(WordPiece::default());
+        assert!(base.merge_vocab(&wordpiece).is_err());
+    }
+
+    #[test]
+    fn merge_unigram_renormalizes() {
+        let unigram = |pieces: &[(&str, f64)]| {
+            let vocab = pieces
+                .iter()
+                .map(|(piece, score)| (piece.to_string(), *score))
+                .collect();
+            Unigram::from(vocab, Some(0), false).unwrap()
+        };
+        let base = unigram(&[("<unk>", -3.0), ("a", -1.0), ("b", -2.0)]);
+        let other = unigram(&[("<unk>", -2.0), ("c", -1.0), ("a", -5.0)]);
+
+        let (merged, report) = base.merge_vocab(&other, &HashMap::new()).unwrap();
+        assert_eq!(report.added, vec![("c".to_string(), 3)]);
+        let scores = merged.iter().map(|(_, score)| *score).collect::<Vec<_>>();
+        let total: f64 = scores.iter().map(|score| score.exp()).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        // The relative scores are kept
+        assert!((scores[1] - scores[2] - 1.0).abs() < 1e-9);
+        assert!((scores[3] - scores[1]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn merge_unigram_reserved() {
+        let unigram = |pieces: &[(&str, f64)]| {
+            let vocab = pieces
+                .iter()
+                .map(|(piece, score)| (piece.to_string(), *score))
+                .collect();
+            Tokenizer::new(Unigram::from(vocab, Some(0), false).unwrap())
+        };
+        let mut base = unigram(&[("<unk>", -3.0), ("a", -1.0), ("b", -2.0)]);
+        base.add_special_tokens(&[AddedToken::from("ab", true)]);
+        let other = unigram(&[("<unk>", -2.0), ("c", -1.0), ("ab", -0.5)]);
+
+        let (merged, report) = base.merge_vocab(&other).unwrap();
+        assert_eq!(report.added, vec![("c".to_string(), 4)]);
+        assert_eq!(report.ids[&2], 3);
+        let unigram = match merged.get_model() {
+            crate::models::ModelWrapper::Unigram(unigram) => unigram,
+            _ => unreachable!(),
+        };
+        // The reserved id is kept by an unused piece, left out of the probabilities
+        assert_eq!(unigram.get_unused(), &[3].iter().copied().collect());
+        let total: f64 = unigram
+            .iter()
+            .enumerate()
+            .filter(|(id, _)| *id != 3)
+            .map(|(_, (_, score))| score.exp())
+            .sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        let encoding = merged.encode("cab", false).unwrap();
+        assert_eq!(encoding.get_tokens(), &["c", "ab"]);
+        assert_eq!(encoding.get_ids(), &[4, 3]);
+        let model_tokens = merged.get_model().tokenize("cab").unwrap();
+        assert_eq!(
+            model_tokens.iter().map(|t| t.id).collect::<Vec<_>>(),
+            vec![4, 1, 2]
+        );
+    }
+}
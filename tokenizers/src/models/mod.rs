@@ -1,6 +1,8 @@
 //! Popular tokenizer models.
 
 pub mod bpe;
+pub mod byte;
+pub mod merge;
 pub mod unigram;
 pub mod wordlevel;
 pub mod wordpiece;
@@ -11,6 +13,7 @@ use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize, Serializer};
 
 use crate::models::bpe::{BpeTrainer, BPE};
+use crate::models::byte::{ByteModel, ByteModelTrainer};
 use crate::models::unigram::{Unigram, UnigramTrainer};
 use crate::models::wordlevel::{WordLevel, WordLevelTrainer};
 use crate::models::wordpiece::{WordPiece, WordPieceTrainer};
@@ -57,6 +60,17 @@ impl<'a> Serialize for OrderedVocabIter<'a> {
     }
 }
 
+/// Give contiguous ids to the given ones, keeping their order, and return the new id of each
+pub(crate) fn compact_ids<I: IntoIterator<Item = u32>>(ids: I) -> HashMap<u32, u32> {
+    let mut ids = ids.into_iter().collect::<Vec<_>>();
+    ids.sort_unstable();
+    ids.dedup();
+    ids.into_iter()
+        .enumerate()
+        .map(|(new_id, id)| (id, new_id as u32))
+        .collect()
+}
+
 #[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
 #[serde(untagged)]
 pub enum ModelWrapper {
@@ -66,12 +80,14 @@ pub enum ModelWrapper {
     WordPiece(WordPiece),
     WordLevel(WordLevel),
     Unigram(Unigram),
+    ByteModel(ByteModel),
 }
 
 impl_enum_from!(WordLevel, ModelWrapper, WordLevel);
 impl_enum_from!(WordPiece, ModelWrapper, WordPiece);
 impl_enum_from!(BPE, ModelWrapper, BPE);
 impl_enum_from!(Unigram, ModelWrapper, Unigram);
+impl_enum_from!(ByteModel, ModelWrapper, ByteModel);
 
 impl Model for ModelWrapper {
     type Trainer = TrainerWrapper;
@@ -82,6 +98,27 @@ impl Model for ModelWrapper {
             Self::WordPiece(t) => t.tokenize(tokens),
             Self::BPE(t) => t.tokenize(tokens),
             Self::Unigram(t) => t.tokenize(tokens),
+            Self::ByteModel(t) => t.tokenize(tokens),
+        }
+    }
+
+    fn tokenize_nbest(&self, tokens: &str, n: usize) -> Result<Vec<(Vec<Token>, f64)>> {
+        match self {
+            Self::WordLevel(t) => t.tokenize_nbest(tokens, n),
+            Self::WordPiece(t) => t.tokenize_nbest(tokens, n),
+            Self::BPE(t) => t.tokenize_nbest(tokens, n),
+            Self::Unigram(t) => t.tokenize_nbest(tokens, n),
+            Self::ByteModel(t) => t.tokenize_nbest(tokens, n),
+        }
+    }
+
+    fn count_tokens(&self, tokens: &str) -> Result<usize> {
+        match self {
+            Self::WordLevel(t) => t.count_tokens(tokens),
+            Self::WordPiece(t) => t.count_tokens(tokens),
+            Self::BPE(t) => t.count_tokens(tokens),
+            Self::Unigram(t) => t.count_tokens(tokens),
+            Self::ByteModel(t) => t.count_tokens(tokens),
         }
     }
 
@@ -91,6 +128,7 @@ impl Model for ModelWrapper {
             Self::WordPiece(t) => t.token_to_id(token),
             Self::BPE(t) => t.token_to_id(token),
             Self::Unigram(t) => t.token_to_id(token),
+            Self::ByteModel(t) => t.token_to_id(token),
         }
     }
 
@@ -100,6 +138,7 @@ impl Model for ModelWrapper {
             Self::WordPiece(t) => t.id_to_token(id),
             Self::BPE(t) => t.id_to_token(id),
             Self::Unigram(t) => t.id_to_token(id),
+            Self::ByteModel(t) => t.id_to_token(id),
         }
     }
 
@@ -109,6 +148,7 @@ impl Model for ModelWrapper {
             Self::WordPiece(t) => t.get_vocab(),
             Self::BPE(t) => t.get_vocab(),
             Self::Unigram(t) => t.get_vocab(),
+            Self::ByteModel(t) => t.get_vocab(),
         }
     }
 
@@ -118,6 +158,7 @@ impl Model for ModelWrapper {
             Self::WordPiece(t) => t.get_vocab_size(),
             Self::BPE(t) => t.get_vocab_size(),
             Self::Unigram(t) => t.get_vocab_size(),
+            Self::ByteModel(t) => t.get_vocab_size(),
         }
     }
 
@@ -127,6 +168,7 @@ impl Model for ModelWrapper {
             Self::WordPiece(t) => t.save(folder, name),
             Self::BPE(t) => t.save(folder, name),
             Self::Unigram(t) => t.save(folder, name),
+            Self::ByteModel(t) => t.save(folder, name),
         }
     }
 
@@ -136,6 +178,17 @@ impl Model for ModelWrapper {
             Self::WordPiece(t) => t.get_trainer().into(),
             Self::BPE(t) => t.get_trainer().into(),
             Self::Unigram(t) => t.get_trainer().into(),
+            Self::ByteModel(t) => t.get_trainer().into(),
+        }
+    }
+
+    fn prune_vocab(&mut self, keep: &dyn Fn(u32) -> bool) -> Result<HashMap<u32, u32>> {
+        match self {
+            Self::WordLevel(t) => t.prune_vocab(keep),
+            Self::WordPiece(t) => t.prune_vocab(keep),
+            Self::BPE(t) => t.prune_vocab(keep),
+            Self::Unigram(t) => t.prune_vocab(keep),
+            Self::ByteModel(t) => t.prune_vocab(keep),
         }
     }
 }
@@ -146,6 +199,7 @@ pub enum TrainerWrapper {
     WordPieceTrainer(WordPieceTrainer),
     WordLevelTrainer(WordLevelTrainer),
     UnigramTrainer(UnigramTrainer),
+    ByteModelTrainer(ByteModelTrainer),
 }
 
 impl Trainer for TrainerWrapper {
@@ -157,6 +211,7 @@ impl Trainer for TrainerWrapper {
             Self::WordPieceTrainer(wpt) => wpt.should_show_progress(),
             Self::WordLevelTrainer(wpt) => wpt.should_show_progress(),
             Self::UnigramTrainer(wpt) => wpt.should_show_progress(),
+            Self::ByteModelTrainer(bmt) => bmt.should_show_progress(),
         }
     }
 
@@ -178,6 +233,10 @@ impl Trainer for TrainerWrapper {
                 ModelWrapper::Unigram(u) => t.train(u),
                 _ => Err("UnigramTrainer can only train a Unigram".into()),
             },
+            Self::ByteModelTrainer(t) => match model {
+                ModelWrapper::ByteModel(bm) => t.train(bm),
+                _ => Err("ByteModelTrainer can only train a ByteModel".into()),
+            },
         }
     }
 
@@ -192,6 +251,7 @@ impl Trainer for TrainerWrapper {
             Self::WordPieceTrainer(wpt) => wpt.feed(iterator, process),
             Self::WordLevelTrainer(wpt) => wpt.feed(iterator, process),
             Self::UnigramTrainer(wpt) => wpt.feed(iterator, process),
+            Self::ByteModelTrainer(bmt) => bmt.feed(iterator, process),
         }
     }
 }
@@ -200,6 +260,7 @@ impl_enum_from!(BpeTrainer, TrainerWrapper, BpeTrainer);
 impl_enum_from!(WordPieceTrainer, TrainerWrapper, WordPieceTrainer);
 impl_enum_from!(UnigramTrainer, TrainerWrapper, UnigramTrainer);
 impl_enum_from!(WordLevelTrainer, TrainerWrapper, WordLevelTrainer);
+impl_enum_from!(ByteModelTrainer, TrainerWrapper, ByteModelTrainer);
 
 #[cfg(test)]
 mod tests {
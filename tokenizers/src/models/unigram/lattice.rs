@@ -5,7 +5,7 @@ use std::cmp::{min, Ordering};
 use std::collections::BinaryHeap;
 use std::rc::Rc;
 
-type NodeRef = Rc<RefCell<Node>>;
+pub(super) type NodeRef = Rc<RefCell<Node>>;
 type HypothesisRef = Rc<RefCell<Hypothesis>>;
 type Agenda = BinaryHeap<Hypothesis>;
 
@@ -92,7 +92,7 @@ pub struct Node {
     length: usize,
     prev: Option<NodeRef>,
     backtrace_score: f64,
-    score: f64,
+    pub(super) score: f64,
 }
 
 impl PartialEq for Node {
@@ -328,6 +328,26 @@ impl<'a> Lattice<'a> {
         self.sentence
     }
 
+    /// Returns the log of the sum of the probabilities of all the segmentations.
+    pub fn log_partition(&self) -> f64 {
+        let len = self.len();
+        let mut alpha = vec![0.0; self.nodes.len()];
+        for pos in 0..=len {
+            for rnode in &self.begin_nodes[pos] {
+                for lnode in &self.end_nodes[pos] {
+                    let lid = lnode.borrow().node_id;
+                    let rid = rnode.borrow().node_id;
+                    alpha[rid] = log_sum_exp(
+                        alpha[rid],
+                        lnode.borrow().score + alpha[lid],
+                        *lnode == self.end_nodes[pos][0],
+                    );
+                }
+            }
+        }
+        alpha[self.eos_node().borrow().node_id]
+    }
+
     pub fn populate_marginal(&self, freq: f64, expected: &mut [f64]) -> f64 {
         let len = self.len();
         let n_nodes = self.nodes.len();
@@ -378,6 +398,11 @@ impl<'a> Lattice<'a> {
     }
 
     pub fn sample(&self, theta: f64) -> Vec<NodeRef> {
+        self.sample_with_rng(theta, &mut thread_rng())
+    }
+
+    /// Sample a segmentation, using the given RNG.
+    pub fn sample_with_rng<R: Rng + ?Sized>(&self, theta: f64, rng: &mut R) -> Vec<NodeRef> {
         let len = self.len();
         if len == 0 {
             return vec![];
@@ -397,7 +422,6 @@ impl<'a> Lattice<'a> {
             }
         }
 
-        let mut rng = thread_rng();
         let mut results: Vec<NodeRef> = vec![];
         let mut probs: Vec<f64> = vec![];
         let mut z = alpha[self.eos_node().borrow().node_id];
@@ -410,7 +434,7 @@ impl<'a> Lattice<'a> {
                 probs.push((alpha[lid] + theta * lnode.borrow().score - z).exp())
             }
             let dist = WeightedIndex::new(&probs).unwrap();
-            let index = dist.sample(&mut rng);
+            let index = dist.sample(rng);
             node = Rc::clone(&self.end_nodes[pos][index]);
             if node == self.bos_node() {
                 break;
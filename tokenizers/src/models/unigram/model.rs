@@ -1,12 +1,20 @@
 use super::{
-    lattice::Lattice,
+    lattice::{Lattice, NodeRef},
     trainer::UnigramTrainer,
     trie::{Trie, TrieBuilder},
 };
+use crate::models::{
+    compact_ids,
+    merge::{merge_vocabs, VocabMergeError, VocabMergeReport},
+};
 use crate::tokenizer::{Model, Result, Token};
-use crate::utils::cache::Cache;
+use crate::utils::cache::{Cache, CacheStats};
+use crate::utils::rng::sample_rng;
 
-use std::collections::HashMap;
+use rand::distributions::WeightedIndex;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
 use std::fs::read_to_string;
 use std::path::{Path, PathBuf};
@@ -14,12 +22,32 @@ use std::path::{Path, PathBuf};
 type TokenMap = HashMap<String, u32>;
 type Vocab = Vec<(String, f64)>;
 
+/// Configuration of the subword regularization used by a `Unigram` model. Instead of the best
+/// segmentation, each word gets tokenized using a segmentation sampled from the lattice, as
+/// described in [Subword Regularization](https://arxiv.org/abs/1804.10959).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct UnigramSampling {
+    /// Smoothing parameter applied to the scores of the segmentations. Lower values
+    /// make the sampling distribution closer to uniform.
+    pub alpha: f64,
+    /// Sample among the `nbest_size` best segmentations. When `None`, the sample is taken
+    /// from all the possible segmentations.
+    pub nbest_size: Option<usize>,
+    /// When set, the samples are reproducible: each word is sampled from the seed, the word, its
+    /// position in its input and the index of this input in its batch. Encoding the same inputs
+    /// thus always gives the same samples, whatever the threads encoding them, while the same
+    /// word gets different samples at different positions. The samples are then the same at each
+    /// epoch of a training: change the seed at each epoch, like `seed + epoch`, to get new ones.
+    pub seed: Option<u64>,
+}
+
 /// A `Unigram` model to encode sentences.
 pub struct Unigram {
     token_to_ids: TokenMap,
     pub(crate) vocab: Vocab,
     cache: Cache<String, Vec<String>>,
     trie: Trie<u8>,
+    unused: HashSet<usize>,
     pub min_score: f64,
     pub(super) unk_id: Option<usize>,
     pub(super) bos_id: usize,
@@ -28,10 +56,11 @@ pub struct Unigram {
     fuse_unk: bool,
     is_optimized: bool,
     byte_fallback: bool,
+    sampling: Option<UnigramSampling>,
 }
 impl PartialEq for Unigram {
     fn eq(&self, other: &Self) -> bool {
-        self.unk_id == other.unk_id && self.vocab == other.vocab
+        self.unk_id == other.unk_id && self.vocab == other.vocab && self.unused == other.unused
     }
 }
 
@@ -45,6 +74,7 @@ impl Clone for Unigram {
             cache: fresh_cache,
             token_to_ids: self.token_to_ids.clone(),
             trie: self.trie.clone(),
+            unused: self.unused.clone(),
             min_score: self.min_score,
             unk_id: self.unk_id,
             bos_id: self.bos_id,
@@ -52,6 +82,7 @@ impl Clone for Unigram {
             fuse_unk: self.fuse_unk,
             is_optimized: self.is_optimized,
             byte_fallback: self.byte_fallback,
+            sampling: self.sampling,
         }
     }
 }
@@ -62,6 +93,7 @@ impl std::fmt::Debug for Unigram {
             .field("vocab", &self.vocab.len())
             .field("unk_id", &self.unk_id)
             .field("byte_fallback", &self.byte_fallback)
+            .field("sampling", &self.sampling)
             .finish()
     }
 }
@@ -99,7 +131,6 @@ impl Unigram {
     ) -> Result<Self> {
         let n = vocab.len();
         let mut token_to_ids: TokenMap = HashMap::new();
-        let mut builder = TrieBuilder::default();
 
         if let Some(unk_id) = unk_id {
             if vocab.is_empty() {
@@ -112,16 +143,11 @@ impl Unigram {
         let bos_id = n + 1;
         let eos_id = n + 2;
 
-        let mut min_score = f64::INFINITY;
-        for (id, (token, score)) in vocab.iter().enumerate() {
+        for (id, (token, _)) in vocab.iter().enumerate() {
             token_to_ids.insert(token.to_string(), id as u32);
-            let bytes: Vec<u8> = token.bytes().collect();
-            builder.push(&bytes);
-            if score < &min_score {
-                min_score = *score;
-            }
         }
-        let trie = builder.build();
+        let unused = HashSet::new();
+        let (trie, min_score) = Self::build_trie(&vocab, &unused);
         let fuse_unk = true;
         let is_optimized = true;
 
@@ -129,6 +155,7 @@ impl Unigram {
             vocab,
             token_to_ids,
             trie,
+            unused,
             min_score,
             bos_id,
             eos_id,
@@ -137,9 +164,27 @@ impl Unigram {
             cache: Cache::default(),
             is_optimized,
             byte_fallback,
+            sampling: None,
         })
     }
 
+    /// Build the trie of the pieces that can be produced, along with their lowest score
+    fn build_trie(vocab: &[(String, f64)], unused: &HashSet<usize>) -> (Trie<u8>, f64) {
+        let mut builder = TrieBuilder::default();
+        let mut min_score = f64::INFINITY;
+        for (id, (token, score)) in vocab.iter().enumerate() {
+            if unused.contains(&id) {
+                continue;
+            }
+            let bytes: Vec<u8> = token.bytes().collect();
+            builder.push(&bytes);
+            if score < &min_score {
+                min_score = *score;
+            }
+        }
+        (builder.build(), min_score)
+    }
+
     #[cfg(test)]
     pub(super) fn set_fuse_unk(&mut self, fuse_unk: bool) {
         self.fuse_unk = fuse_unk;
@@ -150,9 +195,129 @@ impl Unigram {
     pub(super) fn set_optimized(&mut self, is_optimized: bool) {
         self.is_optimized = is_optimized;
     }
+
+    pub fn unk_id(&self) -> Option<usize> {
+        self.unk_id
+    }
+
     pub fn byte_fallback(&self) -> bool {
         self.byte_fallback
     }
+
+    /// Get the ids of the unused pieces.
+    pub fn get_unused(&self) -> &HashSet<usize> {
+        &self.unused
+    }
+
+    /// Mark the pieces with the given ids as unused. They keep their id and can still be looked
+    /// up, but the tokenization never produces them.
+    pub fn set_unused(&mut self, unused: HashSet<usize>) {
+        let (trie, min_score) = Self::build_trie(&self.vocab, &unused);
+        self.trie = trie;
+        self.min_score = min_score;
+        self.unused = unused;
+        self.cache = self.cache.fresh();
+    }
+
+    /// Get the subword regularization configuration, if enabled.
+    pub fn get_sampling(&self) -> Option<&UnigramSampling> {
+        self.sampling.as_ref()
+    }
+
+    /// Enable or disable the subword regularization. The cache is not used while sampling.
+    pub fn set_sampling(&mut self, sampling: Option<UnigramSampling>) {
+        self.sampling = sampling;
+    }
+
+    /// The number of sentences that the cache can contain, 0 when it is disabled.
+    pub fn get_cache_capacity(&self) -> usize {
+        self.cache.capacity
+    }
+
+    /// Set the cache's capacity, starting with an empty cache. Set to 0 to disable caching.
+    pub fn set_cache_capacity(&mut self, capacity: usize) {
+        self.cache = Cache::new(capacity);
+    }
+
+    /// Get the hit/miss statistics of the cache.
+    pub fn cache_stats(&self) -> CacheStats {
+        self.cache.stats()
+    }
+
+    /// Merge the vocabulary of the other `Unigram` into this one, as explained in
+    /// [`ModelWrapper::merge_vocab`](crate::models::ModelWrapper::merge_vocab). The shared pieces
+    /// keep the score of this model, and the new ones keep their score. All the scores are then
+    /// renormalized so that their probabilities sum to 1. The reserved ids are filled with
+    /// unused pieces, which are never produced.
+    pub fn merge_vocab(
+        &self,
+        other: &Unigram,
+        reserved: &HashMap<u32, String>,
+    ) -> Result<(Unigram, VocabMergeReport)> {
+        let (vocab, report) = merge_vocabs(
+            &self.token_to_ids,
+            reserved,
+            other
+                .vocab
+                .iter()
+                .enumerate()
+                .map(|(id, (piece, _))| (id as u32, piece.as_str())),
+        );
+
+        let size = vocab
+            .values()
+            .chain(reserved.keys())
+            .max()
+            .map_or(0, |id| *id as usize + 1);
+        let mut pieces: Vec<Option<(String, f64)>> = vec![None; size];
+        let mut unused = self.unused.clone();
+        for (piece, id) in vocab {
+            let score = match self.token_to_ids.get(&piece) {
+                Some(id) => self.vocab[*id as usize].1,
+                None => {
+                    let other_id = other.token_to_ids[&piece] as usize;
+                    if other.unused.contains(&other_id) {
+                        unused.insert(id as usize);
+                    }
+                    other.vocab[other_id].1
+                }
+            };
+            pieces[id as usize] = Some((piece, score));
+        }
+        for (id, token) in reserved {
+            let piece = &mut pieces[*id as usize];
+            if piece.is_none() {
+                *piece = Some((token.clone(), self.min_score));
+                unused.insert(*id as usize);
+            }
+        }
+        let mut pieces = pieces
+            .into_iter()
+            .enumerate()
+            .map(|(id, piece)| piece.ok_or(VocabMergeError::MissingId(id as u32)))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let scores = || {
+            pieces
+                .iter()
+                .enumerate()
+                .filter(|(id, _)| !unused.contains(id))
+                .map(|(_, (_, score))| *score)
+        };
+        let max = scores().fold(f64::NEG_INFINITY, f64::max);
+        let log_sum = max + scores().map(|score| (score - max).exp()).sum::<f64>().ln();
+        for (_, score) in pieces.iter_mut() {
+            *score -= log_sum;
+        }
+
+        let mut unigram = Self::from(pieces, self.unk_id, self.byte_fallback)?;
+        unigram.fuse_unk = self.fuse_unk;
+        unigram.is_optimized = self.is_optimized;
+        unigram.sampling = self.sampling;
+        unigram.set_unused(unused);
+        unigram.cache = self.cache.fresh();
+        Ok((unigram, report))
+    }
     pub(super) fn len(&self) -> usize {
         self.vocab.len()
     }
@@ -222,6 +387,9 @@ impl Unigram {
         if sentence.is_empty() {
             return Ok(vec![]);
         }
+        if let Some(sampling) = &self.sampling {
+            return self.encode_sampled(sentence, sampling);
+        }
         if let Some(result) = self.cache.get(sentence) {
             Ok(result.to_vec())
         } else {
@@ -336,10 +504,41 @@ impl Unigram {
     fn encode_unoptimized(&self, sentence: &str) -> Result<Vec<String>> {
         let mut lattice = Lattice::from(sentence, self.bos_id, self.eos_id);
         self.populate_nodes(&mut lattice);
+        let nodes = lattice.viterbi();
+        self.nodes_to_pieces(&lattice, &nodes)
+    }
+
+    fn encode_sampled(&self, sentence: &str, sampling: &UnigramSampling) -> Result<Vec<String>> {
+        let mut rng: Box<dyn RngCore> = match sampling.seed {
+            Some(seed) => Box::new(sample_rng(seed, sentence)),
+            None => Box::new(thread_rng()),
+        };
+        let mut lattice = Lattice::from(sentence, self.bos_id, self.eos_id);
+        self.populate_nodes(&mut lattice);
+        let nodes = match sampling.nbest_size {
+            None => lattice.sample_with_rng(sampling.alpha, &mut rng),
+            Some(0) | Some(1) => lattice.viterbi(),
+            Some(nbest_size) => {
+                let mut nbests = lattice.nbest(nbest_size);
+                let scores = nbests
+                    .iter()
+                    .map(|nodes| {
+                        sampling.alpha * nodes.iter().map(|n| n.borrow().score).sum::<f64>()
+                    })
+                    .collect::<Vec<_>>();
+                let max = scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                let dist = WeightedIndex::new(scores.iter().map(|score| (score - max).exp()))?;
+                nbests.swap_remove(dist.sample(&mut rng))
+            }
+        };
+        self.nodes_to_pieces(&lattice, &nodes)
+    }
+
+    fn nodes_to_pieces(&self, lattice: &Lattice, nodes: &[NodeRef]) -> Result<Vec<String>> {
         if self.fuse_unk {
             let mut results = vec![];
             let mut token = String::new();
-            for node in lattice.viterbi().iter() {
+            for node in nodes {
                 let item = lattice.piece(&node.borrow());
                 if node.borrow().id == self.unk_id.ok_or(UnigramError::MissingUnkId)? {
                     token.push_str(&item);
@@ -356,8 +555,46 @@ impl Unigram {
             }
             Ok(results)
         } else {
-            Ok(lattice.tokens())
+            Ok(nodes
+                .iter()
+                .map(|node| lattice.piece(&node.borrow()))
+                .collect())
+        }
+    }
+
+    fn pieces_to_tokens(&self, pieces: Vec<String>) -> Result<Vec<Token>> {
+        let mut offset = 0;
+        let mut tokens = Vec::with_capacity(pieces.len());
+        for string in pieces {
+            let len = string.len();
+            let offsets = (offset, offset + len);
+            let id: u32 = match self.token_to_ids.get(&string) {
+                Some(id) => *id,
+                None => {
+                    if self.byte_fallback {
+                        let byte_tokens: Option<Vec<_>> = string
+                            .bytes()
+                            .map(|byte| -> Option<Token> {
+                                let byte_string = format!("<0x{:02X}>", byte);
+                                let id = self.token_to_ids.get(&byte_string);
+                                id.map(|id| Token::new(*id, byte_string, (offset, offset + len)))
+                            })
+                            .collect();
+                        if let Some(byte_tokens) = byte_tokens {
+                            for token in byte_tokens {
+                                tokens.push(token);
+                            }
+                            offset += len;
+                            continue;
+                        }
+                    }
+                    self.unk_id.ok_or(UnigramError::MissingUnkId)? as u32
+                }
+            };
+            offset += len;
+            tokens.push(Token::new(id, string, offsets));
         }
+        Ok(tokens)
     }
 
     /// Iterate of vocabulary of the model as a pair of `(token, score)`.
@@ -412,39 +649,29 @@ impl Model for Unigram {
     }
 
     fn tokenize(&self, sentence: &str) -> Result<Vec<Token>> {
-        let str_tokens = self.encode(sentence)?;
-        let mut offset = 0;
-        let mut tokens = Vec::with_capacity(str_tokens.len());
-        for string in str_tokens {
-            let len = string.len();
-            let offsets = (offset, offset + len);
-            let id: u32 = match self.token_to_ids.get(&string) {
-                Some(id) => *id,
-                None => {
-                    if self.byte_fallback {
-                        let byte_tokens: Option<Vec<_>> = string
-                            .bytes()
-                            .map(|byte| -> Option<Token> {
-                                let byte_string = format!("<0x{:02X}>", byte);
-                                let id = self.token_to_ids.get(&byte_string);
-                                id.map(|id| Token::new(*id, byte_string, (offset, offset + len)))
-                            })
-                            .collect();
-                        if let Some(byte_tokens) = byte_tokens {
-                            for token in byte_tokens {
-                                tokens.push(token);
-                            }
-                            offset += len;
-                            continue;
-                        }
-                    }
-                    self.unk_id.ok_or(UnigramError::MissingUnkId)? as u32
-                }
-            };
-            offset += len;
-            tokens.push(Token::new(id, string, offsets));
+        let pieces = self.encode(sentence)?;
+        self.pieces_to_tokens(pieces)
+    }
+
+    fn tokenize_nbest(&self, sentence: &str, n: usize) -> Result<Vec<(Vec<Token>, f64)>> {
+        if n == 0 {
+            return Ok(vec![]);
         }
-        Ok(tokens)
+        if sentence.is_empty() {
+            return Ok(vec![(vec![], 0.0)]);
+        }
+        let mut lattice = Lattice::from(sentence, self.bos_id, self.eos_id);
+        self.populate_nodes(&mut lattice);
+        let log_z = lattice.log_partition();
+        lattice
+            .nbest(n)
+            .into_iter()
+            .map(|nodes| {
+                let score = nodes.iter().map(|node| node.borrow().score).sum::<f64>() - log_z;
+                let pieces = self.nodes_to_pieces(&lattice, &nodes)?;
+                Ok((self.pieces_to_tokens(pieces)?, score))
+            })
+            .collect()
     }
 
     fn token_to_id(&self, token: &str) -> Option<u32> {
@@ -471,11 +698,42 @@ impl Model for Unigram {
     fn get_trainer(&self) -> Self::Trainer {
         UnigramTrainer::default()
     }
+
+    /// The unknown token, and the bytes used as fallback, are always kept. Since the other
+    /// pieces keep their score, the segmentations only using kept pieces don't change.
+    fn prune_vocab(&mut self, keep: &dyn Fn(u32) -> bool) -> Result<HashMap<u32, u32>> {
+        let mut kept: HashSet<u32> = (0..self.vocab.len() as u32)
+            .filter(|id| keep(*id))
+            .collect();
+        kept.extend(self.unk_id.map(|unk_id| unk_id as u32));
+        if self.byte_fallback {
+            kept.extend((0..=255u8).filter_map(|b| self.token_to_id(&format!("<0x{:02X}>", b))));
+        }
+
+        let ids = compact_ids(kept);
+        let vocab = self
+            .vocab
+            .iter()
+            .enumerate()
+            .filter(|(id, _)| ids.contains_key(&(*id as u32)))
+            .map(|(_, piece)| piece.clone())
+            .collect();
+        let unk_id = self.unk_id.map(|unk_id| ids[&(unk_id as u32)] as usize);
+
+        let mut pruned = Self::from(vocab, unk_id, self.byte_fallback)?;
+        pruned.fuse_unk = self.fuse_unk;
+        pruned.is_optimized = self.is_optimized;
+        pruned.sampling = self.sampling;
+        pruned.cache = self.cache.fresh();
+        *self = pruned;
+        Ok(ids)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::rng::with_sample_stream;
 
     #[test]
     fn test_populate_nodes_unk() {
@@ -602,6 +860,172 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_encode_sampling() {
+        let sentencepieces = vec![
+            ("<unk>".to_string(), 0.0),
+            ("a".to_string(), -1.0),
+            ("b".to_string(), -1.0),
+            ("c".to_string(), -1.0),
+            ("ab".to_string(), -1.5),
+            ("bc".to_string(), -1.5),
+            ("abc".to_string(), -1.0),
+        ];
+        let mut model = Unigram::from(sentencepieces, Some(0), false).unwrap();
+        assert_eq!(model.encode("abc").unwrap(), vec!["abc"]);
+
+        for nbest_size in [None, Some(4)] {
+            model.set_sampling(Some(UnigramSampling {
+                alpha: 0.1,
+                nbest_size,
+                seed: None,
+            }));
+            let samples = (0..100)
+                .map(|_| model.encode("abc").unwrap())
+                .collect::<std::collections::HashSet<_>>();
+            assert!(samples.len() > 1);
+            for sample in samples {
+                assert_eq!(sample.concat(), "abc");
+            }
+        }
+
+        model.set_sampling(Some(UnigramSampling {
+            alpha: 0.1,
+            nbest_size: Some(1),
+            seed: None,
+        }));
+        assert_eq!(model.encode("abc").unwrap(), vec!["abc"]);
+
+        // The samples never end up in the cache
+        model.set_sampling(None);
+        assert_eq!(model.encode("abc").unwrap(), vec!["abc"]);
+    }
+
+    #[test]
+    fn test_encode_sampling_seed() {
+        let sentencepieces = vec![
+            ("<unk>".to_string(), 0.0),
+            ("a".to_string(), -1.0),
+            ("b".to_string(), -1.0),
+            ("ab".to_string(), -1.0),
+        ];
+        let mut model = Unigram::from(sentencepieces, Some(0), false).unwrap();
+        let words = (1..50).map(|n| "ab".repeat(n)).collect::<Vec<_>>();
+        let sample = |model: &Unigram| {
+            words
+                .iter()
+                .map(|word| model.encode(word).unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        for nbest_size in [None, Some(8)] {
+            let mut sampling = UnigramSampling {
+                alpha: 0.5,
+                nbest_size,
+                seed: Some(1),
+            };
+            model.set_sampling(Some(sampling));
+            let first = sample(&model);
+            // The samples are reproduced, by the model or a clone
+            assert_eq!(first, sample(&model));
+            assert_eq!(first, sample(&model.clone()));
+
+            // The same word gets new samples at each of its positions in an input
+            let samples = with_sample_stream(0, || {
+                (0..16)
+                    .map(|_| model.encode(&words[8]).unwrap())
+                    .collect::<HashSet<_>>()
+            });
+            assert!(samples.len() > 1);
+
+            sampling.seed = Some(2);
+            model.set_sampling(Some(sampling));
+            assert_ne!(first, sample(&model));
+        }
+    }
+
+    #[test]
+    fn test_encode_sampling_seed_batch() {
+        use crate::Tokenizer;
+
+        let sentencepieces = vec![
+            ("<unk>".to_string(), 0.0),
+            ("a".to_string(), -1.0),
+            ("b".to_string(), -1.0),
+            ("ab".to_string(), -1.0),
+        ];
+        let mut model = Unigram::from(sentencepieces, Some(0), false).unwrap();
+        model.set_sampling(Some(UnigramSampling {
+            alpha: 0.5,
+            nbest_size: None,
+            seed: Some(1),
+        }));
+        let tokenizer = Tokenizer::new(model);
+
+        // The sentences of a batch are sampled independently, even when they are the same
+        let sentences = vec!["abababab"; 16];
+        let samples = tokenizer
+            .encode_batch(sentences, false)
+            .unwrap()
+            .into_iter()
+            .map(|encoding| encoding.get_tokens().to_vec())
+            .collect::<HashSet<_>>();
+        assert!(samples.len() > 1);
+        for sample in samples {
+            assert_eq!(sample.concat(), "abababab");
+        }
+
+        // The samples neither depend on the threads encoding the batch, nor on the previous ones
+        let sentences = (0..200)
+            .map(|n| "ab".repeat(n % 20 + 1))
+            .collect::<Vec<_>>();
+        let encode_batch = |num_threads| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap()
+                .install(|| tokenizer.encode_batch(sentences.clone(), false).unwrap())
+        };
+        let first = encode_batch(1);
+        assert_eq!(first, encode_batch(8));
+        assert_eq!(first, encode_batch(1));
+        assert_eq!(
+            first[0],
+            tokenizer.encode(sentences[0].as_str(), false).unwrap()
+        );
+        assert_ne!(first[7], first[27]);
+    }
+
+    #[test]
+    fn test_tokenize_nbest() {
+        let sentencepieces = vec![
+            ("<unk>".to_string(), 0.0),
+            ("a".to_string(), -1.0),
+            ("b".to_string(), -1.0),
+            ("ab".to_string(), -1.5),
+        ];
+        let model = Unigram::from(sentencepieces, Some(0), false).unwrap();
+
+        let nbest = model.tokenize_nbest("ab", 5).unwrap();
+        assert_eq!(nbest.len(), 2);
+        assert_eq!(nbest[0].0, vec![Token::new(3, "ab".to_string(), (0, 2))]);
+        assert_eq!(
+            nbest[1].0,
+            vec![
+                Token::new(1, "a".to_string(), (0, 1)),
+                Token::new(2, "b".to_string(), (1, 2)),
+            ]
+        );
+        // The scores are the log-probabilities of each segmentation
+        let (p_ab, p_a_b) = ((-1.5f64).exp(), (-2.0f64).exp());
+        assert!((nbest[0].1 - (p_ab / (p_ab + p_a_b)).ln()).abs() < 1e-6);
+        assert!((nbest[1].1 - (p_a_b / (p_ab + p_a_b)).ln()).abs() < 1e-6);
+
+        assert_eq!(model.tokenize_nbest("ab", 1).unwrap().len(), 1);
+        assert!(model.tokenize_nbest("ab", 0).unwrap().is_empty());
+        assert_eq!(model.tokenize_nbest("", 2).unwrap(), vec![(vec![], 0.0)]);
+    }
+
     #[test]
     fn test_unigram_bytefallback() {
         // In [97]: processor.encode_as_pieces("⅐⅛⅑ ")
@@ -632,4 +1056,19 @@ mod tests {
         let tokens = unigram.tokenize("?é").unwrap();
         assert_eq!(tokens[0].id, 0);
     }
+
+    #[test]
+    fn test_prune_vocab() {
+        let vocab = ["<unk>", "a", "b", "ab", "c"]
+            .iter()
+            .enumerate()
+            .map(|(i, piece)| (piece.to_string(), -(i as f64)))
+            .collect();
+        let mut unigram = Unigram::from(vocab, Some(0), false).unwrap();
+        let ids = unigram.prune_vocab(&|id| id == 3 || id == 4).unwrap();
+        assert_eq!(ids, [(0, 0), (3, 1), (4, 2)].iter().copied().collect());
+        assert_eq!(unigram.token_to_id("ab"), Some(1));
+        let tokens = unigram.tokenize("abc").unwrap();
+        assert_eq!(tokens.iter().map(|t| t.id).collect::<Vec<_>>(), vec![1, 2]);
+    }
 }
@@ -1,9 +1,11 @@
-use super::model::Unigram;
+use super::model::{Unigram, UnigramSampling};
+use crate::utils::cache::DEFAULT_CACHE_CAPACITY;
 use serde::{
     de::{Error, MapAccess, Visitor},
     ser::SerializeStruct,
     Deserialize, Deserializer, Serialize, Serializer,
 };
+use std::collections::HashSet;
 
 impl Serialize for Unigram {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -16,6 +18,25 @@ impl Serialize for Unigram {
         model.serialize_field("unk_id", &self.unk_id)?;
         model.serialize_field("vocab", &self.vocab)?;
         model.serialize_field("byte_fallback", &self.byte_fallback())?;
+        // The unused pieces are only listed when there are some
+        if self.get_unused().is_empty() {
+            model.skip_field("unused")?;
+        } else {
+            let mut unused = self.get_unused().iter().copied().collect::<Vec<_>>();
+            unused.sort_unstable();
+            model.serialize_field("unused", &unused)?;
+        }
+        if let Some(sampling) = self.get_sampling() {
+            model.serialize_field("sampling", sampling)?;
+        } else {
+            model.skip_field("sampling")?;
+        }
+        // The capacity of the cache is only kept when it was changed
+        if self.get_cache_capacity() != DEFAULT_CACHE_CAPACITY {
+            model.serialize_field("cache_capacity", &self.get_cache_capacity())?;
+        } else {
+            model.skip_field("cache_capacity")?;
+        }
 
         model.end()
     }
@@ -28,7 +49,15 @@ impl<'de> Deserialize<'de> for Unigram {
     {
         deserializer.deserialize_struct(
             "Unigram",
-            &["type", "vocab", "unk_id", "byte_fallback"],
+            &[
+                "type",
+                "vocab",
+                "unk_id",
+                "byte_fallback",
+                "unused",
+                "sampling",
+                "cache_capacity",
+            ],
             UnigramVisitor,
         )
     }
@@ -49,12 +78,18 @@ impl<'de> Visitor<'de> for UnigramVisitor {
         let mut vocab: Option<Vec<(String, f64)>> = None;
         let mut unk_id: Option<usize> = None;
         let mut byte_fallback: bool = false;
+        let mut unused: Option<HashSet<usize>> = None;
+        let mut sampling: Option<UnigramSampling> = None;
+        let mut cache_capacity: Option<usize> = None;
         while let Some(key) = map.next_key::<String>()? {
             match key.as_ref() {
                 "unk_id" => {
                     unk_id = map.next_value()?;
                 }
                 "byte_fallback" => byte_fallback = map.next_value()?,
+                "unused" => unused = map.next_value()?,
+                "sampling" => sampling = map.next_value()?,
+                "cache_capacity" => cache_capacity = map.next_value()?,
                 "vocab" => vocab = Some(map.next_value()?),
                 "type" => match map.next_value()? {
                     "Unigram" => {}
@@ -69,8 +104,18 @@ impl<'de> Visitor<'de> for UnigramVisitor {
             }
         }
         match (vocab, unk_id, byte_fallback) {
-            (Some(vocab), unk_id, byte_fallback) => Ok(Unigram::from(vocab, unk_id, byte_fallback)
-                .map_err(|err| Error::custom(format!("Unable to load vocab {:?}", err)))?),
+            (Some(vocab), unk_id, byte_fallback) => {
+                let mut model = Unigram::from(vocab, unk_id, byte_fallback)
+                    .map_err(|err| Error::custom(format!("Unable to load vocab {:?}", err)))?;
+                if let Some(unused) = unused {
+                    model.set_unused(unused);
+                }
+                model.set_sampling(sampling);
+                if let Some(capacity) = cache_capacity {
+                    model.set_cache_capacity(capacity);
+                }
+                Ok(model)
+            }
             (None, _, _) => Err(Error::custom("Missing vocab")),
         }
     }
@@ -102,6 +147,63 @@ mod test {
         assert_eq!(model, reconstructed);
     }
 
+    #[test]
+    fn test_serialization_sampling() {
+        let vocab = vec![("<unk>".to_string(), 0.0), ("a".to_string(), -0.5)];
+        let mut model = Unigram::from(vocab, Some(0), false).unwrap();
+
+        let data = serde_json::to_string(&model).unwrap();
+        assert!(!data.contains("sampling"));
+
+        let sampling = UnigramSampling {
+            alpha: 0.1,
+            nbest_size: None,
+            seed: Some(42),
+        };
+        model.set_sampling(Some(sampling));
+        let data = serde_json::to_string(&model).unwrap();
+        assert_eq!(
+            data,
+            r#"{"type":"Unigram","unk_id":0,"vocab":[["<unk>",0.0],["a",-0.5]],"byte_fallback":false,"sampling":{"alpha":0.1,"nbest_size":null,"seed":42}}"#
+        );
+        let reconstructed: Unigram = serde_json::from_str(&data).unwrap();
+        assert_eq!(reconstructed.get_sampling(), Some(&sampling));
+    }
+
+    #[test]
+    fn test_serialization_cache_capacity() {
+        let vocab = vec![("<unk>".to_string(), 0.0), ("a".to_string(), -0.5)];
+        let mut model = Unigram::from(vocab, Some(0), false).unwrap();
+        model.set_cache_capacity(100);
+
+        let data = serde_json::to_string(&model).unwrap();
+        assert_eq!(
+            data,
+            r#"{"type":"Unigram","unk_id":0,"vocab":[["<unk>",0.0],["a",-0.5]],"byte_fallback":false,"cache_capacity":100}"#
+        );
+        let reconstructed: Unigram = serde_json::from_str(&data).unwrap();
+        assert_eq!(reconstructed.get_cache_capacity(), 100);
+    }
+
+    #[test]
+    fn test_serialization_unused() {
+        let vocab = vec![
+            ("<unk>".to_string(), 0.0),
+            ("a".to_string(), -0.5),
+            ("b".to_string(), -0.5),
+        ];
+        let mut model = Unigram::from(vocab, Some(0), false).unwrap();
+        model.set_unused(vec![2].into_iter().collect());
+
+        let data = serde_json::to_string(&model).unwrap();
+        assert_eq!(
+            data,
+            r#"{"type":"Unigram","unk_id":0,"vocab":[["<unk>",0.0],["a",-0.5],["b",-0.5]],"byte_fallback":false,"unused":[2]}"#
+        );
+        let reconstructed: Unigram = serde_json::from_str(&data).unwrap();
+        assert_eq!(model, reconstructed);
+    }
+
     #[test]
     fn test_serialization_no_unk_id() {
         let vocab = vec![("a".to_string(), -0.5)];
@@ -1,4 +1,4 @@
-use super::OrderedVocabIter;
+use super::{compact_ids, OrderedVocabIter};
 use crate::tokenizer::{Model, Result, Token};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -176,6 +176,14 @@ impl Model for WordLevel {
         }
     }
 
+    fn count_tokens(&self, token: &str) -> Result<usize> {
+        if self.vocab.contains_key(token) || self.vocab.contains_key(&self.unk_token) {
+            Ok(1)
+        } else {
+            Err(Box::new(Error::MissingUnkToken))
+        }
+    }
+
     fn token_to_id(&self, token: &str) -> Option<u32> {
         self.vocab.get(token).copied()
     }
@@ -213,6 +221,28 @@ impl Model for WordLevel {
     fn get_trainer(&self) -> Self::Trainer {
         WordLevelTrainer::default()
     }
+
+    /// The unknown token is always kept
+    fn prune_vocab(&mut self, keep: &dyn Fn(u32) -> bool) -> Result<HashMap<u32, u32>> {
+        let unk_id = self.vocab.get(&self.unk_token).copied();
+        let ids = compact_ids(
+            self.vocab_r
+                .keys()
+                .copied()
+                .filter(|id| keep(*id) || Some(*id) == unk_id),
+        );
+        self.vocab_r = self
+            .vocab_r
+            .drain()
+            .filter_map(|(id, token)| Some((*ids.get(&id)?, token)))
+            .collect();
+        self.vocab = self
+            .vocab_r
+            .iter()
+            .map(|(id, token)| (token.clone(), *id))
+            .collect();
+        Ok(ids)
+    }
 }
 
 #[cfg(test)]
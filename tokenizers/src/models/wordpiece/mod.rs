@@ -1,7 +1,11 @@
 //! [WordPiece](https://static.googleusercontent.com/media/research.google.com/en//pubs/archive/37842.pdf)
 //! model.
 
-use crate::models::bpe::BPE;
+use crate::models::{
+    bpe::BPE,
+    compact_ids,
+    merge::{merge_vocabs, VocabMergeError, VocabMergeReport},
+};
 use crate::tokenizer::{Model, Result, Token};
 use std::{
     borrow::Cow,
@@ -14,7 +18,9 @@ use std::{
 
 mod serialization;
 mod trainer;
+mod trie;
 pub use trainer::*;
+use trie::WordPieceTrie;
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -106,9 +112,12 @@ impl WordPieceBuilder {
             .map(|(key, val)| (*val, key.to_owned()))
             .collect();
 
+        let trie = WordPieceTrie::new(&self.config.vocab, &self.config.continuing_subword_prefix);
+
         Ok(WordPiece {
             vocab: self.config.vocab,
             vocab_r,
+            trie,
             unk_token: self.config.unk_token,
             continuing_subword_prefix: self.config.continuing_subword_prefix,
             max_input_chars_per_word: self.config.max_input_chars_per_word,
@@ -123,6 +132,10 @@ impl WordPieceBuilder {
 pub struct WordPiece {
     vocab: Vocab,
     vocab_r: VocabR,
+    /// Built for the vocab and the `continuing_subword_prefix`, and rebuilt whenever they get
+    /// changed by the model. If the public prefix is modified directly, we fall back to the
+    /// quadratic longest-match-first.
+    trie: WordPieceTrie,
     pub unk_token: String,
     pub continuing_subword_prefix: String,
     pub max_input_chars_per_word: usize,
@@ -144,6 +157,7 @@ impl Default for WordPiece {
         Self {
             vocab: HashMap::new(),
             vocab_r: HashMap::new(),
+            trie: WordPieceTrie::new(&HashMap::new(), "##"),
             unk_token: String::from("[UNK]"),
             continuing_subword_prefix: String::from("##"),
             max_input_chars_per_word: 100,
@@ -176,44 +190,57 @@ impl WordPiece {
         WordPiece::builder().files(vocab.to_owned())
     }
 
+    /// Set the `continuing_subword_prefix`, rebuilding the trie used to tokenize for it.
+    pub fn set_continuing_subword_prefix(&mut self, prefix: String) {
+        self.continuing_subword_prefix = prefix;
+        self.trie = WordPieceTrie::new(&self.vocab, &self.continuing_subword_prefix);
+    }
+
     /// Create a `WordPiece` model from a `BPE` model.
     pub fn from_bpe(bpe: &BPE) -> Self {
-        let mut wp = Self::builder().vocab(bpe.get_vocab()).build().unwrap();
+        let mut builder = Self::builder().vocab(bpe.get_vocab());
         if let Some(unk) = bpe.get_unk_token() {
-            unk.clone_into(&mut wp.unk_token);
+            builder = builder.unk_token(unk.to_owned());
         }
         if let Some(prefix) = bpe.get_continuing_subword_prefix() {
-            prefix.clone_into(&mut wp.continuing_subword_prefix);
+            builder = builder.continuing_subword_prefix(prefix.to_owned());
         }
-        wp
+        builder.build().unwrap()
     }
-}
 
-impl Model for WordPiece {
-    type Trainer = WordPieceTrainer;
-
-    fn get_vocab(&self) -> HashMap<String, u32> {
-        self.vocab.clone()
-    }
+    /// Merge the vocabulary of the other `WordPiece` into this one, as explained in
+    /// [`ModelWrapper::merge_vocab`](crate::models::ModelWrapper::merge_vocab). The other
+    /// options are the ones of this model.
+    pub fn merge_vocab(
+        &self,
+        other: &WordPiece,
+        reserved: &HashMap<u32, String>,
+    ) -> Result<(WordPiece, VocabMergeReport)> {
+        if self.continuing_subword_prefix != other.continuing_subword_prefix {
+            return Err(Box::new(VocabMergeError::IncompatibleOption(
+                "continuing_subword_prefix",
+            )));
+        }
 
-    fn get_vocab_size(&self) -> usize {
-        self.vocab.len()
+        let (vocab, report) = merge_vocabs(
+            &self.vocab,
+            reserved,
+            other
+                .vocab_r
+                .iter()
+                .map(|(id, token)| (*id, token.as_str())),
+        );
+        let wordpiece = WordPiece::builder()
+            .vocab(vocab)
+            .unk_token(self.unk_token.clone())
+            .continuing_subword_prefix(self.continuing_subword_prefix.clone())
+            .max_input_chars_per_word(self.max_input_chars_per_word)
+            .build()?;
+        Ok((wordpiece, report))
     }
 
-    fn tokenize(&self, sequence: &str) -> Result<Vec<Token>> {
-        let char_len = sequence.chars().count();
-        if char_len > self.max_input_chars_per_word {
-            return Ok(vec![Token {
-                value: self.unk_token.clone(),
-                id: *self
-                    .vocab
-                    .get(&self.unk_token)
-                    .ok_or(Error::MissingUnkToken)?,
-                offsets: (0, sequence.len()),
-            }]);
-        }
-
-        let mut is_bad = false;
+    /// Greedy longest-match-first, trying every possible end for each subword
+    fn tokenize_greedy(&self, sequence: &str) -> Option<Vec<Token>> {
         let mut start = 0;
         let mut sub_tokens: Vec<Token> = vec![];
 
@@ -238,26 +265,54 @@ impl Model for WordPiece {
                 end -= substr.chars().last().map_or(1, |c| c.len_utf8());
             }
 
-            if cur_str.is_none() {
-                is_bad = true;
-                break;
-            }
-
-            sub_tokens.push(cur_str.unwrap());
+            sub_tokens.push(cur_str?);
             start = end;
         }
 
-        if is_bad {
-            Ok(vec![Token {
+        Some(sub_tokens)
+    }
+}
+
+impl Model for WordPiece {
+    type Trainer = WordPieceTrainer;
+
+    fn get_vocab(&self) -> HashMap<String, u32> {
+        self.vocab.clone()
+    }
+
+    fn get_vocab_size(&self) -> usize {
+        self.vocab.len()
+    }
+
+    fn tokenize(&self, sequence: &str) -> Result<Vec<Token>> {
+        let char_len = sequence.chars().count();
+        if char_len > self.max_input_chars_per_word {
+            return Ok(vec![Token {
                 value: self.unk_token.clone(),
                 id: *self
                     .vocab
                     .get(&self.unk_token)
                     .ok_or(Error::MissingUnkToken)?,
                 offsets: (0, sequence.len()),
-            }])
+            }]);
+        }
+
+        let sub_tokens = if self.trie.prefix == self.continuing_subword_prefix {
+            self.trie.tokenize(sequence)
         } else {
-            Ok(sub_tokens)
+            self.tokenize_greedy(sequence)
+        };
+
+        match sub_tokens {
+            Some(sub_tokens) => Ok(sub_tokens),
+            None => Ok(vec![Token {
+                value: self.unk_token.clone(),
+                id: *self
+                    .vocab
+                    .get(&self.unk_token)
+                    .ok_or(Error::MissingUnkToken)?,
+                offsets: (0, sequence.len()),
+            }]),
         }
     }
 
@@ -295,6 +350,29 @@ impl Model for WordPiece {
     fn get_trainer(&self) -> Self::Trainer {
         WordPieceTrainer::builder().build()
     }
+
+    /// The unknown token is always kept
+    fn prune_vocab(&mut self, keep: &dyn Fn(u32) -> bool) -> Result<HashMap<u32, u32>> {
+        let unk_id = self.vocab.get(&self.unk_token).copied();
+        let ids = compact_ids(
+            self.vocab_r
+                .keys()
+                .copied()
+                .filter(|id| keep(*id) || Some(*id) == unk_id),
+        );
+        self.vocab_r = self
+            .vocab_r
+            .drain()
+            .filter_map(|(id, token)| Some((*ids.get(&id)?, token)))
+            .collect();
+        self.vocab = self
+            .vocab_r
+            .iter()
+            .map(|(id, token)| (token.clone(), *id))
+            .collect();
+        self.trie = WordPieceTrie::new(&self.vocab, &self.continuing_subword_prefix);
+        Ok(ids)
+    }
 }
 
 #[cfg(test)]
@@ -305,4 +383,83 @@ mod tests {
     fn test_error_display() {
         assert!(format!("{}", Error::MissingUnkToken).contains("Missing [UNK] token"));
     }
+
+    #[test]
+    fn test_tokenize_same_as_greedy() {
+        let alphabet = ['a', 'b', 'é', '#'];
+        let vocab = [
+            "[UNK]", "a", "b", "é", "ab", "aba", "abab", "bé", "##a", "##b", "##ab", "##bab",
+            "##éa", "#", "##", "##a#", "###",
+        ];
+        let mut words = vec![String::new()];
+        for length in 1..=6 {
+            let previous = words.clone();
+            for word in previous.iter().filter(|w| w.chars().count() == length - 1) {
+                for c in alphabet {
+                    words.push(format!("{}{}", word, c));
+                }
+            }
+        }
+
+        for prefix in ["##", "#", ""] {
+            let wp = WordPiece::builder()
+                .vocab(
+                    vocab
+                        .iter()
+                        .enumerate()
+                        .map(|(i, token)| (token.to_string(), i as u32))
+                        .collect(),
+                )
+                .continuing_subword_prefix(prefix.to_string())
+                .max_input_chars_per_word(5)
+                .build()
+                .unwrap();
+            for word in &words {
+                let expected = if word.chars().count() > 5 {
+                    None
+                } else {
+                    wp.tokenize_greedy(word)
+                };
+                let expected = expected
+                    .unwrap_or_else(|| vec![Token::new(0, "[UNK]".into(), (0, word.len()))]);
+                assert_eq!(wp.tokenize(word).unwrap(), expected, "{:?}", word);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tokenize_prefix_changed() {
+        let vocab: Vocab = [("[UNK]", 0), ("a", 1), ("##b", 2), ("@@b", 3)]
+            .iter()
+            .map(|(token, id)| (token.to_string(), *id))
+            .collect();
+        let mut wp = WordPiece::builder().vocab(vocab).build().unwrap();
+        assert_eq!(
+            wp.tokenize("ab").unwrap(),
+            vec![
+                Token::new(1, "a".into(), (0, 1)),
+                Token::new(2, "##b".into(), (1, 2))
+            ]
+        );
+
+        wp.continuing_subword_prefix = "@@".into();
+        assert_eq!(
+            wp.tokenize("ab").unwrap(),
+            vec![
+                Token::new(1, "a".into(), (0, 1)),
+                Token::new(3, "@@b".into(), (1, 2))
+            ]
+        );
+
+        // The setter rebuilds the trie for the new prefix
+        wp.set_continuing_subword_prefix("##".into());
+        assert_eq!(wp.trie.prefix, "##");
+        assert_eq!(
+            wp.tokenize("ab").unwrap(),
+            vec![
+                Token::new(1, "a".into(), (0, 1)),
+                Token::new(2, "##b".into(), (1, 2))
+            ]
+        );
+    }
 }
@@ -167,9 +167,10 @@ impl WordPieceTrainer {
         let special_tokens = self.bpe_trainer.train(&mut bpe)?;
         let new_wordpiece = WordPiece::from_bpe(&bpe);
 
-        // Transfer the vocab
+        // Transfer the vocab, along with the trie built for it
         model.vocab = new_wordpiece.vocab;
         model.vocab_r = new_wordpiece.vocab_r;
+        model.trie = new_wordpiece.trie;
         // The continuing_subword_prefix is the only other option to be overriden by the trainer
         model.continuing_subword_prefix = new_wordpiece.continuing_subword_prefix;
 
@@ -197,3 +198,37 @@ impl Trainer for WordPieceTrainer {
         self.bpe_trainer.feed(iterator, process)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Model;
+
+    #[test]
+    fn test_train_then_tokenize() {
+        let mut trainer = WordPieceTrainer::builder()
+            .show_progress(false)
+            .special_tokens(vec![AddedToken::from("[UNK]", true)])
+            .build();
+        trainer
+            .feed(["hello world hello there hello"].iter(), |sequence| {
+                Ok(sequence.split(' ').map(|word| word.to_owned()).collect())
+            })
+            .unwrap();
+        let mut model = WordPiece::default();
+        trainer.train(&mut model).unwrap();
+
+        for word in ["hello", "world", "there"] {
+            let tokens = model.tokenize(word).unwrap();
+            assert_eq!(
+                tokens.iter().map(|t| t.value.as_str()).collect::<Vec<_>>(),
+                vec![word]
+            );
+        }
+        let tokens = model.tokenize("xyz").unwrap();
+        assert_eq!(
+            tokens.iter().map(|t| t.value.as_str()).collect::<Vec<_>>(),
+            vec!["[UNK]"]
+        );
+    }
+}
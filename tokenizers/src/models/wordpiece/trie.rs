@@ -0,0 +1,163 @@
+//! Linear-time WordPiece tokenization, using the LinMaxMatch algorithm described in
+//! [Fast WordPiece Tokenization](https://arxiv.org/abs/2012.15524).
+
+use super::Vocab;
+use crate::tokenizer::Token;
+use std::collections::HashMap;
+
+/// The node matching the beginning of a word
+const ROOT: u32 = 0;
+/// The node matching the `continuing_subword_prefix`, from which any subword following the
+/// first one of a word is matched
+const SUFFIX_ROOT: u32 = 1;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Node {
+    /// The piece matched by the string leading to this node, if it is part of the vocab
+    piece: Option<u32>,
+    /// The node to continue from when the next char can't be matched
+    fail: Option<u32>,
+    /// The pieces to emit when following the failure link
+    fail_pops: Vec<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Piece {
+    id: u32,
+    value: String,
+    /// The number of bytes of the word covered by this piece
+    len: usize,
+}
+
+/// A trie of the vocabulary with precomputed failure links, which lets us tokenize a word
+/// with the same greedy longest-match-first results as `WordPiece`, but in linear time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct WordPieceTrie {
+    /// The `continuing_subword_prefix` used to build this trie
+    pub(super) prefix: String,
+    nodes: Vec<Node>,
+    transitions: HashMap<(u32, char), u32>,
+    pieces: Vec<Piece>,
+}
+
+impl WordPieceTrie {
+    pub(super) fn new(vocab: &Vocab, prefix: &str) -> Self {
+        let mut trie = Self {
+            prefix: prefix.to_owned(),
+            nodes: vec![Node::default(), Node::default()],
+            transitions: HashMap::new(),
+            pieces: vec![],
+        };
+        // For each node, its parent and the char leading to it, along with its depth
+        let mut parents: Vec<Option<(u32, char)>> = vec![None, None];
+        let mut depths: Vec<usize> = vec![0, 0];
+
+        // Sort the vocab to always build the same trie
+        let mut tokens = vocab.iter().collect::<Vec<_>>();
+        tokens.sort_unstable_by(|a, b| (a.1, a.0).cmp(&(b.1, b.0)));
+        for (token, id) in tokens {
+            // Tokens are matched as is at the beginning of a word, and without their prefix
+            // everywhere else
+            let mut matches = vec![(ROOT, token.as_str())];
+            if let Some(suffix) = token.strip_prefix(prefix) {
+                matches.push((SUFFIX_ROOT, suffix));
+            }
+            for (mut node, string) in matches {
+                if string.is_empty() {
+                    continue;
+                }
+                for c in string.chars() {
+                    node = match trie.transitions.get(&(node, c)) {
+                        Some(next) => *next,
+                        None => {
+                            let next = trie.nodes.len() as u32;
+                            trie.nodes.push(Node::default());
+                            parents.push(Some((node, c)));
+                            depths.push(depths[node as usize] + 1);
+                            trie.transitions.insert((node, c), next);
+                            next
+                        }
+                    };
+                }
+                trie.nodes[node as usize].piece = Some(trie.pieces.len() as u32);
+                trie.pieces.push(Piece {
+                    id: *id,
+                    value: token.to_owned(),
+                    len: string.len(),
+                });
+            }
+        }
+
+        // The failure links of a node only depend on nodes with a lower depth, so we can
+        // compute them in a breadth-first order.
+        let mut order = (0..trie.nodes.len() as u32).collect::<Vec<_>>();
+        order.sort_by_key(|node| depths[*node as usize]);
+        for node in order {
+            let (parent, c) = match parents[node as usize] {
+                Some(parent) => parent,
+                None => continue,
+            };
+            if let Some(piece) = trie.nodes[node as usize].piece {
+                // We emit the whole piece and continue with a new subword
+                trie.nodes[node as usize].fail = Some(SUFFIX_ROOT);
+                trie.nodes[node as usize].fail_pops = vec![piece];
+                continue;
+            }
+
+            let mut fail = trie.nodes[parent as usize].fail;
+            let mut fail_pops = trie.nodes[parent as usize].fail_pops.clone();
+            while let Some(current) = fail {
+                if let Some(next) = trie.transitions.get(&(current, c)) {
+                    trie.nodes[node as usize].fail = Some(*next);
+                    trie.nodes[node as usize].fail_pops = fail_pops;
+                    break;
+                }
+                fail_pops.extend(&trie.nodes[current as usize].fail_pops);
+                fail = trie.nodes[current as usize].fail;
+            }
+        }
+
+        trie
+    }
+
+    /// Tokenize the given word, returning `None` if it can't be represented with the vocab
+    pub(super) fn tokenize(&self, word: &str) -> Option<Vec<Token>> {
+        let mut tokens = vec![];
+        let mut offset = 0;
+        let mut node = ROOT;
+        for c in word.chars() {
+            loop {
+                if let Some(next) = self.transitions.get(&(node, c)) {
+                    node = *next;
+                    break;
+                }
+                node = self.follow_failure(node, &mut tokens, &mut offset)?;
+            }
+        }
+        while node != ROOT && node != SUFFIX_ROOT {
+            node = self.follow_failure(node, &mut tokens, &mut offset)?;
+        }
+
+        Some(tokens)
+    }
+
+    fn follow_failure(
+        &self,
+        node: u32,
+        tokens: &mut Vec<Token>,
+        offset: &mut usize,
+    ) -> Option<u32> {
+        let node = &self.nodes[node as usize];
+        let fail = node.fail?;
+        for piece in &node.fail_pops {
+            let piece = &self.pieces[*piece as usize];
+            tokens.push(Token::new(
+                piece.id,
+                piece.value.clone(),
+                (*offset, *offset + piece.len),
+            ));
+            *offset += piece.len;
+        }
+        Some(fail)
+    }
+}
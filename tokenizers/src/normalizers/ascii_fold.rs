@@ -0,0 +1,179 @@
+use super::ascii_fold_table::ASCII_FOLDING;
+use crate::tokenizer::{NormalizedString, Normalizer, Result};
+use crate::utils::macro_rules_attribute;
+use serde::{Deserialize, Serialize};
+
+/// What to do with the non ASCII chars that can't be folded
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AsciiFoldFallback {
+    /// Keep them as they are
+    #[default]
+    Keep,
+    /// Remove them
+    Drop,
+    /// Replace each of them by the given string
+    Replace(String),
+}
+
+/// Folds the chars to their closest ASCII equivalent when there is one, like `è` to `e`,
+/// `ß` to `ss`, `’` to `'` or the fullwidth forms to ASCII. The combining marks are removed.
+#[derive(Clone, Debug, Default)]
+#[macro_rules_attribute(impl_serde_type!)]
+pub struct AsciiFold {
+    #[serde(default)]
+    pub fallback: AsciiFoldFallback,
+}
+
+impl AsciiFold {
+    pub fn new(fallback: AsciiFoldFallback) -> Self {
+        Self { fallback }
+    }
+}
+
+/// Add the first char replacing one original char, and keep the following ones to be inserted
+/// once all the chars have been replaced. An empty replacement removes the original char.
+fn push_folding<'a>(
+    transformations: &mut Vec<(char, isize)>,
+    expansions: &mut Vec<(usize, &'a str)>,
+    initial_offset: &mut usize,
+    folding: &'a str,
+) {
+    let mut chars = folding.chars();
+    match chars.next() {
+        Some(first) => {
+            if !chars.as_str().is_empty() {
+                expansions.push((transformations.len(), chars.as_str()));
+            }
+            transformations.push((first, 0));
+        }
+        None => match transformations.last_mut() {
+            Some((_, change)) => *change -= 1,
+            None => *initial_offset += 1,
+        },
+    }
+}
+
+impl Normalizer for AsciiFold {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        if normalized.get().is_ascii() {
+            return Ok(());
+        }
+
+        let mut transformations = Vec::with_capacity(normalized.len());
+        let mut expansions = vec![];
+        let mut initial_offset = 0;
+        for c in normalized.get().chars() {
+            if c.is_ascii() {
+                transformations.push((c, 0));
+                continue;
+            }
+            let folding = match ASCII_FOLDING.binary_search_by_key(&c, |(c, _)| *c) {
+                Ok(i) => ASCII_FOLDING[i].1,
+                Err(_) => match &self.fallback {
+                    AsciiFoldFallback::Keep => {
+                        transformations.push((c, 0));
+                        continue;
+                    }
+                    AsciiFoldFallback::Drop => "",
+                    AsciiFoldFallback::Replace(replacement) => replacement,
+                },
+            };
+            push_folding(
+                &mut transformations,
+                &mut expansions,
+                &mut initial_offset,
+                folding,
+            );
+        }
+        normalized.transform(transformations, initial_offset);
+
+        // The chars following the first one of a folding are only inserted once the removed chars
+        // are gone, so that they share its alignment instead of the one of a removed char.
+        if !expansions.is_empty() {
+            let mut expansions = expansions.into_iter().peekable();
+            let mut transformations = Vec::with_capacity(normalized.len());
+            for (i, c) in normalized.get().chars().enumerate() {
+                transformations.push((c, 0));
+                if let Some((_, rest)) = expansions.next_if(|(index, _)| *index == i) {
+                    transformations.extend(rest.chars().map(|c| (c, 1)));
+                }
+            }
+            normalized.transform(transformations, 0);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalizers::NormalizerWrapper;
+    use crate::tokenizer::normalizer::Range;
+
+    fn fold(fallback: AsciiFoldFallback, s: &str) -> NormalizedString {
+        let mut n = NormalizedString::from(s);
+        AsciiFold::new(fallback).normalize(&mut n).unwrap();
+        n
+    }
+
+    #[test]
+    fn table_is_sorted() {
+        assert!(ASCII_FOLDING.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(ASCII_FOLDING.iter().all(|(_, folding)| folding.is_ascii()));
+    }
+
+    #[test]
+    fn folds_to_ascii() {
+        let n = fold(Default::default(), "Crème brûlée, Straße");
+        assert_eq!(n.get(), "Creme brulee, Strasse");
+        assert_eq!(n.get_range_original(Range::Normalized(2..3)), Some("è"));
+        assert_eq!(n.get_range_original(Range::Normalized(18..20)), Some("ß"));
+
+        let n = fold(Default::default(), "“Ｈｉ” — it’s Øre…");
+        assert_eq!(n.get(), "\"Hi\" - it's Ore...");
+        assert_eq!(n.get_range_original(Range::Normalized(1..3)), Some("Ｈｉ"));
+
+        // The decomposed accents are removed too
+        let n = fold(Default::default(), "e\u{301}te\u{301}");
+        assert_eq!(n.get(), "ete");
+        assert_eq!(n.get_range_original(Range::Normalized(1..2)), Some("t"));
+
+        // A removed char following a one-to-many folding doesn't shift its alignments
+        let n = fold(Default::default(), "aß\u{301}b");
+        assert_eq!(n.get(), "assb");
+        assert_eq!(n.get_range_original(Range::Normalized(1..3)), Some("ß"));
+        assert_eq!(n.get_range_original(Range::Normalized(2..3)), Some("ß"));
+        assert_eq!(n.get_range_original(Range::Normalized(3..4)), Some("b"));
+    }
+
+    #[test]
+    fn fallbacks() {
+        let text = "日本 é 語";
+        assert_eq!(fold(AsciiFoldFallback::Keep, text).get(), "日本 e 語");
+        assert_eq!(fold(AsciiFoldFallback::Drop, text).get(), " e ");
+        let n = fold(AsciiFoldFallback::Replace("?".into()), text);
+        assert_eq!(n.get(), "?? e ?");
+        assert_eq!(n.get_range_original(Range::Normalized(5..6)), Some("語"));
+    }
+
+    #[test]
+    fn serialization() {
+        let ascii_fold: NormalizerWrapper =
+            AsciiFold::new(AsciiFoldFallback::Replace("?".into())).into();
+        let json = serde_json::to_string(&ascii_fold).unwrap();
+        assert_eq!(json, r#"{"type":"AsciiFold","fallback":{"replace":"?"}}"#);
+        assert!(matches!(
+            serde_json::from_str(&json).unwrap(),
+            NormalizerWrapper::AsciiFold(AsciiFold {
+                fallback: AsciiFoldFallback::Replace(_)
+            })
+        ));
+        assert!(matches!(
+            serde_json::from_str(r#"{"type":"AsciiFold"}"#).unwrap(),
+            NormalizerWrapper::AsciiFold(AsciiFold {
+                fallback: AsciiFoldFallback::Keep
+            })
+        ));
+    }
+}
@@ -0,0 +1,2454 @@
+// Generated by scripts/generate_ascii_fold_table.py from the NFKD decompositions of Unicode
+// 14.0.0, without the combining marks, and a list of letters and punctuation without
+// an ASCII decomposition. Do not edit.
+
+/// The ASCII folding of the chars that have one, sorted by char. The combining marks fold to
+/// an empty string.
+pub(super) static ASCII_FOLDING: &[(char, &str)] = &[
+    ('\u{A0}', " "),
+    ('\u{A1}', "!"),
+    ('\u{A6}', "|"),
+    ('\u{A8}', " "),
+    ('\u{A9}', "(C)"),
+    ('\u{AA}', "a"),
+    ('\u{AB}', "\""),
+    ('\u{AC}', "-"),
+    ('\u{AE}', "(R)"),
+    ('\u{AF}', " "),
+    ('\u{B0}', "o"),
+    ('\u{B1}', "+/-"),
+    ('\u{B2}', "2"),
+    ('\u{B3}', "3"),
+    ('\u{B4}', " "),
+    ('\u{B7}', "."),
+    ('\u{B8}', " "),
+    ('\u{B9}', "1"),
+    ('\u{BA}', "o"),
+    ('\u{BB}', "\""),
+    ('\u{BC}', "1/4"),
+    ('\u{BD}', "1/2"),
+    ('\u{BE}', "3/4"),
+    ('\u{BF}', "?"),
+    ('\u{C0}', "A"),
+    ('\u{C1}', "A"),
+    ('\u{C2}', "A"),
+    ('\u{C3}', "A"),
+    ('\u{C4}', "A"),
+    ('\u{C5}', "A"),
+    ('\u{C6}', "AE"),
+    ('\u{C7}', "C"),
+    ('\u{C8}', "E"),
+    ('\u{C9}', "E"),
+    ('\u{CA}', "E"),
+    ('\u{CB}', "E"),
+    ('\u{CC}', "I"),
+    ('\u{CD}', "I"),
+    ('\u{CE}', "I"),
+    ('\u{CF}', "I"),
+    ('\u{D0}', "D"),
+    ('\u{D1}', "N"),
+    ('\u{D2}', "O"),
+    ('\u{D3}', "O"),
+    ('\u{D4}', "O"),
+    ('\u{D5}', "O"),
+    ('\u{D6}', "O"),
+    ('\u{D7}', "x"),
+    ('\u{D8}', "O"),
+    ('\u{D9}', "U"),
+    ('\u{DA}', "U"),
+    ('\u{DB}', "U"),
+    ('\u{DC}', "U"),
+    ('\u{DD}', "Y"),
+    ('\u{DE}', "TH"),
+    ('\u{DF}', "ss"),
+    ('\u{E0}', "a"),
+    ('\u{E1}', "a"),
+    ('\u{E2}', "a"),
+    ('\u{E3}', "a"),
+    ('\u{E4}', "a"),
+    ('\u{E5}', "a"),
+    ('\u{E6}', "ae"),
+    ('\u{E7}', "c"),
+    ('\u{E8}', "e"),
+    ('\u{E9}', "e"),
+    ('\u{EA}', "e"),
+    ('\u{EB}', "e"),
+    ('\u{EC}', "i"),
+    ('\u{ED}', "i"),
+    ('\u{EE}', "i"),
+    ('\u{EF}', "i"),
+    ('\u{F0}', "d"),
+    ('\u{F1}', "n"),
+    ('\u{F2}', "o"),
+    ('\u{F3}', "o"),
+    ('\u{F4}', "o"),
+    ('\u{F5}', "o"),
+    ('\u{F6}', "o"),
+    ('\u{F7}', "/"),
+    ('\u{F8}', "o"),
+    ('\u{F9}', "u"),
+    ('\u{FA}', "u"),
+    ('\u{FB}', "u"),
+    ('\u{FC}', "u"),
+    ('\u{FD}', "y"),
+    ('\u{FE}', "th"),
+    ('\u{FF}', "y"),
+    ('\u{100}', "A"),
+    ('\u{101}', "a"),
+    ('\u{102}', "A"),
+    ('\u{103}', "a"),
+    ('\u{104}', "A"),
+    ('\u{105}', "a"),
+    ('\u{106}', "C"),
+    ('\u{107}', "c"),
+    ('\u{108}', "C"),
+    ('\u{109}', "c"),
+    ('\u{10A}', "C"),
+    ('\u{10B}', "c"),
+    ('\u{10C}', "C"),
+    ('\u{10D}', "c"),
+    ('\u{10E}', "D"),
+    ('\u{10F}', "d"),
+    ('\u{110}', "D"),
+    ('\u{111}', "d"),
+    ('\u{112}', "E"),
+    ('\u{113}', "e"),
+    ('\u{114}', "E"),
+    ('\u{115}', "e"),
+    ('\u{116}', "E"),
+    ('\u{117}', "e"),
+    ('\u{118}', "E"),
+    ('\u{119}', "e"),
+    ('\u{11A}', "E"),
+    ('\u{11B}', "e"),
+    ('\u{11C}', "G"),
+    ('\u{11D}', "g"),
+    ('\u{11E}', "G"),
+    ('\u{11F}', "g"),
+    ('\u{120}', "G"),
+    ('\u{121}', "g"),
+    ('\u{122}', "G"),
+    ('\u{123}', "g"),
+    ('\u{124}', "H"),
+    ('\u{125}', "h"),
+    ('\u{126}', "H"),
+    ('\u{127}', "h"),
+    ('\u{128}', "I"),
+    ('\u{129}', "i"),
+    ('\u{12A}', "I"),
+    ('\u{12B}', "i"),
+    ('\u{12C}', "I"),
+    ('\u{12D}', "i"),
+    ('\u{12E}', "I"),
+    ('\u{12F}', "i"),
+    ('\u{130}', "I"),
+    ('\u{131}', "i"),
+    ('\u{132}', "IJ"),
+    ('\u{133}', "ij"),
+    ('\u{134}', "J"),
+    ('\u{135}', "j"),
+    ('\u{136}', "K"),
+    ('\u{137}', "k"),
+    ('\u{138}', "q"),
+    ('\u{139}', "L"),
+    ('\u{13A}', "l"),
+    ('\u{13B}', "L"),
+    ('\u{13C}', "l"),
+    ('\u{13D}', "L"),
+    ('\u{13E}', "l"),
+    ('\u{13F}', "L."),
+    ('\u{140}', "l."),
+    ('\u{141}', "L"),
+    ('\u{142}', "l"),
+    ('\u{143}', "N"),
+    ('\u{144}', "n"),
+    ('\u{145}', "N"),
+    ('\u{146}', "n"),
+    ('\u{147}', "N"),
+    ('\u{148}', "n"),
+    ('\u{149}', "'n"),
+    ('\u{14C}', "O"),
+    ('\u{14D}', "o"),
+    ('\u{14E}', "O"),
+    ('\u{14F}', "o"),
+    ('\u{150}', "O"),
+    ('\u{151}', "o"),
+    ('\u{152}', "OE"),
+    ('\u{153}', "oe"),
+    ('\u{154}', "R"),
+    ('\u{155}', "r"),
+    ('\u{156}', "R"),
+    ('\u{157}', "r"),
+    ('\u{158}', "R"),
+    ('\u{159}', "r"),
+    ('\u{15A}', "S"),
+    ('\u{15B}', "s"),
+    ('\u{15C}', "S"),
+    ('\u{15D}', "s"),
+    ('\u{15E}', "S"),
+    ('\u{15F}', "s"),
+    ('\u{160}', "S"),
+    ('\u{161}', "s"),
+    ('\u{162}', "T"),
+    ('\u{163}', "t"),
+    ('\u{164}', "T"),
+    ('\u{165}', "t"),
+    ('\u{166}', "T"),
+    ('\u{167}', "t"),
+    ('\u{168}', "U"),
+    ('\u{169}', "u"),
+    ('\u{16A}', "U"),
+    ('\u{16B}', "u"),
+    ('\u{16C}', "U"),
+    ('\u{16D}', "u"),
+    ('\u{16E}', "U"),
+    ('\u{16F}', "u"),
+    ('\u{170}', "U"),
+    ('\u{171}', "u"),
+    ('\u{172}', "U"),
+    ('\u{173}', "u"),
+    ('\u{174}', "W"),
+    ('\u{175}', "w"),
+    ('\u{176}', "Y"),
+    ('\u{177}', "y"),
+    ('\u{178}', "Y"),
+    ('\u{179}', "Z"),
+    ('\u{17A}', "z"),
+    ('\u{17B}', "Z"),
+    ('\u{17C}', "z"),
+    ('\u{17D}', "Z"),
+    ('\u{17E}', "z"),
+    ('\u{17F}', "s"),
+    ('\u{180}', "b"),
+    ('\u{181}', "B"),
+    ('\u{182}', "B"),
+    ('\u{183}', "b"),
+    ('\u{186}', "O"),
+    ('\u{187}', "C"),
+    ('\u{188}', "c"),
+    ('\u{189}', "D"),
+    ('\u{18A}', "D"),
+    ('\u{18B}', "D"),
+    ('\u{18C}', "d"),
+    ('\u{18E}', "E"),
+    ('\u{190}', "E"),
+    ('\u{191}', "F"),
+    ('\u{192}', "f"),
+    ('\u{193}', "G"),
+    ('\u{197}', "I"),
+    ('\u{198}', "K"),
+    ('\u{199}', "k"),
+    ('\u{19A}', "l"),
+    ('\u{19D}', "N"),
+    ('\u{19E}', "n"),
+    ('\u{19F}', "O"),
+    ('\u{1A0}', "O"),
+    ('\u{1A1}', "o"),
+    ('\u{1A4}', "P"),
+    ('\u{1A5}', "p"),
+    ('\u{1AB}', "t"),
+    ('\u{1AC}', "T"),
+    ('\u{1AD}', "t"),
+    ('\u{1AE}', "T"),
+    ('\u{1AF}', "U"),
+    ('\u{1B0}', "u"),
+    ('\u{1B2}', "V"),
+    ('\u{1B3}', "Y"),
+    ('\u{1B4}', "y"),
+    ('\u{1B5}', "Z"),
+    ('\u{1B6}', "z"),
+    ('\u{1C4}', "DZ"),
+    ('\u{1C5}', "Dz"),
+    ('\u{1C6}', "dz"),
+    ('\u{1C7}', "LJ"),
+    ('\u{1C8}', "Lj"),
+    ('\u{1C9}', "lj"),
+    ('\u{1CA}', "NJ"),
+    ('\u{1CB}', "Nj"),
+    ('\u{1CC}', "nj"),
+    ('\u{1CD}', "A"),
+    ('\u{1CE}', "a"),
+    ('\u{1CF}', "I"),
+    ('\u{1D0}', "i"),
+    ('\u{1D1}', "O"),
+    ('\u{1D2}', "o"),
+    ('\u{1D3}', "U"),
+    ('\u{1D4}', "u"),
+    ('\u{1D5}', "U"),
+    ('\u{1D6}', "u"),
+    ('\u{1D7}', "U"),
+    ('\u{1D8}', "u"),
+    ('\u{1D9}', "U"),
+    ('\u{1DA}', "u"),
+    ('\u{1DB}', "U"),
+    ('\u{1DC}', "u"),
+    ('\u{1DE}', "A"),
+    ('\u{1DF}', "a"),
+    ('\u{1E0}', "A"),
+    ('\u{1E1}', "a"),
+    ('\u{1E2}', "AE"),
+    ('\u{1E3}', "ae"),
+    ('\u{1E6}', "G"),
+    ('\u{1E7}', "g"),
+    ('\u{1E8}', "K"),
+    ('\u{1E9}', "k"),
+    ('\u{1EA}', "O"),
+    ('\u{1EB}', "o"),
+    ('\u{1EC}', "O"),
+    ('\u{1ED}', "o"),
+    ('\u{1F0}', "j"),
+    ('\u{1F1}', "DZ"),
+    ('\u{1F2}', "Dz"),
+    ('\u{1F3}', "dz"),
+    ('\u{1F4}', "G"),
+    ('\u{1F5}', "g"),
+    ('\u{1F8}', "N"),
+    ('\u{1F9}', "n"),
+    ('\u{1FA}', "A"),
+    ('\u{1FB}', "a"),
+    ('\u{1FC}', "AE"),
+    ('\u{1FD}', "ae"),
+    ('\u{1FE}', "O"),
+    ('\u{1FF}', "o"),
+    ('\u{200}', "A"),
+    ('\u{201}', "a"),
+    ('\u{202}', "A"),
+    ('\u{203}', "a"),
+    ('\u{204}', "E"),
+    ('\u{205}', "e"),
+    ('\u{206}', "E"),
+    ('\u{207}', "e"),
+    ('\u{208}', "I"),
+    ('\u{209}', "i"),
+    ('\u{20A}', "I"),
+    ('\u{20B}', "i"),
+    ('\u{20C}', "O"),
+    ('\u{20D}', "o"),
+    ('\u{20E}', "O"),
+    ('\u{20F}', "o"),
+    ('\u{210}', "R"),
+    ('\u{211}', "r"),
+    ('\u{212}', "R"),
+    ('\u{213}', "r"),
+    ('\u{214}', "U"),
+    ('\u{215}', "u"),
+    ('\u{216}', "U"),
+    ('\u{217}', "u"),
+    ('\u{218}', "S"),
+    ('\u{219}', "s"),
+    ('\u{21A}', "T"),
+    ('\u{21B}', "t"),
+    ('\u{21E}', "H"),
+    ('\u{21F}', "h"),
+    ('\u{224}', "Z"),
+    ('\u{225}', "z"),
+    ('\u{226}', "A"),
+    ('\u{227}', "a"),
+    ('\u{228}', "E"),
+    ('\u{229}', "e"),
+    ('\u{22A}', "O"),
+    ('\u{22B}', "o"),
+    ('\u{22C}', "O"),
+    ('\u{22D}', "o"),
+    ('\u{22E}', "O"),
+    ('\u{22F}', "o"),
+    ('\u{230}', "O"),
+    ('\u{231}', "o"),
+    ('\u{232}', "Y"),
+    ('\u{233}', "y"),
+    ('\u{234}', "l"),
+    ('\u{235}', "n"),
+    ('\u{236}', "t"),
+    ('\u{237}', "j"),
+    ('\u{238}', "db"),
+    ('\u{239}', "qp"),
+    ('\u{23A}', "A"),
+    ('\u{23B}', "C"),
+    ('\u{23C}', "c"),
+    ('\u{23D}', "L"),
+    ('\u{23E}', "T"),
+    ('\u{23F}', "s"),
+    ('\u{240}', "z"),
+    ('\u{243}', "B"),
+    ('\u{244}', "U"),
+    ('\u{246}', "E"),
+    ('\u{247}', "e"),
+    ('\u{248}', "J"),
+    ('\u{249}', "j"),
+    ('\u{24A}', "Q"),
+    ('\u{24B}', "q"),
+    ('\u{24C}', "R"),
+    ('\u{24D}', "r"),
+    ('\u{24E}', "Y"),
+    ('\u{24F}', "y"),
+    ('\u{253}', "b"),
+    ('\u{255}', "c"),
+    ('\u{256}', "d"),
+    ('\u{257}', "d"),
+    ('\u{25B}', "e"),
+    ('\u{25F}', "j"),
+    ('\u{260}', "g"),
+    ('\u{261}', "g"),
+    ('\u{262}', "G"),
+    ('\u{266}', "h"),
+    ('\u{267}', "h"),
+    ('\u{268}', "i"),
+    ('\u{26A}', "I"),
+    ('\u{26B}', "l"),
+    ('\u{26C}', "l"),
+    ('\u{26D}', "l"),
+    ('\u{271}', "m"),
+    ('\u{272}', "n"),
+    ('\u{273}', "n"),
+    ('\u{274}', "N"),
+    ('\u{276}', "OE"),
+    ('\u{27C}', "r"),
+    ('\u{27D}', "r"),
+    ('\u{27E}', "r"),
+    ('\u{280}', "R"),
+    ('\u{282}', "s"),
+    ('\u{288}', "t"),
+    ('\u{289}', "u"),
+    ('\u{28B}', "v"),
+    ('\u{28F}', "Y"),
+    ('\u{290}', "z"),
+    ('\u{291}', "z"),
+    ('\u{299}', "B"),
+    ('\u{29B}', "G"),
+    ('\u{29C}', "H"),
+    ('\u{29D}', "j"),
+    ('\u{29F}', "L"),
+    ('\u{2A0}', "q"),
+    ('\u{2B0}', "h"),
+    ('\u{2B1}', "h"),
+    ('\u{2B2}', "j"),
+    ('\u{2B3}', "r"),
+    ('\u{2B7}', "w"),
+    ('\u{2B8}', "y"),
+    ('\u{2B9}', "'"),
+    ('\u{2BB}', "'"),
+    ('\u{2BC}', "'"),
+    ('\u{2BD}', "'"),
+    ('\u{2C8}', "'"),
+    ('\u{2D8}', " "),
+    ('\u{2D9}', " "),
+    ('\u{2DA}', " "),
+    ('\u{2DB}', " "),
+    ('\u{2DC}', " "),
+    ('\u{2DD}', " "),
+    ('\u{2E1}', "l"),
+    ('\u{2E2}', "s"),
+    ('\u{2E3}', "x"),
+    ('\u{300}', ""),
+    ('\u{301}', ""),
+    ('\u{302}', ""),
+    ('\u{303}', ""),
+    ('\u{304}', ""),
+    ('\u{305}', ""),
+    ('\u{306}', ""),
+    ('\u{307}', ""),
+    ('\u{308}', ""),
+    ('\u{309}', ""),
+    ('\u{30A}', ""),
+    ('\u{30B}', ""),
+    ('\u{30C}', ""),
+    ('\u{30D}', ""),
+    ('\u{30E}', ""),
+    ('\u{30F}', ""),
+    ('\u{310}', ""),
+    ('\u{311}', ""),
+    ('\u{312}', ""),
+    ('\u{313}', ""),
+    ('\u{314}', ""),
+    ('\u{315}', ""),
+    ('\u{316}', ""),
+    ('\u{317}', ""),
+    ('\u{318}', ""),
+    ('\u{319}', ""),
+    ('\u{31A}', ""),
+    ('\u{31B}', ""),
+    ('\u{31C}', ""),
+    ('\u{31D}', ""),
+    ('\u{31E}', ""),
+    ('\u{31F}', ""),
+    ('\u{320}', ""),
+    ('\u{321}', ""),
+    ('\u{322}', ""),
+    ('\u{323}', ""),
+    ('\u{324}', ""),
+    ('\u{325}', ""),
+    ('\u{326}', ""),
+    ('\u{327}', ""),
+    ('\u{328}', ""),
+    ('\u{329}', ""),
+    ('\u{32A}', ""),
+    ('\u{32B}', ""),
+    ('\u{32C}', ""),
+    ('\u{32D}', ""),
+    ('\u{32E}', ""),
+    ('\u{32F}', ""),
+    ('\u{330}', ""),
+    ('\u{331}', ""),
+    ('\u{332}', ""),
+    ('\u{333}', ""),
+    ('\u{334}', ""),
+    ('\u{335}', ""),
+    ('\u{336}', ""),
+    ('\u{337}', ""),
+    ('\u{338}', ""),
+    ('\u{339}', ""),
+    ('\u{33A}', ""),
+    ('\u{33B}', ""),
+    ('\u{33C}', ""),
+    ('\u{33D}', ""),
+    ('\u{33E}', ""),
+    ('\u{33F}', ""),
+    ('\u{340}', ""),
+    ('\u{341}', ""),
+    ('\u{342}', ""),
+    ('\u{343}', ""),
+    ('\u{344}', ""),
+    ('\u{345}', ""),
+    ('\u{346}', ""),
+    ('\u{347}', ""),
+    ('\u{348}', ""),
+    ('\u{349}', ""),
+    ('\u{34A}', ""),
+    ('\u{34B}', ""),
+    ('\u{34C}', ""),
+    ('\u{34D}', ""),
+    ('\u{34E}', ""),
+    ('\u{34F}', ""),
+    ('\u{350}', ""),
+    ('\u{351}', ""),
+    ('\u{352}', ""),
+    ('\u{353}', ""),
+    ('\u{354}', ""),
+    ('\u{355}', ""),
+    ('\u{356}', ""),
+    ('\u{357}', ""),
+    ('\u{358}', ""),
+    ('\u{359}', ""),
+    ('\u{35A}', ""),
+    ('\u{35B}', ""),
+    ('\u{35C}', ""),
+    ('\u{35D}', ""),
+    ('\u{35E}', ""),
+    ('\u{35F}', ""),
+    ('\u{360}', ""),
+    ('\u{361}', ""),
+    ('\u{362}', ""),
+    ('\u{363}', ""),
+    ('\u{364}', ""),
+    ('\u{365}', ""),
+    ('\u{366}', ""),
+    ('\u{367}', ""),
+    ('\u{368}', ""),
+    ('\u{369}', ""),
+    ('\u{36A}', ""),
+    ('\u{36B}', ""),
+    ('\u{36C}', ""),
+    ('\u{36D}', ""),
+    ('\u{36E}', ""),
+    ('\u{36F}', ""),
+    ('\u{374}', "'"),
+    ('\u{37A}', " "),
+    ('\u{37E}', ";"),
+    ('\u{384}', " "),
+    ('\u{385}', " "),
+    ('\u{387}', "."),
+    ('\u{1AB0}', ""),
+    ('\u{1AB1}', ""),
+    ('\u{1AB2}', ""),
+    ('\u{1AB3}', ""),
+    ('\u{1AB4}', ""),
+    ('\u{1AB5}', ""),
+    ('\u{1AB6}', ""),
+    ('\u{1AB7}', ""),
+    ('\u{1AB8}', ""),
+    ('\u{1AB9}', ""),
+    ('\u{1ABA}', ""),
+    ('\u{1ABB}', ""),
+    ('\u{1ABC}', ""),
+    ('\u{1ABD}', ""),
+    ('\u{1ABF}', ""),
+    ('\u{1AC0}', ""),
+    ('\u{1AC1}', ""),
+    ('\u{1AC2}', ""),
+    ('\u{1AC3}', ""),
+    ('\u{1AC4}', ""),
+    ('\u{1AC5}', ""),
+    ('\u{1AC6}', ""),
+    ('\u{1AC7}', ""),
+    ('\u{1AC8}', ""),
+    ('\u{1AC9}', ""),
+    ('\u{1ACA}', ""),
+    ('\u{1ACB}', ""),
+    ('\u{1ACC}', ""),
+    ('\u{1ACD}', ""),
+    ('\u{1ACE}', ""),
+    ('\u{1D2C}', "A"),
+    ('\u{1D2D}', "AE"),
+    ('\u{1D2E}', "B"),
+    ('\u{1D30}', "D"),
+    ('\u{1D31}', "E"),
+    ('\u{1D32}', "E"),
+    ('\u{1D33}', "G"),
+    ('\u{1D34}', "H"),
+    ('\u{1D35}', "I"),
+    ('\u{1D36}', "J"),
+    ('\u{1D37}', "K"),
+    ('\u{1D38}', "L"),
+    ('\u{1D39}', "M"),
+    ('\u{1D3A}', "N"),
+    ('\u{1D3C}', "O"),
+    ('\u{1D3E}', "P"),
+    ('\u{1D3F}', "R"),
+    ('\u{1D40}', "T"),
+    ('\u{1D41}', "U"),
+    ('\u{1D42}', "W"),
+    ('\u{1D43}', "a"),
+    ('\u{1D47}', "b"),
+    ('\u{1D48}', "d"),
+    ('\u{1D49}', "e"),
+    ('\u{1D4B}', "e"),
+    ('\u{1D4D}', "g"),
+    ('\u{1D4F}', "k"),
+    ('\u{1D50}', "m"),
+    ('\u{1D52}', "o"),
+    ('\u{1D56}', "p"),
+    ('\u{1D57}', "t"),
+    ('\u{1D58}', "u"),
+    ('\u{1D5B}', "v"),
+    ('\u{1D62}', "i"),
+    ('\u{1D63}', "r"),
+    ('\u{1D64}', "u"),
+    ('\u{1D65}', "v"),
+    ('\u{1D9C}', "c"),
+    ('\u{1D9D}', "c"),
+    ('\u{1D9E}', "d"),
+    ('\u{1DA0}', "f"),
+    ('\u{1DA1}', "j"),
+    ('\u{1DA2}', "g"),
+    ('\u{1DA4}', "i"),
+    ('\u{1DA6}', "I"),
+    ('\u{1DA8}', "j"),
+    ('\u{1DA9}', "l"),
+    ('\u{1DAB}', "L"),
+    ('\u{1DAC}', "m"),
+    ('\u{1DAE}', "n"),
+    ('\u{1DAF}', "n"),
+    ('\u{1DB0}', "N"),
+    ('\u{1DB3}', "s"),
+    ('\u{1DB5}', "t"),
+    ('\u{1DB6}', "u"),
+    ('\u{1DB9}', "v"),
+    ('\u{1DBB}', "z"),
+    ('\u{1DBC}', "z"),
+    ('\u{1DBD}', "z"),
+    ('\u{1DC0}', ""),
+    ('\u{1DC1}', ""),
+    ('\u{1DC2}', ""),
+    ('\u{1DC3}', ""),
+    ('\u{1DC4}', ""),
+    ('\u{1DC5}', ""),
+    ('\u{1DC6}', ""),
+    ('\u{1DC7}', ""),
+    ('\u{1DC8}', ""),
+    ('\u{1DC9}', ""),
+    ('\u{1DCA}', ""),
+    ('\u{1DCB}', ""),
+    ('\u{1DCC}', ""),
+    ('\u{1DCD}', ""),
+    ('\u{1DCE}', ""),
+    ('\u{1DCF}', ""),
+    ('\u{1DD0}', ""),
+    ('\u{1DD1}', ""),
+    ('\u{1DD2}', ""),
+    ('\u{1DD3}', ""),
+    ('\u{1DD4}', ""),
+    ('\u{1DD5}', ""),
+    ('\u{1DD6}', ""),
+    ('\u{1DD7}', ""),
+    ('\u{1DD8}', ""),
+    ('\u{1DD9}', ""),
+    ('\u{1DDA}', ""),
+    ('\u{1DDB}', ""),
+    ('\u{1DDC}', ""),
+    ('\u{1DDD}', ""),
+    ('\u{1DDE}', ""),
+    ('\u{1DDF}', ""),
+    ('\u{1DE0}', ""),
+    ('\u{1DE1}', ""),
+    ('\u{1DE2}', ""),
+    ('\u{1DE3}', ""),
+    ('\u{1DE4}', ""),
+    ('\u{1DE5}', ""),
+    ('\u{1DE6}', ""),
+    ('\u{1DE7}', ""),
+    ('\u{1DE8}', ""),
+    ('\u{1DE9}', ""),
+    ('\u{1DEA}', ""),
+    ('\u{1DEB}', ""),
+    ('\u{1DEC}', ""),
+    ('\u{1DED}', ""),
+    ('\u{1DEE}', ""),
+    ('\u{1DEF}', ""),
+    ('\u{1DF0}', ""),
+    ('\u{1DF1}', ""),
+    ('\u{1DF2}', ""),
+    ('\u{1DF3}', ""),
+    ('\u{1DF4}', ""),
+    ('\u{1DF5}', ""),
+    ('\u{1DF6}', ""),
+    ('\u{1DF7}', ""),
+    ('\u{1DF8}', ""),
+    ('\u{1DF9}', ""),
+    ('\u{1DFA}', ""),
+    ('\u{1DFB}', ""),
+    ('\u{1DFC}', ""),
+    ('\u{1DFD}', ""),
+    ('\u{1DFE}', ""),
+    ('\u{1DFF}', ""),
+    ('\u{1E00}', "A"),
+    ('\u{1E01}', "a"),
+    ('\u{1E02}', "B"),
+    ('\u{1E03}', "b"),
+    ('\u{1E04}', "B"),
+    ('\u{1E05}', "b"),
+    ('\u{1E06}', "B"),
+    ('\u{1E07}', "b"),
+    ('\u{1E08}', "C"),
+    ('\u{1E09}', "c"),
+    ('\u{1E0A}', "D"),
+    ('\u{1E0B}', "d"),
+    ('\u{1E0C}', "D"),
+    ('\u{1E0D}', "d"),
+    ('\u{1E0E}', "D"),
+    ('\u{1E0F}', "d"),
+    ('\u{1E10}', "D"),
+    ('\u{1E11}', "d"),
+    ('\u{1E12}', "D"),
+    ('\u{1E13}', "d"),
+    ('\u{1E14}', "E"),
+    ('\u{1E15}', "e"),
+    ('\u{1E16}', "E"),
+    ('\u{1E17}', "e"),
+    ('\u{1E18}', "E"),
+    ('\u{1E19}', "e"),
+    ('\u{1E1A}', "E"),
+    ('\u{1E1B}', "e"),
+    ('\u{1E1C}', "E"),
+    ('\u{1E1D}', "e"),
+    ('\u{1E1E}', "F"),
+    ('\u{1E1F}', "f"),
+    ('\u{1E20}', "G"),
+    ('\u{1E21}', "g"),
+    ('\u{1E22}', "H"),
+    ('\u{1E23}', "h"),
+    ('\u{1E24}', "H"),
+    ('\u{1E25}', "h"),
+    ('\u{1E26}', "H"),
+    ('\u{1E27}', "h"),
+    ('\u{1E28}', "H"),
+    ('\u{1E29}', "h"),
+    ('\u{1E2A}', "H"),
+    ('\u{1E2B}', "h"),
+    ('\u{1E2C}', "I"),
+    ('\u{1E2D}', "i"),
+    ('\u{1E2E}', "I"),
+    ('\u{1E2F}', "i"),
+    ('\u{1E30}', "K"),
+    ('\u{1E31}', "k"),
+    ('\u{1E32}', "K"),
+    ('\u{1E33}', "k"),
+    ('\u{1E34}', "K"),
+    ('\u{1E35}', "k"),
+    ('\u{1E36}', "L"),
+    ('\u{1E37}', "l"),
+    ('\u{1E38}', "L"),
+    ('\u{1E39}', "l"),
+    ('\u{1E3A}', "L"),
+    ('\u{1E3B}', "l"),
+    ('\u{1E3C}', "L"),
+    ('\u{1E3D}', "l"),
+    ('\u{1E3E}', "M"),
+    ('\u{1E3F}', "m"),
+    ('\u{1E40}', "M"),
+    ('\u{1E41}', "m"),
+    ('\u{1E42}', "M"),
+    ('\u{1E43}', "m"),
+    ('\u{1E44}', "N"),
+    ('\u{1E45}', "n"),
+    ('\u{1E46}', "N"),
+    ('\u{1E47}', "n"),
+    ('\u{1E48}', "N"),
+    ('\u{1E49}', "n"),
+    ('\u{1E4A}', "N"),
+    ('\u{1E4B}', "n"),
+    ('\u{1E4C}', "O"),
+    ('\u{1E4D}', "o"),
+    ('\u{1E4E}', "O"),
+    ('\u{1E4F}', "o"),
+    ('\u{1E50}', "O"),
+    ('\u{1E51}', "o"),
+    ('\u{1E52}', "O"),
+    ('\u{1E53}', "o"),
+    ('\u{1E54}', "P"),
+    ('\u{1E55}', "p"),
+    ('\u{1E56}', "P"),
+    ('\u{1E57}', "p"),
+    ('\u{1E58}', "R"),
+    ('\u{1E59}', "r"),
+    ('\u{1E5A}', "R"),
+    ('\u{1E5B}', "r"),
+    ('\u{1E5C}', "R"),
+    ('\u{1E5D}', "r"),
+    ('\u{1E5E}', "R"),
+    ('\u{1E5F}', "r"),
+    ('\u{1E60}', "S"),
+    ('\u{1E61}', "s"),
+    ('\u{1E62}', "S"),
+    ('\u{1E63}', "s"),
+    ('\u{1E64}', "S"),
+    ('\u{1E65}', "s"),
+    ('\u{1E66}', "S"),
+    ('\u{1E67}', "s"),
+    ('\u{1E68}', "S"),
+    ('\u{1E69}', "s"),
+    ('\u{1E6A}', "T"),
+    ('\u{1E6B}', "t"),
+    ('\u{1E6C}', "T"),
+    ('\u{1E6D}', "t"),
+    ('\u{1E6E}', "T"),
+    ('\u{1E6F}', "t"),
+    ('\u{1E70}', "T"),
+    ('\u{1E71}', "t"),
+    ('\u{1E72}', "U"),
+    ('\u{1E73}', "u"),
+    ('\u{1E74}', "U"),
+    ('\u{1E75}', "u"),
+    ('\u{1E76}', "U"),
+    ('\u{1E77}', "u"),
+    ('\u{1E78}', "U"),
+    ('\u{1E79}', "u"),
+    ('\u{1E7A}', "U"),
+    ('\u{1E7B}', "u"),
+    ('\u{1E7C}', "V"),
+    ('\u{1E7D}', "v"),
+    ('\u{1E7E}', "V"),
+    ('\u{1E7F}', "v"),
+    ('\u{1E80}', "W"),
+    ('\u{1E81}', "w"),
+    ('\u{1E82}', "W"),
+    ('\u{1E83}', "w"),
+    ('\u{1E84}', "W"),
+    ('\u{1E85}', "w"),
+    ('\u{1E86}', "W"),
+    ('\u{1E87}', "w"),
+    ('\u{1E88}', "W"),
+    ('\u{1E89}', "w"),
+    ('\u{1E8A}', "X"),
+    ('\u{1E8B}', "x"),
+    ('\u{1E8C}', "X"),
+    ('\u{1E8D}', "x"),
+    ('\u{1E8E}', "Y"),
+    ('\u{1E8F}', "y"),
+    ('\u{1E90}', "Z"),
+    ('\u{1E91}', "z"),
+    ('\u{1E92}', "Z"),
+    ('\u{1E93}', "z"),
+    ('\u{1E94}', "Z"),
+    ('\u{1E95}', "z"),
+    ('\u{1E96}', "h"),
+    ('\u{1E97}', "t"),
+    ('\u{1E98}', "w"),
+    ('\u{1E99}', "y"),
+    ('\u{1E9B}', "s"),
+    ('\u{1E9E}', "SS"),
+    ('\u{1EA0}', "A"),
+    ('\u{1EA1}', "a"),
+    ('\u{1EA2}', "A"),
+    ('\u{1EA3}', "a"),
+    ('\u{1EA4}', "A"),
+    ('\u{1EA5}', "a"),
+    ('\u{1EA6}', "A"),
+    ('\u{1EA7}', "a"),
+    ('\u{1EA8}', "A"),
+    ('\u{1EA9}', "a"),
+    ('\u{1EAA}', "A"),
+    ('\u{1EAB}', "a"),
+    ('\u{1EAC}', "A"),
+    ('\u{1EAD}', "a"),
+    ('\u{1EAE}', "A"),
+    ('\u{1EAF}', "a"),
+    ('\u{1EB0}', "A"),
+    ('\u{1EB1}', "a"),
+    ('\u{1EB2}', "A"),
+    ('\u{1EB3}', "a"),
+    ('\u{1EB4}', "A"),
+    ('\u{1EB5}', "a"),
+    ('\u{1EB6}', "A"),
+    ('\u{1EB7}', "a"),
+    ('\u{1EB8}', "E"),
+    ('\u{1EB9}', "e"),
+    ('\u{1EBA}', "E"),
+    ('\u{1EBB}', "e"),
+    ('\u{1EBC}', "E"),
+    ('\u{1EBD}', "e"),
+    ('\u{1EBE}', "E"),
+    ('\u{1EBF}', "e"),
+    ('\u{1EC0}', "E"),
+    ('\u{1EC1}', "e"),
+    ('\u{1EC2}', "E"),
+    ('\u{1EC3}', "e"),
+    ('\u{1EC4}', "E"),
+    ('\u{1EC5}', "e"),
+    ('\u{1EC6}', "E"),
+    ('\u{1EC7}', "e"),
+    ('\u{1EC8}', "I"),
+    ('\u{1EC9}', "i"),
+    ('\u{1ECA}', "I"),
+    ('\u{1ECB}', "i"),
+    ('\u{1ECC}', "O"),
+    ('\u{1ECD}', "o"),
+    ('\u{1ECE}', "O"),
+    ('\u{1ECF}', "o"),
+    ('\u{1ED0}', "O"),
+    ('\u{1ED1}', "o"),
+    ('\u{1ED2}', "O"),
+    ('\u{1ED3}', "o"),
+    ('\u{1ED4}', "O"),
+    ('\u{1ED5}', "o"),
+    ('\u{1ED6}', "O"),
+    ('\u{1ED7}', "o"),
+    ('\u{1ED8}', "O"),
+    ('\u{1ED9}', "o"),
+    ('\u{1EDA}', "O"),
+    ('\u{1EDB}', "o"),
+    ('\u{1EDC}', "O"),
+    ('\u{1EDD}', "o"),
+    ('\u{1EDE}', "O"),
+    ('\u{1EDF}', "o"),
+    ('\u{1EE0}', "O"),
+    ('\u{1EE1}', "o"),
+    ('\u{1EE2}', "O"),
+    ('\u{1EE3}', "o"),
+    ('\u{1EE4}', "U"),
+    ('\u{1EE5}', "u"),
+    ('\u{1EE6}', "U"),
+    ('\u{1EE7}', "u"),
+    ('\u{1EE8}', "U"),
+    ('\u{1EE9}', "u"),
+    ('\u{1EEA}', "U"),
+    ('\u{1EEB}', "u"),
+    ('\u{1EEC}', "U"),
+    ('\u{1EED}', "u"),
+    ('\u{1EEE}', "U"),
+    ('\u{1EEF}', "u"),
+    ('\u{1EF0}', "U"),
+    ('\u{1EF1}', "u"),
+    ('\u{1EF2}', "Y"),
+    ('\u{1EF3}', "y"),
+    ('\u{1EF4}', "Y"),
+    ('\u{1EF5}', "y"),
+    ('\u{1EF6}', "Y"),
+    ('\u{1EF7}', "y"),
+    ('\u{1EF8}', "Y"),
+    ('\u{1EF9}', "y"),
+    ('\u{1FBD}', " "),
+    ('\u{1FBF}', " "),
+    ('\u{1FC0}', " "),
+    ('\u{1FC1}', " "),
+    ('\u{1FCD}', " "),
+    ('\u{1FCE}', " "),
+    ('\u{1FCF}', " "),
+    ('\u{1FDD}', " "),
+    ('\u{1FDE}', " "),
+    ('\u{1FDF}', " "),
+    ('\u{1FED}', " "),
+    ('\u{1FEE}', " "),
+    ('\u{1FEF}', "`"),
+    ('\u{1FFD}', " "),
+    ('\u{1FFE}', " "),
+    ('\u{2000}', " "),
+    ('\u{2001}', " "),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2004}', " "),
+    ('\u{2005}', " "),
+    ('\u{2006}', " "),
+    ('\u{2007}', " "),
+    ('\u{2008}', " "),
+    ('\u{2009}', " "),
+    ('\u{200A}', " "),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "-"),
+    ('\u{2015}', "-"),
+    ('\u{2017}', " "),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201A}', "'"),
+    ('\u{201B}', "'"),
+    ('\u{201C}', "\""),
+    ('\u{201D}', "\""),
+    ('\u{201E}', "\""),
+    ('\u{201F}', "\""),
+    ('\u{2022}', "*"),
+    ('\u{2024}', "."),
+    ('\u{2025}', ".."),
+    ('\u{2026}', "..."),
+    ('\u{2027}', "."),
+    ('\u{202F}', " "),
+    ('\u{2030}', "%."),
+    ('\u{2032}', "'"),
+    ('\u{2033}', "\""),
+    ('\u{2034}', "'''"),
+    ('\u{2035}', "'"),
+    ('\u{2036}', "\""),
+    ('\u{2037}', "'''"),
+    ('\u{2039}', "'"),
+    ('\u{203A}', "'"),
+    ('\u{203C}', "!!"),
+    ('\u{203E}', " "),
+    ('\u{2043}', "-"),
+    ('\u{2044}', "/"),
+    ('\u{2047}', "??"),
+    ('\u{2048}', "?!"),
+    ('\u{2049}', "!?"),
+    ('\u{2057}', "''''"),
+    ('\u{205F}', " "),
+    ('\u{2070}', "0"),
+    ('\u{2071}', "i"),
+    ('\u{2074}', "4"),
+    ('\u{2075}', "5"),
+    ('\u{2076}', "6"),
+    ('\u{2077}', "7"),
+    ('\u{2078}', "8"),
+    ('\u{2079}', "9"),
+    ('\u{207A}', "+"),
+    ('\u{207B}', "-"),
+    ('\u{207C}', "="),
+    ('\u{207D}', "("),
+    ('\u{207E}', ")"),
+    ('\u{207F}', "n"),
+    ('\u{2080}', "0"),
+    ('\u{2081}', "1"),
+    ('\u{2082}', "2"),
+    ('\u{2083}', "3"),
+    ('\u{2084}', "4"),
+    ('\u{2085}', "5"),
+    ('\u{2086}', "6"),
+    ('\u{2087}', "7"),
+    ('\u{2088}', "8"),
+    ('\u{2089}', "9"),
+    ('\u{208A}', "+"),
+    ('\u{208B}', "-"),
+    ('\u{208C}', "="),
+    ('\u{208D}', "("),
+    ('\u{208E}', ")"),
+    ('\u{2090}', "a"),
+    ('\u{2091}', "e"),
+    ('\u{2092}', "o"),
+    ('\u{2093}', "x"),
+    ('\u{2095}', "h"),
+    ('\u{2096}', "k"),
+    ('\u{2097}', "l"),
+    ('\u{2098}', "m"),
+    ('\u{2099}', "n"),
+    ('\u{209A}', "p"),
+    ('\u{209B}', "s"),
+    ('\u{209C}', "t"),
+    ('\u{20A8}', "Rs"),
+    ('\u{20D0}', ""),
+    ('\u{20D1}', ""),
+    ('\u{20D2}', ""),
+    ('\u{20D3}', ""),
+    ('\u{20D4}', ""),
+    ('\u{20D5}', ""),
+    ('\u{20D6}', ""),
+    ('\u{20D7}', ""),
+    ('\u{20D8}', ""),
+    ('\u{20D9}', ""),
+    ('\u{20DA}', ""),
+    ('\u{20DB}', ""),
+    ('\u{20DC}', ""),
+    ('\u{20E1}', ""),
+    ('\u{20E5}', ""),
+    ('\u{20E6}', ""),
+    ('\u{20E7}', ""),
+    ('\u{20E8}', ""),
+    ('\u{20E9}', ""),
+    ('\u{20EA}', ""),
+    ('\u{20EB}', ""),
+    ('\u{20EC}', ""),
+    ('\u{20ED}', ""),
+    ('\u{20EE}', ""),
+    ('\u{20EF}', ""),
+    ('\u{20F0}', ""),
+    ('\u{2100}', "a/c"),
+    ('\u{2101}', "a/s"),
+    ('\u{2102}', "C"),
+    ('\u{2103}', "oC"),
+    ('\u{2105}', "c/o"),
+    ('\u{2106}', "c/u"),
+    ('\u{2107}', "E"),
+    ('\u{2109}', "oF"),
+    ('\u{210A}', "g"),
+    ('\u{210B}', "H"),
+    ('\u{210C}', "H"),
+    ('\u{210D}', "H"),
+    ('\u{210E}', "h"),
+    ('\u{210F}', "h"),
+    ('\u{2110}', "I"),
+    ('\u{2111}', "I"),
+    ('\u{2112}', "L"),
+    ('\u{2113}', "l"),
+    ('\u{2115}', "N"),
+    ('\u{2116}', "No"),
+    ('\u{2119}', "P"),
+    ('\u{211A}', "Q"),
+    ('\u{211B}', "R"),
+    ('\u{211C}', "R"),
+    ('\u{211D}', "R"),
+    ('\u{2120}', "SM"),
+    ('\u{2121}', "TEL"),
+    ('\u{2122}', "TM"),
+    ('\u{2124}', "Z"),
+    ('\u{2128}', "Z"),
+    ('\u{212A}', "K"),
+    ('\u{212B}', "A"),
+    ('\u{212C}', "B"),
+    ('\u{212D}', "C"),
+    ('\u{212F}', "e"),
+    ('\u{2130}', "E"),
+    ('\u{2131}', "F"),
+    ('\u{2133}', "M"),
+    ('\u{2134}', "o"),
+    ('\u{2139}', "i"),
+    ('\u{213B}', "FAX"),
+    ('\u{2145}', "D"),
+    ('\u{2146}', "d"),
+    ('\u{2147}', "e"),
+    ('\u{2148}', "i"),
+    ('\u{2149}', "j"),
+    ('\u{2150}', "1/7"),
+    ('\u{2151}', "1/9"),
+    ('\u{2152}', "1/10"),
+    ('\u{2153}', "1/3"),
+    ('\u{2154}', "2/3"),
+    ('\u{2155}', "1/5"),
+    ('\u{2156}', "2/5"),
+    ('\u{2157}', "3/5"),
+    ('\u{2158}', "4/5"),
+    ('\u{2159}', "1/6"),
+    ('\u{215A}', "5/6"),
+    ('\u{215B}', "1/8"),
+    ('\u{215C}', "3/8"),
+    ('\u{215D}', "5/8"),
+    ('\u{215E}', "7/8"),
+    ('\u{215F}', "1/"),
+    ('\u{2160}', "I"),
+    ('\u{2161}', "II"),
+    ('\u{2162}', "III"),
+    ('\u{2163}', "IV"),
+    ('\u{2164}', "V"),
+    ('\u{2165}', "VI"),
+    ('\u{2166}', "VII"),
+    ('\u{2167}', "VIII"),
+    ('\u{2168}', "IX"),
+    ('\u{2169}', "X"),
+    ('\u{216A}', "XI"),
+    ('\u{216B}', "XII"),
+    ('\u{216C}', "L"),
+    ('\u{216D}', "C"),
+    ('\u{216E}', "D"),
+    ('\u{216F}', "M"),
+    ('\u{2170}', "i"),
+    ('\u{2171}', "ii"),
+    ('\u{2172}', "iii"),
+    ('\u{2173}', "iv"),
+    ('\u{2174}', "v"),
+    ('\u{2175}', "vi"),
+    ('\u{2176}', "vii"),
+    ('\u{2177}', "viii"),
+    ('\u{2178}', "ix"),
+    ('\u{2179}', "x"),
+    ('\u{217A}', "xi"),
+    ('\u{217B}', "xii"),
+    ('\u{217C}', "l"),
+    ('\u{217D}', "c"),
+    ('\u{217E}', "d"),
+    ('\u{217F}', "m"),
+    ('\u{2189}', "0/3"),
+    ('\u{2212}', "-"),
+    ('\u{2215}', "/"),
+    ('\u{2260}', "="),
+    ('\u{226E}', "<"),
+    ('\u{226F}', ">"),
+    ('\u{2460}', "1"),
+    ('\u{2461}', "2"),
+    ('\u{2462}', "3"),
+    ('\u{2463}', "4"),
+    ('\u{2464}', "5"),
+    ('\u{2465}', "6"),
+    ('\u{2466}', "7"),
+    ('\u{2467}', "8"),
+    ('\u{2468}', "9"),
+    ('\u{2469}', "10"),
+    ('\u{246A}', "11"),
+    ('\u{246B}', "12"),
+    ('\u{246C}', "13"),
+    ('\u{246D}', "14"),
+    ('\u{246E}', "15"),
+    ('\u{246F}', "16"),
+    ('\u{2470}', "17"),
+    ('\u{2471}', "18"),
+    ('\u{2472}', "19"),
+    ('\u{2473}', "20"),
+    ('\u{2474}', "(1)"),
+    ('\u{2475}', "(2)"),
+    ('\u{2476}', "(3)"),
+    ('\u{2477}', "(4)"),
+    ('\u{2478}', "(5)"),
+    ('\u{2479}', "(6)"),
+    ('\u{247A}', "(7)"),
+    ('\u{247B}', "(8)"),
+    ('\u{247C}', "(9)"),
+    ('\u{247D}', "(10)"),
+    ('\u{247E}', "(11)"),
+    ('\u{247F}', "(12)"),
+    ('\u{2480}', "(13)"),
+    ('\u{2481}', "(14)"),
+    ('\u{2482}', "(15)"),
+    ('\u{2483}', "(16)"),
+    ('\u{2484}', "(17)"),
+    ('\u{2485}', "(18)"),
+    ('\u{2486}', "(19)"),
+    ('\u{2487}', "(20)"),
+    ('\u{2488}', "1."),
+    ('\u{2489}', "2."),
+    ('\u{248A}', "3."),
+    ('\u{248B}', "4."),
+    ('\u{248C}', "5."),
+    ('\u{248D}', "6."),
+    ('\u{248E}', "7."),
+    ('\u{248F}', "8."),
+    ('\u{2490}', "9."),
+    ('\u{2491}', "10."),
+    ('\u{2492}', "11."),
+    ('\u{2493}', "12."),
+    ('\u{2494}', "13."),
+    ('\u{2495}', "14."),
+    ('\u{2496}', "15."),
+    ('\u{2497}', "16."),
+    ('\u{2498}', "17."),
+    ('\u{2499}', "18."),
+    ('\u{249A}', "19."),
+    ('\u{249B}', "20."),
+    ('\u{249C}', "(a)"),
+    ('\u{249D}', "(b)"),
+    ('\u{249E}', "(c)"),
+    ('\u{249F}', "(d)"),
+    ('\u{24A0}', "(e)"),
+    ('\u{24A1}', "(f)"),
+    ('\u{24A2}', "(g)"),
+    ('\u{24A3}', "(h)"),
+    ('\u{24A4}', "(i)"),
+    ('\u{24A5}', "(j)"),
+    ('\u{24A6}', "(k)"),
+    ('\u{24A7}', "(l)"),
+    ('\u{24A8}', "(m)"),
+    ('\u{24A9}', "(n)"),
+    ('\u{24AA}', "(o)"),
+    ('\u{24AB}', "(p)"),
+    ('\u{24AC}', "(q)"),
+    ('\u{24AD}', "(r)"),
+    ('\u{24AE}', "(s)"),
+    ('\u{24AF}', "(t)"),
+    ('\u{24B0}', "(u)"),
+    ('\u{24B1}', "(v)"),
+    ('\u{24B2}', "(w)"),
+    ('\u{24B3}', "(x)"),
+    ('\u{24B4}', "(y)"),
+    ('\u{24B5}', "(z)"),
+    ('\u{24B6}', "A"),
+    ('\u{24B7}', "B"),
+    ('\u{24B8}', "C"),
+    ('\u{24B9}', "D"),
+    ('\u{24BA}', "E"),
+    ('\u{24BB}', "F"),
+    ('\u{24BC}', "G"),
+    ('\u{24BD}', "H"),
+    ('\u{24BE}', "I"),
+    ('\u{24BF}', "J"),
+    ('\u{24C0}', "K"),
+    ('\u{24C1}', "L"),
+    ('\u{24C2}', "M"),
+    ('\u{24C3}', "N"),
+    ('\u{24C4}', "O"),
+    ('\u{24C5}', "P"),
+    ('\u{24C6}', "Q"),
+    ('\u{24C7}', "R"),
+    ('\u{24C8}', "S"),
+    ('\u{24C9}', "T"),
+    ('\u{24CA}', "U"),
+    ('\u{24CB}', "V"),
+    ('\u{24CC}', "W"),
+    ('\u{24CD}', "X"),
+    ('\u{24CE}', "Y"),
+    ('\u{24CF}', "Z"),
+    ('\u{24D0}', "a"),
+    ('\u{24D1}', "b"),
+    ('\u{24D2}', "c"),
+    ('\u{24D3}', "d"),
+    ('\u{24D4}', "e"),
+    ('\u{24D5}', "f"),
+    ('\u{24D6}', "g"),
+    ('\u{24D7}', "h"),
+    ('\u{24D8}', "i"),
+    ('\u{24D9}', "j"),
+    ('\u{24DA}', "k"),
+    ('\u{24DB}', "l"),
+    ('\u{24DC}', "m"),
+    ('\u{24DD}', "n"),
+    ('\u{24DE}', "o"),
+    ('\u{24DF}', "p"),
+    ('\u{24E0}', "q"),
+    ('\u{24E1}', "r"),
+    ('\u{24E2}', "s"),
+    ('\u{24E3}', "t"),
+    ('\u{24E4}', "u"),
+    ('\u{24E5}', "v"),
+    ('\u{24E6}', "w"),
+    ('\u{24E7}', "x"),
+    ('\u{24E8}', "y"),
+    ('\u{24E9}', "z"),
+    ('\u{24EA}', "0"),
+    ('\u{2A74}', "::="),
+    ('\u{2A75}', "=="),
+    ('\u{2A76}', "==="),
+    ('\u{2C7C}', "j"),
+    ('\u{2C7D}', "V"),
+    ('\u{3000}', " "),
+    ('\u{3250}', "PTE"),
+    ('\u{3251}', "21"),
+    ('\u{3252}', "22"),
+    ('\u{3253}', "23"),
+    ('\u{3254}', "24"),
+    ('\u{3255}', "25"),
+    ('\u{3256}', "26"),
+    ('\u{3257}', "27"),
+    ('\u{3258}', "28"),
+    ('\u{3259}', "29"),
+    ('\u{325A}', "30"),
+    ('\u{325B}', "31"),
+    ('\u{325C}', "32"),
+    ('\u{325D}', "33"),
+    ('\u{325E}', "34"),
+    ('\u{325F}', "35"),
+    ('\u{32B1}', "36"),
+    ('\u{32B2}', "37"),
+    ('\u{32B3}', "38"),
+    ('\u{32B4}', "39"),
+    ('\u{32B5}', "40"),
+    ('\u{32B6}', "41"),
+    ('\u{32B7}', "42"),
+    ('\u{32B8}', "43"),
+    ('\u{32B9}', "44"),
+    ('\u{32BA}', "45"),
+    ('\u{32BB}', "46"),
+    ('\u{32BC}', "47"),
+    ('\u{32BD}', "48"),
+    ('\u{32BE}', "49"),
+    ('\u{32BF}', "50"),
+    ('\u{32CC}', "Hg"),
+    ('\u{32CD}', "erg"),
+    ('\u{32CE}', "eV"),
+    ('\u{32CF}', "LTD"),
+    ('\u{3371}', "hPa"),
+    ('\u{3372}', "da"),
+    ('\u{3373}', "AU"),
+    ('\u{3374}', "bar"),
+    ('\u{3375}', "oV"),
+    ('\u{3376}', "pc"),
+    ('\u{3377}', "dm"),
+    ('\u{3378}', "dm2"),
+    ('\u{3379}', "dm3"),
+    ('\u{337A}', "IU"),
+    ('\u{3380}', "pA"),
+    ('\u{3381}', "nA"),
+    ('\u{3383}', "mA"),
+    ('\u{3384}', "kA"),
+    ('\u{3385}', "KB"),
+    ('\u{3386}', "MB"),
+    ('\u{3387}', "GB"),
+    ('\u{3388}', "cal"),
+    ('\u{3389}', "kcal"),
+    ('\u{338A}', "pF"),
+    ('\u{338B}', "nF"),
+    ('\u{338E}', "mg"),
+    ('\u{338F}', "kg"),
+    ('\u{3390}', "Hz"),
+    ('\u{3391}', "kHz"),
+    ('\u{3392}', "MHz"),
+    ('\u{3393}', "GHz"),
+    ('\u{3394}', "THz"),
+    ('\u{3396}', "ml"),
+    ('\u{3397}', "dl"),
+    ('\u{3398}', "kl"),
+    ('\u{3399}', "fm"),
+    ('\u{339A}', "nm"),
+    ('\u{339C}', "mm"),
+    ('\u{339D}', "cm"),
+    ('\u{339E}', "km"),
+    ('\u{339F}', "mm2"),
+    ('\u{33A0}', "cm2"),
+    ('\u{33A1}', "m2"),
+    ('\u{33A2}', "km2"),
+    ('\u{33A3}', "mm3"),
+    ('\u{33A4}', "cm3"),
+    ('\u{33A5}', "m3"),
+    ('\u{33A6}', "km3"),
+    ('\u{33A7}', "m/s"),
+    ('\u{33A8}', "m/s2"),
+    ('\u{33A9}', "Pa"),
+    ('\u{33AA}', "kPa"),
+    ('\u{33AB}', "MPa"),
+    ('\u{33AC}', "GPa"),
+    ('\u{33AD}', "rad"),
+    ('\u{33AE}', "rad/s"),
+    ('\u{33AF}', "rad/s2"),
+    ('\u{33B0}', "ps"),
+    ('\u{33B1}', "ns"),
+    ('\u{33B3}', "ms"),
+    ('\u{33B4}', "pV"),
+    ('\u{33B5}', "nV"),
+    ('\u{33B7}', "mV"),
+    ('\u{33B8}', "kV"),
+    ('\u{33B9}', "MV"),
+    ('\u{33BA}', "pW"),
+    ('\u{33BB}', "nW"),
+    ('\u{33BD}', "mW"),
+    ('\u{33BE}', "kW"),
+    ('\u{33BF}', "MW"),
+    ('\u{33C2}', "a.m."),
+    ('\u{33C3}', "Bq"),
+    ('\u{33C4}', "cc"),
+    ('\u{33C5}', "cd"),
+    ('\u{33C6}', "C/kg"),
+    ('\u{33C7}', "Co."),
+    ('\u{33C8}', "dB"),
+    ('\u{33C9}', "Gy"),
+    ('\u{33CA}', "ha"),
+    ('\u{33CB}', "HP"),
+    ('\u{33CC}', "in"),
+    ('\u{33CD}', "KK"),
+    ('\u{33CE}', "KM"),
+    ('\u{33CF}', "kt"),
+    ('\u{33D0}', "lm"),
+    ('\u{33D1}', "ln"),
+    ('\u{33D2}', "log"),
+    ('\u{33D3}', "lx"),
+    ('\u{33D4}', "mb"),
+    ('\u{33D5}', "mil"),
+    ('\u{33D6}', "mol"),
+    ('\u{33D7}', "PH"),
+    ('\u{33D8}', "p.m."),
+    ('\u{33D9}', "PPM"),
+    ('\u{33DA}', "PR"),
+    ('\u{33DB}', "sr"),
+    ('\u{33DC}', "Sv"),
+    ('\u{33DD}', "Wb"),
+    ('\u{33DE}', "V/m"),
+    ('\u{33DF}', "A/m"),
+    ('\u{33FF}', "gal"),
+    ('\u{A7F2}', "C"),
+    ('\u{A7F3}', "F"),
+    ('\u{A7F4}', "Q"),
+    ('\u{A7F8}', "H"),
+    ('\u{A7F9}', "oe"),
+    ('\u{AB5E}', "l"),
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB05}', "st"),
+    ('\u{FB06}', "st"),
+    ('\u{FB29}', "+"),
+    ('\u{FE10}', ","),
+    ('\u{FE13}', ":"),
+    ('\u{FE14}', ";"),
+    ('\u{FE15}', "!"),
+    ('\u{FE16}', "?"),
+    ('\u{FE19}', "..."),
+    ('\u{FE20}', ""),
+    ('\u{FE21}', ""),
+    ('\u{FE22}', ""),
+    ('\u{FE23}', ""),
+    ('\u{FE24}', ""),
+    ('\u{FE25}', ""),
+    ('\u{FE26}', ""),
+    ('\u{FE27}', ""),
+    ('\u{FE28}', ""),
+    ('\u{FE29}', ""),
+    ('\u{FE2A}', ""),
+    ('\u{FE2B}', ""),
+    ('\u{FE2C}', ""),
+    ('\u{FE2D}', ""),
+    ('\u{FE2E}', ""),
+    ('\u{FE2F}', ""),
+    ('\u{FE30}', ".."),
+    ('\u{FE31}', "-"),
+    ('\u{FE32}', "-"),
+    ('\u{FE33}', "_"),
+    ('\u{FE34}', "_"),
+    ('\u{FE35}', "("),
+    ('\u{FE36}', ")"),
+    ('\u{FE37}', "{"),
+    ('\u{FE38}', "}"),
+    ('\u{FE47}', "["),
+    ('\u{FE48}', "]"),
+    ('\u{FE49}', " "),
+    ('\u{FE4A}', " "),
+    ('\u{FE4B}', " "),
+    ('\u{FE4C}', " "),
+    ('\u{FE4D}', "_"),
+    ('\u{FE4E}', "_"),
+    ('\u{FE4F}', "_"),
+    ('\u{FE50}', ","),
+    ('\u{FE52}', "."),
+    ('\u{FE54}', ";"),
+    ('\u{FE55}', ":"),
+    ('\u{FE56}', "?"),
+    ('\u{FE57}', "!"),
+    ('\u{FE58}', "-"),
+    ('\u{FE59}', "("),
+    ('\u{FE5A}', ")"),
+    ('\u{FE5B}', "{"),
+    ('\u{FE5C}', "}"),
+    ('\u{FE5F}', "#"),
+    ('\u{FE60}', "&"),
+    ('\u{FE61}', "*"),
+    ('\u{FE62}', "+"),
+    ('\u{FE63}', "-"),
+    ('\u{FE64}', "<"),
+    ('\u{FE65}', ">"),
+    ('\u{FE66}', "="),
+    ('\u{FE68}', "\\"),
+    ('\u{FE69}', "$"),
+    ('\u{FE6A}', "%"),
+    ('\u{FE6B}', "@"),
+    ('\u{FF01}', "!"),
+    ('\u{FF02}', "\""),
+    ('\u{FF03}', "#"),
+    ('\u{FF04}', "$"),
+    ('\u{FF05}', "%"),
+    ('\u{FF06}', "&"),
+    ('\u{FF07}', "'"),
+    ('\u{FF08}', "("),
+    ('\u{FF09}', ")"),
+    ('\u{FF0A}', "*"),
+    ('\u{FF0B}', "+"),
+    ('\u{FF0C}', ","),
+    ('\u{FF0D}', "-"),
+    ('\u{FF0E}', "."),
+    ('\u{FF0F}', "/"),
+    ('\u{FF10}', "0"),
+    ('\u{FF11}', "1"),
+    ('\u{FF12}', "2"),
+    ('\u{FF13}', "3"),
+    ('\u{FF14}', "4"),
+    ('\u{FF15}', "5"),
+    ('\u{FF16}', "6"),
+    ('\u{FF17}', "7"),
+    ('\u{FF18}', "8"),
+    ('\u{FF19}', "9"),
+    ('\u{FF1A}', ":"),
+    ('\u{FF1B}', ";"),
+    ('\u{FF1C}', "<"),
+    ('\u{FF1D}', "="),
+    ('\u{FF1E}', ">"),
+    ('\u{FF1F}', "?"),
+    ('\u{FF20}', "@"),
+    ('\u{FF21}', "A"),
+    ('\u{FF22}', "B"),
+    ('\u{FF23}', "C"),
+    ('\u{FF24}', "D"),
+    ('\u{FF25}', "E"),
+    ('\u{FF26}', "F"),
+    ('\u{FF27}', "G"),
+    ('\u{FF28}', "H"),
+    ('\u{FF29}', "I"),
+    ('\u{FF2A}', "J"),
+    ('\u{FF2B}', "K"),
+    ('\u{FF2C}', "L"),
+    ('\u{FF2D}', "M"),
+    ('\u{FF2E}', "N"),
+    ('\u{FF2F}', "O"),
+    ('\u{FF30}', "P"),
+    ('\u{FF31}', "Q"),
+    ('\u{FF32}', "R"),
+    ('\u{FF33}', "S"),
+    ('\u{FF34}', "T"),
+    ('\u{FF35}', "U"),
+    ('\u{FF36}', "V"),
+    ('\u{FF37}', "W"),
+    ('\u{FF38}', "X"),
+    ('\u{FF39}', "Y"),
+    ('\u{FF3A}', "Z"),
+    ('\u{FF3B}', "["),
+    ('\u{FF3C}', "\\"),
+    ('\u{FF3D}', "]"),
+    ('\u{FF3E}', "^"),
+    ('\u{FF3F}', "_"),
+    ('\u{FF40}', "`"),
+    ('\u{FF41}', "a"),
+    ('\u{FF42}', "b"),
+    ('\u{FF43}', "c"),
+    ('\u{FF44}', "d"),
+    ('\u{FF45}', "e"),
+    ('\u{FF46}', "f"),
+    ('\u{FF47}', "g"),
+    ('\u{FF48}', "h"),
+    ('\u{FF49}', "i"),
+    ('\u{FF4A}', "j"),
+    ('\u{FF4B}', "k"),
+    ('\u{FF4C}', "l"),
+    ('\u{FF4D}', "m"),
+    ('\u{FF4E}', "n"),
+    ('\u{FF4F}', "o"),
+    ('\u{FF50}', "p"),
+    ('\u{FF51}', "q"),
+    ('\u{FF52}', "r"),
+    ('\u{FF53}', "s"),
+    ('\u{FF54}', "t"),
+    ('\u{FF55}', "u"),
+    ('\u{FF56}', "v"),
+    ('\u{FF57}', "w"),
+    ('\u{FF58}', "x"),
+    ('\u{FF59}', "y"),
+    ('\u{FF5A}', "z"),
+    ('\u{FF5B}', "{"),
+    ('\u{FF5C}', "|"),
+    ('\u{FF5D}', "}"),
+    ('\u{FF5E}', "~"),
+    ('\u{FFE2}', "-"),
+    ('\u{FFE3}', " "),
+    ('\u{FFE4}', "|"),
+    ('\u{10783}', "ae"),
+    ('\u{10784}', "B"),
+    ('\u{10785}', "b"),
+    ('\u{1078B}', "d"),
+    ('\u{1078C}', "d"),
+    ('\u{10792}', "G"),
+    ('\u{10793}', "g"),
+    ('\u{10794}', "G"),
+    ('\u{10795}', "h"),
+    ('\u{10796}', "H"),
+    ('\u{10797}', "h"),
+    ('\u{1079B}', "l"),
+    ('\u{107A2}', "o"),
+    ('\u{107A3}', "OE"),
+    ('\u{107A5}', "q"),
+    ('\u{107A8}', "r"),
+    ('\u{107A9}', "r"),
+    ('\u{107AA}', "R"),
+    ('\u{107AF}', "t"),
+    ('\u{107B2}', "Y"),
+    ('\u{1D400}', "A"),
+    ('\u{1D401}', "B"),
+    ('\u{1D402}', "C"),
+    ('\u{1D403}', "D"),
+    ('\u{1D404}', "E"),
+    ('\u{1D405}', "F"),
+    ('\u{1D406}', "G"),
+    ('\u{1D407}', "H"),
+    ('\u{1D408}', "I"),
+    ('\u{1D409}', "J"),
+    ('\u{1D40A}', "K"),
+    ('\u{1D40B}', "L"),
+    ('\u{1D40C}', "M"),
+    ('\u{1D40D}', "N"),
+    ('\u{1D40E}', "O"),
+    ('\u{1D40F}', "P"),
+    ('\u{1D410}', "Q"),
+    ('\u{1D411}', "R"),
+    ('\u{1D412}', "S"),
+    ('\u{1D413}', "T"),
+    ('\u{1D414}', "U"),
+    ('\u{1D415}', "V"),
+    ('\u{1D416}', "W"),
+    ('\u{1D417}', "X"),
+    ('\u{1D418}', "Y"),
+    ('\u{1D419}', "Z"),
+    ('\u{1D41A}', "a"),
+    ('\u{1D41B}', "b"),
+    ('\u{1D41C}', "c"),
+    ('\u{1D41D}', "d"),
+    ('\u{1D41E}', "e"),
+    ('\u{1D41F}', "f"),
+    ('\u{1D420}', "g"),
+    ('\u{1D421}', "h"),
+    ('\u{1D422}', "i"),
+    ('\u{1D423}', "j"),
+    ('\u{1D424}', "k"),
+    ('\u{1D425}', "l"),
+    ('\u{1D426}', "m"),
+    ('\u{1D427}', "n"),
+    ('\u{1D428}', "o"),
+    ('\u{1D429}', "p"),
+    ('\u{1D42A}', "q"),
+    ('\u{1D42B}', "r"),
+    ('\u{1D42C}', "s"),
+    ('\u{1D42D}', "t"),
+    ('\u{1D42E}', "u"),
+    ('\u{1D42F}', "v"),
+    ('\u{1D430}', "w"),
+    ('\u{1D431}', "x"),
+    ('\u{1D432}', "y"),
+    ('\u{1D433}', "z"),
+    ('\u{1D434}', "A"),
+    ('\u{1D435}', "B"),
+    ('\u{1D436}', "C"),
+    ('\u{1D437}', "D"),
+    ('\u{1D438}', "E"),
+    ('\u{1D439}', "F"),
+    ('\u{1D43A}', "G"),
+    ('\u{1D43B}', "H"),
+    ('\u{1D43C}', "I"),
+    ('\u{1D43D}', "J"),
+    ('\u{1D43E}', "K"),
+    ('\u{1D43F}', "L"),
+    ('\u{1D440}', "M"),
+    ('\u{1D441}', "N"),
+    ('\u{1D442}', "O"),
+    ('\u{1D443}', "P"),
+    ('\u{1D444}', "Q"),
+    ('\u{1D445}', "R"),
+    ('\u{1D446}', "S"),
+    ('\u{1D447}', "T"),
+    ('\u{1D448}', "U"),
+    ('\u{1D449}', "V"),
+    ('\u{1D44A}', "W"),
+    ('\u{1D44B}', "X"),
+    ('\u{1D44C}', "Y"),
+    ('\u{1D44D}', "Z"),
+    ('\u{1D44E}', "a"),
+    ('\u{1D44F}', "b"),
+    ('\u{1D450}', "c"),
+    ('\u{1D451}', "d"),
+    ('\u{1D452}', "e"),
+    ('\u{1D453}', "f"),
+    ('\u{1D454}', "g"),
+    ('\u{1D456}', "i"),
+    ('\u{1D457}', "j"),
+    ('\u{1D458}', "k"),
+    ('\u{1D459}', "l"),
+    ('\u{1D45A}', "m"),
+    ('\u{1D45B}', "n"),
+    ('\u{1D45C}', "o"),
+    ('\u{1D45D}', "p"),
+    ('\u{1D45E}', "q"),
+    ('\u{1D45F}', "r"),
+    ('\u{1D460}', "s"),
+    ('\u{1D461}', "t"),
+    ('\u{1D462}', "u"),
+    ('\u{1D463}', "v"),
+    ('\u{1D464}', "w"),
+    ('\u{1D465}', "x"),
+    ('\u{1D466}', "y"),
+    ('\u{1D467}', "z"),
+    ('\u{1D468}', "A"),
+    ('\u{1D469}', "B"),
+    ('\u{1D46A}', "C"),
+    ('\u{1D46B}', "D"),
+    ('\u{1D46C}', "E"),
+    ('\u{1D46D}', "F"),
+    ('\u{1D46E}', "G"),
+    ('\u{1D46F}', "H"),
+    ('\u{1D470}', "I"),
+    ('\u{1D471}', "J"),
+    ('\u{1D472}', "K"),
+    ('\u{1D473}', "L"),
+    ('\u{1D474}', "M"),
+    ('\u{1D475}', "N"),
+    ('\u{1D476}', "O"),
+    ('\u{1D477}', "P"),
+    ('\u{1D478}', "Q"),
+    ('\u{1D479}', "R"),
+    ('\u{1D47A}', "S"),
+    ('\u{1D47B}', "T"),
+    ('\u{1D47C}', "U"),
+    ('\u{1D47D}', "V"),
+    ('\u{1D47E}', "W"),
+    ('\u{1D47F}', "X"),
+    ('\u{1D480}', "Y"),
+    ('\u{1D481}', "Z"),
+    ('\u{1D482}', "a"),
+    ('\u{1D483}', "b"),
+    ('\u{1D484}', "c"),
+    ('\u{1D485}', "d"),
+    ('\u{1D486}', "e"),
+    ('\u{1D487}', "f"),
+    ('\u{1D488}', "g"),
+    ('\u{1D489}', "h"),
+    ('\u{1D48A}', "i"),
+    ('\u{1D48B}', "j"),
+    ('\u{1D48C}', "k"),
+    ('\u{1D48D}', "l"),
+    ('\u{1D48E}', "m"),
+    ('\u{1D48F}', "n"),
+    ('\u{1D490}', "o"),
+    ('\u{1D491}', "p"),
+    ('\u{1D492}', "q"),
+    ('\u{1D493}', "r"),
+    ('\u{1D494}', "s"),
+    ('\u{1D495}', "t"),
+    ('\u{1D496}', "u"),
+    ('\u{1D497}', "v"),
+    ('\u{1D498}', "w"),
+    ('\u{1D499}', "x"),
+    ('\u{1D49A}', "y"),
+    ('\u{1D49B}', "z"),
+    ('\u{1D49C}', "A"),
+    ('\u{1D49E}', "C"),
+    ('\u{1D49F}', "D"),
+    ('\u{1D4A2}', "G"),
+    ('\u{1D4A5}', "J"),
+    ('\u{1D4A6}', "K"),
+    ('\u{1D4A9}', "N"),
+    ('\u{1D4AA}', "O"),
+    ('\u{1D4AB}', "P"),
+    ('\u{1D4AC}', "Q"),
+    ('\u{1D4AE}', "S"),
+    ('\u{1D4AF}', "T"),
+    ('\u{1D4B0}', "U"),
+    ('\u{1D4B1}', "V"),
+    ('\u{1D4B2}', "W"),
+    ('\u{1D4B3}', "X"),
+    ('\u{1D4B4}', "Y"),
+    ('\u{1D4B5}', "Z"),
+    ('\u{1D4B6}', "a"),
+    ('\u{1D4B7}', "b"),
+    ('\u{1D4B8}', "c"),
+    ('\u{1D4B9}', "d"),
+    ('\u{1D4BB}', "f"),
+    ('\u{1D4BD}', "h"),
+    ('\u{1D4BE}', "i"),
+    ('\u{1D4BF}', "j"),
+    ('\u{1D4C0}', "k"),
+    ('\u{1D4C1}', "l"),
+    ('\u{1D4C2}', "m"),
+    ('\u{1D4C3}', "n"),
+    ('\u{1D4C5}', "p"),
+    ('\u{1D4C6}', "q"),
+    ('\u{1D4C7}', "r"),
+    ('\u{1D4C8}', "s"),
+    ('\u{1D4C9}', "t"),
+    ('\u{1D4CA}', "u"),
+    ('\u{1D4CB}', "v"),
+    ('\u{1D4CC}', "w"),
+    ('\u{1D4CD}', "x"),
+    ('\u{1D4CE}', "y"),
+    ('\u{1D4CF}', "z"),
+    ('\u{1D4D0}', "A"),
+    ('\u{1D4D1}', "B"),
+    ('\u{1D4D2}', "C"),
+    ('\u{1D4D3}', "D"),
+    ('\u{1D4D4}', "E"),
+    ('\u{1D4D5}', "F"),
+    ('\u{1D4D6}', "G"),
+    ('\u{1D4D7}', "H"),
+    ('\u{1D4D8}', "I"),
+    ('\u{1D4D9}', "J"),
+    ('\u{1D4DA}', "K"),
+    ('\u{1D4DB}', "L"),
+    ('\u{1D4DC}', "M"),
+    ('\u{1D4DD}', "N"),
+    ('\u{1D4DE}', "O"),
+    ('\u{1D4DF}', "P"),
+    ('\u{1D4E0}', "Q"),
+    ('\u{1D4E1}', "R"),
+    ('\u{1D4E2}', "S"),
+    ('\u{1D4E3}', "T"),
+    ('\u{1D4E4}', "U"),
+    ('\u{1D4E5}', "V"),
+    ('\u{1D4E6}', "W"),
+    ('\u{1D4E7}', "X"),
+    ('\u{1D4E8}', "Y"),
+    ('\u{1D4E9}', "Z"),
+    ('\u{1D4EA}', "a"),
+    ('\u{1D4EB}', "b"),
+    ('\u{1D4EC}', "c"),
+    ('\u{1D4ED}', "d"),
+    ('\u{1D4EE}', "e"),
+    ('\u{1D4EF}', "f"),
+    ('\u{1D4F0}', "g"),
+    ('\u{1D4F1}', "h"),
+    ('\u{1D4F2}', "i"),
+    ('\u{1D4F3}', "j"),
+    ('\u{1D4F4}', "k"),
+    ('\u{1D4F5}', "l"),
+    ('\u{1D4F6}', "m"),
+    ('\u{1D4F7}', "n"),
+    ('\u{1D4F8}', "o"),
+    ('\u{1D4F9}', "p"),
+    ('\u{1D4FA}', "q"),
+    ('\u{1D4FB}', "r"),
+    ('\u{1D4FC}', "s"),
+    ('\u{1D4FD}', "t"),
+    ('\u{1D4FE}', "u"),
+    ('\u{1D4FF}', "v"),
+    ('\u{1D500}', "w"),
+    ('\u{1D501}', "x"),
+    ('\u{1D502}', "y"),
+    ('\u{1D503}', "z"),
+    ('\u{1D504}', "A"),
+    ('\u{1D505}', "B"),
+    ('\u{1D507}', "D"),
+    ('\u{1D508}', "E"),
+    ('\u{1D509}', "F"),
+    ('\u{1D50A}', "G"),
+    ('\u{1D50D}', "J"),
+    ('\u{1D50E}', "K"),
+    ('\u{1D50F}', "L"),
+    ('\u{1D510}', "M"),
+    ('\u{1D511}', "N"),
+    ('\u{1D512}', "O"),
+    ('\u{1D513}', "P"),
+    ('\u{1D514}', "Q"),
+    ('\u{1D516}', "S"),
+    ('\u{1D517}', "T"),
+    ('\u{1D518}', "U"),
+    ('\u{1D519}', "V"),
+    ('\u{1D51A}', "W"),
+    ('\u{1D51B}', "X"),
+    ('\u{1D51C}', "Y"),
+    ('\u{1D51E}', "a"),
+    ('\u{1D51F}', "b"),
+    ('\u{1D520}', "c"),
+    ('\u{1D521}', "d"),
+    ('\u{1D522}', "e"),
+    ('\u{1D523}', "f"),
+    ('\u{1D524}', "g"),
+    ('\u{1D525}', "h"),
+    ('\u{1D526}', "i"),
+    ('\u{1D527}', "j"),
+    ('\u{1D528}', "k"),
+    ('\u{1D529}', "l"),
+    ('\u{1D52A}', "m"),
+    ('\u{1D52B}', "n"),
+    ('\u{1D52C}', "o"),
+    ('\u{1D52D}', "p"),
+    ('\u{1D52E}', "q"),
+    ('\u{1D52F}', "r"),
+    ('\u{1D530}', "s"),
+    ('\u{1D531}', "t"),
+    ('\u{1D532}', "u"),
+    ('\u{1D533}', "v"),
+    ('\u{1D534}', "w"),
+    ('\u{1D535}', "x"),
+    ('\u{1D536}', "y"),
+    ('\u{1D537}', "z"),
+    ('\u{1D538}', "A"),
+    ('\u{1D539}', "B"),
+    ('\u{1D53B}', "D"),
+    ('\u{1D53C}', "E"),
+    ('\u{1D53D}', "F"),
+    ('\u{1D53E}', "G"),
+    ('\u{1D540}', "I"),
+    ('\u{1D541}', "J"),
+    ('\u{1D542}', "K"),
+    ('\u{1D543}', "L"),
+    ('\u{1D544}', "M"),
+    ('\u{1D546}', "O"),
+    ('\u{1D54A}', "S"),
+    ('\u{1D54B}', "T"),
+    ('\u{1D54C}', "U"),
+    ('\u{1D54D}', "V"),
+    ('\u{1D54E}', "W"),
+    ('\u{1D54F}', "X"),
+    ('\u{1D550}', "Y"),
+    ('\u{1D552}', "a"),
+    ('\u{1D553}', "b"),
+    ('\u{1D554}', "c"),
+    ('\u{1D555}', "d"),
+    ('\u{1D556}', "e"),
+    ('\u{1D557}', "f"),
+    ('\u{1D558}', "g"),
+    ('\u{1D559}', "h"),
+    ('\u{1D55A}', "i"),
+    ('\u{1D55B}', "j"),
+    ('\u{1D55C}', "k"),
+    ('\u{1D55D}', "l"),
+    ('\u{1D55E}', "m"),
+    ('\u{1D55F}', "n"),
+    ('\u{1D560}', "o"),
+    ('\u{1D561}', "p"),
+    ('\u{1D562}', "q"),
+    ('\u{1D563}', "r"),
+    ('\u{1D564}', "s"),
+    ('\u{1D565}', "t"),
+    ('\u{1D566}', "u"),
+    ('\u{1D567}', "v"),
+    ('\u{1D568}', "w"),
+    ('\u{1D569}', "x"),
+    ('\u{1D56A}', "y"),
+    ('\u{1D56B}', "z"),
+    ('\u{1D56C}', "A"),
+    ('\u{1D56D}', "B"),
+    ('\u{1D56E}', "C"),
+    ('\u{1D56F}', "D"),
+    ('\u{1D570}', "E"),
+    ('\u{1D571}', "F"),
+    ('\u{1D572}', "G"),
+    ('\u{1D573}', "H"),
+    ('\u{1D574}', "I"),
+    ('\u{1D575}', "J"),
+    ('\u{1D576}', "K"),
+    ('\u{1D577}', "L"),
+    ('\u{1D578}', "M"),
+    ('\u{1D579}', "N"),
+    ('\u{1D57A}', "O"),
+    ('\u{1D57B}', "P"),
+    ('\u{1D57C}', "Q"),
+    ('\u{1D57D}', "R"),
+    ('\u{1D57E}', "S"),
+    ('\u{1D57F}', "T"),
+    ('\u{1D580}', "U"),
+    ('\u{1D581}', "V"),
+    ('\u{1D582}', "W"),
+    ('\u{1D583}', "X"),
+    ('\u{1D584}', "Y"),
+    ('\u{1D585}', "Z"),
+    ('\u{1D586}', "a"),
+    ('\u{1D587}', "b"),
+    ('\u{1D588}', "c"),
+    ('\u{1D589}', "d"),
+    ('\u{1D58A}', "e"),
+    ('\u{1D58B}', "f"),
+    ('\u{1D58C}', "g"),
+    ('\u{1D58D}', "h"),
+    ('\u{1D58E}', "i"),
+    ('\u{1D58F}', "j"),
+    ('\u{1D590}', "k"),
+    ('\u{1D591}', "l"),
+    ('\u{1D592}', "m"),
+    ('\u{1D593}', "n"),
+    ('\u{1D594}', "o"),
+    ('\u{1D595}', "p"),
+    ('\u{1D596}', "q"),
+    ('\u{1D597}', "r"),
+    ('\u{1D598}', "s"),
+    ('\u{1D599}', "t"),
+    ('\u{1D59A}', "u"),
+    ('\u{1D59B}', "v"),
+    ('\u{1D59C}', "w"),
+    ('\u{1D59D}', "x"),
+    ('\u{1D59E}', "y"),
+    ('\u{1D59F}', "z"),
+    ('\u{1D5A0}', "A"),
+    ('\u{1D5A1}', "B"),
+    ('\u{1D5A2}', "C"),
+    ('\u{1D5A3}', "D"),
+    ('\u{1D5A4}', "E"),
+    ('\u{1D5A5}', "F"),
+    ('\u{1D5A6}', "G"),
+    ('\u{1D5A7}', "H"),
+    ('\u{1D5A8}', "I"),
+    ('\u{1D5A9}', "J"),
+    ('\u{1D5AA}', "K"),
+    ('\u{1D5AB}', "L"),
+    ('\u{1D5AC}', "M"),
+    ('\u{1D5AD}', "N"),
+    ('\u{1D5AE}', "O"),
+    ('\u{1D5AF}', "P"),
+    ('\u{1D5B0}', "Q"),
+    ('\u{1D5B1}', "R"),
+    ('\u{1D5B2}', "S"),
+    ('\u{1D5B3}', "T"),
+    ('\u{1D5B4}', "U"),
+    ('\u{1D5B5}', "V"),
+    ('\u{1D5B6}', "W"),
+    ('\u{1D5B7}', "X"),
+    ('\u{1D5B8}', "Y"),
+    ('\u{1D5B9}', "Z"),
+    ('\u{1D5BA}', "a"),
+    ('\u{1D5BB}', "b"),
+    ('\u{1D5BC}', "c"),
+    ('\u{1D5BD}', "d"),
+    ('\u{1D5BE}', "e"),
+    ('\u{1D5BF}', "f"),
+    ('\u{1D5C0}', "g"),
+    ('\u{1D5C1}', "h"),
+    ('\u{1D5C2}', "i"),
+    ('\u{1D5C3}', "j"),
+    ('\u{1D5C4}', "k"),
+    ('\u{1D5C5}', "l"),
+    ('\u{1D5C6}', "m"),
+    ('\u{1D5C7}', "n"),
+    ('\u{1D5C8}', "o"),
+    ('\u{1D5C9}', "p"),
+    ('\u{1D5CA}', "q"),
+    ('\u{1D5CB}', "r"),
+    ('\u{1D5CC}', "s"),
+    ('\u{1D5CD}', "t"),
+    ('\u{1D5CE}', "u"),
+    ('\u{1D5CF}', "v"),
+    ('\u{1D5D0}', "w"),
+    ('\u{1D5D1}', "x"),
+    ('\u{1D5D2}', "y"),
+    ('\u{1D5D3}', "z"),
+    ('\u{1D5D4}', "A"),
+    ('\u{1D5D5}', "B"),
+    ('\u{1D5D6}', "C"),
+    ('\u{1D5D7}', "D"),
+    ('\u{1D5D8}', "E"),
+    ('\u{1D5D9}', "F"),
+    ('\u{1D5DA}', "G"),
+    ('\u{1D5DB}', "H"),
+    ('\u{1D5DC}', "I"),
+    ('\u{1D5DD}', "J"),
+    ('\u{1D5DE}', "K"),
+    ('\u{1D5DF}', "L"),
+    ('\u{1D5E0}', "M"),
+    ('\u{1D5E1}', "N"),
+    ('\u{1D5E2}', "O"),
+    ('\u{1D5E3}', "P"),
+    ('\u{1D5E4}', "Q"),
+    ('\u{1D5E5}', "R"),
+    ('\u{1D5E6}', "S"),
+    ('\u{1D5E7}', "T"),
+    ('\u{1D5E8}', "U"),
+    ('\u{1D5E9}', "V"),
+    ('\u{1D5EA}', "W"),
+    ('\u{1D5EB}', "X"),
+    ('\u{1D5EC}', "Y"),
+    ('\u{1D5ED}', "Z"),
+    ('\u{1D5EE}', "a"),
+    ('\u{1D5EF}', "b"),
+    ('\u{1D5F0}', "c"),
+    ('\u{1D5F1}', "d"),
+    ('\u{1D5F2}', "e"),
+    ('\u{1D5F3}', "f"),
+    ('\u{1D5F4}', "g"),
+    ('\u{1D5F5}', "h"),
+    ('\u{1D5F6}', "i"),
+    ('\u{1D5F7}', "j"),
+    ('\u{1D5F8}', "k"),
+    ('\u{1D5F9}', "l"),
+    ('\u{1D5FA}', "m"),
+    ('\u{1D5FB}', "n"),
+    ('\u{1D5FC}', "o"),
+    ('\u{1D5FD}', "p"),
+    ('\u{1D5FE}', "q"),
+    ('\u{1D5FF}', "r"),
+    ('\u{1D600}', "s"),
+    ('\u{1D601}', "t"),
+    ('\u{1D602}', "u"),
+    ('\u{1D603}', "v"),
+    ('\u{1D604}', "w"),
+    ('\u{1D605}', "x"),
+    ('\u{1D606}', "y"),
+    ('\u{1D607}', "z"),
+    ('\u{1D608}', "A"),
+    ('\u{1D609}', "B"),
+    ('\u{1D60A}', "C"),
+    ('\u{1D60B}', "D"),
+    ('\u{1D60C}', "E"),
+    ('\u{1D60D}', "F"),
+    ('\u{1D60E}', "G"),
+    ('\u{1D60F}', "H"),
+    ('\u{1D610}', "I"),
+    ('\u{1D611}', "J"),
+    ('\u{1D612}', "K"),
+    ('\u{1D613}', "L"),
+    ('\u{1D614}', "M"),
+    ('\u{1D615}', "N"),
+    ('\u{1D616}', "O"),
+    ('\u{1D617}', "P"),
+    ('\u{1D618}', "Q"),
+    ('\u{1D619}', "R"),
+    ('\u{1D61A}', "S"),
+    ('\u{1D61B}', "T"),
+    ('\u{1D61C}', "U"),
+    ('\u{1D61D}', "V"),
+    ('\u{1D61E}', "W"),
+    ('\u{1D61F}', "X"),
+    ('\u{1D620}', "Y"),
+    ('\u{1D621}', "Z"),
+    ('\u{1D622}', "a"),
+    ('\u{1D623}', "b"),
+    ('\u{1D624}', "c"),
+    ('\u{1D625}', "d"),
+    ('\u{1D626}', "e"),
+    ('\u{1D627}', "f"),
+    ('\u{1D628}', "g"),
+    ('\u{1D629}', "h"),
+    ('\u{1D62A}', "i"),
+    ('\u{1D62B}', "j"),
+    ('\u{1D62C}', "k"),
+    ('\u{1D62D}', "l"),
+    ('\u{1D62E}', "m"),
+    ('\u{1D62F}', "n"),
+    ('\u{1D630}', "o"),
+    ('\u{1D631}', "p"),
+    ('\u{1D632}', "q"),
+    ('\u{1D633}', "r"),
+    ('\u{1D634}', "s"),
+    ('\u{1D635}', "t"),
+    ('\u{1D636}', "u"),
+    ('\u{1D637}', "v"),
+    ('\u{1D638}', "w"),
+    ('\u{1D639}', "x"),
+    ('\u{1D63A}', "y"),
+    ('\u{1D63B}', "z"),
+    ('\u{1D63C}', "A"),
+    ('\u{1D63D}', "B"),
+    ('\u{1D63E}', "C"),
+    ('\u{1D63F}', "D"),
+    ('\u{1D640}', "E"),
+    ('\u{1D641}', "F"),
+    ('\u{1D642}', "G"),
+    ('\u{1D643}', "H"),
+    ('\u{1D644}', "I"),
+    ('\u{1D645}', "J"),
+    ('\u{1D646}', "K"),
+    ('\u{1D647}', "L"),
+    ('\u{1D648}', "M"),
+    ('\u{1D649}', "N"),
+    ('\u{1D64A}', "O"),
+    ('\u{1D64B}', "P"),
+    ('\u{1D64C}', "Q"),
+    ('\u{1D64D}', "R"),
+    ('\u{1D64E}', "S"),
+    ('\u{1D64F}', "T"),
+    ('\u{1D650}', "U"),
+    ('\u{1D651}', "V"),
+    ('\u{1D652}', "W"),
+    ('\u{1D653}', "X"),
+    ('\u{1D654}', "Y"),
+    ('\u{1D655}', "Z"),
+    ('\u{1D656}', "a"),
+    ('\u{1D657}', "b"),
+    ('\u{1D658}', "c"),
+    ('\u{1D659}', "d"),
+    ('\u{1D65A}', "e"),
+    ('\u{1D65B}', "f"),
+    ('\u{1D65C}', "g"),
+    ('\u{1D65D}', "h"),
+    ('\u{1D65E}', "i"),
+    ('\u{1D65F}', "j"),
+    ('\u{1D660}', "k"),
+    ('\u{1D661}', "l"),
+    ('\u{1D662}', "m"),
+    ('\u{1D663}', "n"),
+    ('\u{1D664}', "o"),
+    ('\u{1D665}', "p"),
+    ('\u{1D666}', "q"),
+    ('\u{1D667}', "r"),
+    ('\u{1D668}', "s"),
+    ('\u{1D669}', "t"),
+    ('\u{1D66A}', "u"),
+    ('\u{1D66B}', "v"),
+    ('\u{1D66C}', "w"),
+    ('\u{1D66D}', "x"),
+    ('\u{1D66E}', "y"),
+    ('\u{1D66F}', "z"),
+    ('\u{1D670}', "A"),
+    ('\u{1D671}', "B"),
+    ('\u{1D672}', "C"),
+    ('\u{1D673}', "D"),
+    ('\u{1D674}', "E"),
+    ('\u{1D675}', "F"),
+    ('\u{1D676}', "G"),
+    ('\u{1D677}', "H"),
+    ('\u{1D678}', "I"),
+    ('\u{1D679}', "J"),
+    ('\u{1D67A}', "K"),
+    ('\u{1D67B}', "L"),
+    ('\u{1D67C}', "M"),
+    ('\u{1D67D}', "N"),
+    ('\u{1D67E}', "O"),
+    ('\u{1D67F}', "P"),
+    ('\u{1D680}', "Q"),
+    ('\u{1D681}', "R"),
+    ('\u{1D682}', "S"),
+    ('\u{1D683}', "T"),
+    ('\u{1D684}', "U"),
+    ('\u{1D685}', "V"),
+    ('\u{1D686}', "W"),
+    ('\u{1D687}', "X"),
+    ('\u{1D688}', "Y"),
+    ('\u{1D689}', "Z"),
+    ('\u{1D68A}', "a"),
+    ('\u{1D68B}', "b"),
+    ('\u{1D68C}', "c"),
+    ('\u{1D68D}', "d"),
+    ('\u{1D68E}', "e"),
+    ('\u{1D68F}', "f"),
+    ('\u{1D690}', "g"),
+    ('\u{1D691}', "h"),
+    ('\u{1D692}', "i"),
+    ('\u{1D693}', "j"),
+    ('\u{1D694}', "k"),
+    ('\u{1D695}', "l"),
+    ('\u{1D696}', "m"),
+    ('\u{1D697}', "n"),
+    ('\u{1D698}', "o"),
+    ('\u{1D699}', "p"),
+    ('\u{1D69A}', "q"),
+    ('\u{1D69B}', "r"),
+    ('\u{1D69C}', "s"),
+    ('\u{1D69D}', "t"),
+    ('\u{1D69E}', "u"),
+    ('\u{1D69F}', "v"),
+    ('\u{1D6A0}', "w"),
+    ('\u{1D6A1}', "x"),
+    ('\u{1D6A2}', "y"),
+    ('\u{1D6A3}', "z"),
+    ('\u{1D6A4}', "i"),
+    ('\u{1D6A5}', "j"),
+    ('\u{1D7CE}', "0"),
+    ('\u{1D7CF}', "1"),
+    ('\u{1D7D0}', "2"),
+    ('\u{1D7D1}', "3"),
+    ('\u{1D7D2}', "4"),
+    ('\u{1D7D3}', "5"),
+    ('\u{1D7D4}', "6"),
+    ('\u{1D7D5}', "7"),
+    ('\u{1D7D6}', "8"),
+    ('\u{1D7D7}', "9"),
+    ('\u{1D7D8}', "0"),
+    ('\u{1D7D9}', "1"),
+    ('\u{1D7DA}', "2"),
+    ('\u{1D7DB}', "3"),
+    ('\u{1D7DC}', "4"),
+    ('\u{1D7DD}', "5"),
+    ('\u{1D7DE}', "6"),
+    ('\u{1D7DF}', "7"),
+    ('\u{1D7E0}', "8"),
+    ('\u{1D7E1}', "9"),
+    ('\u{1D7E2}', "0"),
+    ('\u{1D7E3}', "1"),
+    ('\u{1D7E4}', "2"),
+    ('\u{1D7E5}', "3"),
+    ('\u{1D7E6}', "4"),
+    ('\u{1D7E7}', "5"),
+    ('\u{1D7E8}', "6"),
+    ('\u{1D7E9}', "7"),
+    ('\u{1D7EA}', "8"),
+    ('\u{1D7EB}', "9"),
+    ('\u{1D7EC}', "0"),
+    ('\u{1D7ED}', "1"),
+    ('\u{1D7EE}', "2"),
+    ('\u{1D7EF}', "3"),
+    ('\u{1D7F0}', "4"),
+    ('\u{1D7F1}', "5"),
+    ('\u{1D7F2}', "6"),
+    ('\u{1D7F3}', "7"),
+    ('\u{1D7F4}', "8"),
+    ('\u{1D7F5}', "9"),
+    ('\u{1D7F6}', "0"),
+    ('\u{1D7F7}', "1"),
+    ('\u{1D7F8}', "2"),
+    ('\u{1D7F9}', "3"),
+    ('\u{1D7FA}', "4"),
+    ('\u{1D7FB}', "5"),
+    ('\u{1D7FC}', "6"),
+    ('\u{1D7FD}', "7"),
+    ('\u{1D7FE}', "8"),
+    ('\u{1D7FF}', "9"),
+    ('\u{1F100}', "0."),
+    ('\u{1F101}', "0,"),
+    ('\u{1F102}', "1,"),
+    ('\u{1F103}', "2,"),
+    ('\u{1F104}', "3,"),
+    ('\u{1F105}', "4,"),
+    ('\u{1F106}', "5,"),
+    ('\u{1F107}', "6,"),
+    ('\u{1F108}', "7,"),
+    ('\u{1F109}', "8,"),
+    ('\u{1F10A}', "9,"),
+    ('\u{1F110}', "(A)"),
+    ('\u{1F111}', "(B)"),
+    ('\u{1F112}', "(C)"),
+    ('\u{1F113}', "(D)"),
+    ('\u{1F114}', "(E)"),
+    ('\u{1F115}', "(F)"),
+    ('\u{1F116}', "(G)"),
+    ('\u{1F117}', "(H)"),
+    ('\u{1F118}', "(I)"),
+    ('\u{1F119}', "(J)"),
+    ('\u{1F11A}', "(K)"),
+    ('\u{1F11B}', "(L)"),
+    ('\u{1F11C}', "(M)"),
+    ('\u{1F11D}', "(N)"),
+    ('\u{1F11E}', "(O)"),
+    ('\u{1F11F}', "(P)"),
+    ('\u{1F120}', "(Q)"),
+    ('\u{1F121}', "(R)"),
+    ('\u{1F122}', "(S)"),
+    ('\u{1F123}', "(T)"),
+    ('\u{1F124}', "(U)"),
+    ('\u{1F125}', "(V)"),
+    ('\u{1F126}', "(W)"),
+    ('\u{1F127}', "(X)"),
+    ('\u{1F128}', "(Y)"),
+    ('\u{1F129}', "(Z)"),
+    ('\u{1F12B}', "C"),
+    ('\u{1F12C}', "R"),
+    ('\u{1F12D}', "CD"),
+    ('\u{1F12E}', "WZ"),
+    ('\u{1F130}', "A"),
+    ('\u{1F131}', "B"),
+    ('\u{1F132}', "C"),
+    ('\u{1F133}', "D"),
+    ('\u{1F134}', "E"),
+    ('\u{1F135}', "F"),
+    ('\u{1F136}', "G"),
+    ('\u{1F137}', "H"),
+    ('\u{1F138}', "I"),
+    ('\u{1F139}', "J"),
+    ('\u{1F13A}', "K"),
+    ('\u{1F13B}', "L"),
+    ('\u{1F13C}', "M"),
+    ('\u{1F13D}', "N"),
+    ('\u{1F13E}', "O"),
+    ('\u{1F13F}', "P"),
+    ('\u{1F140}', "Q"),
+    ('\u{1F141}', "R"),
+    ('\u{1F142}', "S"),
+    ('\u{1F143}', "T"),
+    ('\u{1F144}', "U"),
+    ('\u{1F145}', "V"),
+    ('\u{1F146}', "W"),
+    ('\u{1F147}', "X"),
+    ('\u{1F148}', "Y"),
+    ('\u{1F149}', "Z"),
+    ('\u{1F14A}', "HV"),
+    ('\u{1F14B}', "MV"),
+    ('\u{1F14C}', "SD"),
+    ('\u{1F14D}', "SS"),
+    ('\u{1F14E}', "PPV"),
+    ('\u{1F14F}', "WC"),
+    ('\u{1F16A}', "MC"),
+    ('\u{1F16B}', "MD"),
+    ('\u{1F16C}', "MR"),
+    ('\u{1F190}', "DJ"),
+    ('\u{1FBF0}', "0"),
+    ('\u{1FBF1}', "1"),
+    ('\u{1FBF2}', "2"),
+    ('\u{1FBF3}', "3"),
+    ('\u{1FBF4}', "4"),
+    ('\u{1FBF5}', "5"),
+    ('\u{1FBF6}', "6"),
+    ('\u{1FBF7}', "7"),
+    ('\u{1FBF8}', "8"),
+    ('\u{1FBF9}', "9"),
+];
@@ -0,0 +1,246 @@
+use super::case_tables::{FULL_CASE_FOLDING, SIMPLE_CASE_FOLDING};
+use crate::tokenizer::{NormalizedString, Normalizer, Result};
+use crate::utils::macro_rules_attribute;
+use serde::{Deserialize, Serialize};
+
+/// The case folding to apply
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseFoldMode {
+    /// The full case folding, where a char can become many, like `ß` becoming `ss`
+    #[default]
+    Full,
+    /// The simple case folding, where each char stays a single char
+    Simple,
+    /// The `NFKC_Casefold` mapping: NFKC and full case folding, without the default ignorable
+    /// code points. This is what is used to compare identifiers.
+    NfkcCasefold,
+}
+
+/// Case folds the input, which is more appropriate than lowercasing for caseless matching: the
+/// final sigma, `ß` or the ligatures are folded like their other forms.
+#[derive(Copy, Clone, Debug, Default)]
+#[macro_rules_attribute(impl_serde_type!)]
+pub struct CaseFold {
+    #[serde(default)]
+    pub mode: CaseFoldMode,
+}
+
+impl CaseFold {
+    pub fn new(mode: CaseFoldMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl Normalizer for CaseFold {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        match self.mode {
+            CaseFoldMode::Full => case_fold(normalized),
+            CaseFoldMode::Simple => {
+                normalized.map(simple_case_fold);
+            }
+            CaseFoldMode::NfkcCasefold => {
+                normalized.filter(|c| !is_default_ignorable(c)).nfkc();
+                case_fold(normalized);
+                normalized.nfkc();
+            }
+        }
+        Ok(())
+    }
+}
+
+fn case_fold(normalized: &mut NormalizedString) {
+    let mut new_chars: Vec<(char, isize)> = Vec::with_capacity(normalized.len());
+    normalized.for_each(
+        |c| match FULL_CASE_FOLDING.binary_search_by_key(&c, |(c, _)| *c) {
+            Ok(i) => new_chars.extend(
+                FULL_CASE_FOLDING[i]
+                    .1
+                    .chars()
+                    .enumerate()
+                    .map(|(index, c)| (c, isize::from(index > 0))),
+            ),
+            Err(_) => new_chars.push((c, 0)),
+        },
+    );
+    normalized.transform(new_chars, 0);
+}
+
+fn simple_case_fold(c: char) -> char {
+    match SIMPLE_CASE_FOLDING.binary_search_by_key(&c, |(c, _)| *c) {
+        Ok(i) => SIMPLE_CASE_FOLDING[i].1,
+        Err(_) => c,
+    }
+}
+
+/// Whether the char has the `Default_Ignorable_Code_Point` property: it should be invisible
+/// when not supported, like the zero width joiners or the variation selectors.
+pub(crate) fn is_default_ignorable(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x00AD
+            | 0x034F
+            | 0x061C
+            | 0x115F..=0x1160
+            | 0x17B4..=0x17B5
+            | 0x180B..=0x180F
+            | 0x200B..=0x200F
+            | 0x202A..=0x202E
+            | 0x2060..=0x206F
+            | 0x3164
+            | 0xFE00..=0xFE0F
+            | 0xFEFF
+            | 0xFFA0
+            | 0xFFF0..=0xFFF8
+            | 0x1BCA0..=0x1BCA3
+            | 0x1D173..=0x1D17A
+            | 0xE0000..=0xE0FFF
+    )
+}
+
+/// The languages having their own lowercasing rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseLocale {
+    Turkish,
+    Azeri,
+}
+
+/// Lowercases the input following the rules of the given language. In Turkish and Azeri, `I`
+/// becomes the dotless `ı` and `İ` becomes `i`, also when written as `I` followed by a
+/// combining dot.
+#[derive(Copy, Clone, Debug)]
+#[macro_rules_attribute(impl_serde_type!)]
+pub struct LocaleLowercase {
+    pub locale: CaseLocale,
+}
+
+impl LocaleLowercase {
+    pub fn new(locale: CaseLocale) -> Self {
+        Self { locale }
+    }
+}
+
+impl Normalizer for LocaleLowercase {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        self.locale.lowercase(normalized);
+        Ok(())
+    }
+}
+
+impl CaseLocale {
+    fn lowercase(&self, normalized: &mut NormalizedString) {
+        match self {
+            Self::Turkish | Self::Azeri => {
+                let mut new_chars: Vec<(char, isize)> = Vec::with_capacity(normalized.len());
+                let mut chars = normalized.get().chars().peekable();
+                while let Some(c) = chars.next() {
+                    match c {
+                        'I' if chars.peek() == Some(&'\u{307}') => {
+                            chars.next();
+                            new_chars.push(('i', -1));
+                        }
+                        'I' => new_chars.push(('ı', 0)),
+                        '\u{130}' => new_chars.push(('i', 0)),
+                        c => new_chars.extend(
+                            c.to_lowercase()
+                                .enumerate()
+                                .map(|(index, c)| (c, isize::from(index > 0))),
+                        ),
+                    }
+                }
+                normalized.transform(new_chars, 0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalizers::{Lowercase, NormalizerWrapper};
+    use crate::tokenizer::normalizer::Range;
+
+    #[test]
+    fn tables_are_sorted() {
+        assert!(FULL_CASE_FOLDING.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(SIMPLE_CASE_FOLDING.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn full_case_fold() {
+        let mut n = NormalizedString::from("Straße ΣΑΣ ς ﬁx");
+        CaseFold::default().normalize(&mut n).unwrap();
+        assert_eq!(n.get(), "strasse σασ σ fix");
+        // Each char of the folding is aligned on the original char
+        assert_eq!(
+            n.get_range_original(Range::Normalized(0..7)),
+            Some("Straße")
+        );
+        assert_eq!(n.get_range_original(Range::Normalized(4..6)), Some("ß"));
+        assert_eq!(n.get_range_original(Range::Normalized(18..20)), Some("ﬁ"));
+
+        let mut n = NormalizedString::from("Straße ẞ");
+        CaseFold::new(CaseFoldMode::Simple)
+            .normalize(&mut n)
+            .unwrap();
+        assert_eq!(n.get(), "straße ß");
+    }
+
+    #[test]
+    fn nfkc_casefold() {
+        let mut n = NormalizedString::from("Ｈｅ\u{200B}ｌｌｏ ﬀ Å");
+        CaseFold::new(CaseFoldMode::NfkcCasefold)
+            .normalize(&mut n)
+            .unwrap();
+        assert_eq!(n.get(), "hello ff å");
+        assert_eq!(
+            n.get_range_original(Range::Normalized(0..5)),
+            Some("Ｈｅ\u{200B}ｌｌｏ")
+        );
+    }
+
+    #[test]
+    fn turkish_lowercase() {
+        let mut n = NormalizedString::from("DİYARBAKIR I\u{307}");
+        LocaleLowercase::new(CaseLocale::Turkish)
+            .normalize(&mut n)
+            .unwrap();
+        assert_eq!(n.get(), "diyarbakır i");
+        // The removed dot isn't aligned with anything anymore
+        assert_eq!(n.get_range_original(Range::Normalized(12..13)), Some("I"));
+
+        let mut n = NormalizedString::from("DİYARBAKIR");
+        Lowercase.normalize(&mut n).unwrap();
+        assert_eq!(n.get(), "di\u{307}yarbakir");
+    }
+
+    #[test]
+    fn serialization() {
+        let case_fold: NormalizerWrapper = CaseFold::new(CaseFoldMode::NfkcCasefold).into();
+        let json = serde_json::to_string(&case_fold).unwrap();
+        assert_eq!(json, r#"{"type":"CaseFold","mode":"nfkc_casefold"}"#);
+        assert!(matches!(
+            serde_json::from_str(&json).unwrap(),
+            NormalizerWrapper::CaseFold(CaseFold {
+                mode: CaseFoldMode::NfkcCasefold
+            })
+        ));
+        assert!(matches!(
+            serde_json::from_str(r#"{"type":"CaseFold"}"#).unwrap(),
+            NormalizerWrapper::CaseFold(CaseFold {
+                mode: CaseFoldMode::Full
+            })
+        ));
+
+        let lowercase: NormalizerWrapper = LocaleLowercase::new(CaseLocale::Azeri).into();
+        let json = serde_json::to_string(&lowercase).unwrap();
+        assert_eq!(json, r#"{"type":"LocaleLowercase","locale":"azeri"}"#);
+        assert!(matches!(
+            serde_json::from_str(&json).unwrap(),
+            NormalizerWrapper::LocaleLowercase(LocaleLowercase {
+                locale: CaseLocale::Azeri
+            })
+        ));
+    }
+}
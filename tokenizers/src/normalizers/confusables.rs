@@ -0,0 +1,149 @@
+use super::case::is_default_ignorable;
+use super::confusables_table::CONFUSABLES;
+use crate::tokenizer::{NormalizedString, Normalizer, Result};
+use crate::utils::macro_rules_attribute;
+use serde::{Deserialize, Serialize};
+
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+/// Replaces the text by its confusable skeleton, like the Unicode TR39 does: after NFD, each
+/// char that looks like another is replaced by its prototype, like the Cyrillic `а` by the
+/// Latin `a`, or `1` by `l`, so that lookalike texts get the same skeleton.
+///
+/// The confusables are those of `confusables.txt` of Unicode 15.0.0, as compiled into ICU 72.
+/// Their prototypes are not always Latin: the Cyrillic `П` is replaced by the Greek `Π`, and the
+/// Katakana `エ` by the CJK `工`. Like in the TR39, the compatibility forms, like the fullwidth `ｍ`,
+/// are not confusables, a NFKC can be applied first to replace them.
+///
+/// The default ignorable code points, invisible in most fonts, are removed first. They include
+/// the zero width spaces, the bidi controls and the zero width (non-)joiners, which can be kept
+/// since some scripts need them.
+#[derive(Copy, Clone, Debug, Default)]
+#[macro_rules_attribute(impl_serde_type!)]
+pub struct ConfusableSkeleton {
+    #[serde(default)]
+    pub keep_joiners: bool,
+}
+
+impl ConfusableSkeleton {
+    pub fn new(keep_joiners: bool) -> Self {
+        Self { keep_joiners }
+    }
+
+    fn keeps(&self, c: char) -> bool {
+        !is_default_ignorable(c) || (self.keep_joiners && (c == ZWNJ || c == ZWJ))
+    }
+}
+
+impl Normalizer for ConfusableSkeleton {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        normalized.filter(|c| self.keeps(c)).nfd();
+
+        let mut transformations: Vec<(char, isize)> = Vec::with_capacity(normalized.len());
+        normalized.for_each(
+            |c| match CONFUSABLES.binary_search_by_key(&c, |(c, _)| *c) {
+                Ok(i) => transformations.extend(
+                    CONFUSABLES[i]
+                        .1
+                        .chars()
+                        .enumerate()
+                        .map(|(index, c)| (c, isize::from(index > 0))),
+                ),
+                Err(_) => transformations.push((c, 0)),
+            },
+        );
+        normalized.transform(transformations, 0);
+        normalized.nfd();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalizers::NormalizerWrapper;
+    use crate::tokenizer::normalizer::Range;
+
+    fn skeleton(keep_joiners: bool, s: &str) -> NormalizedString {
+        let mut n = NormalizedString::from(s);
+        ConfusableSkeleton::new(keep_joiners)
+            .normalize(&mut n)
+            .unwrap();
+        n
+    }
+
+    #[test]
+    fn table_is_sorted_and_stable() {
+        assert!(CONFUSABLES.windows(2).all(|w| w[0].0 < w[1].0));
+        // The prototypes are never mapped again
+        assert!(CONFUSABLES.iter().all(|(_, prototype)| prototype
+            .chars()
+            .all(|c| CONFUSABLES.binary_search_by_key(&c, |(c, _)| *c).is_err())));
+    }
+
+    #[test]
+    fn lookalikes_share_their_skeleton() {
+        // Greek Rho and Cyrillic a, with a zero width space
+        let spoofed = skeleton(false, "\u{3A1}\u{430}y\u{200B}pal");
+        assert_eq!(spoofed.get(), skeleton(false, "Paypal").get());
+        assert_eq!(spoofed.get(), "Paypal");
+        assert_eq!(
+            spoofed.get_range_original(Range::Normalized(0..2)),
+            Some("\u{3A1}\u{430}")
+        );
+
+        assert_eq!(skeleton(false, "I1|l").get(), "llll");
+        let n = skeleton(false, "\u{1D5C6}é");
+        assert_eq!(n.get(), "rne\u{301}");
+        assert_eq!(
+            n.get_range_original(Range::Normalized(0..2)),
+            Some("\u{1D5C6}")
+        );
+        assert_eq!(n.get_range_original(Range::Normalized(2..5)), Some("é"));
+        // The compatibility forms are kept
+        assert_eq!(skeleton(false, "ｍ").get(), "ｍ");
+    }
+
+    #[test]
+    fn non_latin_prototypes() {
+        // Cyrillic Pe and Ghe, Greek Pi and Gamma
+        assert_eq!(skeleton(false, "\u{41F}\u{413}").get(), "\u{3A0}\u{393}");
+        assert_eq!(skeleton(false, "\u{3A0}\u{393}").get(), "\u{3A0}\u{393}");
+        // Micro sign and Greek mu
+        assert_eq!(skeleton(false, "\u{B5}").get(), "\u{3BC}");
+        // Katakana E and CJK "work", CJK "one" and the Katakana prolonged sound mark
+        let n = skeleton(false, "\u{30A8}\u{4E00}");
+        assert_eq!(n.get(), "\u{5DE5}\u{30FC}");
+        assert_eq!(
+            n.get_range_original(Range::Normalized(3..6)),
+            Some("\u{4E00}")
+        );
+        // Arabic prototypes
+        assert_eq!(skeleton(false, "\u{62B}").get(), "\u{649}\u{6DB}");
+    }
+
+    #[test]
+    fn joiners() {
+        let text = "\u{915}\u{94D}\u{200D}\u{937}\u{feff}";
+        assert_eq!(skeleton(false, text).get(), "\u{915}\u{94D}\u{937}");
+        assert_eq!(skeleton(true, text).get(), "\u{915}\u{94D}\u{200D}\u{937}");
+    }
+
+    #[test]
+    fn serialization() {
+        let skeleton: NormalizerWrapper = ConfusableSkeleton::new(true).into();
+        let json = serde_json::to_string(&skeleton).unwrap();
+        assert_eq!(json, r#"{"type":"ConfusableSkeleton","keep_joiners":true}"#);
+        assert!(matches!(
+            serde_json::from_str(&json).unwrap(),
+            NormalizerWrapper::ConfusableSkeleton(ConfusableSkeleton { keep_joiners: true })
+        ));
+        assert!(matches!(
+            serde_json::from_str(r#"{"type":"ConfusableSkeleton"}"#).unwrap(),
+            NormalizerWrapper::ConfusableSkeleton(ConfusableSkeleton {
+                keep_joiners: false
+            })
+        ));
+    }
+}
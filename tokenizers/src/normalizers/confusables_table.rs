@@ -0,0 +1,5252 @@
+// Generated by scripts/generate_confusables_table.py from the confusables of ICU 72 (Unicode 15.0.0), do not edit.
+
+/// The prototype of each confusable char, sorted by char
+pub(super) static CONFUSABLES: &[(char, &str)] = &[
+    ('\u{22}', "''"),
+    ('\u{25}', "\u{BA}/\u{2080}"),
+    ('\u{30}', "O"),
+    ('\u{31}', "l"),
+    ('\u{49}', "l"),
+    ('\u{60}', "'"),
+    ('\u{6D}', "rn"),
+    ('\u{7C}', "l"),
+    ('\u{A0}', " "),
+    ('\u{A2}', "c\u{338}"),
+    ('\u{A5}', "Y\u{335}"),
+    ('\u{AF}', "\u{2C9}"),
+    ('\u{B4}', "'"),
+    ('\u{B5}', "\u{3BC}"),
+    ('\u{B8}', ","),
+    ('\u{C6}', "AE"),
+    ('\u{D0}', "D\u{335}"),
+    ('\u{D7}', "x"),
+    ('\u{D8}', "O\u{338}"),
+    ('\u{E6}', "ae"),
+    ('\u{F0}', "\u{2202}\u{335}"),
+    ('\u{F8}', "o\u{338}"),
+    ('\u{110}', "D\u{335}"),
+    ('\u{111}', "d\u{335}"),
+    ('\u{126}', "H\u{335}"),
+    ('\u{127}', "h\u{335}"),
+    ('\u{131}', "i"),
+    ('\u{132}', "lJ"),
+    ('\u{133}', "ij"),
+    ('\u{13F}', "l\u{B7}"),
+    ('\u{140}', "l\u{B7}"),
+    ('\u{141}', "L\u{338}"),
+    ('\u{142}', "l\u{338}"),
+    ('\u{149}', "'n"),
+    ('\u{152}', "OE"),
+    ('\u{153}', "oe"),
+    ('\u{166}', "T\u{335}"),
+    ('\u{167}', "t\u{335}"),
+    ('\u{17F}', "f"),
+    ('\u{180}', "b\u{335}"),
+    ('\u{181}', "'B"),
+    ('\u{182}', "b\u{304}"),
+    ('\u{183}', "b\u{304}"),
+    ('\u{184}', "b"),
+    ('\u{187}', "C'"),
+    ('\u{189}', "D\u{335}"),
+    ('\u{18A}', "'D"),
+    ('\u{18C}', "d\u{304}"),
+    ('\u{18D}', "g"),
+    ('\u{191}', "F\u{326}"),
+    ('\u{192}', "f\u{326}"),
+    ('\u{193}', "G'"),
+    ('\u{196}', "l"),
+    ('\u{197}', "l\u{335}"),
+    ('\u{198}', "K'"),
+    ('\u{199}', "k\u{314}"),
+    ('\u{19A}', "l\u{335}"),
+    ('\u{19D}', "N\u{326}"),
+    ('\u{19E}', "n\u{329}"),
+    ('\u{19F}', "O\u{335}"),
+    ('\u{1A4}', "'P"),
+    ('\u{1A5}', "p\u{314}"),
+    ('\u{1A6}', "R"),
+    ('\u{1A7}', "2"),
+    ('\u{1AC}', "'T"),
+    ('\u{1AD}', "t\u{314}"),
+    ('\u{1AE}', "T\u{328}"),
+    ('\u{1B3}', "'Y"),
+    ('\u{1B4}', "y\u{314}"),
+    ('\u{1B5}', "Z\u{335}"),
+    ('\u{1B6}', "z\u{335}"),
+    ('\u{1B7}', "3"),
+    ('\u{1BB}', "2\u{335}"),
+    ('\u{1BC}', "5"),
+    ('\u{1BD}', "s"),
+    ('\u{1BF}', "\u{FE}"),
+    ('\u{1C0}', "l"),
+    ('\u{1C1}', "ll"),
+    ('\u{1C3}', "!"),
+    ('\u{1C4}', "DZ\u{306}"),
+    ('\u{1C5}', "Dz\u{306}"),
+    ('\u{1C6}', "dz\u{306}"),
+    ('\u{1C7}', "LJ"),
+    ('\u{1C8}', "Lj"),
+    ('\u{1C9}', "lj"),
+    ('\u{1CA}', "NJ"),
+    ('\u{1CB}', "Nj"),
+    ('\u{1CC}', "nj"),
+    ('\u{1E4}', "G\u{335}"),
+    ('\u{1E5}', "g\u{335}"),
+    ('\u{1F1}', "DZ"),
+    ('\u{1F2}', "Dz"),
+    ('\u{1F3}', "dz"),
+    ('\u{21C}', "3"),
+    ('\u{222}', "8"),
+    ('\u{223}', "8"),
+    ('\u{224}', "Z\u{326}"),
+    ('\u{225}', "z\u{326}"),
+    ('\u{23C}', "c\u{338}"),
+    ('\u{23E}', "T\u{338}"),
+    ('\u{241}', "?"),
+    ('\u{244}', "U\u{335}"),
+    ('\u{246}', "E\u{338}"),
+    ('\u{247}', "e\u{338}"),
+    ('\u{248}', "J\u{335}"),
+    ('\u{249}', "j\u{335}"),
+    ('\u{24D}', "r\u{335}"),
+    ('\u{24E}', "Y\u{335}"),
+    ('\u{24F}', "y\u{335}"),
+    ('\u{251}', "a"),
+    ('\u{253}', "b\u{314}"),
+    ('\u{256}', "d\u{328}"),
+    ('\u{257}', "d\u{314}"),
+    ('\u{259}', "\u{1DD}"),
+    ('\u{25A}', "\u{1DD}\u{2DE}"),
+    ('\u{25B}', "\u{A793}"),
+    ('\u{260}', "g\u{314}"),
+    ('\u{261}', "g"),
+    ('\u{263}', "y"),
+    ('\u{266}', "h\u{314}"),
+    ('\u{268}', "i\u{335}"),
+    ('\u{269}', "i"),
+    ('\u{26A}', "i"),
+    ('\u{26B}', "l\u{334}"),
+    ('\u{26D}', "l\u{328}"),
+    ('\u{26E}', "l\u{21D}"),
+    ('\u{26F}', "w"),
+    ('\u{271}', "rn\u{326}"),
+    ('\u{273}', "n\u{328}"),
+    ('\u{275}', "o\u{335}"),
+    ('\u{276}', "o\u{1D07}"),
+    ('\u{27C}', "r\u{329}"),
+    ('\u{27D}', "r\u{328}"),
+    ('\u{282}', "s\u{328}"),
+    ('\u{28B}', "u"),
+    ('\u{28F}', "y"),
+    ('\u{290}', "z\u{328}"),
+    ('\u{292}', "\u{21D}"),
+    ('\u{294}', "?"),
+    ('\u{2A0}', "q\u{314}"),
+    ('\u{2A3}', "dz"),
+    ('\u{2A4}', "d\u{21D}"),
+    ('\u{2A5}', "d\u{291}"),
+    ('\u{2A6}', "ts"),
+    ('\u{2A7}', "t\u{283}"),
+    ('\u{2A8}', "t\u{255}"),
+    ('\u{2A9}', "f\u{14B}"),
+    ('\u{2AA}', "ls"),
+    ('\u{2AB}', "lz"),
+    ('\u{2B3}', "\u{18F4}"),
+    ('\u{2B9}', "'"),
+    ('\u{2BA}', "''"),
+    ('\u{2BB}', "'"),
+    ('\u{2BC}', "'"),
+    ('\u{2BD}', "'"),
+    ('\u{2BE}', "'"),
+    ('\u{2BF}', "\u{559}"),
+    ('\u{2C2}', "<"),
+    ('\u{2C3}', ">"),
+    ('\u{2C4}', "^"),
+    ('\u{2C6}', "^"),
+    ('\u{2C8}', "'"),
+    ('\u{2CA}', "'"),
+    ('\u{2CB}', "'"),
+    ('\u{2D0}', ":"),
+    ('\u{2D3}', "\u{559}"),
+    ('\u{2D7}', "-"),
+    ('\u{2D8}', "\u{2C7}"),
+    ('\u{2D9}', "\u{971}"),
+    ('\u{2DA}', "\u{B0}"),
+    ('\u{2DB}', "i"),
+    ('\u{2DC}', "~"),
+    ('\u{2DD}', "''"),
+    ('\u{2E1}', "\u{18F3}"),
+    ('\u{2E2}', "\u{18F5}"),
+    ('\u{2E4}', "\u{2C1}"),
+    ('\u{2EE}', "''"),
+    ('\u{2F4}', "'"),
+    ('\u{2F6}', "''"),
+    ('\u{2F8}', ":"),
+    ('\u{2FB}', "\u{2EA}"),
+    ('\u{305}', "\u{304}"),
+    ('\u{30C}', "\u{306}"),
+    ('\u{30D}', "\u{670}"),
+    ('\u{310}', "\u{306}\u{307}"),
+    ('\u{311}', "\u{302}"),
+    ('\u{315}', "\u{313}"),
+    ('\u{317}', "\u{650}"),
+    ('\u{320}', "\u{331}"),
+    ('\u{321}', "\u{326}"),
+    ('\u{322}', "\u{328}"),
+    ('\u{327}', "\u{326}"),
+    ('\u{336}', "\u{335}"),
+    ('\u{337}', "\u{338}"),
+    ('\u{339}', "\u{326}"),
+    ('\u{342}', "\u{303}"),
+    ('\u{345}', "\u{328}"),
+    ('\u{347}', "\u{333}"),
+    ('\u{357}', "\u{350}"),
+    ('\u{358}', "\u{307}"),
+    ('\u{366}', "\u{30A}"),
+    ('\u{36E}', "\u{306}"),
+    ('\u{370}', "\u{2C75}"),
+    ('\u{375}', "\u{2CF}"),
+    ('\u{376}', "\u{418}"),
+    ('\u{377}', "\u{1D0E}"),
+    ('\u{37A}', "i"),
+    ('\u{37B}', "\u{254}"),
+    ('\u{37D}', "\u{A73F}"),
+    ('\u{37F}', "J"),
+    ('\u{384}', "'"),
+    ('\u{391}', "A"),
+    ('\u{392}', "B"),
+    ('\u{395}', "E"),
+    ('\u{396}', "Z"),
+    ('\u{397}', "H"),
+    ('\u{398}', "O\u{335}"),
+    ('\u{399}', "l"),
+    ('\u{39A}', "K"),
+    ('\u{39B}', "\u{245}"),
+    ('\u{39C}', "M"),
+    ('\u{39D}', "N"),
+    ('\u{39F}', "O"),
+    ('\u{3A1}', "P"),
+    ('\u{3A3}', "\u{1A9}"),
+    ('\u{3A4}', "T"),
+    ('\u{3A5}', "Y"),
+    ('\u{3A7}', "X"),
+    ('\u{3B1}', "a"),
+    ('\u{3B2}', "\u{DF}"),
+    ('\u{3B3}', "y"),
+    ('\u{3B4}', "\u{1E9F}"),
+    ('\u{3B5}', "\u{A793}"),
+    ('\u{3B7}', "n\u{329}"),
+    ('\u{3B8}', "O\u{335}"),
+    ('\u{3B9}', "i"),
+    ('\u{3BA}', "\u{138}"),
+    ('\u{3BD}', "v"),
+    ('\u{3BF}', "o"),
+    ('\u{3C1}', "p"),
+    ('\u{3C3}', "o"),
+    ('\u{3C4}', "\u{1D1B}"),
+    ('\u{3C5}', "u"),
+    ('\u{3C6}', "\u{278}"),
+    ('\u{3D0}', "\u{DF}"),
+    ('\u{3D1}', "O\u{335}"),
+    ('\u{3D2}', "Y"),
+    ('\u{3D5}', "\u{278}"),
+    ('\u{3D6}', "\u{3C0}"),
+    ('\u{3DB}', "\u{3C2}"),
+    ('\u{3DC}', "F"),
+    ('\u{3E8}', "2"),
+    ('\u{3E9}', "\u{1A8}"),
+    ('\u{3F0}', "\u{138}"),
+    ('\u{3F1}', "p"),
+    ('\u{3F2}', "c"),
+    ('\u{3F3}', "j"),
+    ('\u{3F4}', "O\u{335}"),
+    ('\u{3F5}', "\u{A793}"),
+    ('\u{3F7}', "\u{DE}"),
+    ('\u{3F8}', "\u{FE}"),
+    ('\u{3F9}', "C"),
+    ('\u{3FA}', "M"),
+    ('\u{3FD}', "\u{186}"),
+    ('\u{3FF}', "\u{A73E}"),
+    ('\u{404}', "\u{A792}"),
+    ('\u{405}', "S"),
+    ('\u{406}', "l"),
+    ('\u{408}', "J"),
+    ('\u{410}', "A"),
+    ('\u{411}', "b\u{304}"),
+    ('\u{412}', "B"),
+    ('\u{413}', "\u{393}"),
+    ('\u{415}', "E"),
+    ('\u{417}', "3"),
+    ('\u{41A}', "K"),
+    ('\u{41B}', "\u{245}"),
+    ('\u{41C}', "M"),
+    ('\u{41D}', "H"),
+    ('\u{41E}', "O"),
+    ('\u{41F}', "\u{3A0}"),
+    ('\u{420}', "P"),
+    ('\u{421}', "C"),
+    ('\u{422}', "T"),
+    ('\u{423}', "Y"),
+    ('\u{424}', "\u{3A6}"),
+    ('\u{425}', "X"),
+    ('\u{42B}', "bl"),
+    ('\u{42C}', "b"),
+    ('\u{42E}', "lO"),
+    ('\u{430}', "a"),
+    ('\u{431}', "6"),
+    ('\u{432}', "\u{299}"),
+    ('\u{433}', "r"),
+    ('\u{435}', "e"),
+    ('\u{437}', "\u{25C}"),
+    ('\u{438}', "\u{1D0E}"),
+    ('\u{43A}', "\u{138}"),
+    ('\u{43C}', "\u{28D}"),
+    ('\u{43D}', "\u{29C}"),
+    ('\u{43E}', "o"),
+    ('\u{43F}', "\u{3C0}"),
+    ('\u{440}', "p"),
+    ('\u{441}', "c"),
+    ('\u{442}', "\u{1D1B}"),
+    ('\u{443}', "y"),
+    ('\u{444}', "\u{278}"),
+    ('\u{445}', "x"),
+    ('\u{44A}', "\u{2C9}b"),
+    ('\u{44B}', "\u{185}i"),
+    ('\u{44C}', "\u{185}"),
+    ('\u{44F}', "\u{1D19}"),
+    ('\u{454}', "\u{A793}"),
+    ('\u{455}', "s"),
+    ('\u{456}', "i"),
+    ('\u{458}', "j"),
+    ('\u{45B}', "h\u{335}"),
+    ('\u{461}', "w"),
+    ('\u{462}', "b\u{335}"),
+    ('\u{463}', "b\u{335}"),
+    ('\u{470}', "\u{3A8}"),
+    ('\u{471}', "\u{3C8}"),
+    ('\u{472}', "O\u{335}"),
+    ('\u{473}', "o\u{335}"),
+    ('\u{474}', "V"),
+    ('\u{475}', "v"),
+    ('\u{47C}', "\u{460}\u{486}\u{487}"),
+    ('\u{47D}', "w\u{486}\u{487}"),
+    ('\u{48A}', "\u{418}\u{326}\u{300}"),
+    ('\u{48B}', "\u{1D0E}\u{326}\u{306}"),
+    ('\u{48C}', "b\u{335}"),
+    ('\u{48D}', "b\u{335}"),
+    ('\u{490}', "\u{393}'"),
+    ('\u{491}', "r'"),
+    ('\u{492}', "\u{393}\u{335}"),
+    ('\u{493}', "r\u{335}"),
+    ('\u{496}', "\u{416}\u{329}"),
+    ('\u{497}', "\u{436}\u{329}"),
+    ('\u{498}', "3\u{326}"),
+    ('\u{499}', "\u{25C}\u{326}"),
+    ('\u{49A}', "K\u{329}"),
+    ('\u{49B}', "\u{138}\u{329}"),
+    ('\u{49E}', "K\u{335}"),
+    ('\u{49F}', "\u{138}\u{335}"),
+    ('\u{4A2}', "H\u{329}"),
+    ('\u{4A3}', "\u{29C}\u{329}"),
+    ('\u{4AA}', "C\u{326}"),
+    ('\u{4AB}', "c\u{326}"),
+    ('\u{4AC}', "T\u{329}"),
+    ('\u{4AD}', "\u{1D1B}\u{329}"),
+    ('\u{4AE}', "Y"),
+    ('\u{4AF}', "y"),
+    ('\u{4B0}', "Y\u{335}"),
+    ('\u{4B1}', "y\u{335}"),
+    ('\u{4B2}', "X\u{329}"),
+    ('\u{4BB}', "h"),
+    ('\u{4BD}', "e"),
+    ('\u{4BE}', "\u{4BC}\u{328}"),
+    ('\u{4BF}', "e\u{328}"),
+    ('\u{4C0}', "l"),
+    ('\u{4C5}', "\u{245}\u{326}"),
+    ('\u{4C6}', "\u{43B}\u{326}"),
+    ('\u{4C7}', "H\u{326}"),
+    ('\u{4C8}', "\u{29C}\u{326}"),
+    ('\u{4C9}', "H\u{326}"),
+    ('\u{4CA}', "\u{29C}\u{326}"),
+    ('\u{4CB}', "\u{4B6}"),
+    ('\u{4CC}', "\u{4B7}"),
+    ('\u{4CD}', "M\u{326}"),
+    ('\u{4CE}', "\u{28D}\u{326}"),
+    ('\u{4CF}', "i"),
+    ('\u{4D4}', "AE"),
+    ('\u{4D5}', "ae"),
+    ('\u{4D8}', "\u{18F}"),
+    ('\u{4D9}', "\u{1DD}"),
+    ('\u{4E0}', "3"),
+    ('\u{4E1}', "\u{21D}"),
+    ('\u{4E8}', "O\u{335}"),
+    ('\u{4E9}', "o\u{335}"),
+    ('\u{501}', "d"),
+    ('\u{50A}', "\u{1F6}"),
+    ('\u{50C}', "G"),
+    ('\u{50D}', "\u{262}"),
+    ('\u{510}', "\u{190}"),
+    ('\u{511}', "\u{A793}"),
+    ('\u{51B}', "q"),
+    ('\u{51C}', "W"),
+    ('\u{51D}', "w"),
+    ('\u{53B}', "\u{12AE}"),
+    ('\u{544}', "\u{1206}"),
+    ('\u{54A}', "\u{1323}"),
+    ('\u{54C}', "\u{1261}"),
+    ('\u{54D}', "U"),
+    ('\u{54F}', "S"),
+    ('\u{553}', "\u{3A6}"),
+    ('\u{555}', "O"),
+    ('\u{55A}', "'"),
+    ('\u{55D}', "'"),
+    ('\u{561}', "w"),
+    ('\u{563}', "q"),
+    ('\u{566}', "q"),
+    ('\u{56E}', "\u{1E9F}"),
+    ('\u{570}', "h"),
+    ('\u{575}', "\u{237}"),
+    ('\u{578}', "n"),
+    ('\u{57A}', "\u{270}"),
+    ('\u{57C}', "n"),
+    ('\u{57D}', "u"),
+    ('\u{581}', "g"),
+    ('\u{584}', "f"),
+    ('\u{585}', "o"),
+    ('\u{587}', "\u{565}\u{582}"),
+    ('\u{589}', ":"),
+    ('\u{59C}', "\u{301}"),
+    ('\u{59D}', "\u{301}"),
+    ('\u{5A4}', "\u{59A}"),
+    ('\u{5A8}', "\u{599}"),
+    ('\u{5AD}', "\u{596}"),
+    ('\u{5AE}', "\u{598}"),
+    ('\u{5AF}', "\u{30A}"),
+    ('\u{5B4}', "\u{323}"),
+    ('\u{5B9}', "\u{307}"),
+    ('\u{5BA}', "\u{307}"),
+    ('\u{5C0}', "l"),
+    ('\u{5C1}', "\u{307}"),
+    ('\u{5C2}', "\u{307}"),
+    ('\u{5C3}', ":"),
+    ('\u{5C4}', "\u{307}"),
+    ('\u{5C5}', "\u{323}"),
+    ('\u{5D5}', "l"),
+    ('\u{5D8}', "v"),
+    ('\u{5D9}', "'"),
+    ('\u{5DF}', "l"),
+    ('\u{5E1}', "o"),
+    ('\u{5F0}', "ll"),
+    ('\u{5F1}', "l'"),
+    ('\u{5F2}', "''"),
+    ('\u{5F3}', "'"),
+    ('\u{5F4}', "''"),
+    ('\u{609}', "\u{BA}/\u{2080}\u{2080}"),
+    ('\u{60A}', "\u{BA}/\u{2080}\u{2080}\u{2080}"),
+    ('\u{60D}', ","),
+    ('\u{60F}', "\u{639}"),
+    ('\u{618}', "\u{301}"),
+    ('\u{619}', "\u{313}"),
+    ('\u{61A}', "\u{650}"),
+    ('\u{627}', "l"),
+    ('\u{62B}', "\u{649}\u{6DB}"),
+    ('\u{634}', "\u{633}\u{6DB}"),
+    ('\u{63D}', "\u{649}\u{302}"),
+    ('\u{63F}', "\u{649}\u{6DB}"),
+    ('\u{647}', "o"),
+    ('\u{64A}', "\u{649}"),
+    ('\u{64B}', "\u{30B}"),
+    ('\u{64E}', "\u{301}"),
+    ('\u{64F}', "\u{313}"),
+    ('\u{652}', "\u{30A}"),
+    ('\u{653}', "\u{303}"),
+    ('\u{656}', "\u{329}"),
+    ('\u{657}', "\u{312}"),
+    ('\u{658}', "\u{306}"),
+    ('\u{659}', "\u{304}"),
+    ('\u{65A}', "\u{306}"),
+    ('\u{65B}', "\u{302}"),
+    ('\u{65C}', "\u{323}"),
+    ('\u{65D}', "\u{314}"),
+    ('\u{65F}', "\u{655}"),
+    ('\u{660}', "."),
+    ('\u{661}', "l"),
+    ('\u{665}', "o"),
+    ('\u{667}', "V"),
+    ('\u{668}', "\u{245}"),
+    ('\u{66A}', "\u{BA}/\u{2080}"),
+    ('\u{66B}', ","),
+    ('\u{66C}', "\u{60C}"),
+    ('\u{66D}', "*"),
+    ('\u{66E}', "\u{649}"),
+    ('\u{66F}', "\u{6A1}"),
+    ('\u{672}', "l\u{674}"),
+    ('\u{673}', "l\u{655}"),
+    ('\u{675}', "l\u{674}"),
+    ('\u{676}', "\u{648}\u{674}"),
+    ('\u{677}', "\u{648}\u{313}\u{674}"),
+    ('\u{678}', "\u{649}\u{674}"),
+    ('\u{679}', "\u{649}\u{615}"),
+    ('\u{67E}', "\u{649}\u{6DB}"),
+    ('\u{681}', "\u{62D}\u{654}"),
+    ('\u{685}', "\u{62D}\u{6DB}"),
+    ('\u{688}', "\u{62F}\u{615}"),
+    ('\u{68B}', "\u{68A}\u{615}"),
+    ('\u{68E}', "\u{62F}\u{6DB}"),
+    ('\u{691}', "\u{631}\u{615}"),
+    ('\u{692}', "\u{631}\u{306}"),
+    ('\u{698}', "\u{631}\u{6DB}"),
+    ('\u{69E}', "\u{635}\u{6DB}"),
+    ('\u{69F}', "\u{637}\u{6DB}"),
+    ('\u{6A4}', "\u{6A1}\u{6DB}"),
+    ('\u{6A7}', "\u{641}"),
+    ('\u{6A8}', "\u{6A1}\u{6DB}"),
+    ('\u{6A9}', "\u{643}"),
+    ('\u{6AA}', "\u{643}"),
+    ('\u{6AD}', "\u{643}\u{6DB}"),
+    ('\u{6B4}', "\u{6AF}\u{6DB}"),
+    ('\u{6B5}', "\u{644}\u{306}"),
+    ('\u{6B7}', "\u{644}\u{6DB}"),
+    ('\u{6BA}', "\u{649}"),
+    ('\u{6BB}', "\u{649}\u{615}"),
+    ('\u{6BD}', "\u{649}\u{6DB}"),
+    ('\u{6BE}', "o"),
+    ('\u{6C1}', "o"),
+    ('\u{6C3}', "\u{629}"),
+    ('\u{6C6}', "\u{648}\u{306}"),
+    ('\u{6C7}', "\u{648}\u{313}"),
+    ('\u{6C8}', "\u{648}\u{670}"),
+    ('\u{6C9}', "\u{648}\u{302}"),
+    ('\u{6CB}', "\u{648}\u{6DB}"),
+    ('\u{6CC}', "\u{649}"),
+    ('\u{6CE}', "\u{649}\u{306}"),
+    ('\u{6D0}', "\u{67B}"),
+    ('\u{6D1}', "\u{649}\u{6DB}"),
+    ('\u{6D2}', "\u{649}"),
+    ('\u{6D4}', "-"),
+    ('\u{6D5}', "o"),
+    ('\u{6DF}', "\u{30A}"),
+    ('\u{6E8}', "\u{306}\u{307}"),
+    ('\u{6EC}', "\u{307}"),
+    ('\u{6EE}', "\u{62F}\u{302}"),
+    ('\u{6EF}', "\u{631}\u{302}"),
+    ('\u{6F0}', "."),
+    ('\u{6F1}', "l"),
+    ('\u{6F2}', "\u{662}"),
+    ('\u{6F3}', "\u{663}"),
+    ('\u{6F4}', "\u{664}"),
+    ('\u{6F5}', "o"),
+    ('\u{6F6}', "\u{666}"),
+    ('\u{6F7}', "V"),
+    ('\u{6F8}', "\u{245}"),
+    ('\u{6F9}', "\u{669}"),
+    ('\u{6FD}', "\u{621}\u{348}"),
+    ('\u{6FE}', "\u{645}\u{348}"),
+    ('\u{6FF}', "o\u{302}"),
+    ('\u{701}', "."),
+    ('\u{702}', "."),
+    ('\u{703}', ":"),
+    ('\u{704}', ":"),
+    ('\u{740}', "\u{307}"),
+    ('\u{741}', "\u{307}"),
+    ('\u{742}', "\u{73C}"),
+    ('\u{747}', "\u{301}"),
+    ('\u{751}', "\u{628}\u{6DB}"),
+    ('\u{756}', "\u{649}\u{306}"),
+    ('\u{762}', "\u{6AC}"),
+    ('\u{763}', "\u{643}\u{6DB}"),
+    ('\u{767}', "\u{754}"),
+    ('\u{768}', "\u{646}\u{615}"),
+    ('\u{769}', "\u{646}\u{306}"),
+    ('\u{76C}', "\u{631}\u{654}"),
+    ('\u{771}', "\u{697}\u{615}"),
+    ('\u{772}', "\u{62D}\u{654}"),
+    ('\u{77E}', "\u{633}\u{302}"),
+    ('\u{7C0}', "O"),
+    ('\u{7CA}', "l"),
+    ('\u{7EB}', "\u{304}"),
+    ('\u{7ED}', "\u{307}"),
+    ('\u{7EE}', "\u{302}"),
+    ('\u{7F3}', "\u{308}"),
+    ('\u{7F4}', "'"),
+    ('\u{7F5}', "'"),
+    ('\u{7FA}', "_"),
+    ('\u{8A1}', "\u{628}\u{654}"),
+    ('\u{8A4}', "\u{6A2}\u{6DB}"),
+    ('\u{8A7}', "\u{645}\u{6DB}"),
+    ('\u{8A8}', "\u{649}\u{654}"),
+    ('\u{8A9}', "\u{754}"),
+    ('\u{8AE}', "\u{62F}\u{324}\u{323}"),
+    ('\u{8AF}', "\u{635}\u{324}\u{323}"),
+    ('\u{8B0}', "\u{6AF}"),
+    ('\u{8B1}', "\u{648}"),
+    ('\u{8B2}', "\u{632}\u{302}"),
+    ('\u{8B6}', "\u{628}\u{6E2}"),
+    ('\u{8B7}', "\u{649}\u{6DB}\u{6E2}"),
+    ('\u{8B9}', "\u{631}\u{306}\u{307}"),
+    ('\u{8BA}', "\u{649}\u{306}\u{307}"),
+    ('\u{8BB}', "\u{6A1}"),
+    ('\u{8BC}', "\u{6A1}"),
+    ('\u{8BD}', "\u{649}"),
+    ('\u{8E5}', "\u{64C}"),
+    ('\u{8E8}', "\u{64C}"),
+    ('\u{8EA}', "\u{307}"),
+    ('\u{8EB}', "\u{308}"),
+    ('\u{8ED}', "\u{323}"),
+    ('\u{8EE}', "\u{324}"),
+    ('\u{8F0}', "\u{30B}"),
+    ('\u{8F1}', "\u{64C}"),
+    ('\u{8F2}', "\u{64D}"),
+    ('\u{8F3}', "\u{313}"),
+    ('\u{8F8}', "\u{350}"),
+    ('\u{8F9}', "\u{354}"),
+    ('\u{8FA}', "\u{355}"),
+    ('\u{8FF}', "\u{350}"),
+    ('\u{900}', "\u{352}"),
+    ('\u{901}', "\u{306}\u{307}"),
+    ('\u{902}', "\u{307}"),
+    ('\u{903}', ":"),
+    ('\u{904}', "\u{905}\u{946}"),
+    ('\u{906}', "\u{905}\u{93E}"),
+    ('\u{908}', "\u{930}\u{94D}\u{907}"),
+    ('\u{90D}', "\u{90F}\u{945}"),
+    ('\u{90E}', "\u{90F}\u{946}"),
+    ('\u{910}', "\u{90F}\u{947}"),
+    ('\u{911}', "\u{905}\u{949}"),
+    ('\u{912}', "\u{905}\u{93E}\u{946}"),
+    ('\u{913}', "\u{905}\u{93E}\u{947}"),
+    ('\u{914}', "\u{905}\u{93E}\u{948}"),
+    ('\u{93C}', "\u{323}"),
+    ('\u{952}', "\u{331}"),
+    ('\u{953}', "\u{300}"),
+    ('\u{954}', "\u{301}"),
+    ('\u{965}', "\u{964}\u{964}"),
+    ('\u{966}', "o"),
+    ('\u{967}', "\u{669}"),
+    ('\u{97D}', "?"),
+    ('\u{981}', "\u{306}\u{307}"),
+    ('\u{986}', "\u{985}\u{9BE}"),
+    ('\u{9BC}', "\u{323}"),
+    ('\u{9E0}', "\u{98B}\u{9C3}"),
+    ('\u{9E1}', "\u{98B}\u{9C3}"),
+    ('\u{9E6}', "O"),
+    ('\u{9EA}', "8"),
+    ('\u{9ED}', "9"),
+    ('\u{A02}', "\u{307}"),
+    ('\u{A03}', "\u{983}"),
+    ('\u{A06}', "\u{A05}\u{A3E}"),
+    ('\u{A07}', "\u{A72}\u{A3F}"),
+    ('\u{A08}', "\u{A72}\u{A40}"),
+    ('\u{A09}', "\u{A73}\u{A41}"),
+    ('\u{A0A}', "\u{A73}\u{A42}"),
+    ('\u{A0F}', "\u{A72}\u{A47}"),
+    ('\u{A10}', "\u{A05}\u{A48}"),
+    ('\u{A14}', "\u{A05}\u{A4C}"),
+    ('\u{A3C}', "\u{323}"),
+    ('\u{A4B}', "\u{946}"),
+    ('\u{A4D}', "\u{94D}"),
+    ('\u{A66}', "o"),
+    ('\u{A67}', "9"),
+    ('\u{A6A}', "8"),
+    ('\u{A81}', "\u{306}\u{307}"),
+    ('\u{A82}', "\u{307}"),
+    ('\u{A83}', ":"),
+    ('\u{A86}', "\u{A85}\u{ABE}"),
+    ('\u{A8D}', "\u{A85}\u{AC5}"),
+    ('\u{A8F}', "\u{A85}\u{AC7}"),
+    ('\u{A90}', "\u{A85}\u{AC8}"),
+    ('\u{A91}', "\u{A85}\u{ABE}\u{AC5}"),
+    ('\u{A93}', "\u{A85}\u{ABE}\u{AC7}"),
+    ('\u{A94}', "\u{A85}\u{ABE}\u{AC8}"),
+    ('\u{ABC}', "\u{323}"),
+    ('\u{ABD}', "\u{93D}"),
+    ('\u{AC1}', "\u{941}"),
+    ('\u{AC2}', "\u{942}"),
+    ('\u{ACD}', "\u{94D}"),
+    ('\u{AE6}', "o"),
+    ('\u{AE8}', "\u{968}"),
+    ('\u{AE9}', "\u{969}"),
+    ('\u{AEA}', "\u{96A}"),
+    ('\u{AEE}', "\u{96E}"),
+    ('\u{AF0}', "\u{970}"),
+    ('\u{B01}', "\u{306}\u{307}"),
+    ('\u{B03}', "8"),
+    ('\u{B06}', "\u{B05}\u{B3E}"),
+    ('\u{B20}', "O"),
+    ('\u{B3C}', "\u{323}"),
+    ('\u{B66}', "O"),
+    ('\u{B68}', "9"),
+    ('\u{B82}', "\u{30A}"),
+    ('\u{B8A}', "\u{B89}\u{BB3}"),
+    ('\u{B9C}', "\u{B90}"),
+    ('\u{BB0}', "\u{B88}"),
+    ('\u{BBE}', "\u{B88}"),
+    ('\u{BC8}', "\u{BA9}"),
+    ('\u{BCD}', "\u{307}"),
+    ('\u{BD7}', "\u{BB3}"),
+    ('\u{BE6}', "o"),
+    ('\u{BE7}', "\u{B95}"),
+    ('\u{BE8}', "\u{B89}"),
+    ('\u{BEA}', "\u{B9A}"),
+    ('\u{BEB}', "\u{B88}\u{BC1}"),
+    ('\u{BEC}', "\u{B9A}\u{BC1}"),
+    ('\u{BED}', "\u{B8E}"),
+    ('\u{BEE}', "\u{B85}"),
+    ('\u{BF0}', "\u{BAF}"),
+    ('\u{BF2}', "\u{B9A}\u{BC2}"),
+    ('\u{BF4}', "\u{BAE}\u{BC0}"),
+    ('\u{BF5}', "\u{BF3}"),
+    ('\u{BF7}', "\u{B8E}\u{BB5}"),
+    ('\u{BF8}', "\u{BB7}"),
+    ('\u{BFA}', "\u{BA8}\u{BC0}"),
+    ('\u{C00}', "\u{306}\u{307}"),
+    ('\u{C02}', "o"),
+    ('\u{C03}', "\u{983}"),
+    ('\u{C13}', "\u{C12}\u{C55}"),
+    ('\u{C14}', "\u{C12}\u{C4C}"),
+    ('\u{C20}', "\u{C30}\u{5BC}"),
+    ('\u{C22}', "\u{C21}\u{323}"),
+    ('\u{C25}', "\u{C27}\u{5BC}"),
+    ('\u{C2D}', "\u{C2C}\u{323}"),
+    ('\u{C2E}', "\u{C35}\u{C41}"),
+    ('\u{C37}', "\u{C35}\u{323}"),
+    ('\u{C39}', "\u{C35}\u{C3E}"),
+    ('\u{C42}', "\u{C41}\u{C3E}"),
+    ('\u{C44}', "\u{C43}\u{C3E}"),
+    ('\u{C60}', "\u{C0B}\u{C3E}"),
+    ('\u{C61}', "\u{C0C}\u{C3E}"),
+    ('\u{C66}', "o"),
+    ('\u{C81}', "\u{306}\u{307}"),
+    ('\u{C82}', "o"),
+    ('\u{C83}', "\u{983}"),
+    ('\u{C85}', "\u{C05}"),
+    ('\u{C86}', "\u{C06}"),
+    ('\u{C87}', "\u{C07}"),
+    ('\u{C92}', "\u{C12}"),
+    ('\u{C93}', "\u{C12}\u{C55}"),
+    ('\u{C94}', "\u{C12}\u{C4C}"),
+    ('\u{C9C}', "\u{C1C}"),
+    ('\u{C9E}', "\u{C1E}"),
+    ('\u{CA3}', "\u{C23}"),
+    ('\u{CAF}', "\u{C2F}"),
+    ('\u{CB1}', "\u{C31}"),
+    ('\u{CB2}', "\u{C32}"),
+    ('\u{CE1}', "\u{C8C}\u{CBE}"),
+    ('\u{CE6}', "o"),
+    ('\u{CE7}', "\u{C67}"),
+    ('\u{CE8}', "\u{C68}"),
+    ('\u{CEF}', "\u{C6F}"),
+    ('\u{D01}', "\u{306}\u{307}"),
+    ('\u{D02}', "o"),
+    ('\u{D03}', "\u{983}"),
+    ('\u{D08}', "\u{D07}\u{D57}"),
+    ('\u{D09}', "\u{B89}"),
+    ('\u{D0A}', "\u{B89}\u{D57}"),
+    ('\u{D0C}', "\u{D28}\u{D41}"),
+    ('\u{D10}', "\u{D0E}\u{D46}"),
+    ('\u{D13}', "\u{D12}\u{D3E}"),
+    ('\u{D14}', "\u{D12}\u{D57}"),
+    ('\u{D19}', "\u{D28}\u{D41}"),
+    ('\u{D1C}', "\u{B90}"),
+    ('\u{D20}', "o"),
+    ('\u{D23}', "\u{BA3}"),
+    ('\u{D31}', "\u{D30}"),
+    ('\u{D34}', "\u{BB4}"),
+    ('\u{D36}', "\u{BB6}"),
+    ('\u{D3A}', "\u{B9F}\u{BBF}"),
+    ('\u{D3F}', "\u{BBF}"),
+    ('\u{D40}', "\u{BBF}"),
+    ('\u{D42}', "\u{D41}"),
+    ('\u{D43}', "\u{D41}"),
+    ('\u{D48}', "\u{D46}\u{D46}"),
+    ('\u{D4E}', "\u{971}"),
+    ('\u{D5A}', "\u{D28}\u{D4D}\u{D2E}"),
+    ('\u{D5F}', "o\u{D30}o"),
+    ('\u{D61}', "\u{D1E}"),
+    ('\u{D66}', "o"),
+    ('\u{D6A}', "\u{D30}\u{D4D}"),
+    ('\u{D6B}', "\u{D26}\u{D4D}\u{D30}"),
+    ('\u{D6C}', "\u{D28}\u{D4D}\u{D28}"),
+    ('\u{D6D}', "9"),
+    ('\u{D6E}', "\u{D35}\u{D4D}\u{D30}"),
+    ('\u{D6F}', "\u{D28}\u{D4D}"),
+    ('\u{D76}', "\u{D39}\u{D4D}\u{D2E}"),
+    ('\u{D79}', "\u{D28}\u{D41}"),
+    ('\u{D7B}', "\u{D28}\u{D4D}"),
+    ('\u{D7C}', "\u{D30}\u{D4D}"),
+    ('\u{D82}', "o"),
+    ('\u{D83}', "\u{983}"),
+    ('\u{DE9}', "\u{DE8}\u{DCF}"),
+    ('\u{DEA}', "\u{DA2}"),
+    ('\u{DEB}', "\u{DAF}"),
+    ('\u{DEF}', "\u{DE8}\u{DD3}"),
+    ('\u{E03}', "\u{E02}"),
+    ('\u{E0B}', "\u{E0A}"),
+    ('\u{E0F}', "\u{E0E}"),
+    ('\u{E14}', "\u{E04}"),
+    ('\u{E15}', "\u{E04}"),
+    ('\u{E17}', "\u{E11}"),
+    ('\u{E21}', "\u{E06}"),
+    ('\u{E26}', "\u{E20}"),
+    ('\u{E33}', "\u{30A}\u{E32}"),
+    ('\u{E41}', "\u{E40}\u{E40}"),
+    ('\u{E45}', "\u{E32}"),
+    ('\u{E4D}', "\u{30A}"),
+    ('\u{E50}', "o"),
+    ('\u{E88}', "\u{E08}"),
+    ('\u{E8D}', "\u{E22}"),
+    ('\u{E9A}', "\u{E1A}"),
+    ('\u{E9B}', "\u{E1B}"),
+    ('\u{E9D}', "\u{E1D}"),
+    ('\u{E9E}', "\u{E1E}"),
+    ('\u{E9F}', "\u{E1F}"),
+    ('\u{EB3}', "\u{30A}\u{EB2}"),
+    ('\u{EB8}', "\u{E38}"),
+    ('\u{EB9}', "\u{E39}"),
+    ('\u{EC8}', "\u{E48}"),
+    ('\u{EC9}', "\u{E49}"),
+    ('\u{ECA}', "\u{E4A}"),
+    ('\u{ECB}', "\u{E4B}"),
+    ('\u{ECD}', "\u{30A}"),
+    ('\u{ED0}', "o"),
+    ('\u{EDC}', "\u{EAB}\u{E99}"),
+    ('\u{EDD}', "\u{EAB}\u{EA1}"),
+    ('\u{F00}', "\u{F68}\u{F7C}\u{F7E}"),
+    ('\u{F02}', "\u{F60}\u{F74}\u{F82}\u{F7F}"),
+    ('\u{F03}', "\u{F60}\u{F74}\u{F82}\u{F14}"),
+    ('\u{F0C}', "\u{F0B}"),
+    ('\u{F0E}', "\u{F0D}\u{F0D}"),
+    ('\u{F1B}', "\u{F1A}\u{F1A}"),
+    ('\u{F1E}', "\u{F1D}\u{F1D}"),
+    ('\u{F1F}', "\u{F1A}\u{F1D}"),
+    ('\u{F37}', "\u{325}"),
+    ('\u{F6A}', "\u{F62}"),
+    ('\u{F77}', "\u{FB2}\u{F71}\u{F80}"),
+    ('\u{F79}', "\u{FB3}\u{F71}\u{F80}"),
+    ('\u{FCE}', "\u{F1D}\u{F1A}"),
+    ('\u{FD5}', "\u{5350}"),
+    ('\u{FD6}', "\u{534D}"),
+    ('\u{1000}', "\u{1002}\u{102C}"),
+    ('\u{1010}', "o\u{102C}"),
+    ('\u{101D}', "o"),
+    ('\u{101F}', "\u{1015}\u{102C}"),
+    ('\u{1029}', "\u{101E}\u{103C}"),
+    ('\u{102A}', "\u{101E}\u{103C}\u{1031}\u{102C}\u{103A}"),
+    ('\u{1036}', "\u{30A}"),
+    ('\u{1038}', "\u{983}"),
+    ('\u{1040}', "o"),
+    ('\u{104B}', "\u{104A}\u{104A}"),
+    ('\u{1065}', "\u{1041}"),
+    ('\u{1066}', "\u{1015}\u{103E}"),
+    ('\u{106F}', "\u{1015}\u{102C}\u{103E}"),
+    ('\u{1070}', "\u{1003}\u{103E}"),
+    ('\u{107E}', "\u{107D}\u{103E}"),
+    ('\u{1081}', "\u{1002}\u{103E}"),
+    ('\u{109E}', "\u{1083}\u{30A}"),
+    ('\u{10A0}', "\u{A786}"),
+    ('\u{10E7}', "y"),
+    ('\u{10F3}', "\u{21D}"),
+    ('\u{10FF}', "o"),
+    ('\u{1101}', "\u{1100}\u{1100}"),
+    ('\u{1104}', "\u{1103}\u{1103}"),
+    ('\u{1108}', "\u{1107}\u{1107}"),
+    ('\u{110A}', "\u{1109}\u{1109}"),
+    ('\u{110D}', "\u{110C}\u{110C}"),
+    ('\u{1113}', "\u{1102}\u{1100}"),
+    ('\u{1114}', "\u{1102}\u{1102}"),
+    ('\u{1115}', "\u{1102}\u{1103}"),
+    ('\u{1116}', "\u{1102}\u{1107}"),
+    ('\u{1117}', "\u{1103}\u{1100}"),
+    ('\u{1118}', "\u{1105}\u{1102}"),
+    ('\u{1119}', "\u{1105}\u{1105}"),
+    ('\u{111A}', "\u{1105}\u{1112}"),
+    ('\u{111B}', "\u{1105}\u{110B}"),
+    ('\u{111C}', "\u{1106}\u{1107}"),
+    ('\u{111D}', "\u{1106}\u{110B}"),
+    ('\u{111E}', "\u{1107}\u{1100}"),
+    ('\u{111F}', "\u{1107}\u{1102}"),
+    ('\u{1120}', "\u{1107}\u{1103}"),
+    ('\u{1121}', "\u{1107}\u{1109}"),
+    ('\u{1122}', "\u{1107}\u{1109}\u{1100}"),
+    ('\u{1123}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{1124}', "\u{1107}\u{1109}\u{1107}"),
+    ('\u{1125}', "\u{1107}\u{1109}\u{1109}"),
+    ('\u{1126}', "\u{1107}\u{1109}\u{110C}"),
+    ('\u{1127}', "\u{1107}\u{110C}"),
+    ('\u{1128}', "\u{1107}\u{110E}"),
+    ('\u{1129}', "\u{1107}\u{1110}"),
+    ('\u{112A}', "\u{1107}\u{1111}"),
+    ('\u{112B}', "\u{1107}\u{110B}"),
+    ('\u{112C}', "\u{1107}\u{1107}\u{110B}"),
+    ('\u{112D}', "\u{1109}\u{1100}"),
+    ('\u{112E}', "\u{1109}\u{1102}"),
+    ('\u{112F}', "\u{1109}\u{1103}"),
+    ('\u{1130}', "\u{1109}\u{1105}"),
+    ('\u{1131}', "\u{1109}\u{1106}"),
+    ('\u{1132}', "\u{1109}\u{1107}"),
+    ('\u{1133}', "\u{1109}\u{1107}\u{1100}"),
+    ('\u{1134}', "\u{1109}\u{1109}\u{1109}"),
+    ('\u{1135}', "\u{1109}\u{110B}"),
+    ('\u{1136}', "\u{1109}\u{110C}"),
+    ('\u{1137}', "\u{1109}\u{110E}"),
+    ('\u{1138}', "\u{1109}\u{110F}"),
+    ('\u{1139}', "\u{1109}\u{1110}"),
+    ('\u{113A}', "\u{1109}\u{1111}"),
+    ('\u{113B}', "\u{1105}\u{1112}"),
+    ('\u{113D}', "\u{113C}\u{113C}"),
+    ('\u{113F}', "\u{113E}\u{113E}"),
+    ('\u{1141}', "\u{110B}\u{1100}"),
+    ('\u{1142}', "\u{110B}\u{1103}"),
+    ('\u{1143}', "\u{110B}\u{1106}"),
+    ('\u{1144}', "\u{110B}\u{1107}"),
+    ('\u{1145}', "\u{110B}\u{1109}"),
+    ('\u{1146}', "\u{110B}\u{1140}"),
+    ('\u{1147}', "\u{110B}\u{110B}"),
+    ('\u{1148}', "\u{110B}\u{110C}"),
+    ('\u{1149}', "\u{110B}\u{110E}"),
+    ('\u{114A}', "\u{110B}\u{1110}"),
+    ('\u{114B}', "\u{110B}\u{1111}"),
+    ('\u{114D}', "\u{110C}\u{110B}"),
+    ('\u{114F}', "\u{114E}\u{114E}"),
+    ('\u{1151}', "\u{1150}\u{1150}"),
+    ('\u{1152}', "\u{110E}\u{110F}"),
+    ('\u{1153}', "\u{110E}\u{1112}"),
+    ('\u{1156}', "\u{1111}\u{1107}"),
+    ('\u{1157}', "\u{1111}\u{110B}"),
+    ('\u{1158}', "\u{1112}\u{1112}"),
+    ('\u{115A}', "\u{1100}\u{1103}"),
+    ('\u{115B}', "\u{1102}\u{1109}"),
+    ('\u{115C}', "\u{1102}\u{110C}"),
+    ('\u{115D}', "\u{1102}\u{1112}"),
+    ('\u{115E}', "\u{1103}\u{1105}"),
+    ('\u{1162}', "\u{1161}\u{4E28}"),
+    ('\u{1164}', "\u{1163}\u{4E28}"),
+    ('\u{1166}', "\u{1165}\u{4E28}"),
+    ('\u{1168}', "\u{1167}\u{4E28}"),
+    ('\u{116A}', "\u{1169}\u{1161}"),
+    ('\u{116B}', "\u{1169}\u{1161}\u{4E28}"),
+    ('\u{116C}', "\u{1169}\u{4E28}"),
+    ('\u{116F}', "\u{116E}\u{1165}"),
+    ('\u{1170}', "\u{116E}\u{1165}\u{4E28}"),
+    ('\u{1171}', "\u{116E}\u{4E28}"),
+    ('\u{1173}', "\u{30FC}"),
+    ('\u{1174}', "\u{30FC}\u{4E28}"),
+    ('\u{1175}', "\u{4E28}"),
+    ('\u{1176}', "\u{1161}\u{1169}"),
+    ('\u{1177}', "\u{1161}\u{116E}"),
+    ('\u{1178}', "\u{1163}\u{1169}"),
+    ('\u{1179}', "\u{1163}\u{116D}"),
+    ('\u{117A}', "\u{1165}\u{1169}"),
+    ('\u{117B}', "\u{1165}\u{116E}"),
+    ('\u{117C}', "\u{1165}\u{30FC}"),
+    ('\u{117D}', "\u{1167}\u{1169}"),
+    ('\u{117E}', "\u{1167}\u{116E}"),
+    ('\u{117F}', "\u{1169}\u{1165}"),
+    ('\u{1180}', "\u{1169}\u{1165}\u{4E28}"),
+    ('\u{1181}', "\u{1169}\u{1167}\u{4E28}"),
+    ('\u{1182}', "\u{1169}\u{1169}"),
+    ('\u{1183}', "\u{1169}\u{116E}"),
+    ('\u{1184}', "\u{116D}\u{1163}"),
+    ('\u{1185}', "\u{116D}\u{1163}\u{4E28}"),
+    ('\u{1186}', "\u{116D}\u{1163}"),
+    ('\u{1187}', "\u{116D}\u{1169}"),
+    ('\u{1188}', "\u{116D}\u{4E28}"),
+    ('\u{1189}', "\u{116E}\u{1161}"),
+    ('\u{118A}', "\u{116E}\u{1161}\u{4E28}"),
+    ('\u{118B}', "\u{116E}\u{1165}\u{30FC}"),
+    ('\u{118C}', "\u{116E}\u{1167}\u{4E28}"),
+    ('\u{118D}', "\u{116E}\u{116E}"),
+    ('\u{118E}', "\u{1172}\u{1161}"),
+    ('\u{118F}', "\u{1172}\u{1165}"),
+    ('\u{1190}', "\u{1172}\u{1165}\u{4E28}"),
+    ('\u{1191}', "\u{1172}\u{1167}"),
+    ('\u{1192}', "\u{1172}\u{1167}\u{4E28}"),
+    ('\u{1193}', "\u{1172}\u{116E}"),
+    ('\u{1194}', "\u{1172}\u{4E28}"),
+    ('\u{1195}', "\u{30FC}\u{116E}"),
+    ('\u{1196}', "\u{30FC}\u{30FC}"),
+    ('\u{1197}', "\u{30FC}\u{4E28}\u{116E}"),
+    ('\u{1198}', "\u{4E28}\u{1161}"),
+    ('\u{1199}', "\u{4E28}\u{1163}"),
+    ('\u{119A}', "\u{4E28}\u{1169}"),
+    ('\u{119B}', "\u{4E28}\u{116E}"),
+    ('\u{119C}', "\u{4E28}\u{30FC}"),
+    ('\u{119D}', "\u{4E28}\u{119E}"),
+    ('\u{119F}', "\u{119E}\u{1165}"),
+    ('\u{11A0}', "\u{119E}\u{116E}"),
+    ('\u{11A1}', "\u{119E}\u{4E28}"),
+    ('\u{11A2}', "\u{119E}\u{119E}"),
+    ('\u{11A3}', "\u{1161}\u{30FC}"),
+    ('\u{11A4}', "\u{1163}\u{116E}"),
+    ('\u{11A5}', "\u{1167}\u{1163}"),
+    ('\u{11A6}', "\u{1169}\u{1163}"),
+    ('\u{11A7}', "\u{1169}\u{1163}\u{4E28}"),
+    ('\u{11A8}', "\u{1100}"),
+    ('\u{11A9}', "\u{1100}\u{1100}"),
+    ('\u{11AA}', "\u{1100}\u{1109}"),
+    ('\u{11AB}', "\u{1102}"),
+    ('\u{11AC}', "\u{1102}\u{110C}"),
+    ('\u{11AD}', "\u{1102}\u{1112}"),
+    ('\u{11AE}', "\u{1103}"),
+    ('\u{11AF}', "\u{1105}"),
+    ('\u{11B0}', "\u{1105}\u{1100}"),
+    ('\u{11B1}', "\u{1105}\u{1106}"),
+    ('\u{11B2}', "\u{1105}\u{1107}"),
+    ('\u{11B3}', "\u{1105}\u{1109}"),
+    ('\u{11B4}', "\u{1105}\u{1110}"),
+    ('\u{11B5}', "\u{1105}\u{1111}"),
+    ('\u{11B6}', "\u{1105}\u{1112}"),
+    ('\u{11B7}', "\u{1106}"),
+    ('\u{11B8}', "\u{1107}"),
+    ('\u{11B9}', "\u{1107}\u{1109}"),
+    ('\u{11BA}', "\u{1109}"),
+    ('\u{11BB}', "\u{1109}\u{1109}"),
+    ('\u{11BC}', "\u{110B}"),
+    ('\u{11BD}', "\u{110C}"),
+    ('\u{11BE}', "\u{110E}"),
+    ('\u{11BF}', "\u{110F}"),
+    ('\u{11C0}', "\u{1110}"),
+    ('\u{11C1}', "\u{1111}"),
+    ('\u{11C2}', "\u{1112}"),
+    ('\u{11C3}', "\u{1100}\u{1105}"),
+    ('\u{11C4}', "\u{1100}\u{1109}\u{1100}"),
+    ('\u{11C5}', "\u{1102}\u{1100}"),
+    ('\u{11C6}', "\u{1102}\u{1103}"),
+    ('\u{11C7}', "\u{1102}\u{1109}"),
+    ('\u{11C8}', "\u{1102}\u{1140}"),
+    ('\u{11C9}', "\u{1102}\u{1110}"),
+    ('\u{11CA}', "\u{1103}\u{1100}"),
+    ('\u{11CB}', "\u{1103}\u{1105}"),
+    ('\u{11CC}', "\u{1105}\u{1100}\u{1109}"),
+    ('\u{11CD}', "\u{1105}\u{1102}"),
+    ('\u{11CE}', "\u{1105}\u{1103}"),
+    ('\u{11CF}', "\u{1105}\u{1103}\u{1112}"),
+    ('\u{11D0}', "\u{1105}\u{1105}"),
+    ('\u{11D1}', "\u{1105}\u{1106}\u{1100}"),
+    ('\u{11D2}', "\u{1105}\u{1106}\u{1109}"),
+    ('\u{11D3}', "\u{1105}\u{1107}\u{1109}"),
+    ('\u{11D4}', "\u{1105}\u{1107}\u{1112}"),
+    ('\u{11D5}', "\u{1105}\u{1107}\u{110B}"),
+    ('\u{11D6}', "\u{1105}\u{1109}\u{1109}"),
+    ('\u{11D7}', "\u{1105}\u{1140}"),
+    ('\u{11D8}', "\u{1105}\u{110F}"),
+    ('\u{11D9}', "\u{1105}\u{1159}"),
+    ('\u{11DA}', "\u{1106}\u{1100}"),
+    ('\u{11DB}', "\u{1106}\u{1105}"),
+    ('\u{11DC}', "\u{1106}\u{1107}"),
+    ('\u{11DD}', "\u{1106}\u{1109}"),
+    ('\u{11DE}', "\u{1106}\u{1109}\u{1109}"),
+    ('\u{11DF}', "\u{1106}\u{1140}"),
+    ('\u{11E0}', "\u{1106}\u{110E}"),
+    ('\u{11E1}', "\u{1106}\u{1112}"),
+    ('\u{11E2}', "\u{1106}\u{110B}"),
+    ('\u{11E3}', "\u{1107}\u{1105}"),
+    ('\u{11E4}', "\u{1107}\u{1111}"),
+    ('\u{11E5}', "\u{1107}\u{1112}"),
+    ('\u{11E6}', "\u{1107}\u{110B}"),
+    ('\u{11E7}', "\u{1109}\u{1100}"),
+    ('\u{11E8}', "\u{1109}\u{1103}"),
+    ('\u{11E9}', "\u{1109}\u{1105}"),
+    ('\u{11EA}', "\u{1109}\u{1107}"),
+    ('\u{11EB}', "\u{1140}"),
+    ('\u{11EC}', "\u{110B}\u{1100}"),
+    ('\u{11ED}', "\u{110B}\u{1100}\u{1100}"),
+    ('\u{11EE}', "\u{110B}\u{110B}"),
+    ('\u{11EF}', "\u{110B}\u{110F}"),
+    ('\u{11F0}', "\u{114C}"),
+    ('\u{11F1}', "\u{110B}\u{1109}"),
+    ('\u{11F2}', "\u{110B}\u{1140}"),
+    ('\u{11F3}', "\u{1111}\u{1107}"),
+    ('\u{11F4}', "\u{1111}\u{110B}"),
+    ('\u{11F5}', "\u{1112}\u{1102}"),
+    ('\u{11F6}', "\u{1112}\u{1105}"),
+    ('\u{11F7}', "\u{1112}\u{1106}"),
+    ('\u{11F8}', "\u{1112}\u{1107}"),
+    ('\u{11F9}', "\u{1159}"),
+    ('\u{11FA}', "\u{1100}\u{1102}"),
+    ('\u{11FB}', "\u{1100}\u{1107}"),
+    ('\u{11FC}', "\u{1100}\u{110E}"),
+    ('\u{11FD}', "\u{1100}\u{110F}"),
+    ('\u{11FE}', "\u{1100}\u{1112}"),
+    ('\u{11FF}', "\u{1102}\u{1102}"),
+    ('\u{1200}', "U"),
+    ('\u{1223}', "\u{270}"),
+    ('\u{1240}', "\u{3A6}"),
+    ('\u{1260}', "\u{548}"),
+    ('\u{1294}', "\u{571}"),
+    ('\u{12D0}', "O"),
+    ('\u{13A0}', "D"),
+    ('\u{13A1}', "R"),
+    ('\u{13A2}', "T"),
+    ('\u{13A4}', "O'"),
+    ('\u{13A5}', "i"),
+    ('\u{13A8}', "\u{2C75}"),
+    ('\u{13A9}', "Y"),
+    ('\u{13AA}', "A"),
+    ('\u{13AB}', "J"),
+    ('\u{13AC}', "E"),
+    ('\u{13AE}', "?"),
+    ('\u{13B0}', "\u{2C75}"),
+    ('\u{13B1}', "\u{393}"),
+    ('\u{13B3}', "W"),
+    ('\u{13B7}', "M"),
+    ('\u{13BB}', "H"),
+    ('\u{13BD}', "Y"),
+    ('\u{13BE}', "O\u{335}"),
+    ('\u{13BF}', "\u{1AB}"),
+    ('\u{13C0}', "G"),
+    ('\u{13C2}', "h"),
+    ('\u{13C3}', "Z"),
+    ('\u{13C7}', "\u{460}"),
+    ('\u{13CB}', "\u{190}"),
+    ('\u{13CC}', "U\u{335}"),
+    ('\u{13CE}', "4"),
+    ('\u{13CF}', "b"),
+    ('\u{13D2}', "R"),
+    ('\u{13D4}', "W"),
+    ('\u{13D5}', "S"),
+    ('\u{13D9}', "V"),
+    ('\u{13DA}', "S"),
+    ('\u{13DE}', "L"),
+    ('\u{13DF}', "C"),
+    ('\u{13E2}', "P"),
+    ('\u{13E6}', "K"),
+    ('\u{13E7}', "d"),
+    ('\u{13EB}', "O\u{335}"),
+    ('\u{13EE}', "6"),
+    ('\u{13F0}', "\u{DF}"),
+    ('\u{13F2}', "h\u{314}"),
+    ('\u{13F3}', "G"),
+    ('\u{13F4}', "B"),
+    ('\u{13FB}', "\u{262}"),
+    ('\u{13FC}', "\u{299}"),
+    ('\u{1400}', "="),
+    ('\u{1403}', "\u{394}"),
+    ('\u{140C}', "\u{B7}\u{1401}"),
+    ('\u{140D}', "\u{1401}\u{B7}"),
+    ('\u{140E}', "\u{B7}\u{394}"),
+    ('\u{140F}', "\u{394}\u{B7}"),
+    ('\u{1410}', "\u{B7}\u{1404}"),
+    ('\u{1411}', "\u{1404}\u{B7}"),
+    ('\u{1412}', "\u{B7}\u{1405}"),
+    ('\u{1413}', "\u{1405}\u{B7}"),
+    ('\u{1414}', "\u{B7}\u{1406}"),
+    ('\u{1415}', "\u{1406}\u{B7}"),
+    ('\u{1417}', "\u{B7}\u{140A}"),
+    ('\u{1418}', "\u{140A}\u{B7}"),
+    ('\u{1419}', "\u{B7}\u{140B}"),
+    ('\u{141A}', "\u{140B}\u{B7}"),
+    ('\u{1427}', "\u{B7}"),
+    ('\u{142B}', "\u{1401}\u{1420}"),
+    ('\u{142C}', "\u{394}\u{1420}"),
+    ('\u{142D}', "\u{1405}\u{1420}"),
+    ('\u{142E}', "\u{140A}\u{1420}"),
+    ('\u{142F}', "V"),
+    ('\u{1431}', "\u{245}"),
+    ('\u{1433}', ">"),
+    ('\u{1437}', "\u{B7}>"),
+    ('\u{1438}', "<"),
+    ('\u{143A}', "\u{B7}V"),
+    ('\u{143B}', "V\u{B7}"),
+    ('\u{143C}', "\u{B7}\u{245}"),
+    ('\u{143D}', "\u{245}\u{B7}"),
+    ('\u{143E}', "\u{B7}\u{1432}"),
+    ('\u{143F}', "\u{1432}\u{B7}"),
+    ('\u{1440}', "\u{B7}>"),
+    ('\u{1441}', ">\u{B7}"),
+    ('\u{1442}', "\u{B7}\u{1434}"),
+    ('\u{1443}', "\u{1434}\u{B7}"),
+    ('\u{1444}', "\u{B7}<"),
+    ('\u{1445}', "<\u{B7}"),
+    ('\u{1446}', "\u{B7}\u{1439}"),
+    ('\u{1447}', "\u{1439}\u{B7}"),
+    ('\u{144A}', "'"),
+    ('\u{144C}', "U"),
+    ('\u{144E}', "\u{548}"),
+    ('\u{1454}', "\u{B7}\u{1450}"),
+    ('\u{1457}', "\u{B7}U"),
+    ('\u{1458}', "U\u{B7}"),
+    ('\u{1459}', "\u{B7}\u{548}"),
+    ('\u{145A}', "\u{548}\u{B7}"),
+    ('\u{145B}', "\u{B7}\u{144F}"),
+    ('\u{145C}', "\u{144F}\u{B7}"),
+    ('\u{145D}', "\u{B7}\u{1450}"),
+    ('\u{145E}', "\u{1450}\u{B7}"),
+    ('\u{145F}', "\u{B7}\u{1451}"),
+    ('\u{1460}', "\u{1451}\u{B7}"),
+    ('\u{1461}', "\u{B7}\u{1455}"),
+    ('\u{1462}', "\u{1455}\u{B7}"),
+    ('\u{1463}', "\u{B7}\u{1456}"),
+    ('\u{1464}', "\u{1456}\u{B7}"),
+    ('\u{1467}', "U'"),
+    ('\u{1468}', "\u{548}'"),
+    ('\u{1469}', "\u{1450}'"),
+    ('\u{146A}', "\u{1455}'"),
+    ('\u{146D}', "P"),
+    ('\u{146F}', "d"),
+    ('\u{1472}', "b"),
+    ('\u{1473}', "b\u{307}"),
+    ('\u{1474}', "\u{B7}\u{146B}"),
+    ('\u{1475}', "\u{146B}\u{B7}"),
+    ('\u{1476}', "\u{B7}P"),
+    ('\u{1477}', "p\u{B7}"),
+    ('\u{1478}', "\u{B7}\u{146E}"),
+    ('\u{1479}', "\u{146E}\u{B7}"),
+    ('\u{147A}', "\u{B7}d"),
+    ('\u{147B}', "d\u{B7}"),
+    ('\u{147C}', "\u{B7}\u{1470}"),
+    ('\u{147D}', "\u{1470}\u{B7}"),
+    ('\u{147E}', "\u{B7}b"),
+    ('\u{147F}', "b\u{B7}"),
+    ('\u{1480}', "\u{B7}b\u{307}"),
+    ('\u{1481}', "b\u{307}\u{B7}"),
+    ('\u{1485}', "\u{146B}'"),
+    ('\u{1486}', "P'"),
+    ('\u{1487}', "d'"),
+    ('\u{1488}', "b'"),
+    ('\u{148D}', "J"),
+    ('\u{1492}', "\u{B7}\u{1489}"),
+    ('\u{1493}', "\u{1489}\u{B7}"),
+    ('\u{1494}', "\u{B7}\u{148B}"),
+    ('\u{1495}', "\u{148B}\u{B7}"),
+    ('\u{1496}', "\u{B7}\u{148C}"),
+    ('\u{1497}', "\u{148C}\u{B7}"),
+    ('\u{1498}', "\u{B7}J"),
+    ('\u{1499}', "J\u{B7}"),
+    ('\u{149A}', "\u{B7}\u{148E}"),
+    ('\u{149B}', "\u{148E}\u{B7}"),
+    ('\u{149C}', "\u{B7}\u{1490}"),
+    ('\u{149D}', "\u{1490}\u{B7}"),
+    ('\u{149E}', "\u{B7}\u{1491}"),
+    ('\u{149F}', "\u{1491}\u{B7}"),
+    ('\u{14A5}', "\u{393}"),
+    ('\u{14AA}', "L"),
+    ('\u{14AC}', "\u{B7}\u{14A3}"),
+    ('\u{14AD}', "\u{14A3}\u{B7}"),
+    ('\u{14AE}', "\u{B7}\u{393}"),
+    ('\u{14AF}', "\u{393}\u{B7}"),
+    ('\u{14B0}', "\u{B7}\u{14A6}"),
+    ('\u{14B1}', "\u{14A6}\u{B7}"),
+    ('\u{14B2}', "\u{B7}\u{14A7}"),
+    ('\u{14B3}', "\u{14A7}\u{B7}"),
+    ('\u{14B4}', "\u{B7}\u{14A8}"),
+    ('\u{14B5}', "\u{14A8}\u{B7}"),
+    ('\u{14B6}', "\u{B7}L"),
+    ('\u{14B7}', "l\u{B7}"),
+    ('\u{14B8}', "\u{B7}\u{14AB}"),
+    ('\u{14B9}', "\u{14AB}\u{B7}"),
+    ('\u{14BF}', "2"),
+    ('\u{14C9}', "\u{B7}\u{14C0}"),
+    ('\u{14CA}', "\u{14C0}\u{B7}"),
+    ('\u{14CB}', "\u{B7}\u{14C7}"),
+    ('\u{14CC}', "\u{14C7}\u{B7}"),
+    ('\u{14CD}', "\u{B7}\u{14C8}"),
+    ('\u{14CE}', "\u{14C8}\u{B7}"),
+    ('\u{14D1}', "\u{1421}"),
+    ('\u{14DC}', "\u{B7}\u{14D3}"),
+    ('\u{14DD}', "\u{14D3}\u{B7}"),
+    ('\u{14DE}', "\u{B7}\u{14D5}"),
+    ('\u{14DF}', "\u{14D5}\u{B7}"),
+    ('\u{14E0}', "\u{B7}\u{14D6}"),
+    ('\u{14E1}', "\u{14D6}\u{B7}"),
+    ('\u{14E2}', "\u{B7}\u{14D7}"),
+    ('\u{14E3}', "\u{14D7}\u{B7}"),
+    ('\u{14E4}', "\u{B7}\u{14D8}"),
+    ('\u{14E5}', "\u{14D8}\u{B7}"),
+    ('\u{14E6}', "\u{B7}\u{14DA}"),
+    ('\u{14E7}', "\u{14DA}\u{B7}"),
+    ('\u{14E8}', "\u{B7}\u{14DB}"),
+    ('\u{14E9}', "\u{14DB}\u{B7}"),
+    ('\u{14F6}', "\u{B7}\u{14ED}"),
+    ('\u{14F7}', "\u{14ED}\u{B7}"),
+    ('\u{14F8}', "\u{B7}\u{14EF}"),
+    ('\u{14F9}', "\u{14EF}\u{B7}"),
+    ('\u{14FA}', "\u{B7}\u{14F0}"),
+    ('\u{14FB}', "\u{14F0}\u{B7}"),
+    ('\u{14FC}', "\u{B7}\u{14F1}"),
+    ('\u{14FD}', "\u{14F1}\u{B7}"),
+    ('\u{14FE}', "\u{B7}\u{14F2}"),
+    ('\u{14FF}', "\u{14F2}\u{B7}"),
+    ('\u{1500}', "\u{B7}\u{14F4}"),
+    ('\u{1501}', "\u{14F4}\u{B7}"),
+    ('\u{1502}', "\u{B7}\u{14F5}"),
+    ('\u{1503}', "\u{14F5}\u{B7}"),
+    ('\u{150C}', "\u{150B}<"),
+    ('\u{150D}', "\u{150B}\u{1455}"),
+    ('\u{150E}', "\u{150B}b"),
+    ('\u{150F}', "\u{150B}\u{1490}"),
+    ('\u{1517}', "\u{B7}\u{1510}"),
+    ('\u{1518}', "\u{1510}\u{B7}"),
+    ('\u{1519}', "\u{B7}\u{1511}"),
+    ('\u{151A}', "\u{1511}\u{B7}"),
+    ('\u{151B}', "\u{B7}\u{1512}"),
+    ('\u{151C}', "\u{1512}\u{B7}"),
+    ('\u{151D}', "\u{B7}\u{1513}"),
+    ('\u{151E}', "\u{1513}\u{B7}"),
+    ('\u{151F}', "\u{B7}\u{1514}"),
+    ('\u{1520}', "\u{1514}\u{B7}"),
+    ('\u{1521}', "\u{B7}\u{1515}"),
+    ('\u{1522}', "\u{1515}\u{B7}"),
+    ('\u{1523}', "\u{B7}\u{1516}"),
+    ('\u{1524}', "\u{1516}\u{B7}"),
+    ('\u{152F}', "\u{B7}4"),
+    ('\u{1530}', "4\u{B7}"),
+    ('\u{1531}', "\u{B7}\u{1528}"),
+    ('\u{1532}', "\u{1528}\u{B7}"),
+    ('\u{1533}', "\u{B7}\u{1529}"),
+    ('\u{1534}', "\u{1529}\u{B7}"),
+    ('\u{1535}', "\u{B7}\u{152A}"),
+    ('\u{1536}', "\u{152A}\u{B7}"),
+    ('\u{1537}', "\u{B7}\u{152B}"),
+    ('\u{1538}', "\u{152B}\u{B7}"),
+    ('\u{1539}', "\u{B7}\u{152D}"),
+    ('\u{153A}', "\u{152D}\u{B7}"),
+    ('\u{153B}', "\u{B7}\u{152E}"),
+    ('\u{153C}', "\u{152E}\u{B7}"),
+    ('\u{1540}', "\u{1429}"),
+    ('\u{1541}', "x"),
+    ('\u{154E}', "\u{B7}\u{154C}"),
+    ('\u{154F}', "\u{154C}\u{B7}"),
+    ('\u{155B}', "\u{B7}\u{155A}"),
+    ('\u{155C}', "\u{155A}\u{B7}"),
+    ('\u{1568}', "\u{B7}\u{1567}"),
+    ('\u{1569}', "\u{1567}\u{B7}"),
+    ('\u{1577}', "\u{1E9F}"),
+    ('\u{157C}', "H"),
+    ('\u{157D}', "x"),
+    ('\u{157E}', "\u{1550}\u{146C}"),
+    ('\u{157F}', "\u{1550}P"),
+    ('\u{1580}', "\u{1550}\u{146E}"),
+    ('\u{1581}', "\u{1550}d"),
+    ('\u{1582}', "\u{1550}\u{1470}"),
+    ('\u{1583}', "\u{1550}b"),
+    ('\u{1584}', "\u{1550}b\u{307}"),
+    ('\u{1585}', "\u{1550}\u{1483}"),
+    ('\u{1587}', "R"),
+    ('\u{158E}', "\u{1595}\u{148A}"),
+    ('\u{158F}', "\u{1595}\u{148B}"),
+    ('\u{1590}', "\u{1595}\u{148C}"),
+    ('\u{1591}', "\u{1595}J"),
+    ('\u{1592}', "\u{1595}\u{148E}"),
+    ('\u{1593}', "\u{1595}\u{1490}"),
+    ('\u{1594}', "\u{1595}\u{1491}"),
+    ('\u{15AF}', "b"),
+    ('\u{15B4}', "F"),
+    ('\u{15B5}', "\u{2132}"),
+    ('\u{15B7}', "\u{A7FB}"),
+    ('\u{15C4}', "\u{2C6F}"),
+    ('\u{15C5}', "A"),
+    ('\u{15DE}', "D"),
+    ('\u{15EA}', "D"),
+    ('\u{15EF}', "\u{460}"),
+    ('\u{15F0}', "M"),
+    ('\u{15F7}', "B"),
+    ('\u{1602}', "\u{1490}"),
+    ('\u{1603}', "\u{1489}"),
+    ('\u{1604}', "\u{14D3}"),
+    ('\u{1607}', "\u{14DA}"),
+    ('\u{1622}', "\u{1543}"),
+    ('\u{1623}', "\u{1546}"),
+    ('\u{1624}', "\u{154A}"),
+    ('\u{162E}', "\u{1B1}"),
+    ('\u{162F}', "\u{3A9}"),
+    ('\u{1634}', "\u{1B1}"),
+    ('\u{1635}', "\u{3A9}"),
+    ('\u{166D}', "X"),
+    ('\u{166E}', "x"),
+    ('\u{166F}', "\u{1550}\u{146B}"),
+    ('\u{1670}', "\u{1595}\u{1489}"),
+    ('\u{1671}', "\u{1596}\u{148B}"),
+    ('\u{1672}', "\u{1596}\u{148C}"),
+    ('\u{1673}', "\u{1596}J"),
+    ('\u{1674}', "\u{1596}\u{148E}"),
+    ('\u{1675}', "\u{1596}\u{1490}"),
+    ('\u{1676}', "\u{1596}\u{1491}"),
+    ('\u{1677}', "\u{15A7}\u{B7}"),
+    ('\u{1678}', "\u{15A8}\u{B7}"),
+    ('\u{1679}', "\u{15A9}\u{B7}"),
+    ('\u{167A}', "\u{15AA}\u{B7}"),
+    ('\u{167B}', "\u{15AB}\u{B7}"),
+    ('\u{167C}', "\u{15AC}\u{B7}"),
+    ('\u{167D}', "\u{15AD}\u{B7}"),
+    ('\u{1680}', " "),
+    ('\u{16B2}', "<"),
+    ('\u{16B7}', "X"),
+    ('\u{16C1}', "l"),
+    ('\u{16C2}', "\u{16BD}"),
+    ('\u{16CC}', "'"),
+    ('\u{16D5}', "K"),
+    ('\u{16D6}', "M"),
+    ('\u{16D8}', "\u{3A8}"),
+    ('\u{16E1}', "\u{16BC}"),
+    ('\u{16EB}', "\u{B7}"),
+    ('\u{16EC}', ":"),
+    ('\u{16ED}', "+"),
+    ('\u{16F0}', "\u{3A6}"),
+    ('\u{1735}', "/"),
+    ('\u{17A3}', "\u{17A2}"),
+    ('\u{17B7}', "\u{E34}"),
+    ('\u{17B8}', "\u{E35}"),
+    ('\u{17B9}', "\u{E36}"),
+    ('\u{17BA}', "\u{E37}"),
+    ('\u{17C6}', "\u{30A}"),
+    ('\u{17CB}', "\u{E48}"),
+    ('\u{17D3}', "\u{30A}"),
+    ('\u{17D4}', "\u{E2F}"),
+    ('\u{17D5}', "\u{E5A}"),
+    ('\u{17D9}', "\u{E4F}"),
+    ('\u{17DA}', "\u{E5B}"),
+    ('\u{1803}', ":"),
+    ('\u{1809}', ":"),
+    ('\u{1855}', "\u{1835}"),
+    ('\u{1896}', "\u{185C}"),
+    ('\u{18B3}', "\u{B7}\u{18B1}"),
+    ('\u{18B6}', "\u{B7}\u{18B4}"),
+    ('\u{18B9}', "\u{B7}\u{18B8}"),
+    ('\u{18C2}', "\u{B7}\u{18C0}"),
+    ('\u{18C6}', "\u{B7}\u{14C2}"),
+    ('\u{18C7}', "\u{14C2}\u{B7}"),
+    ('\u{18C8}', "\u{B7}\u{14C3}"),
+    ('\u{18C9}', "\u{14C3}\u{B7}"),
+    ('\u{18CA}', "\u{B7}\u{14C4}"),
+    ('\u{18CB}', "\u{14C4}\u{B7}"),
+    ('\u{18CC}', "\u{B7}\u{14C5}"),
+    ('\u{18CD}', "\u{14C5}\u{B7}"),
+    ('\u{18CE}', "\u{B7}\u{1543}"),
+    ('\u{18CF}', "\u{B7}\u{1546}"),
+    ('\u{18D0}', "\u{B7}\u{1547}"),
+    ('\u{18D1}', "\u{B7}\u{1548}"),
+    ('\u{18D2}', "\u{B7}\u{1549}"),
+    ('\u{18D3}', "\u{B7}\u{154B}"),
+    ('\u{18DB}', "\u{18F5}"),
+    ('\u{18DC}', "\u{18DF}\u{141E}"),
+    ('\u{18DD}', "\u{141E}\u{18DF}"),
+    ('\u{18E0}', "\u{1543}\u{B7}"),
+    ('\u{18E3}', "\u{155E}\u{B7}"),
+    ('\u{18E4}', "\u{1566}\u{B7}"),
+    ('\u{18E5}', "\u{156B}\u{B7}"),
+    ('\u{18E8}', "\u{1586}\u{B7}"),
+    ('\u{18EA}', "\u{1597}\u{B7}"),
+    ('\u{18ED}', "\u{460}\u{B7}"),
+    ('\u{18F0}', "\u{15F4}\u{B7}"),
+    ('\u{18F2}', "\u{161B}\u{B7}"),
+    ('\u{19D0}', "\u{199E}"),
+    ('\u{19D1}', "\u{19B1}"),
+    ('\u{1A80}', "\u{1A45}"),
+    ('\u{1A90}', "\u{1A45}"),
+    ('\u{1AA9}', "\u{1AA8}\u{1AA8}"),
+    ('\u{1AAB}', "\u{1AAA}\u{1AA8}"),
+    ('\u{1AB4}', "\u{6DB}"),
+    ('\u{1AB7}', "\u{328}"),
+    ('\u{1B52}', "\u{1B0D}"),
+    ('\u{1B53}', "\u{1B11}"),
+    ('\u{1B58}', "\u{1B28}"),
+    ('\u{1B5C}', "\u{1B50}"),
+    ('\u{1B5F}', "\u{1B5E}\u{1B5E}"),
+    ('\u{1C3C}', "\u{1C3B}\u{1C3B}"),
+    ('\u{1C7F}', "\u{1C7E}\u{1C7E}"),
+    ('\u{1CD0}', "\u{302}"),
+    ('\u{1CD2}', "\u{304}"),
+    ('\u{1CD3}', "''"),
+    ('\u{1CD5}', "\u{32B}"),
+    ('\u{1CD8}', "\u{32E}"),
+    ('\u{1CD9}', "\u{32D}"),
+    ('\u{1CDA}', "\u{30E}"),
+    ('\u{1CDC}', "\u{329}"),
+    ('\u{1CDD}', "\u{323}"),
+    ('\u{1CDE}', "\u{324}"),
+    ('\u{1CED}', "\u{316}"),
+    ('\u{1D04}', "c"),
+    ('\u{1D08}', "\u{25C}"),
+    ('\u{1D0B}', "\u{138}"),
+    ('\u{1D0D}', "\u{28D}"),
+    ('\u{1D0F}', "o"),
+    ('\u{1D10}', "\u{254}"),
+    ('\u{1D11}', "o"),
+    ('\u{1D14}', "\u{1DD}o"),
+    ('\u{1D1C}', "u"),
+    ('\u{1D20}', "v"),
+    ('\u{1D21}', "w"),
+    ('\u{1D22}', "z"),
+    ('\u{1D24}', "\u{1A8}"),
+    ('\u{1D26}', "r"),
+    ('\u{1D27}', "\u{28C}"),
+    ('\u{1D28}', "\u{3C0}"),
+    ('\u{1D29}', "\u{1D18}"),
+    ('\u{1D2B}', "\u{43B}"),
+    ('\u{1D3E}', "\u{18D6}"),
+    ('\u{1D52}', "\u{BA}"),
+    ('\u{1D6B}', "ue"),
+    ('\u{1D6E}', "f\u{334}"),
+    ('\u{1D6F}', "rn\u{334}"),
+    ('\u{1D70}', "n\u{334}"),
+    ('\u{1D72}', "r\u{334}"),
+    ('\u{1D73}', "\u{27E}\u{334}"),
+    ('\u{1D74}', "s\u{334}"),
+    ('\u{1D75}', "t\u{334}"),
+    ('\u{1D76}', "z\u{334}"),
+    ('\u{1D78}', "\u{1D34}"),
+    ('\u{1D7B}', "i\u{335}"),
+    ('\u{1D7C}', "i\u{335}"),
+    ('\u{1D7D}', "p\u{335}"),
+    ('\u{1D7E}', "u\u{335}"),
+    ('\u{1D7F}', "\u{28A}\u{335}"),
+    ('\u{1D83}', "g"),
+    ('\u{1D8C}', "y"),
+    ('\u{1D90}', "\u{24B}"),
+    ('\u{1D9F}', "\u{1D4B}"),
+    ('\u{1DA2}', "\u{1D4D}"),
+    ('\u{1DBA}', "\u{18D4}"),
+    ('\u{1DBB}', "\u{1646}"),
+    ('\u{1DEE}', "\u{2DEC}"),
+    ('\u{1E9A}', "a\u{309}"),
+    ('\u{1E9D}', "f"),
+    ('\u{1EFF}', "y"),
+    ('\u{1FBD}', "'"),
+    ('\u{1FBF}', "'"),
+    ('\u{1FC0}', "~"),
+    ('\u{1FFE}', "'"),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2004}', " "),
+    ('\u{2005}', " "),
+    ('\u{2006}', " "),
+    ('\u{2007}', " "),
+    ('\u{2008}', " "),
+    ('\u{2009}', " "),
+    ('\u{200A}', " "),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "\u{30FC}"),
+    ('\u{2015}', "\u{30FC}"),
+    ('\u{2016}', "ll"),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201A}', ","),
+    ('\u{201B}', "'"),
+    ('\u{201C}', "''"),
+    ('\u{201D}', "''"),
+    ('\u{201F}', "''"),
+    ('\u{2022}', "\u{B7}"),
+    ('\u{2024}', "."),
+    ('\u{2025}', ".."),
+    ('\u{2026}', "..."),
+    ('\u{2027}', "\u{B7}"),
+    ('\u{2028}', " "),
+    ('\u{2029}', " "),
+    ('\u{202F}', " "),
+    ('\u{2030}', "\u{BA}/\u{2080}\u{2080}"),
+    ('\u{2031}', "\u{BA}/\u{2080}\u{2080}\u{2080}"),
+    ('\u{2032}', "'"),
+    ('\u{2033}', "''"),
+    ('\u{2034}', "'''"),
+    ('\u{2035}', "'"),
+    ('\u{2036}', "''"),
+    ('\u{2037}', "'''"),
+    ('\u{2039}', "<"),
+    ('\u{203A}', ">"),
+    ('\u{203C}', "!!"),
+    ('\u{203E}', "\u{2C9}"),
+    ('\u{2041}', "/"),
+    ('\u{2043}', "-"),
+    ('\u{2044}', "/"),
+    ('\u{2047}', "??"),
+    ('\u{2048}', "?!"),
+    ('\u{2049}', "!?"),
+    ('\u{204E}', "*"),
+    ('\u{2052}', "\u{BA}/\u{2080}"),
+    ('\u{2053}', "~"),
+    ('\u{2057}', "''''"),
+    ('\u{205A}', ":"),
+    ('\u{205D}', "\u{2D57}"),
+    ('\u{205E}', "\u{2D42}"),
+    ('\u{205F}', " "),
+    ('\u{2070}', "\u{BA}"),
+    ('\u{2079}', "\u{A770}"),
+    ('\u{20A1}', "C\u{20EB}"),
+    ('\u{20A4}', "\u{A3}"),
+    ('\u{20A5}', "rn\u{338}"),
+    ('\u{20A8}', "Rs"),
+    ('\u{20A9}', "W\u{335}"),
+    ('\u{20AB}', "d\u{335}\u{331}"),
+    ('\u{20AC}', "\u{A792}"),
+    ('\u{20AD}', "K\u{335}"),
+    ('\u{20AE}', "T\u{20EB}"),
+    ('\u{20B6}', "lt"),
+    ('\u{20BD}', "\u{554}"),
+    ('\u{20DB}', "\u{6DB}"),
+    ('\u{2100}', "a/c"),
+    ('\u{2101}', "a/s"),
+    ('\u{2102}', "C"),
+    ('\u{2103}', "\u{B0}C"),
+    ('\u{2105}', "c/o"),
+    ('\u{2106}', "c/u"),
+    ('\u{2107}', "\u{190}"),
+    ('\u{2108}', "\u{42D}"),
+    ('\u{2109}', "\u{B0}F"),
+    ('\u{210A}', "g"),
+    ('\u{210B}', "H"),
+    ('\u{210C}', "H"),
+    ('\u{210D}', "H"),
+    ('\u{210E}', "h"),
+    ('\u{210F}', "h\u{335}"),
+    ('\u{2110}', "l"),
+    ('\u{2111}', "l"),
+    ('\u{2112}', "L"),
+    ('\u{2113}', "l"),
+    ('\u{2115}', "N"),
+    ('\u{2116}', "No"),
+    ('\u{2119}', "P"),
+    ('\u{211A}', "Q"),
+    ('\u{211B}', "R"),
+    ('\u{211C}', "R"),
+    ('\u{211D}', "R"),
+    ('\u{2121}', "TEL"),
+    ('\u{2124}', "Z"),
+    ('\u{2127}', "\u{1B1}"),
+    ('\u{2128}', "Z"),
+    ('\u{2129}', "\u{27F}"),
+    ('\u{212C}', "B"),
+    ('\u{212D}', "C"),
+    ('\u{212E}', "e"),
+    ('\u{212F}', "e"),
+    ('\u{2130}', "E"),
+    ('\u{2131}', "F"),
+    ('\u{2133}', "M"),
+    ('\u{2134}', "o"),
+    ('\u{2135}', "\u{5D0}"),
+    ('\u{2136}', "\u{5D1}"),
+    ('\u{2137}', "\u{5D2}"),
+    ('\u{2138}', "\u{5D3}"),
+    ('\u{2139}', "i"),
+    ('\u{213B}', "FAX"),
+    ('\u{213C}', "\u{3C0}"),
+    ('\u{213D}', "y"),
+    ('\u{213E}', "\u{393}"),
+    ('\u{213F}', "\u{3A0}"),
+    ('\u{2140}', "\u{1A9}"),
+    ('\u{2141}', "\u{A4E8}"),
+    ('\u{2142}', "\u{A4F6}"),
+    ('\u{2143}', "\u{16F00}"),
+    ('\u{2145}', "D"),
+    ('\u{2146}', "d"),
+    ('\u{2147}', "e"),
+    ('\u{2148}', "i"),
+    ('\u{2149}', "j"),
+    ('\u{2160}', "l"),
+    ('\u{2161}', "ll"),
+    ('\u{2162}', "lll"),
+    ('\u{2163}', "lV"),
+    ('\u{2164}', "V"),
+    ('\u{2165}', "Vl"),
+    ('\u{2166}', "Vll"),
+    ('\u{2167}', "Vlll"),
+    ('\u{2168}', "lX"),
+    ('\u{2169}', "X"),
+    ('\u{216A}', "Xl"),
+    ('\u{216B}', "Xll"),
+    ('\u{216C}', "L"),
+    ('\u{216D}', "C"),
+    ('\u{216E}', "D"),
+    ('\u{216F}', "M"),
+    ('\u{2170}', "i"),
+    ('\u{2171}', "ii"),
+    ('\u{2172}', "iii"),
+    ('\u{2173}', "iv"),
+    ('\u{2174}', "v"),
+    ('\u{2175}', "vi"),
+    ('\u{2176}', "vii"),
+    ('\u{2177}', "viii"),
+    ('\u{2178}', "ix"),
+    ('\u{2179}', "x"),
+    ('\u{217A}', "xi"),
+    ('\u{217B}', "xii"),
+    ('\u{217C}', "l"),
+    ('\u{217D}', "c"),
+    ('\u{217E}', "d"),
+    ('\u{217F}', "rn"),
+    ('\u{2183}', "\u{186}"),
+    ('\u{2184}', "\u{254}"),
+    ('\u{2191}', "\u{16CF}"),
+    ('\u{2195}', "\u{16E8}"),
+    ('\u{21B5}', "\u{21B2}"),
+    ('\u{21BA}', "\u{1F10E}"),
+    ('\u{21BE}', "\u{16DA}"),
+    ('\u{21BF}', "\u{16D0}"),
+    ('\u{2200}', "\u{2C6F}"),
+    ('\u{2203}', "\u{18E}"),
+    ('\u{2206}', "\u{394}"),
+    ('\u{220F}', "\u{3A0}"),
+    ('\u{2211}', "\u{1A9}"),
+    ('\u{2212}', "-"),
+    ('\u{2214}', "+\u{307}"),
+    ('\u{2215}', "/"),
+    ('\u{2216}', "\u{5C}"),
+    ('\u{2217}', "*"),
+    ('\u{2218}', "\u{B0}"),
+    ('\u{2219}', "\u{B7}"),
+    ('\u{221E}', "oo"),
+    ('\u{2223}', "l"),
+    ('\u{2225}', "ll"),
+    ('\u{2228}', "v"),
+    ('\u{2229}', "\u{548}"),
+    ('\u{222A}', "U"),
+    ('\u{222B}', "\u{283}"),
+    ('\u{222C}', "\u{283}\u{283}"),
+    ('\u{222D}', "\u{283}\u{283}\u{283}"),
+    ('\u{222F}', "\u{222E}\u{222E}"),
+    ('\u{2230}', "\u{222E}\u{222E}\u{222E}"),
+    ('\u{2236}', ":"),
+    ('\u{2238}', "-\u{307}"),
+    ('\u{223C}', "~"),
+    ('\u{2250}', "=\u{307}"),
+    ('\u{2251}', "=\u{323}\u{307}"),
+    ('\u{2257}', "=\u{30A}"),
+    ('\u{2259}', "=\u{302}"),
+    ('\u{225A}', "=\u{306}"),
+    ('\u{225E}', "=\u{36B}"),
+    ('\u{2263}', "\u{2261}"),
+    ('\u{226A}', "<<"),
+    ('\u{226B}', ">>"),
+    ('\u{2282}', "\u{1455}"),
+    ('\u{2283}', "\u{1450}"),
+    ('\u{2295}', "\u{102A8}"),
+    ('\u{2296}', "O\u{335}"),
+    ('\u{2299}', "\u{298}"),
+    ('\u{229D}', "O\u{335}"),
+    ('\u{22A4}', "T"),
+    ('\u{22A5}', "\u{A4D5}"),
+    ('\u{22C0}', "\u{2227}"),
+    ('\u{22C1}', "v"),
+    ('\u{22C2}', "\u{548}"),
+    ('\u{22C3}', "U"),
+    ('\u{22C4}', "\u{16DC}"),
+    ('\u{22C5}', "\u{B7}"),
+    ('\u{22C8}', "\u{16DE}"),
+    ('\u{22D6}', "<\u{B7}"),
+    ('\u{22D7}', "\u{B7}>"),
+    ('\u{22D8}', "<<<"),
+    ('\u{22D9}', ">>>"),
+    ('\u{22EE}', "\u{2D57}"),
+    ('\u{22EF}', "\u{B7}\u{B7}\u{B7}"),
+    ('\u{22F4}', "\u{A793}"),
+    ('\u{22FF}', "E"),
+    ('\u{2300}', "\u{2205}"),
+    ('\u{2325}', "\u{2324}"),
+    ('\u{2341}', "\u{303C}"),
+    ('\u{2359}', "\u{394}\u{332}"),
+    ('\u{235A}', "\u{16DC}\u{332}"),
+    ('\u{235C}', "\u{B0}\u{332}"),
+    ('\u{235F}', "\u{229B}"),
+    ('\u{2361}', "T\u{308}"),
+    ('\u{2362}', "\u{2207}\u{308}"),
+    ('\u{2363}', "\u{22C6}\u{308}"),
+    ('\u{2364}', "\u{B0}\u{308}"),
+    ('\u{2365}', "\u{629}"),
+    ('\u{2368}', "~\u{308}"),
+    ('\u{2369}', "\u{1435}"),
+    ('\u{236B}', "\u{2207}\u{334}"),
+    ('\u{236C}', "O\u{335}"),
+    ('\u{2373}', "i"),
+    ('\u{2374}', "p"),
+    ('\u{2375}', "\u{3C9}"),
+    ('\u{2376}', "a\u{332}"),
+    ('\u{2377}', "\u{A793}\u{332}"),
+    ('\u{2378}', "i\u{332}"),
+    ('\u{2379}', "\u{3C9}\u{332}"),
+    ('\u{237A}', "a"),
+    ('\u{237F}', "\u{16BD}"),
+    ('\u{239C}', "\u{4E28}"),
+    ('\u{239F}', "\u{4E28}"),
+    ('\u{23A2}', "\u{4E28}"),
+    ('\u{23A5}', "\u{4E28}"),
+    ('\u{23AA}', "\u{4E28}"),
+    ('\u{23AE}', "\u{4E28}"),
+    ('\u{23C1}', "\u{2355}"),
+    ('\u{23C2}', "\u{234E}"),
+    ('\u{23C3}', "\u{234B}"),
+    ('\u{23C6}', "\u{236D}"),
+    ('\u{23E8}', "\u{2081}\u{2080}"),
+    ('\u{23FC}', "\u{23FB}"),
+    ('\u{23FD}', "l"),
+    ('\u{23FE}', "\u{263E}"),
+    ('\u{244A}', "\u{5C}\u{5C}"),
+    ('\u{2460}', "\u{2780}"),
+    ('\u{2461}', "\u{2781}"),
+    ('\u{2462}', "\u{2782}"),
+    ('\u{2463}', "\u{2783}"),
+    ('\u{2464}', "\u{2784}"),
+    ('\u{2465}', "\u{2785}"),
+    ('\u{2466}', "\u{2786}"),
+    ('\u{2467}', "\u{2787}"),
+    ('\u{2468}', "\u{2788}"),
+    ('\u{2469}', "\u{2789}"),
+    ('\u{2474}', "(l)"),
+    ('\u{2475}', "(2)"),
+    ('\u{2476}', "(3)"),
+    ('\u{2477}', "(4)"),
+    ('\u{2478}', "(5)"),
+    ('\u{2479}', "(6)"),
+    ('\u{247A}', "(7)"),
+    ('\u{247B}', "(8)"),
+    ('\u{247C}', "(9)"),
+    ('\u{247D}', "(lO)"),
+    ('\u{247E}', "(ll)"),
+    ('\u{247F}', "(l2)"),
+    ('\u{2480}', "(l3)"),
+    ('\u{2481}', "(l4)"),
+    ('\u{2482}', "(l5)"),
+    ('\u{2483}', "(l6)"),
+    ('\u{2484}', "(l7)"),
+    ('\u{2485}', "(l8)"),
+    ('\u{2486}', "(l9)"),
+    ('\u{2487}', "(2O)"),
+    ('\u{2488}', "l."),
+    ('\u{2489}', "2."),
+    ('\u{248A}', "3."),
+    ('\u{248B}', "4."),
+    ('\u{248C}', "5."),
+    ('\u{248D}', "6."),
+    ('\u{248E}', "7."),
+    ('\u{248F}', "8."),
+    ('\u{2490}', "9."),
+    ('\u{2491}', "lO."),
+    ('\u{2492}', "ll."),
+    ('\u{2493}', "l2."),
+    ('\u{2494}', "l3."),
+    ('\u{2495}', "l4."),
+    ('\u{2496}', "l5."),
+    ('\u{2497}', "l6."),
+    ('\u{2498}', "l7."),
+    ('\u{2499}', "l8."),
+    ('\u{249A}', "l9."),
+    ('\u{249B}', "2O."),
+    ('\u{249C}', "(a)"),
+    ('\u{249D}', "(b)"),
+    ('\u{249E}', "(c)"),
+    ('\u{249F}', "(d)"),
+    ('\u{24A0}', "(e)"),
+    ('\u{24A1}', "(f)"),
+    ('\u{24A2}', "(g)"),
+    ('\u{24A3}', "(h)"),
+    ('\u{24A4}', "(i)"),
+    ('\u{24A5}', "(j)"),
+    ('\u{24A6}', "(k)"),
+    ('\u{24A7}', "(l)"),
+    ('\u{24A8}', "(rn)"),
+    ('\u{24A9}', "(n)"),
+    ('\u{24AA}', "(o)"),
+    ('\u{24AB}', "(p)"),
+    ('\u{24AC}', "(q)"),
+    ('\u{24AD}', "(r)"),
+    ('\u{24AE}', "(s)"),
+    ('\u{24AF}', "(t)"),
+    ('\u{24B0}', "(u)"),
+    ('\u{24B1}', "(v)"),
+    ('\u{24B2}', "(w)"),
+    ('\u{24B3}', "(x)"),
+    ('\u{24B4}', "(y)"),
+    ('\u{24B5}', "(z)"),
+    ('\u{24B8}', "\u{A9}"),
+    ('\u{24C5}', "\u{2117}"),
+    ('\u{24C7}', "\u{AE}"),
+    ('\u{24DB}', "\u{24BE}"),
+    ('\u{24EA}', "\u{1F10D}"),
+    ('\u{2500}', "\u{30FC}"),
+    ('\u{2501}', "\u{30FC}"),
+    ('\u{2503}', "\u{2502}"),
+    ('\u{250F}', "\u{250C}"),
+    ('\u{2523}', "\u{251C}"),
+    ('\u{2571}', "/"),
+    ('\u{2573}', "X"),
+    ('\u{2588}', "\u{220E}"),
+    ('\u{2590}', "\u{258C}"),
+    ('\u{2594}', "\u{2C9}"),
+    ('\u{2597}', "\u{2596}"),
+    ('\u{259D}', "\u{2598}"),
+    ('\u{25A0}', "\u{220E}"),
+    ('\u{25B1}', "\u{23E5}"),
+    ('\u{25B3}', "\u{394}"),
+    ('\u{25B7}', "\u{22B3}"),
+    ('\u{25B8}', "\u{25B6}"),
+    ('\u{25BA}', "\u{25B6}"),
+    ('\u{25BD}', "\u{102BC}"),
+    ('\u{25C1}', "\u{22B2}"),
+    ('\u{25C7}', "\u{16DC}"),
+    ('\u{25CA}', "\u{16DC}"),
+    ('\u{25CB}', "\u{B0}"),
+    ('\u{25CE}', "\u{233E}"),
+    ('\u{25E0}', "\u{2312}"),
+    ('\u{25E6}', "\u{B0}"),
+    ('\u{2609}', "\u{298}"),
+    ('\u{2610}', "\u{25A1}"),
+    ('\u{2625}', "\u{1099E}"),
+    ('\u{2630}', "\u{2CB6}"),
+    ('\u{2638}', "\u{2388}"),
+    ('\u{264E}', "\u{224F}"),
+    ('\u{2662}', "\u{16DC}"),
+    ('\u{2669}', "\u{1D158}\u{1D165}"),
+    ('\u{266A}', "\u{1D158}\u{1D165}\u{1D16E}"),
+    ('\u{26AC}', "\u{970}"),
+    ('\u{2768}', "("),
+    ('\u{2769}', ")"),
+    ('\u{276E}', "<"),
+    ('\u{276F}', ">"),
+    ('\u{2772}', "("),
+    ('\u{2773}', ")"),
+    ('\u{2774}', "{"),
+    ('\u{2775}', "}"),
+    ('\u{2795}', "+"),
+    ('\u{2796}', "-"),
+    ('\u{2797}', "\u{F7}"),
+    ('\u{27C2}', "\u{A4D5}"),
+    ('\u{27C8}', "\u{5C}\u{1455}"),
+    ('\u{27C9}', "\u{1450}/"),
+    ('\u{27CB}', "/"),
+    ('\u{27CD}', "\u{5C}"),
+    ('\u{27D9}', "T"),
+    ('\u{27E8}', "\u{276C}"),
+    ('\u{27E9}', "\u{276D}"),
+    ('\u{292B}', "x"),
+    ('\u{292C}', "x"),
+    ('\u{2963}', "\u{16D0}\u{16DA}"),
+    ('\u{2965}', "\u{21C3}\u{21C2}"),
+    ('\u{296E}', "\u{16D0}\u{21C2}"),
+    ('\u{296F}', "\u{21C3}\u{16DA}"),
+    ('\u{2999}', "\u{2D42}"),
+    ('\u{29B0}', "\u{2349}"),
+    ('\u{29BE}', "\u{233E}"),
+    ('\u{29C4}', "\u{303C}"),
+    ('\u{29C5}', "\u{2342}"),
+    ('\u{29C7}', "\u{233B}"),
+    ('\u{29D6}', "\u{102C0}"),
+    ('\u{29D9}', "\u{299A}"),
+    ('\u{29F4}', ":\u{2192}"),
+    ('\u{29F5}', "\u{5C}"),
+    ('\u{29F6}', "/\u{304}"),
+    ('\u{29F8}', "/"),
+    ('\u{29F9}', "\u{5C}"),
+    ('\u{2A00}', "\u{298}"),
+    ('\u{2A01}', "\u{102A8}"),
+    ('\u{2A02}', "\u{2297}"),
+    ('\u{2A03}', "\u{228D}"),
+    ('\u{2A04}', "\u{228E}"),
+    ('\u{2A05}', "\u{2293}"),
+    ('\u{2A06}', "\u{2294}"),
+    ('\u{2A0C}', "\u{283}\u{283}\u{283}\u{283}"),
+    ('\u{2A1D}', "\u{16DE}"),
+    ('\u{2A20}', ">>"),
+    ('\u{2A21}', "\u{16DA}"),
+    ('\u{2A22}', "+\u{30A}"),
+    ('\u{2A23}', "+\u{302}"),
+    ('\u{2A24}', "+\u{303}"),
+    ('\u{2A25}', "+\u{323}"),
+    ('\u{2A26}', "+\u{330}"),
+    ('\u{2A27}', "+\u{2082}"),
+    ('\u{2A29}', "-\u{313}"),
+    ('\u{2A2A}', "-\u{323}"),
+    ('\u{2A2F}', "x"),
+    ('\u{2A30}', "x\u{307}"),
+    ('\u{2A3D}', "\u{2319}"),
+    ('\u{2A3E}', "\u{2A1F}"),
+    ('\u{2A3F}', "\u{2210}"),
+    ('\u{2A6A}', "~\u{307}"),
+    ('\u{2A6E}', "=\u{20F0}"),
+    ('\u{2A74}', "::="),
+    ('\u{2A75}', "=="),
+    ('\u{2A76}', "==="),
+    ('\u{2AA5}', "><"),
+    ('\u{2AAA}', "\u{15D5}"),
+    ('\u{2AAB}', "\u{15D2}"),
+    ('\u{2AD7}', "\u{1450}\u{1455}"),
+    ('\u{2AFB}', "///"),
+    ('\u{2AFD}', "//"),
+    ('\u{2BEC}', "\u{219E}"),
+    ('\u{2BED}', "\u{219F}"),
+    ('\u{2BEE}', "\u{21A0}"),
+    ('\u{2BEF}', "\u{21A1}"),
+    ('\u{2C67}', "H\u{329}"),
+    ('\u{2C69}', "K\u{329}"),
+    ('\u{2C84}', "\u{393}"),
+    ('\u{2C85}', "r"),
+    ('\u{2C86}', "\u{394}"),
+    ('\u{2C88}', "\u{A792}"),
+    ('\u{2C89}', "\u{A793}"),
+    ('\u{2C8E}', "H"),
+    ('\u{2C92}', "l"),
+    ('\u{2C94}', "K"),
+    ('\u{2C95}', "\u{138}"),
+    ('\u{2C96}', "\u{3BB}"),
+    ('\u{2C98}', "M"),
+    ('\u{2C9A}', "N"),
+    ('\u{2C9E}', "O"),
+    ('\u{2C9F}', "o"),
+    ('\u{2CA0}', "\u{3A0}"),
+    ('\u{2CA2}', "P"),
+    ('\u{2CA3}', "p"),
+    ('\u{2CA4}', "C"),
+    ('\u{2CA5}', "c"),
+    ('\u{2CA6}', "T"),
+    ('\u{2CA8}', "Y"),
+    ('\u{2CAA}', "\u{3A6}"),
+    ('\u{2CAB}', "\u{278}"),
+    ('\u{2CAC}', "X"),
+    ('\u{2CAD}', "\u{3C7}"),
+    ('\u{2CAE}', "\u{3A8}"),
+    ('\u{2CB1}', "\u{3C9}"),
+    ('\u{2CB4}', "<\u{B7}"),
+    ('\u{2CBA}', "-"),
+    ('\u{2CBC}', "\u{428}"),
+    ('\u{2CBD}', "\u{448}"),
+    ('\u{2CC6}', "/"),
+    ('\u{2CCA}', "9"),
+    ('\u{2CCC}', "3"),
+    ('\u{2CCD}', "\u{21D}"),
+    ('\u{2CD0}', "L"),
+    ('\u{2CD1}', "\u{29F}"),
+    ('\u{2CD2}', "6"),
+    ('\u{2CDC}', "\u{3EC}"),
+    ('\u{2CE4}', "\u{3D7}"),
+    ('\u{2CE9}', "\u{2627}"),
+    ('\u{2CF9}', "\u{5C}\u{5C}"),
+    ('\u{2D31}', "O\u{335}"),
+    ('\u{2D37}', "\u{245}"),
+    ('\u{2D38}', "V"),
+    ('\u{2D39}', "E"),
+    ('\u{2D3A}', "\u{18E}"),
+    ('\u{2D41}', "O\u{338}"),
+    ('\u{2D48}', "\u{B7}\u{B7}\u{B7}"),
+    ('\u{2D49}', "\u{1A9}"),
+    ('\u{2D4F}', "l"),
+    ('\u{2D51}', "!"),
+    ('\u{2D54}', "O"),
+    ('\u{2D55}', "Q"),
+    ('\u{2D59}', "\u{298}"),
+    ('\u{2D5D}', "X"),
+    ('\u{2D60}', "\u{394}"),
+    ('\u{2D63}', "\u{16EF}"),
+    ('\u{2DE8}', "\u{1DDF}"),
+    ('\u{2DEA}', "\u{30A}"),
+    ('\u{2DED}', "\u{368}"),
+    ('\u{2DEF}', "\u{36F}"),
+    ('\u{2DF6}', "\u{363}"),
+    ('\u{2DF7}', "\u{364}"),
+    ('\u{2E1A}', "-\u{308}"),
+    ('\u{2E1E}', "~\u{307}"),
+    ('\u{2E1F}', "~\u{323}"),
+    ('\u{2E26}', "\u{1455}"),
+    ('\u{2E27}', "\u{1450}"),
+    ('\u{2E28}', "(("),
+    ('\u{2E29}', "))"),
+    ('\u{2E2A}', "\u{2235}"),
+    ('\u{2E2B}', "\u{2234}"),
+    ('\u{2E2C}', "\u{2237}"),
+    ('\u{2E2E}', "\u{61F}"),
+    ('\u{2E30}', "\u{B0}"),
+    ('\u{2E31}', "\u{B7}"),
+    ('\u{2E32}', "\u{60C}"),
+    ('\u{2E35}', "\u{61B}"),
+    ('\u{2E39}', "\u{1E9F}"),
+    ('\u{2E3D}', "\u{2D42}"),
+    ('\u{2E3F}', "\u{B6}"),
+    ('\u{2E40}', "="),
+    ('\u{2E82}', "\u{4E5B}"),
+    ('\u{2E83}', "\u{4E5A}"),
+    ('\u{2E85}', "\u{4EBB}"),
+    ('\u{2E89}', "\u{5202}"),
+    ('\u{2E8B}', "\u{353E}"),
+    ('\u{2E8E}', "\u{5140}"),
+    ('\u{2E8F}', "\u{5C23}"),
+    ('\u{2E90}', "\u{5C22}"),
+    ('\u{2E92}', "\u{5DF3}"),
+    ('\u{2E93}', "\u{5E7A}"),
+    ('\u{2E94}', "\u{5F51}"),
+    ('\u{2E96}', "\u{5FC4}"),
+    ('\u{2E97}', "\u{38FA}"),
+    ('\u{2E98}', "\u{624C}"),
+    ('\u{2E99}', "\u{6535}"),
+    ('\u{2E9B}', "\u{65E1}"),
+    ('\u{2E9E}', "\u{6B7A}"),
+    ('\u{2E9F}', "\u{6BCD}"),
+    ('\u{2EA0}', "\u{6C11}"),
+    ('\u{2EA1}', "\u{6C35}"),
+    ('\u{2EA2}', "\u{6C3A}"),
+    ('\u{2EA3}', "\u{706C}"),
+    ('\u{2EA4}', "\u{722B}"),
+    ('\u{2EA6}', "\u{4E2C}"),
+    ('\u{2EA8}', "\u{72AD}"),
+    ('\u{2EAB}', "\u{7F52}"),
+    ('\u{2EAD}', "\u{793B}"),
+    ('\u{2EAF}', "\u{7CF9}"),
+    ('\u{2EB1}', "\u{7F53}"),
+    ('\u{2EB2}', "\u{7F52}"),
+    ('\u{2EB9}', "\u{8002}"),
+    ('\u{2EBA}', "\u{8080}"),
+    ('\u{2EBE}', "\u{8279}"),
+    ('\u{2EBF}', "\u{8279}"),
+    ('\u{2EC0}', "\u{8279}"),
+    ('\u{2EC1}', "\u{864E}"),
+    ('\u{2EC2}', "\u{8864}"),
+    ('\u{2EC3}', "\u{8980}"),
+    ('\u{2EC4}', "\u{897F}"),
+    ('\u{2EC5}', "\u{89C1}"),
+    ('\u{2EC8}', "\u{8BA0}"),
+    ('\u{2EC9}', "\u{8D1D}"),
+    ('\u{2ECB}', "\u{8F66}"),
+    ('\u{2ECC}', "\u{8FB6}"),
+    ('\u{2ECD}', "\u{8FB6}"),
+    ('\u{2ECF}', "\u{961D}"),
+    ('\u{2ED0}', "\u{9485}"),
+    ('\u{2ED1}', "\u{9577}"),
+    ('\u{2ED2}', "\u{9578}"),
+    ('\u{2ED3}', "\u{957F}"),
+    ('\u{2ED4}', "\u{95E8}"),
+    ('\u{2ED6}', "\u{961D}"),
+    ('\u{2ED8}', "\u{9752}"),
+    ('\u{2ED9}', "\u{97E6}"),
+    ('\u{2EDA}', "\u{9875}"),
+    ('\u{2EDB}', "\u{98CE}"),
+    ('\u{2EDC}', "\u{98DE}"),
+    ('\u{2EDD}', "\u{98DF}"),
+    ('\u{2EDF}', "\u{98E0}"),
+    ('\u{2EE0}', "\u{9963}"),
+    ('\u{2EE2}', "\u{9A6C}"),
+    ('\u{2EE4}', "\u{9B3C}"),
+    ('\u{2EE5}', "\u{9C7C}"),
+    ('\u{2EE8}', "\u{9EA6}"),
+    ('\u{2EE9}', "\u{9EC4}"),
+    ('\u{2EEB}', "\u{6589}"),
+    ('\u{2EEC}', "\u{9F50}"),
+    ('\u{2EED}', "\u{6B6F}"),
+    ('\u{2EEE}', "\u{9F7F}"),
+    ('\u{2EEF}', "\u{7ADC}"),
+    ('\u{2EF0}', "\u{9F99}"),
+    ('\u{2EF2}', "\u{4E80}"),
+    ('\u{2EF3}', "\u{9F9F}"),
+    ('\u{2F00}', "\u{30FC}"),
+    ('\u{2F01}', "\u{4E28}"),
+    ('\u{2F02}', "\u{5C}"),
+    ('\u{2F03}', "/"),
+    ('\u{2F04}', "\u{4E59}"),
+    ('\u{2F05}', "\u{4E85}"),
+    ('\u{2F06}', "\u{4E8C}"),
+    ('\u{2F07}', "\u{4EA0}"),
+    ('\u{2F08}', "\u{4EBA}"),
+    ('\u{2F09}', "\u{513F}"),
+    ('\u{2F0A}', "\u{5165}"),
+    ('\u{2F0B}', "\u{516B}"),
+    ('\u{2F0C}', "\u{5182}"),
+    ('\u{2F0D}', "\u{5196}"),
+    ('\u{2F0E}', "\u{51AB}"),
+    ('\u{2F0F}', "\u{51E0}"),
+    ('\u{2F10}', "\u{51F5}"),
+    ('\u{2F11}', "\u{5200}"),
+    ('\u{2F12}', "\u{529B}"),
+    ('\u{2F13}', "\u{52F9}"),
+    ('\u{2F14}', "\u{5315}"),
+    ('\u{2F15}', "\u{531A}"),
+    ('\u{2F16}', "\u{5338}"),
+    ('\u{2F17}', "\u{5341}"),
+    ('\u{2F18}', "\u{535C}"),
+    ('\u{2F19}', "\u{5369}"),
+    ('\u{2F1A}', "\u{5382}"),
+    ('\u{2F1B}', "\u{53B6}"),
+    ('\u{2F1C}', "\u{53C8}"),
+    ('\u{2F1D}', "\u{53E3}"),
+    ('\u{2F1E}', "\u{53E3}"),
+    ('\u{2F1F}', "\u{571F}"),
+    ('\u{2F20}', "\u{571F}"),
+    ('\u{2F21}', "\u{5902}"),
+    ('\u{2F22}', "\u{590A}"),
+    ('\u{2F23}', "\u{5915}"),
+    ('\u{2F24}', "\u{5927}"),
+    ('\u{2F25}', "\u{5973}"),
+    ('\u{2F26}', "\u{5B50}"),
+    ('\u{2F27}', "\u{5B80}"),
+    ('\u{2F28}', "\u{5BF8}"),
+    ('\u{2F29}', "\u{5C0F}"),
+    ('\u{2F2A}', "\u{5C22}"),
+    ('\u{2F2B}', "\u{5C38}"),
+    ('\u{2F2C}', "\u{5C6E}"),
+    ('\u{2F2D}', "\u{5C71}"),
+    ('\u{2F2E}', "\u{5DDB}"),
+    ('\u{2F2F}', "\u{5DE5}"),
+    ('\u{2F30}', "\u{5DF1}"),
+    ('\u{2F31}', "\u{5DFE}"),
+    ('\u{2F32}', "\u{5E72}"),
+    ('\u{2F33}', "\u{5E7A}"),
+    ('\u{2F34}', "\u{5E7F}"),
+    ('\u{2F35}', "\u{5EF4}"),
+    ('\u{2F36}', "\u{5EFE}"),
+    ('\u{2F37}', "\u{5F0B}"),
+    ('\u{2F38}', "\u{5F13}"),
+    ('\u{2F39}', "\u{5F50}"),
+    ('\u{2F3A}', "\u{5F61}"),
+    ('\u{2F3B}', "\u{5F73}"),
+    ('\u{2F3C}', "\u{5FC3}"),
+    ('\u{2F3D}', "\u{6208}"),
+    ('\u{2F3E}', "\u{6236}"),
+    ('\u{2F3F}', "\u{624B}"),
+    ('\u{2F40}', "\u{652F}"),
+    ('\u{2F41}', "\u{6534}"),
+    ('\u{2F42}', "\u{6587}"),
+    ('\u{2F43}', "\u{6597}"),
+    ('\u{2F44}', "\u{65A4}"),
+    ('\u{2F45}', "\u{65B9}"),
+    ('\u{2F46}', "\u{65E0}"),
+    ('\u{2F47}', "\u{65E5}"),
+    ('\u{2F48}', "\u{66F0}"),
+    ('\u{2F49}', "\u{6708}"),
+    ('\u{2F4A}', "\u{6728}"),
+    ('\u{2F4B}', "\u{6B20}"),
+    ('\u{2F4C}', "\u{6B62}"),
+    ('\u{2F4D}', "\u{6B79}"),
+    ('\u{2F4E}', "\u{6BB3}"),
+    ('\u{2F4F}', "\u{6BCB}"),
+    ('\u{2F50}', "\u{6BD4}"),
+    ('\u{2F51}', "\u{6BDB}"),
+    ('\u{2F52}', "\u{6C0F}"),
+    ('\u{2F53}', "\u{6C14}"),
+    ('\u{2F54}', "\u{6C34}"),
+    ('\u{2F55}', "\u{706B}"),
+    ('\u{2F56}', "\u{722A}"),
+    ('\u{2F57}', "\u{7236}"),
+    ('\u{2F58}', "\u{723B}"),
+    ('\u{2F59}', "\u{723F}"),
+    ('\u{2F5A}', "\u{7247}"),
+    ('\u{2F5B}', "\u{7259}"),
+    ('\u{2F5C}', "\u{725B}"),
+    ('\u{2F5D}', "\u{72AC}"),
+    ('\u{2F5E}', "\u{7384}"),
+    ('\u{2F5F}', "\u{7389}"),
+    ('\u{2F60}', "\u{74DC}"),
+    ('\u{2F61}', "\u{74E6}"),
+    ('\u{2F62}', "\u{7518}"),
+    ('\u{2F63}', "\u{751F}"),
+    ('\u{2F64}', "\u{7528}"),
+    ('\u{2F65}', "\u{7530}"),
+    ('\u{2F66}', "\u{758B}"),
+    ('\u{2F67}', "\u{7592}"),
+    ('\u{2F68}', "\u{7676}"),
+    ('\u{2F69}', "\u{767D}"),
+    ('\u{2F6A}', "\u{76AE}"),
+    ('\u{2F6B}', "\u{76BF}"),
+    ('\u{2F6C}', "\u{76EE}"),
+    ('\u{2F6D}', "\u{77DB}"),
+    ('\u{2F6E}', "\u{77E2}"),
+    ('\u{2F6F}', "\u{77F3}"),
+    ('\u{2F70}', "\u{793A}"),
+    ('\u{2F71}', "\u{79B8}"),
+    ('\u{2F72}', "\u{79BE}"),
+    ('\u{2F73}', "\u{7A74}"),
+    ('\u{2F74}', "\u{7ACB}"),
+    ('\u{2F75}', "\u{7AF9}"),
+    ('\u{2F76}', "\u{7C73}"),
+    ('\u{2F77}', "\u{7CF8}"),
+    ('\u{2F78}', "\u{7F36}"),
+    ('\u{2F79}', "\u{7F51}"),
+    ('\u{2F7A}', "\u{7F8A}"),
+    ('\u{2F7B}', "\u{7FBD}"),
+    ('\u{2F7C}', "\u{8001}"),
+    ('\u{2F7D}', "\u{800C}"),
+    ('\u{2F7E}', "\u{8012}"),
+    ('\u{2F7F}', "\u{8033}"),
+    ('\u{2F80}', "\u{807F}"),
+    ('\u{2F81}', "\u{8089}"),
+    ('\u{2F82}', "\u{81E3}"),
+    ('\u{2F83}', "\u{81EA}"),
+    ('\u{2F84}', "\u{81F3}"),
+    ('\u{2F85}', "\u{81FC}"),
+    ('\u{2F86}', "\u{820C}"),
+    ('\u{2F87}', "\u{821B}"),
+    ('\u{2F88}', "\u{821F}"),
+    ('\u{2F89}', "\u{826E}"),
+    ('\u{2F8A}', "\u{8272}"),
+    ('\u{2F8B}', "\u{8278}"),
+    ('\u{2F8C}', "\u{864D}"),
+    ('\u{2F8D}', "\u{866B}"),
+    ('\u{2F8E}', "\u{8840}"),
+    ('\u{2F8F}', "\u{884C}"),
+    ('\u{2F90}', "\u{8863}"),
+    ('\u{2F91}', "\u{897E}"),
+    ('\u{2F92}', "\u{898B}"),
+    ('\u{2F93}', "\u{89D2}"),
+    ('\u{2F94}', "\u{8A00}"),
+    ('\u{2F95}', "\u{8C37}"),
+    ('\u{2F96}', "\u{8C46}"),
+    ('\u{2F97}', "\u{8C55}"),
+    ('\u{2F98}', "\u{8C78}"),
+    ('\u{2F99}', "\u{8C9D}"),
+    ('\u{2F9A}', "\u{8D64}"),
+    ('\u{2F9B}', "\u{8D70}"),
+    ('\u{2F9C}', "\u{8DB3}"),
+    ('\u{2F9D}', "\u{8EAB}"),
+    ('\u{2F9E}', "\u{8ECA}"),
+    ('\u{2F9F}', "\u{8F9B}"),
+    ('\u{2FA0}', "\u{8FB0}"),
+    ('\u{2FA1}', "\u{8FB5}"),
+    ('\u{2FA2}', "\u{9091}"),
+    ('\u{2FA3}', "\u{9149}"),
+    ('\u{2FA4}', "\u{91C6}"),
+    ('\u{2FA5}', "\u{91CC}"),
+    ('\u{2FA6}', "\u{91D1}"),
+    ('\u{2FA7}', "\u{9577}"),
+    ('\u{2FA8}', "\u{9580}"),
+    ('\u{2FA9}', "\u{961C}"),
+    ('\u{2FAA}', "\u{96B6}"),
+    ('\u{2FAB}', "\u{96B9}"),
+    ('\u{2FAC}', "\u{96E8}"),
+    ('\u{2FAD}', "\u{9751}"),
+    ('\u{2FAE}', "\u{975E}"),
+    ('\u{2FAF}', "\u{9762}"),
+    ('\u{2FB0}', "\u{9769}"),
+    ('\u{2FB1}', "\u{97CB}"),
+    ('\u{2FB2}', "\u{97ED}"),
+    ('\u{2FB3}', "\u{97F3}"),
+    ('\u{2FB4}', "\u{9801}"),
+    ('\u{2FB5}', "\u{98A8}"),
+    ('\u{2FB6}', "\u{98DB}"),
+    ('\u{2FB7}', "\u{98DF}"),
+    ('\u{2FB8}', "\u{9996}"),
+    ('\u{2FB9}', "\u{9999}"),
+    ('\u{2FBA}', "\u{99AC}"),
+    ('\u{2FBB}', "\u{9AA8}"),
+    ('\u{2FBC}', "\u{9AD8}"),
+    ('\u{2FBD}', "\u{9ADF}"),
+    ('\u{2FBE}', "\u{9B25}"),
+    ('\u{2FBF}', "\u{9B2F}"),
+    ('\u{2FC0}', "\u{9B32}"),
+    ('\u{2FC1}', "\u{9B3C}"),
+    ('\u{2FC2}', "\u{9B5A}"),
+    ('\u{2FC3}', "\u{9CE5}"),
+    ('\u{2FC4}', "\u{9E75}"),
+    ('\u{2FC5}', "\u{9E7F}"),
+    ('\u{2FC6}', "\u{9EA5}"),
+    ('\u{2FC7}', "\u{9EBB}"),
+    ('\u{2FC8}', "\u{9EC3}"),
+    ('\u{2FC9}', "\u{9ECD}"),
+    ('\u{2FCA}', "\u{9ED1}"),
+    ('\u{2FCB}', "\u{9EF9}"),
+    ('\u{2FCC}', "\u{9EFD}"),
+    ('\u{2FCD}', "\u{9F0E}"),
+    ('\u{2FCE}', "\u{9F13}"),
+    ('\u{2FCF}', "\u{9F20}"),
+    ('\u{2FD0}', "\u{9F3B}"),
+    ('\u{2FD1}', "\u{9F4A}"),
+    ('\u{2FD2}', "\u{9F52}"),
+    ('\u{2FD3}', "\u{9F8D}"),
+    ('\u{2FD4}', "\u{9F9C}"),
+    ('\u{2FD5}', "\u{9FA0}"),
+    ('\u{3002}', "\u{2F3}"),
+    ('\u{3003}', "''"),
+    ('\u{3007}', "O"),
+    ('\u{3008}', "\u{276C}"),
+    ('\u{3009}', "\u{276D}"),
+    ('\u{3012}', "\u{20B8}"),
+    ('\u{3014}', "("),
+    ('\u{3015}', ")"),
+    ('\u{301A}', "\u{27E6}"),
+    ('\u{301B}', "\u{27E7}"),
+    ('\u{302C}', "\u{309}"),
+    ('\u{302D}', "\u{325}"),
+    ('\u{3033}', "/"),
+    ('\u{3036}', "\u{20B8}"),
+    ('\u{3038}', "\u{5341}"),
+    ('\u{3039}', "\u{5344}"),
+    ('\u{303A}', "\u{5345}"),
+    ('\u{304F}', "\u{276C}"),
+    ('\u{309A}', "\u{30A}"),
+    ('\u{309B}', "\u{FF9E}"),
+    ('\u{309C}', "\u{FF9F}"),
+    ('\u{30A0}', "="),
+    ('\u{30A4}', "\u{4EBB}"),
+    ('\u{30A8}', "\u{5DE5}"),
+    ('\u{30AB}', "\u{529B}"),
+    ('\u{30BF}', "\u{5915}"),
+    ('\u{30C8}', "\u{535C}"),
+    ('\u{30CB}', "\u{4E8C}"),
+    ('\u{30CE}', "/"),
+    ('\u{30CF}', "\u{516B}"),
+    ('\u{30D8}', "\u{3078}"),
+    ('\u{30ED}', "\u{53E3}"),
+    ('\u{30FB}', "\u{B7}"),
+    ('\u{3131}', "\u{1100}"),
+    ('\u{3132}', "\u{1100}\u{1100}"),
+    ('\u{3133}', "\u{1100}\u{1109}"),
+    ('\u{3134}', "\u{1102}"),
+    ('\u{3135}', "\u{1102}\u{110C}"),
+    ('\u{3136}', "\u{1102}\u{1112}"),
+    ('\u{3137}', "\u{1103}"),
+    ('\u{3138}', "\u{1103}\u{1103}"),
+    ('\u{3139}', "\u{1105}"),
+    ('\u{313A}', "\u{1105}\u{1100}"),
+    ('\u{313B}', "\u{1105}\u{1106}"),
+    ('\u{313C}', "\u{1105}\u{1107}"),
+    ('\u{313D}', "\u{1105}\u{1109}"),
+    ('\u{313E}', "\u{1105}\u{1110}"),
+    ('\u{313F}', "\u{1105}\u{1111}"),
+    ('\u{3140}', "\u{1105}\u{1112}"),
+    ('\u{3141}', "\u{1106}"),
+    ('\u{3142}', "\u{1107}"),
+    ('\u{3143}', "\u{1107}\u{1107}"),
+    ('\u{3144}', "\u{1107}\u{1109}"),
+    ('\u{3145}', "\u{1109}"),
+    ('\u{3146}', "\u{1109}\u{1109}"),
+    ('\u{3147}', "\u{110B}"),
+    ('\u{3148}', "\u{110C}"),
+    ('\u{3149}', "\u{110C}\u{110C}"),
+    ('\u{314A}', "\u{110E}"),
+    ('\u{314B}', "\u{110F}"),
+    ('\u{314C}', "\u{1110}"),
+    ('\u{314D}', "\u{1111}"),
+    ('\u{314E}', "\u{1112}"),
+    ('\u{314F}', "\u{1161}"),
+    ('\u{3150}', "\u{1161}\u{4E28}"),
+    ('\u{3151}', "\u{1163}"),
+    ('\u{3152}', "\u{1163}\u{4E28}"),
+    ('\u{3153}', "\u{1165}"),
+    ('\u{3154}', "\u{1165}\u{4E28}"),
+    ('\u{3155}', "\u{1167}"),
+    ('\u{3156}', "\u{1167}\u{4E28}"),
+    ('\u{3157}', "\u{1169}"),
+    ('\u{3158}', "\u{1169}\u{1161}"),
+    ('\u{3159}', "\u{1169}\u{1161}\u{4E28}"),
+    ('\u{315A}', "\u{1169}\u{4E28}"),
+    ('\u{315B}', "\u{116D}"),
+    ('\u{315C}', "\u{116E}"),
+    ('\u{315D}', "\u{116E}\u{1165}"),
+    ('\u{315E}', "\u{116E}\u{1165}\u{4E28}"),
+    ('\u{315F}', "\u{116E}\u{4E28}"),
+    ('\u{3160}', "\u{1172}"),
+    ('\u{3161}', "\u{30FC}"),
+    ('\u{3162}', "\u{30FC}\u{4E28}"),
+    ('\u{3163}', "\u{4E28}"),
+    ('\u{3164}', "\u{1160}"),
+    ('\u{3165}', "\u{1102}\u{1102}"),
+    ('\u{3166}', "\u{1102}\u{1103}"),
+    ('\u{3167}', "\u{1102}\u{1109}"),
+    ('\u{3168}', "\u{1102}\u{1140}"),
+    ('\u{3169}', "\u{1105}\u{1100}\u{1109}"),
+    ('\u{316A}', "\u{1105}\u{1103}"),
+    ('\u{316B}', "\u{1105}\u{1107}\u{1109}"),
+    ('\u{316C}', "\u{1105}\u{1140}"),
+    ('\u{316D}', "\u{1105}\u{1159}"),
+    ('\u{316E}', "\u{1106}\u{1107}"),
+    ('\u{316F}', "\u{1106}\u{1109}"),
+    ('\u{3170}', "\u{1106}\u{1140}"),
+    ('\u{3171}', "\u{1106}\u{110B}"),
+    ('\u{3172}', "\u{1107}\u{1100}"),
+    ('\u{3173}', "\u{1107}\u{1103}"),
+    ('\u{3174}', "\u{1107}\u{1109}\u{1100}"),
+    ('\u{3175}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{3176}', "\u{1107}\u{110C}"),
+    ('\u{3177}', "\u{1107}\u{1110}"),
+    ('\u{3178}', "\u{1107}\u{110B}"),
+    ('\u{3179}', "\u{1107}\u{1107}\u{110B}"),
+    ('\u{317A}', "\u{1109}\u{1100}"),
+    ('\u{317B}', "\u{1109}\u{1102}"),
+    ('\u{317C}', "\u{1109}\u{1103}"),
+    ('\u{317D}', "\u{1109}\u{1107}"),
+    ('\u{317E}', "\u{1109}\u{110C}"),
+    ('\u{317F}', "\u{1140}"),
+    ('\u{3180}', "\u{110B}\u{110B}"),
+    ('\u{3181}', "\u{114C}"),
+    ('\u{3182}', "\u{110B}\u{1109}"),
+    ('\u{3183}', "\u{110B}\u{1140}"),
+    ('\u{3184}', "\u{1111}\u{110B}"),
+    ('\u{3185}', "\u{1112}\u{1112}"),
+    ('\u{3186}', "\u{1159}"),
+    ('\u{3187}', "\u{116D}\u{1163}"),
+    ('\u{3188}', "\u{116D}\u{1163}\u{4E28}"),
+    ('\u{3189}', "\u{116D}\u{4E28}"),
+    ('\u{318A}', "\u{1172}\u{1167}"),
+    ('\u{318B}', "\u{1172}\u{1167}\u{4E28}"),
+    ('\u{318C}', "\u{1172}\u{4E28}"),
+    ('\u{318D}', "\u{119E}"),
+    ('\u{318E}', "\u{119E}\u{4E28}"),
+    ('\u{31D0}', "\u{30FC}"),
+    ('\u{31D1}', "\u{4E28}"),
+    ('\u{31D3}', "/"),
+    ('\u{31D4}', "\u{5C}"),
+    ('\u{31D6}', "\u{4E5B}"),
+    ('\u{31DA}', "\u{4E85}"),
+    ('\u{31DB}', "\u{276C}"),
+    ('\u{31DF}', "\u{4E5A}"),
+    ('\u{31E0}', "\u{4E59}"),
+    ('\u{3200}', "(\u{1100})"),
+    ('\u{3201}', "(\u{1102})"),
+    ('\u{3202}', "(\u{1103})"),
+    ('\u{3203}', "(\u{1105})"),
+    ('\u{3204}', "(\u{1106})"),
+    ('\u{3205}', "(\u{1107})"),
+    ('\u{3206}', "(\u{1109})"),
+    ('\u{3207}', "(\u{110B})"),
+    ('\u{3208}', "(\u{110C})"),
+    ('\u{3209}', "(\u{110E})"),
+    ('\u{320A}', "(\u{110F})"),
+    ('\u{320B}', "(\u{1110})"),
+    ('\u{320C}', "(\u{1111})"),
+    ('\u{320D}', "(\u{1112})"),
+    ('\u{320E}', "(\u{1100}\u{1161})"),
+    ('\u{320F}', "(\u{1102}\u{1161})"),
+    ('\u{3210}', "(\u{1103}\u{1161})"),
+    ('\u{3211}', "(\u{1105}\u{1161})"),
+    ('\u{3212}', "(\u{1106}\u{1161})"),
+    ('\u{3213}', "(\u{1107}\u{1161})"),
+    ('\u{3214}', "(\u{1109}\u{1161})"),
+    ('\u{3215}', "(\u{110B}\u{1161})"),
+    ('\u{3216}', "(\u{110C}\u{1161})"),
+    ('\u{3217}', "(\u{110E}\u{1161})"),
+    ('\u{3218}', "(\u{110F}\u{1161})"),
+    ('\u{3219}', "(\u{1110}\u{1161})"),
+    ('\u{321A}', "(\u{1111}\u{1161})"),
+    ('\u{321B}', "(\u{1112}\u{1161})"),
+    ('\u{321C}', "(\u{110C}\u{116E})"),
+    ('\u{321D}', "(\u{110B}\u{1169}\u{110C}\u{1165}\u{1102})"),
+    ('\u{321E}', "(\u{110B}\u{1169}\u{1112}\u{116E})"),
+    ('\u{3220}', "(\u{30FC})"),
+    ('\u{3221}', "(\u{4E8C})"),
+    ('\u{3222}', "(\u{4E09})"),
+    ('\u{3223}', "(\u{56DB})"),
+    ('\u{3224}', "(\u{4E94})"),
+    ('\u{3225}', "(\u{516D})"),
+    ('\u{3226}', "(\u{4E03})"),
+    ('\u{3227}', "(\u{516B})"),
+    ('\u{3228}', "(\u{4E5D})"),
+    ('\u{3229}', "(\u{5341})"),
+    ('\u{322A}', "(\u{6708})"),
+    ('\u{322B}', "(\u{706B})"),
+    ('\u{322C}', "(\u{6C34})"),
+    ('\u{322D}', "(\u{6728})"),
+    ('\u{322E}', "(\u{91D1})"),
+    ('\u{322F}', "(\u{571F})"),
+    ('\u{3230}', "(\u{65E5})"),
+    ('\u{3231}', "(\u{682A})"),
+    ('\u{3232}', "(\u{6709})"),
+    ('\u{3233}', "(\u{793E})"),
+    ('\u{3234}', "(\u{540D})"),
+    ('\u{3235}', "(\u{7279})"),
+    ('\u{3236}', "(\u{8CA1})"),
+    ('\u{3237}', "(\u{795D})"),
+    ('\u{3238}', "(\u{52B4})"),
+    ('\u{3239}', "(\u{4EE3})"),
+    ('\u{323A}', "(\u{547C})"),
+    ('\u{323B}', "(\u{5B66})"),
+    ('\u{323C}', "(\u{76E3})"),
+    ('\u{323D}', "(\u{4F01})"),
+    ('\u{323E}', "(\u{8CC7})"),
+    ('\u{323F}', "(\u{5354})"),
+    ('\u{3240}', "(\u{796D})"),
+    ('\u{3241}', "(\u{4F11})"),
+    ('\u{3242}', "(\u{81EA})"),
+    ('\u{3243}', "(\u{81F3})"),
+    ('\u{32C0}', "l\u{6708}"),
+    ('\u{32C1}', "2\u{6708}"),
+    ('\u{32C2}', "3\u{6708}"),
+    ('\u{32C3}', "4\u{6708}"),
+    ('\u{32C4}', "5\u{6708}"),
+    ('\u{32C5}', "6\u{6708}"),
+    ('\u{32C6}', "7\u{6708}"),
+    ('\u{32C7}', "8\u{6708}"),
+    ('\u{32C8}', "9\u{6708}"),
+    ('\u{32C9}', "lO\u{6708}"),
+    ('\u{32CA}', "ll\u{6708}"),
+    ('\u{32CB}', "l2\u{6708}"),
+    ('\u{3358}', "O\u{70B9}"),
+    ('\u{3359}', "l\u{70B9}"),
+    ('\u{335A}', "2\u{70B9}"),
+    ('\u{335B}', "3\u{70B9}"),
+    ('\u{335C}', "4\u{70B9}"),
+    ('\u{335D}', "5\u{70B9}"),
+    ('\u{335E}', "6\u{70B9}"),
+    ('\u{335F}', "7\u{70B9}"),
+    ('\u{3360}', "8\u{70B9}"),
+    ('\u{3361}', "9\u{70B9}"),
+    ('\u{3362}', "lO\u{70B9}"),
+    ('\u{3363}', "ll\u{70B9}"),
+    ('\u{3364}', "l2\u{70B9}"),
+    ('\u{3365}', "l3\u{70B9}"),
+    ('\u{3366}', "l4\u{70B9}"),
+    ('\u{3367}', "l5\u{70B9}"),
+    ('\u{3368}', "l6\u{70B9}"),
+    ('\u{3369}', "l7\u{70B9}"),
+    ('\u{336A}', "l8\u{70B9}"),
+    ('\u{336B}', "l9\u{70B9}"),
+    ('\u{336C}', "2O\u{70B9}"),
+    ('\u{336D}', "2l\u{70B9}"),
+    ('\u{336E}', "22\u{70B9}"),
+    ('\u{336F}', "23\u{70B9}"),
+    ('\u{3370}', "24\u{70B9}"),
+    ('\u{33E0}', "l\u{65E5}"),
+    ('\u{33E1}', "2\u{65E5}"),
+    ('\u{33E2}', "3\u{65E5}"),
+    ('\u{33E3}', "4\u{65E5}"),
+    ('\u{33E4}', "5\u{65E5}"),
+    ('\u{33E5}', "6\u{65E5}"),
+    ('\u{33E6}', "7\u{65E5}"),
+    ('\u{33E7}', "8\u{65E5}"),
+    ('\u{33E8}', "9\u{65E5}"),
+    ('\u{33E9}', "lO\u{65E5}"),
+    ('\u{33EA}', "ll\u{65E5}"),
+    ('\u{33EB}', "l2\u{65E5}"),
+    ('\u{33EC}', "l3\u{65E5}"),
+    ('\u{33ED}', "l4\u{65E5}"),
+    ('\u{33EE}', "l5\u{65E5}"),
+    ('\u{33EF}', "l6\u{65E5}"),
+    ('\u{33F0}', "l7\u{65E5}"),
+    ('\u{33F1}', "l8\u{65E5}"),
+    ('\u{33F2}', "l9\u{65E5}"),
+    ('\u{33F3}', "2O\u{65E5}"),
+    ('\u{33F4}', "2l\u{65E5}"),
+    ('\u{33F5}', "22\u{65E5}"),
+    ('\u{33F6}', "23\u{65E5}"),
+    ('\u{33F7}', "24\u{65E5}"),
+    ('\u{33F8}', "25\u{65E5}"),
+    ('\u{33F9}', "26\u{65E5}"),
+    ('\u{33FA}', "27\u{65E5}"),
+    ('\u{33FB}', "28\u{65E5}"),
+    ('\u{33FC}', "29\u{65E5}"),
+    ('\u{33FD}', "3O\u{65E5}"),
+    ('\u{33FE}', "3l\u{65E5}"),
+    ('\u{39B3}', "\u{363D}"),
+    ('\u{439B}', "\u{3588}"),
+    ('\u{4420}', "\u{3B3B}"),
+    ('\u{4E00}', "\u{30FC}"),
+    ('\u{4E36}', "\u{5C}"),
+    ('\u{4E3F}', "/"),
+    ('\u{5002}', "\u{4F75}"),
+    ('\u{503C}', "\u{5024}"),
+    ('\u{555F}', "\u{5553}"),
+    ('\u{56D7}', "\u{53E3}"),
+    ('\u{586B}', "\u{5861}"),
+    ('\u{58EB}', "\u{571F}"),
+    ('\u{58FF}', "\u{58AB}"),
+    ('\u{5B00}', "\u{5AAF}"),
+    ('\u{5E32}', "\u{5E21}"),
+    ('\u{5E50}', "\u{3B3A}"),
+    ('\u{6238}', "\u{6236}"),
+    ('\u{6409}', "\u{3A41}"),
+    ('\u{6663}', "\u{403F}"),
+    ('\u{6669}', "\u{665A}"),
+    ('\u{66F6}', "\u{3ADA}"),
+    ('\u{6726}', "\u{4443}"),
+    ('\u{67FF}', "\u{676E}"),
+    ('\u{69E9}', "\u{3BA3}"),
+    ('\u{6A27}', "\u{699D}"),
+    ('\u{6F59}', "\u{6E88}"),
+    ('\u{784F}', "\u{7814}"),
+    ('\u{7D76}', "\u{7D55}"),
+    ('\u{80A6}', "\u{670C}"),
+    ('\u{80CA}', "\u{6710}"),
+    ('\u{80D0}', "\u{670F}"),
+    ('\u{80F6}', "\u{3B35}"),
+    ('\u{8101}', "\u{6713}"),
+    ('\u{8127}', "\u{6718}"),
+    ('\u{8141}', "\u{80FC}"),
+    ('\u{81A7}', "\u{6723}"),
+    ('\u{853F}', "\u{848D}"),
+    ('\u{8641}', "\u{8637}"),
+    ('\u{8A1E}', "\u{46B6}"),
+    ('\u{8A7D}', "\u{8A2E}"),
+    ('\u{8B8F}', "\u{8B86}"),
+    ('\u{8C63}', "\u{8C5C}"),
+    ('\u{8D86}', "\u{8D7F}"),
+    ('\u{8DFA}', "\u{8DE5}"),
+    ('\u{8E9B}', "\u{8E97}"),
+    ('\u{8F27}', "\u{8EFF}"),
+    ('\u{90DE}', "\u{90CE}"),
+    ('\u{93AE}', "\u{93AD}"),
+    ('\u{96B8}', "\u{96B7}"),
+    ('\u{9E43}', "\u{9E42}"),
+    ('\u{9ED2}', "\u{9ED1}"),
+    ('\u{9FC3}', "\u{4039}"),
+    ('\u{A494}', "\u{A2CD}"),
+    ('\u{A49C}', "\u{A0C0}"),
+    ('\u{A49E}', "\u{A04A}"),
+    ('\u{A4A7}', "\u{A458}"),
+    ('\u{A4A8}', "\u{A132}"),
+    ('\u{A4AC}', "\u{A050}"),
+    ('\u{A4B0}', "\u{A3C2}"),
+    ('\u{A4BA}', "\u{A3BF}"),
+    ('\u{A4BE}', "\u{A2B1}"),
+    ('\u{A4BF}', "\u{A259}"),
+    ('\u{A4C0}', "\u{A3AB}"),
+    ('\u{A4C2}', "\u{A3B5}"),
+    ('\u{A4D0}', "B"),
+    ('\u{A4D1}', "P"),
+    ('\u{A4D2}', "d"),
+    ('\u{A4D3}', "D"),
+    ('\u{A4D4}', "T"),
+    ('\u{A4D6}', "G"),
+    ('\u{A4D7}', "K"),
+    ('\u{A4D9}', "J"),
+    ('\u{A4DA}', "C"),
+    ('\u{A4DB}', "\u{186}"),
+    ('\u{A4DC}', "Z"),
+    ('\u{A4DD}', "F"),
+    ('\u{A4DE}', "\u{2132}"),
+    ('\u{A4DF}', "M"),
+    ('\u{A4E0}', "N"),
+    ('\u{A4E1}', "L"),
+    ('\u{A4E2}', "S"),
+    ('\u{A4E3}', "R"),
+    ('\u{A4E5}', "\u{245}"),
+    ('\u{A4E6}', "V"),
+    ('\u{A4E7}', "H"),
+    ('\u{A4EA}', "W"),
+    ('\u{A4EB}', "X"),
+    ('\u{A4EC}', "Y"),
+    ('\u{A4ED}', "\u{1660}"),
+    ('\u{A4EE}', "A"),
+    ('\u{A4EF}', "\u{2C6F}"),
+    ('\u{A4F0}', "E"),
+    ('\u{A4F1}', "\u{18E}"),
+    ('\u{A4F2}', "l"),
+    ('\u{A4F3}', "O"),
+    ('\u{A4F4}', "U"),
+    ('\u{A4F5}', "\u{548}"),
+    ('\u{A4F7}', "\u{15E1}"),
+    ('\u{A4F8}', "."),
+    ('\u{A4F9}', ","),
+    ('\u{A4FA}', ".."),
+    ('\u{A4FB}', ".,"),
+    ('\u{A4FD}', ":"),
+    ('\u{A4FE}', "-."),
+    ('\u{A4FF}', "="),
+    ('\u{A60E}', "."),
+    ('\u{A644}', "2"),
+    ('\u{A645}', "\u{1A8}"),
+    ('\u{A647}', "i"),
+    ('\u{A64D}', "\u{3C9}"),
+    ('\u{A650}', "\u{42A}l"),
+    ('\u{A651}', "\u{2C9}bi"),
+    ('\u{A668}', "\u{298}"),
+    ('\u{A66F}', "\u{20E9}"),
+    ('\u{A67C}', "\u{306}"),
+    ('\u{A67E}', "\u{2C7}"),
+    ('\u{A695}', "h\u{314}"),
+    ('\u{A698}', "OO"),
+    ('\u{A699}', "oo"),
+    ('\u{A69A}', "\u{102A8}"),
+    ('\u{A6A1}', "\u{418}"),
+    ('\u{A6B0}', "\u{16B9}"),
+    ('\u{A6B1}', "\u{2C75}"),
+    ('\u{A6CD}', "\u{2A1}"),
+    ('\u{A6CE}', "\u{245}"),
+    ('\u{A6DB}', "\u{3A0}"),
+    ('\u{A6DF}', "V"),
+    ('\u{A6EB}', "?"),
+    ('\u{A6EF}', "2"),
+    ('\u{A6F0}', "\u{302}"),
+    ('\u{A6F1}', "\u{304}"),
+    ('\u{A6F4}', "\u{A6F3}\u{A6F3}"),
+    ('\u{A714}', "\u{2EB}"),
+    ('\u{A716}', "\u{2EA}"),
+    ('\u{A728}', "T3"),
+    ('\u{A729}', "t\u{21D}"),
+    ('\u{A731}', "s"),
+    ('\u{A732}', "AA"),
+    ('\u{A733}', "aa"),
+    ('\u{A734}', "AO"),
+    ('\u{A735}', "ao"),
+    ('\u{A736}', "AU"),
+    ('\u{A737}', "au"),
+    ('\u{A738}', "AV"),
+    ('\u{A739}', "av"),
+    ('\u{A73A}', "AV"),
+    ('\u{A73B}', "av"),
+    ('\u{A73C}', "AY"),
+    ('\u{A73D}', "ay"),
+    ('\u{A740}', "K\u{335}"),
+    ('\u{A74A}', "O\u{335}"),
+    ('\u{A74B}', "o\u{335}"),
+    ('\u{A74E}', "OO"),
+    ('\u{A74F}', "oo"),
+    ('\u{A75A}', "2"),
+    ('\u{A761}', "w\u{326}"),
+    ('\u{A76A}', "3"),
+    ('\u{A76B}', "\u{21D}"),
+    ('\u{A76E}', "9"),
+    ('\u{A777}', "tf"),
+    ('\u{A778}', "&"),
+    ('\u{A77A}', "\u{A779}"),
+    ('\u{A789}', ":"),
+    ('\u{A78C}', "'"),
+    ('\u{A78F}', "\u{B7}"),
+    ('\u{A795}', "\u{A727}"),
+    ('\u{A798}', "F"),
+    ('\u{A799}', "f"),
+    ('\u{A79A}', "\u{10412}"),
+    ('\u{A79B}', "\u{1043A}"),
+    ('\u{A79D}', "\u{29A}"),
+    ('\u{A79E}', "\u{A4E4}"),
+    ('\u{A79F}', "u"),
+    ('\u{A7AB}', "3"),
+    ('\u{A7B1}', "\u{A4D5}"),
+    ('\u{A7B2}', "J"),
+    ('\u{A7B3}', "X"),
+    ('\u{A7B4}', "B"),
+    ('\u{A7B5}', "\u{DF}"),
+    ('\u{A7B6}', "\u{A64C}"),
+    ('\u{A7B7}', "\u{3C9}"),
+    ('\u{A7F7}', "\u{30FC}"),
+    ('\u{A830}', "\u{964}"),
+    ('\u{A960}', "\u{1103}\u{1106}"),
+    ('\u{A961}', "\u{1103}\u{1107}"),
+    ('\u{A962}', "\u{1103}\u{1109}"),
+    ('\u{A963}', "\u{1103}\u{110C}"),
+    ('\u{A964}', "\u{1105}\u{1100}"),
+    ('\u{A965}', "\u{1105}\u{1100}\u{1100}"),
+    ('\u{A966}', "\u{1105}\u{1103}"),
+    ('\u{A967}', "\u{1105}\u{1103}\u{1103}"),
+    ('\u{A968}', "\u{1105}\u{1106}"),
+    ('\u{A969}', "\u{1105}\u{1107}"),
+    ('\u{A96A}', "\u{1105}\u{1107}\u{1107}"),
+    ('\u{A96B}', "\u{1105}\u{1107}\u{110B}"),
+    ('\u{A96C}', "\u{1105}\u{1109}"),
+    ('\u{A96D}', "\u{1105}\u{110C}"),
+    ('\u{A96E}', "\u{1105}\u{110F}"),
+    ('\u{A96F}', "\u{1106}\u{1100}"),
+    ('\u{A970}', "\u{1106}\u{1103}"),
+    ('\u{A971}', "\u{1106}\u{1109}"),
+    ('\u{A972}', "\u{1107}\u{1109}\u{1110}"),
+    ('\u{A973}', "\u{1107}\u{110F}"),
+    ('\u{A974}', "\u{1107}\u{1112}"),
+    ('\u{A975}', "\u{1109}\u{1109}\u{1107}"),
+    ('\u{A976}', "\u{110B}\u{1105}"),
+    ('\u{A977}', "\u{110B}\u{1112}"),
+    ('\u{A978}', "\u{110C}\u{110C}\u{1112}"),
+    ('\u{A979}', "\u{1110}\u{1110}"),
+    ('\u{A97A}', "\u{1111}\u{1112}"),
+    ('\u{A97B}', "\u{1112}\u{1109}"),
+    ('\u{A97C}', "\u{1159}\u{1159}"),
+    ('\u{A992}', "\u{2C3F}"),
+    ('\u{A9A3}', "\u{A99D}"),
+    ('\u{A9C6}', "\u{A9D0}"),
+    ('\u{A9CF}', "\u{662}"),
+    ('\u{AA53}', "\u{AA01}"),
+    ('\u{AA56}', "\u{AA23}"),
+    ('\u{AB32}', "e"),
+    ('\u{AB35}', "f"),
+    ('\u{AB3D}', "o"),
+    ('\u{AB3E}', "o\u{338}"),
+    ('\u{AB3F}', "\u{254}\u{338}"),
+    ('\u{AB41}', "\u{1DD}o\u{338}"),
+    ('\u{AB42}', "\u{1DD}o\u{335}"),
+    ('\u{AB47}', "r"),
+    ('\u{AB48}', "r"),
+    ('\u{AB4D}', "\u{283}"),
+    ('\u{AB4E}', "u"),
+    ('\u{AB52}', "u"),
+    ('\u{AB53}', "\u{3C7}"),
+    ('\u{AB55}', "\u{3C7}"),
+    ('\u{AB5A}', "y"),
+    ('\u{AB60}', "\u{459}"),
+    ('\u{AB62}', "\u{254}e"),
+    ('\u{AB63}', "uo"),
+    ('\u{AB70}', "\u{1D05}"),
+    ('\u{AB71}', "\u{280}"),
+    ('\u{AB72}', "\u{1D1B}"),
+    ('\u{AB74}', "o\u{31B}"),
+    ('\u{AB75}', "i"),
+    ('\u{AB7A}', "\u{1D00}"),
+    ('\u{AB7B}', "\u{1D0A}"),
+    ('\u{AB7C}', "\u{1D07}"),
+    ('\u{AB7E}', "\u{242}"),
+    ('\u{AB80}', "\u{2C76}"),
+    ('\u{AB81}', "r"),
+    ('\u{AB83}', "w"),
+    ('\u{AB87}', "\u{28D}"),
+    ('\u{AB8B}', "\u{29C}"),
+    ('\u{AB8E}', "o\u{335}"),
+    ('\u{AB90}', "\u{262}"),
+    ('\u{AB93}', "z"),
+    ('\u{AB9B}', "\u{A793}"),
+    ('\u{AB9C}', "u\u{335}"),
+    ('\u{AB9F}', "\u{185}"),
+    ('\u{ABA2}', "\u{280}"),
+    ('\u{ABA9}', "v"),
+    ('\u{ABAA}', "s"),
+    ('\u{ABAE}', "\u{29F}"),
+    ('\u{ABAF}', "c"),
+    ('\u{ABB2}', "\u{1D18}"),
+    ('\u{ABB6}', "\u{138}"),
+    ('\u{ABBB}', "o\u{335}"),
+    ('\u{D7B0}', "\u{1169}\u{1167}"),
+    ('\u{D7B1}', "\u{1169}\u{1169}\u{4E28}"),
+    ('\u{D7B2}', "\u{116D}\u{1161}"),
+    ('\u{D7B3}', "\u{116D}\u{1161}\u{4E28}"),
+    ('\u{D7B4}', "\u{116D}\u{1165}"),
+    ('\u{D7B5}', "\u{116E}\u{1167}"),
+    ('\u{D7B6}', "\u{116E}\u{4E28}\u{4E28}"),
+    ('\u{D7B7}', "\u{1172}\u{1161}\u{4E28}"),
+    ('\u{D7B8}', "\u{1172}\u{1169}"),
+    ('\u{D7B9}', "\u{30FC}\u{1161}"),
+    ('\u{D7BA}', "\u{30FC}\u{1165}"),
+    ('\u{D7BB}', "\u{30FC}\u{1165}\u{4E28}"),
+    ('\u{D7BC}', "\u{30FC}\u{1169}"),
+    ('\u{D7BD}', "\u{4E28}\u{1163}\u{1169}"),
+    ('\u{D7BE}', "\u{4E28}\u{1163}\u{4E28}"),
+    ('\u{D7BF}', "\u{4E28}\u{1167}"),
+    ('\u{D7C0}', "\u{4E28}\u{1167}\u{4E28}"),
+    ('\u{D7C1}', "\u{4E28}\u{1169}\u{4E28}"),
+    ('\u{D7C2}', "\u{4E28}\u{116D}"),
+    ('\u{D7C3}', "\u{4E28}\u{1172}"),
+    ('\u{D7C4}', "\u{4E28}\u{4E28}"),
+    ('\u{D7C5}', "\u{119E}\u{1161}"),
+    ('\u{D7C6}', "\u{119E}\u{1165}\u{4E28}"),
+    ('\u{D7CB}', "\u{1102}\u{1105}"),
+    ('\u{D7CC}', "\u{1102}\u{110E}"),
+    ('\u{D7CD}', "\u{1103}\u{1103}"),
+    ('\u{D7CE}', "\u{1103}\u{1103}\u{1107}"),
+    ('\u{D7CF}', "\u{1103}\u{1107}"),
+    ('\u{D7D0}', "\u{1103}\u{1109}"),
+    ('\u{D7D1}', "\u{1103}\u{1109}\u{1100}"),
+    ('\u{D7D2}', "\u{1103}\u{110C}"),
+    ('\u{D7D3}', "\u{1103}\u{110E}"),
+    ('\u{D7D4}', "\u{1103}\u{1110}"),
+    ('\u{D7D5}', "\u{1105}\u{1100}\u{1100}"),
+    ('\u{D7D6}', "\u{1105}\u{1100}\u{1112}"),
+    ('\u{D7D7}', "\u{1105}\u{1105}\u{110F}"),
+    ('\u{D7D8}', "\u{1105}\u{1106}\u{1112}"),
+    ('\u{D7D9}', "\u{1105}\u{1107}\u{1103}"),
+    ('\u{D7DA}', "\u{1105}\u{1107}\u{1111}"),
+    ('\u{D7DB}', "\u{1105}\u{114C}"),
+    ('\u{D7DC}', "\u{1105}\u{1159}\u{1112}"),
+    ('\u{D7DD}', "\u{1105}\u{110B}"),
+    ('\u{D7DE}', "\u{1106}\u{1102}"),
+    ('\u{D7DF}', "\u{1106}\u{1102}\u{1102}"),
+    ('\u{D7E0}', "\u{1106}\u{1106}"),
+    ('\u{D7E1}', "\u{1106}\u{1107}\u{1109}"),
+    ('\u{D7E2}', "\u{1106}\u{110C}"),
+    ('\u{D7E3}', "\u{1107}\u{1103}"),
+    ('\u{D7E4}', "\u{1107}\u{1105}\u{1111}"),
+    ('\u{D7E5}', "\u{1107}\u{1106}"),
+    ('\u{D7E6}', "\u{1107}\u{1107}"),
+    ('\u{D7E7}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{D7E8}', "\u{1107}\u{110C}"),
+    ('\u{D7E9}', "\u{1107}\u{110E}"),
+    ('\u{D7EA}', "\u{1109}\u{1106}"),
+    ('\u{D7EB}', "\u{1109}\u{1107}\u{110B}"),
+    ('\u{D7EC}', "\u{1109}\u{1109}\u{1100}"),
+    ('\u{D7ED}', "\u{1109}\u{1109}\u{1103}"),
+    ('\u{D7EE}', "\u{1109}\u{1140}"),
+    ('\u{D7EF}', "\u{1109}\u{110C}"),
+    ('\u{D7F0}', "\u{1109}\u{110E}"),
+    ('\u{D7F1}', "\u{1109}\u{1110}"),
+    ('\u{D7F2}', "\u{1105}\u{1112}"),
+    ('\u{D7F3}', "\u{1140}\u{1107}"),
+    ('\u{D7F4}', "\u{1140}\u{1107}\u{110B}"),
+    ('\u{D7F5}', "\u{114C}\u{1106}"),
+    ('\u{D7F6}', "\u{114C}\u{1112}"),
+    ('\u{D7F7}', "\u{110C}\u{1107}"),
+    ('\u{D7F8}', "\u{110C}\u{1107}\u{1107}"),
+    ('\u{D7F9}', "\u{110C}\u{110C}"),
+    ('\u{D7FA}', "\u{1111}\u{1109}"),
+    ('\u{D7FB}', "\u{1111}\u{1110}"),
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB06}', "st"),
+    ('\u{FB13}', "\u{574}\u{576}"),
+    ('\u{FB14}', "\u{574}\u{565}"),
+    ('\u{FB15}', "\u{574}\u{56B}"),
+    ('\u{FB16}', "\u{57E}\u{576}"),
+    ('\u{FB17}', "\u{574}\u{56D}"),
+    ('\u{FB20}', "\u{5E2}"),
+    ('\u{FB21}', "\u{5D0}"),
+    ('\u{FB22}', "\u{5D3}"),
+    ('\u{FB23}', "\u{5D4}"),
+    ('\u{FB24}', "\u{5DB}"),
+    ('\u{FB25}', "\u{5DC}"),
+    ('\u{FB26}', "\u{5DD}"),
+    ('\u{FB27}', "\u{5E8}"),
+    ('\u{FB28}', "\u{5EA}"),
+    ('\u{FB29}', "-\u{307}"),
+    ('\u{FB4F}', "\u{5D0}\u{5DC}"),
+    ('\u{FB50}', "\u{671}"),
+    ('\u{FB51}', "\u{671}"),
+    ('\u{FB52}', "\u{67B}"),
+    ('\u{FB53}', "\u{67B}"),
+    ('\u{FB54}', "\u{67B}"),
+    ('\u{FB55}', "\u{67B}"),
+    ('\u{FB56}', "\u{649}\u{6DB}"),
+    ('\u{FB57}', "\u{649}\u{6DB}"),
+    ('\u{FB58}', "\u{649}\u{6DB}"),
+    ('\u{FB59}', "\u{649}\u{6DB}"),
+    ('\u{FB5A}', "\u{680}"),
+    ('\u{FB5B}', "\u{680}"),
+    ('\u{FB5C}', "\u{680}"),
+    ('\u{FB5D}', "\u{680}"),
+    ('\u{FB5E}', "\u{67A}"),
+    ('\u{FB5F}', "\u{67A}"),
+    ('\u{FB60}', "\u{67A}"),
+    ('\u{FB61}', "\u{67A}"),
+    ('\u{FB62}', "\u{67F}"),
+    ('\u{FB63}', "\u{67F}"),
+    ('\u{FB64}', "\u{67F}"),
+    ('\u{FB65}', "\u{67F}"),
+    ('\u{FB66}', "\u{649}\u{615}"),
+    ('\u{FB67}', "\u{649}\u{615}"),
+    ('\u{FB68}', "\u{649}\u{615}"),
+    ('\u{FB69}', "\u{649}\u{615}"),
+    ('\u{FB6A}', "\u{6A1}\u{6DB}"),
+    ('\u{FB6B}', "\u{6A1}\u{6DB}"),
+    ('\u{FB6C}', "\u{6A1}\u{6DB}"),
+    ('\u{FB6D}', "\u{6A1}\u{6DB}"),
+    ('\u{FB6E}', "\u{6A6}"),
+    ('\u{FB6F}', "\u{6A6}"),
+    ('\u{FB70}', "\u{6A6}"),
+    ('\u{FB71}', "\u{6A6}"),
+    ('\u{FB72}', "\u{684}"),
+    ('\u{FB73}', "\u{684}"),
+    ('\u{FB74}', "\u{684}"),
+    ('\u{FB75}', "\u{684}"),
+    ('\u{FB76}', "\u{683}"),
+    ('\u{FB77}', "\u{683}"),
+    ('\u{FB78}', "\u{683}"),
+    ('\u{FB79}', "\u{683}"),
+    ('\u{FB7A}', "\u{686}"),
+    ('\u{FB7B}', "\u{686}"),
+    ('\u{FB7C}', "\u{686}"),
+    ('\u{FB7D}', "\u{686}"),
+    ('\u{FB7E}', "\u{687}"),
+    ('\u{FB7F}', "\u{687}"),
+    ('\u{FB80}', "\u{687}"),
+    ('\u{FB81}', "\u{687}"),
+    ('\u{FB82}', "\u{68D}"),
+    ('\u{FB83}', "\u{68D}"),
+    ('\u{FB84}', "\u{68C}"),
+    ('\u{FB85}', "\u{68C}"),
+    ('\u{FB86}', "\u{62F}\u{6DB}"),
+    ('\u{FB87}', "\u{62F}\u{6DB}"),
+    ('\u{FB88}', "\u{62F}\u{615}"),
+    ('\u{FB89}', "\u{62F}\u{615}"),
+    ('\u{FB8A}', "\u{631}\u{6DB}"),
+    ('\u{FB8B}', "\u{631}\u{6DB}"),
+    ('\u{FB8C}', "\u{631}\u{615}"),
+    ('\u{FB8D}', "\u{631}\u{615}"),
+    ('\u{FB8E}', "\u{643}"),
+    ('\u{FB8F}', "\u{643}"),
+    ('\u{FB90}', "\u{643}"),
+    ('\u{FB91}', "\u{643}"),
+    ('\u{FB92}', "\u{6AF}"),
+    ('\u{FB93}', "\u{6AF}"),
+    ('\u{FB94}', "\u{6AF}"),
+    ('\u{FB95}', "\u{6AF}"),
+    ('\u{FB96}', "\u{6B3}"),
+    ('\u{FB97}', "\u{6B3}"),
+    ('\u{FB98}', "\u{6B3}"),
+    ('\u{FB99}', "\u{6B3}"),
+    ('\u{FB9A}', "\u{6B1}"),
+    ('\u{FB9B}', "\u{6B1}"),
+    ('\u{FB9C}', "\u{6B1}"),
+    ('\u{FB9D}', "\u{6B1}"),
+    ('\u{FB9E}', "\u{649}"),
+    ('\u{FB9F}', "\u{649}"),
+    ('\u{FBA0}', "\u{649}\u{615}"),
+    ('\u{FBA1}', "\u{649}\u{615}"),
+    ('\u{FBA2}', "\u{649}\u{615}"),
+    ('\u{FBA3}', "\u{649}\u{615}"),
+    ('\u{FBA4}', "o\u{654}"),
+    ('\u{FBA5}', "o\u{654}"),
+    ('\u{FBA6}', "o"),
+    ('\u{FBA7}', "o"),
+    ('\u{FBA8}', "o"),
+    ('\u{FBA9}', "o"),
+    ('\u{FBAA}', "o"),
+    ('\u{FBAB}', "o"),
+    ('\u{FBAC}', "o"),
+    ('\u{FBAD}', "o"),
+    ('\u{FBAE}', "\u{649}"),
+    ('\u{FBAF}', "\u{649}"),
+    ('\u{FBB0}', "\u{649}\u{654}"),
+    ('\u{FBB1}', "\u{649}\u{654}"),
+    ('\u{FBD3}', "\u{643}\u{6DB}"),
+    ('\u{FBD4}', "\u{643}\u{6DB}"),
+    ('\u{FBD5}', "\u{643}\u{6DB}"),
+    ('\u{FBD6}', "\u{643}\u{6DB}"),
+    ('\u{FBD7}', "\u{648}\u{313}"),
+    ('\u{FBD8}', "\u{648}\u{313}"),
+    ('\u{FBD9}', "\u{648}\u{306}"),
+    ('\u{FBDA}', "\u{648}\u{306}"),
+    ('\u{FBDB}', "\u{648}\u{670}"),
+    ('\u{FBDC}', "\u{648}\u{670}"),
+    ('\u{FBDD}', "\u{648}\u{313}\u{674}"),
+    ('\u{FBDE}', "\u{648}\u{6DB}"),
+    ('\u{FBDF}', "\u{648}\u{6DB}"),
+    ('\u{FBE0}', "\u{6C5}"),
+    ('\u{FBE1}', "\u{6C5}"),
+    ('\u{FBE2}', "\u{648}\u{302}"),
+    ('\u{FBE3}', "\u{648}\u{302}"),
+    ('\u{FBE4}', "\u{67B}"),
+    ('\u{FBE5}', "\u{67B}"),
+    ('\u{FBE6}', "\u{67B}"),
+    ('\u{FBE7}', "\u{67B}"),
+    ('\u{FBE8}', "\u{649}"),
+    ('\u{FBE9}', "\u{649}"),
+    ('\u{FBEA}', "\u{649}\u{674}l"),
+    ('\u{FBEB}', "\u{649}\u{674}l"),
+    ('\u{FBEC}', "\u{649}\u{674}o"),
+    ('\u{FBED}', "\u{649}\u{674}o"),
+    ('\u{FBEE}', "\u{649}\u{674}\u{648}"),
+    ('\u{FBEF}', "\u{649}\u{674}\u{648}"),
+    ('\u{FBF0}', "\u{649}\u{674}\u{648}\u{313}"),
+    ('\u{FBF1}', "\u{649}\u{674}\u{648}\u{313}"),
+    ('\u{FBF2}', "\u{649}\u{674}\u{648}\u{306}"),
+    ('\u{FBF3}', "\u{649}\u{674}\u{648}\u{306}"),
+    ('\u{FBF4}', "\u{649}\u{674}\u{648}\u{670}"),
+    ('\u{FBF5}', "\u{649}\u{674}\u{648}\u{670}"),
+    ('\u{FBF6}', "\u{649}\u{674}\u{67B}"),
+    ('\u{FBF7}', "\u{649}\u{674}\u{67B}"),
+    ('\u{FBF8}', "\u{649}\u{674}\u{67B}"),
+    ('\u{FBF9}', "\u{649}\u{674}\u{649}"),
+    ('\u{FBFA}', "\u{649}\u{674}\u{649}"),
+    ('\u{FBFB}', "\u{649}\u{674}\u{649}"),
+    ('\u{FBFC}', "\u{649}"),
+    ('\u{FBFD}', "\u{649}"),
+    ('\u{FBFE}', "\u{649}"),
+    ('\u{FBFF}', "\u{649}"),
+    ('\u{FC00}', "\u{649}\u{674}\u{62C}"),
+    ('\u{FC01}', "\u{649}\u{674}\u{62D}"),
+    ('\u{FC02}', "\u{649}\u{674}\u{645}"),
+    ('\u{FC03}', "\u{649}\u{674}\u{649}"),
+    ('\u{FC04}', "\u{649}\u{674}\u{649}"),
+    ('\u{FC05}', "\u{628}\u{62C}"),
+    ('\u{FC06}', "\u{628}\u{62D}"),
+    ('\u{FC07}', "\u{628}\u{62E}"),
+    ('\u{FC08}', "\u{628}\u{645}"),
+    ('\u{FC09}', "\u{628}\u{649}"),
+    ('\u{FC0A}', "\u{628}\u{649}"),
+    ('\u{FC0B}', "\u{62A}\u{62C}"),
+    ('\u{FC0C}', "\u{62A}\u{62D}"),
+    ('\u{FC0D}', "\u{62A}\u{62E}"),
+    ('\u{FC0E}', "\u{62A}\u{645}"),
+    ('\u{FC0F}', "\u{62A}\u{649}"),
+    ('\u{FC10}', "\u{62A}\u{649}"),
+    ('\u{FC11}', "\u{649}\u{6DB}\u{62C}"),
+    ('\u{FC12}', "\u{649}\u{6DB}\u{645}"),
+    ('\u{FC13}', "\u{649}\u{6DB}\u{649}"),
+    ('\u{FC14}', "\u{649}\u{6DB}\u{649}"),
+    ('\u{FC15}', "\u{62C}\u{62D}"),
+    ('\u{FC16}', "\u{62C}\u{645}"),
+    ('\u{FC17}', "\u{62D}\u{62C}"),
+    ('\u{FC18}', "\u{62D}\u{645}"),
+    ('\u{FC19}', "\u{62E}\u{62C}"),
+    ('\u{FC1A}', "\u{62E}\u{62D}"),
+    ('\u{FC1B}', "\u{62E}\u{645}"),
+    ('\u{FC1C}', "\u{633}\u{62C}"),
+    ('\u{FC1D}', "\u{633}\u{62D}"),
+    ('\u{FC1E}', "\u{633}\u{62E}"),
+    ('\u{FC1F}', "\u{633}\u{645}"),
+    ('\u{FC20}', "\u{635}\u{62D}"),
+    ('\u{FC21}', "\u{635}\u{645}"),
+    ('\u{FC22}', "\u{636}\u{62C}"),
+    ('\u{FC23}', "\u{636}\u{62D}"),
+    ('\u{FC24}', "\u{636}\u{62E}"),
+    ('\u{FC25}', "\u{636}\u{645}"),
+    ('\u{FC26}', "\u{637}\u{62D}"),
+    ('\u{FC27}', "\u{637}\u{645}"),
+    ('\u{FC28}', "\u{638}\u{645}"),
+    ('\u{FC29}', "\u{639}\u{62C}"),
+    ('\u{FC2A}', "\u{639}\u{645}"),
+    ('\u{FC2B}', "\u{63A}\u{62C}"),
+    ('\u{FC2C}', "\u{63A}\u{645}"),
+    ('\u{FC2D}', "\u{641}\u{62C}"),
+    ('\u{FC2E}', "\u{641}\u{62D}"),
+    ('\u{FC2F}', "\u{641}\u{62E}"),
+    ('\u{FC30}', "\u{641}\u{645}"),
+    ('\u{FC31}', "\u{641}\u{649}"),
+    ('\u{FC32}', "\u{641}\u{649}"),
+    ('\u{FC33}', "\u{642}\u{62D}"),
+    ('\u{FC34}', "\u{642}\u{645}"),
+    ('\u{FC35}', "\u{642}\u{649}"),
+    ('\u{FC36}', "\u{642}\u{649}"),
+    ('\u{FC37}', "\u{643}l"),
+    ('\u{FC38}', "\u{643}\u{62C}"),
+    ('\u{FC39}', "\u{643}\u{62D}"),
+    ('\u{FC3A}', "\u{643}\u{62E}"),
+    ('\u{FC3B}', "\u{643}\u{644}"),
+    ('\u{FC3C}', "\u{643}\u{645}"),
+    ('\u{FC3D}', "\u{643}\u{649}"),
+    ('\u{FC3E}', "\u{643}\u{649}"),
+    ('\u{FC3F}', "\u{644}\u{62C}"),
+    ('\u{FC40}', "\u{644}\u{62D}"),
+    ('\u{FC41}', "\u{644}\u{62E}"),
+    ('\u{FC42}', "\u{644}\u{645}"),
+    ('\u{FC43}', "\u{644}\u{649}"),
+    ('\u{FC44}', "\u{644}\u{649}"),
+    ('\u{FC45}', "\u{645}\u{62C}"),
+    ('\u{FC46}', "\u{645}\u{62D}"),
+    ('\u{FC47}', "\u{645}\u{62E}"),
+    ('\u{FC48}', "\u{645}\u{645}"),
+    ('\u{FC49}', "\u{645}\u{649}"),
+    ('\u{FC4A}', "\u{645}\u{649}"),
+    ('\u{FC4B}', "\u{628}\u{62E}"),
+    ('\u{FC4C}', "\u{646}\u{62D}"),
+    ('\u{FC4D}', "\u{646}\u{62E}"),
+    ('\u{FC4E}', "\u{646}\u{645}"),
+    ('\u{FC4F}', "\u{646}\u{649}"),
+    ('\u{FC50}', "\u{646}\u{649}"),
+    ('\u{FC51}', "o\u{62C}"),
+    ('\u{FC52}', "o\u{645}"),
+    ('\u{FC53}', "o\u{649}"),
+    ('\u{FC54}', "o\u{649}"),
+    ('\u{FC55}', "\u{649}\u{62C}"),
+    ('\u{FC56}', "\u{649}\u{62D}"),
+    ('\u{FC57}', "\u{649}\u{62E}"),
+    ('\u{FC58}', "\u{649}\u{645}"),
+    ('\u{FC59}', "\u{649}\u{649}"),
+    ('\u{FC5A}', "\u{649}\u{649}"),
+    ('\u{FC5B}', "\u{630}\u{670}"),
+    ('\u{FC5C}', "\u{631}\u{670}"),
+    ('\u{FC5D}', "\u{649}\u{670}"),
+    ('\u{FC5E}', "\u{FE72}\u{651}"),
+    ('\u{FC5F}', "\u{FE74}\u{651}"),
+    ('\u{FC60}', "\u{FE76}\u{651}"),
+    ('\u{FC61}', "\u{FE78}\u{651}"),
+    ('\u{FC62}', "\u{FE7A}\u{651}"),
+    ('\u{FC63}', "\u{FE7C}\u{670}"),
+    ('\u{FC64}', "\u{649}\u{674}\u{631}"),
+    ('\u{FC65}', "\u{649}\u{674}\u{632}"),
+    ('\u{FC66}', "\u{649}\u{674}\u{645}"),
+    ('\u{FC67}', "\u{649}\u{674}\u{646}"),
+    ('\u{FC68}', "\u{649}\u{674}\u{649}"),
+    ('\u{FC69}', "\u{649}\u{674}\u{649}"),
+    ('\u{FC6A}', "\u{628}\u{631}"),
+    ('\u{FC6B}', "\u{628}\u{632}"),
+    ('\u{FC6C}', "\u{628}\u{645}"),
+    ('\u{FC6D}', "\u{628}\u{646}"),
+    ('\u{FC6E}', "\u{628}\u{649}"),
+    ('\u{FC6F}', "\u{628}\u{649}"),
+    ('\u{FC70}', "\u{62A}\u{631}"),
+    ('\u{FC71}', "\u{62A}\u{632}"),
+    ('\u{FC72}', "\u{62A}\u{645}"),
+    ('\u{FC73}', "\u{62A}\u{646}"),
+    ('\u{FC74}', "\u{62A}\u{649}"),
+    ('\u{FC75}', "\u{62A}\u{649}"),
+    ('\u{FC76}', "\u{649}\u{6DB}\u{631}"),
+    ('\u{FC77}', "\u{649}\u{6DB}\u{632}"),
+    ('\u{FC78}', "\u{649}\u{6DB}\u{645}"),
+    ('\u{FC79}', "\u{649}\u{6DB}\u{646}"),
+    ('\u{FC7A}', "\u{649}\u{6DB}\u{649}"),
+    ('\u{FC7B}', "\u{649}\u{6DB}\u{649}"),
+    ('\u{FC7C}', "\u{641}\u{649}"),
+    ('\u{FC7D}', "\u{641}\u{649}"),
+    ('\u{FC7E}', "\u{642}\u{649}"),
+    ('\u{FC7F}', "\u{642}\u{649}"),
+    ('\u{FC80}', "\u{643}l"),
+    ('\u{FC81}', "\u{643}\u{644}"),
+    ('\u{FC82}', "\u{643}\u{645}"),
+    ('\u{FC83}', "\u{643}\u{649}"),
+    ('\u{FC84}', "\u{643}\u{649}"),
+    ('\u{FC85}', "\u{644}\u{645}"),
+    ('\u{FC86}', "\u{644}\u{649}"),
+    ('\u{FC87}', "\u{644}\u{649}"),
+    ('\u{FC88}', "\u{645}l"),
+    ('\u{FC89}', "\u{645}\u{645}"),
+    ('\u{FC8A}', "\u{646}\u{631}"),
+    ('\u{FC8B}', "\u{646}\u{632}"),
+    ('\u{FC8C}', "\u{646}\u{645}"),
+    ('\u{FC8D}', "\u{646}\u{646}"),
+    ('\u{FC8E}', "\u{646}\u{649}"),
+    ('\u{FC8F}', "\u{646}\u{649}"),
+    ('\u{FC90}', "\u{649}\u{670}"),
+    ('\u{FC91}', "\u{649}\u{631}"),
+    ('\u{FC92}', "\u{649}\u{632}"),
+    ('\u{FC93}', "\u{649}\u{645}"),
+    ('\u{FC94}', "\u{649}\u{646}"),
+    ('\u{FC95}', "\u{649}\u{649}"),
+    ('\u{FC96}', "\u{649}\u{649}"),
+    ('\u{FC97}', "\u{649}\u{674}\u{62C}"),
+    ('\u{FC98}', "\u{649}\u{674}\u{62D}"),
+    ('\u{FC99}', "\u{649}\u{674}\u{62E}"),
+    ('\u{FC9A}', "\u{649}\u{674}\u{645}"),
+    ('\u{FC9B}', "\u{649}\u{674}o"),
+    ('\u{FC9C}', "\u{628}\u{62C}"),
+    ('\u{FC9D}', "\u{628}\u{62D}"),
+    ('\u{FC9E}', "\u{628}\u{62E}"),
+    ('\u{FC9F}', "\u{628}\u{645}"),
+    ('\u{FCA0}', "\u{628}o"),
+    ('\u{FCA1}', "\u{62A}\u{62C}"),
+    ('\u{FCA2}', "\u{62A}\u{62D}"),
+    ('\u{FCA3}', "\u{62A}\u{62E}"),
+    ('\u{FCA4}', "\u{62A}\u{645}"),
+    ('\u{FCA5}', "\u{62A}o"),
+    ('\u{FCA6}', "\u{649}\u{6DB}\u{645}"),
+    ('\u{FCA7}', "\u{62C}\u{62D}"),
+    ('\u{FCA8}', "\u{62C}\u{645}"),
+    ('\u{FCA9}', "\u{62D}\u{62C}"),
+    ('\u{FCAA}', "\u{62D}\u{645}"),
+    ('\u{FCAB}', "\u{62E}\u{62C}"),
+    ('\u{FCAC}', "\u{62E}\u{645}"),
+    ('\u{FCAD}', "\u{633}\u{62C}"),
+    ('\u{FCAE}', "\u{633}\u{62D}"),
+    ('\u{FCAF}', "\u{633}\u{62E}"),
+    ('\u{FCB0}', "\u{633}\u{645}"),
+    ('\u{FCB1}', "\u{635}\u{62D}"),
+    ('\u{FCB2}', "\u{635}\u{62E}"),
+    ('\u{FCB3}', "\u{635}\u{645}"),
+    ('\u{FCB4}', "\u{636}\u{62C}"),
+    ('\u{FCB5}', "\u{636}\u{62D}"),
+    ('\u{FCB6}', "\u{636}\u{62E}"),
+    ('\u{FCB7}', "\u{636}\u{645}"),
+    ('\u{FCB8}', "\u{637}\u{62D}"),
+    ('\u{FCB9}', "\u{638}\u{645}"),
+    ('\u{FCBA}', "\u{639}\u{62C}"),
+    ('\u{FCBB}', "\u{639}\u{645}"),
+    ('\u{FCBC}', "\u{63A}\u{62C}"),
+    ('\u{FCBD}', "\u{63A}\u{645}"),
+    ('\u{FCBE}', "\u{641}\u{62C}"),
+    ('\u{FCBF}', "\u{641}\u{62D}"),
+    ('\u{FCC0}', "\u{641}\u{62E}"),
+    ('\u{FCC1}', "\u{641}\u{645}"),
+    ('\u{FCC2}', "\u{642}\u{62D}"),
+    ('\u{FCC3}', "\u{642}\u{645}"),
+    ('\u{FCC4}', "\u{643}\u{62C}"),
+    ('\u{FCC5}', "\u{643}\u{62D}"),
+    ('\u{FCC6}', "\u{643}\u{62E}"),
+    ('\u{FCC7}', "\u{643}\u{644}"),
+    ('\u{FCC8}', "\u{643}\u{645}"),
+    ('\u{FCC9}', "\u{644}\u{62C}"),
+    ('\u{FCCA}', "\u{644}\u{62D}"),
+    ('\u{FCCB}', "\u{644}\u{62E}"),
+    ('\u{FCCC}', "\u{644}\u{645}"),
+    ('\u{FCCD}', "\u{644}o"),
+    ('\u{FCCE}', "\u{645}\u{62C}"),
+    ('\u{FCCF}', "\u{645}\u{62D}"),
+    ('\u{FCD0}', "\u{645}\u{62E}"),
+    ('\u{FCD1}', "\u{645}\u{645}"),
+    ('\u{FCD2}', "\u{628}\u{62E}"),
+    ('\u{FCD3}', "\u{646}\u{62D}"),
+    ('\u{FCD4}', "\u{646}\u{62E}"),
+    ('\u{FCD5}', "\u{646}\u{645}"),
+    ('\u{FCD6}', "\u{646}o"),
+    ('\u{FCD7}', "o\u{62C}"),
+    ('\u{FCD8}', "o\u{645}"),
+    ('\u{FCD9}', "o\u{670}"),
+    ('\u{FCDA}', "\u{649}\u{62C}"),
+    ('\u{FCDB}', "\u{649}\u{62D}"),
+    ('\u{FCDC}', "\u{649}\u{62E}"),
+    ('\u{FCDD}', "\u{649}\u{645}"),
+    ('\u{FCDE}', "\u{649}o"),
+    ('\u{FCDF}', "\u{649}\u{674}\u{645}"),
+    ('\u{FCE0}', "\u{649}\u{674}o"),
+    ('\u{FCE1}', "\u{628}\u{645}"),
+    ('\u{FCE2}', "\u{628}o"),
+    ('\u{FCE3}', "\u{62A}\u{645}"),
+    ('\u{FCE4}', "\u{62A}o"),
+    ('\u{FCE5}', "\u{649}\u{6DB}\u{645}"),
+    ('\u{FCE6}', "\u{649}\u{6DB}o"),
+    ('\u{FCE7}', "\u{633}\u{645}"),
+    ('\u{FCE8}', "\u{633}o"),
+    ('\u{FCE9}', "\u{633}\u{6DB}\u{645}"),
+    ('\u{FCEA}', "\u{633}\u{6DB}o"),
+    ('\u{FCEB}', "\u{643}\u{644}"),
+    ('\u{FCEC}', "\u{643}\u{645}"),
+    ('\u{FCED}', "\u{644}\u{645}"),
+    ('\u{FCEE}', "\u{646}\u{645}"),
+    ('\u{FCEF}', "\u{646}o"),
+    ('\u{FCF0}', "\u{649}\u{645}"),
+    ('\u{FCF1}', "\u{649}o"),
+    ('\u{FCF2}', "\u{FE77}\u{651}"),
+    ('\u{FCF3}', "\u{FE79}\u{651}"),
+    ('\u{FCF4}', "\u{FE7B}\u{651}"),
+    ('\u{FCF5}', "\u{637}\u{649}"),
+    ('\u{FCF6}', "\u{637}\u{649}"),
+    ('\u{FCF7}', "\u{639}\u{649}"),
+    ('\u{FCF8}', "\u{639}\u{649}"),
+    ('\u{FCF9}', "\u{63A}\u{649}"),
+    ('\u{FCFA}', "\u{63A}\u{649}"),
+    ('\u{FCFB}', "\u{633}\u{649}"),
+    ('\u{FCFC}', "\u{633}\u{649}"),
+    ('\u{FCFD}', "\u{633}\u{6DB}\u{649}"),
+    ('\u{FCFE}', "\u{633}\u{6DB}\u{649}"),
+    ('\u{FCFF}', "\u{62D}\u{649}"),
+    ('\u{FD00}', "\u{62D}\u{649}"),
+    ('\u{FD01}', "\u{62C}\u{649}"),
+    ('\u{FD02}', "\u{62C}\u{649}"),
+    ('\u{FD03}', "\u{62E}\u{649}"),
+    ('\u{FD04}', "\u{62E}\u{649}"),
+    ('\u{FD05}', "\u{635}\u{649}"),
+    ('\u{FD06}', "\u{635}\u{649}"),
+    ('\u{FD07}', "\u{636}\u{649}"),
+    ('\u{FD08}', "\u{636}\u{649}"),
+    ('\u{FD09}', "\u{633}\u{6DB}\u{62C}"),
+    ('\u{FD0A}', "\u{633}\u{6DB}\u{62D}"),
+    ('\u{FD0B}', "\u{633}\u{6DB}\u{62E}"),
+    ('\u{FD0C}', "\u{633}\u{6DB}\u{645}"),
+    ('\u{FD0D}', "\u{633}\u{6DB}\u{631}"),
+    ('\u{FD0E}', "\u{633}\u{631}"),
+    ('\u{FD0F}', "\u{635}\u{631}"),
+    ('\u{FD10}', "\u{636}\u{631}"),
+    ('\u{FD11}', "\u{637}\u{649}"),
+    ('\u{FD12}', "\u{637}\u{649}"),
+    ('\u{FD13}', "\u{639}\u{649}"),
+    ('\u{FD14}', "\u{639}\u{649}"),
+    ('\u{FD15}', "\u{63A}\u{649}"),
+    ('\u{FD16}', "\u{63A}\u{649}"),
+    ('\u{FD17}', "\u{633}\u{649}"),
+    ('\u{FD18}', "\u{633}\u{649}"),
+    ('\u{FD19}', "\u{633}\u{6DB}\u{649}"),
+    ('\u{FD1A}', "\u{633}\u{6DB}\u{649}"),
+    ('\u{FD1B}', "\u{62D}\u{649}"),
+    ('\u{FD1C}', "\u{62D}\u{649}"),
+    ('\u{FD1D}', "\u{62C}\u{649}"),
+    ('\u{FD1E}', "\u{62C}\u{649}"),
+    ('\u{FD1F}', "\u{62E}\u{649}"),
+    ('\u{FD20}', "\u{62E}\u{649}"),
+    ('\u{FD21}', "\u{635}\u{649}"),
+    ('\u{FD22}', "\u{635}\u{649}"),
+    ('\u{FD23}', "\u{636}\u{649}"),
+    ('\u{FD24}', "\u{636}\u{649}"),
+    ('\u{FD25}', "\u{633}\u{6DB}\u{62C}"),
+    ('\u{FD26}', "\u{633}\u{6DB}\u{62D}"),
+    ('\u{FD27}', "\u{633}\u{6DB}\u{62E}"),
+    ('\u{FD28}', "\u{633}\u{6DB}\u{645}"),
+    ('\u{FD29}', "\u{633}\u{6DB}\u{631}"),
+    ('\u{FD2A}', "\u{633}\u{631}"),
+    ('\u{FD2B}', "\u{635}\u{631}"),
+    ('\u{FD2C}', "\u{636}\u{631}"),
+    ('\u{FD2D}', "\u{633}\u{6DB}\u{62C}"),
+    ('\u{FD2E}', "\u{633}\u{6DB}\u{62D}"),
+    ('\u{FD2F}', "\u{633}\u{6DB}\u{62E}"),
+    ('\u{FD30}', "\u{633}\u{6DB}\u{645}"),
+    ('\u{FD31}', "\u{633}o"),
+    ('\u{FD32}', "\u{633}\u{6DB}o"),
+    ('\u{FD33}', "\u{637}\u{645}"),
+    ('\u{FD34}', "\u{633}\u{62C}"),
+    ('\u{FD35}', "\u{633}\u{62D}"),
+    ('\u{FD36}', "\u{633}\u{62E}"),
+    ('\u{FD37}', "\u{633}\u{6DB}\u{62C}"),
+    ('\u{FD38}', "\u{633}\u{6DB}\u{62D}"),
+    ('\u{FD39}', "\u{633}\u{6DB}\u{62E}"),
+    ('\u{FD3A}', "\u{637}\u{645}"),
+    ('\u{FD3B}', "\u{638}\u{645}"),
+    ('\u{FD3C}', "l\u{30B}"),
+    ('\u{FD3D}', "l\u{30B}"),
+    ('\u{FD3E}', "("),
+    ('\u{FD3F}', ")"),
+    ('\u{FD50}', "\u{62A}\u{62C}\u{645}"),
+    ('\u{FD51}', "\u{62A}\u{62D}\u{62C}"),
+    ('\u{FD52}', "\u{62A}\u{62D}\u{62C}"),
+    ('\u{FD53}', "\u{62A}\u{62D}\u{645}"),
+    ('\u{FD54}', "\u{62A}\u{62E}\u{645}"),
+    ('\u{FD55}', "\u{62A}\u{645}\u{62C}"),
+    ('\u{FD56}', "\u{62A}\u{645}\u{62D}"),
+    ('\u{FD57}', "\u{62A}\u{645}\u{62E}"),
+    ('\u{FD58}', "\u{62C}\u{645}\u{62D}"),
+    ('\u{FD59}', "\u{62C}\u{645}\u{62D}"),
+    ('\u{FD5A}', "\u{62D}\u{645}\u{649}"),
+    ('\u{FD5B}', "\u{62D}\u{645}\u{649}"),
+    ('\u{FD5C}', "\u{633}\u{62D}\u{62C}"),
+    ('\u{FD5D}', "\u{633}\u{62C}\u{62D}"),
+    ('\u{FD5E}', "\u{633}\u{62C}\u{649}"),
+    ('\u{FD5F}', "\u{633}\u{645}\u{62D}"),
+    ('\u{FD60}', "\u{633}\u{645}\u{62D}"),
+    ('\u{FD61}', "\u{633}\u{645}\u{62C}"),
+    ('\u{FD62}', "\u{633}\u{645}\u{645}"),
+    ('\u{FD63}', "\u{633}\u{645}\u{645}"),
+    ('\u{FD64}', "\u{635}\u{62D}\u{62D}"),
+    ('\u{FD65}', "\u{635}\u{62D}\u{62D}"),
+    ('\u{FD66}', "\u{635}\u{645}\u{645}"),
+    ('\u{FD67}', "\u{633}\u{6DB}\u{62D}\u{645}"),
+    ('\u{FD68}', "\u{633}\u{6DB}\u{62D}\u{645}"),
+    ('\u{FD69}', "\u{633}\u{6DB}\u{62C}\u{649}"),
+    ('\u{FD6A}', "\u{633}\u{6DB}\u{645}\u{62E}"),
+    ('\u{FD6B}', "\u{633}\u{6DB}\u{645}\u{62E}"),
+    ('\u{FD6C}', "\u{633}\u{6DB}\u{645}\u{645}"),
+    ('\u{FD6D}', "\u{633}\u{6DB}\u{645}\u{645}"),
+    ('\u{FD6E}', "\u{636}\u{62D}\u{649}"),
+    ('\u{FD6F}', "\u{636}\u{62E}\u{645}"),
+    ('\u{FD70}', "\u{636}\u{62E}\u{645}"),
+    ('\u{FD71}', "\u{637}\u{645}\u{62D}"),
+    ('\u{FD72}', "\u{637}\u{645}\u{62D}"),
+    ('\u{FD73}', "\u{637}\u{645}\u{645}"),
+    ('\u{FD74}', "\u{637}\u{645}\u{649}"),
+    ('\u{FD75}', "\u{639}\u{62C}\u{645}"),
+    ('\u{FD76}', "\u{639}\u{645}\u{645}"),
+    ('\u{FD77}', "\u{639}\u{645}\u{645}"),
+    ('\u{FD78}', "\u{639}\u{645}\u{649}"),
+    ('\u{FD79}', "\u{63A}\u{645}\u{645}"),
+    ('\u{FD7A}', "\u{63A}\u{645}\u{649}"),
+    ('\u{FD7B}', "\u{63A}\u{645}\u{649}"),
+    ('\u{FD7C}', "\u{641}\u{62E}\u{645}"),
+    ('\u{FD7D}', "\u{641}\u{62E}\u{645}"),
+    ('\u{FD7E}', "\u{642}\u{645}\u{62D}"),
+    ('\u{FD7F}', "\u{642}\u{645}\u{645}"),
+    ('\u{FD80}', "\u{644}\u{62D}\u{645}"),
+    ('\u{FD81}', "\u{644}\u{62D}\u{649}"),
+    ('\u{FD82}', "\u{644}\u{62D}\u{649}"),
+    ('\u{FD83}', "\u{644}\u{62C}\u{62C}"),
+    ('\u{FD84}', "\u{644}\u{62C}\u{62C}"),
+    ('\u{FD85}', "\u{644}\u{62E}\u{645}"),
+    ('\u{FD86}', "\u{644}\u{62E}\u{645}"),
+    ('\u{FD87}', "\u{644}\u{645}\u{62D}"),
+    ('\u{FD88}', "\u{644}\u{645}\u{62D}"),
+    ('\u{FD89}', "\u{645}\u{62D}\u{62C}"),
+    ('\u{FD8A}', "\u{645}\u{62D}\u{645}"),
+    ('\u{FD8B}', "\u{645}\u{62D}\u{649}"),
+    ('\u{FD8C}', "\u{645}\u{62C}\u{62D}"),
+    ('\u{FD8D}', "\u{645}\u{62C}\u{645}"),
+    ('\u{FD8E}', "\u{645}\u{62E}\u{62C}"),
+    ('\u{FD8F}', "\u{645}\u{62E}\u{645}"),
+    ('\u{FD92}', "\u{645}\u{62C}\u{62E}"),
+    ('\u{FD93}', "o\u{645}\u{62C}"),
+    ('\u{FD94}', "o\u{645}\u{645}"),
+    ('\u{FD95}', "\u{646}\u{62D}\u{645}"),
+    ('\u{FD96}', "\u{646}\u{62D}\u{649}"),
+    ('\u{FD97}', "\u{646}\u{62C}\u{645}"),
+    ('\u{FD98}', "\u{646}\u{62C}\u{645}"),
+    ('\u{FD99}', "\u{646}\u{62C}\u{649}"),
+    ('\u{FD9A}', "\u{646}\u{645}\u{649}"),
+    ('\u{FD9B}', "\u{646}\u{645}\u{649}"),
+    ('\u{FD9C}', "\u{649}\u{645}\u{645}"),
+    ('\u{FD9D}', "\u{649}\u{645}\u{645}"),
+    ('\u{FD9E}', "\u{628}\u{62E}\u{649}"),
+    ('\u{FD9F}', "\u{62A}\u{62C}\u{649}"),
+    ('\u{FDA0}', "\u{62A}\u{62C}\u{649}"),
+    ('\u{FDA1}', "\u{62A}\u{62E}\u{649}"),
+    ('\u{FDA2}', "\u{62A}\u{62E}\u{649}"),
+    ('\u{FDA3}', "\u{62A}\u{645}\u{649}"),
+    ('\u{FDA4}', "\u{62A}\u{645}\u{649}"),
+    ('\u{FDA5}', "\u{62C}\u{645}\u{649}"),
+    ('\u{FDA6}', "\u{62C}\u{62D}\u{649}"),
+    ('\u{FDA7}', "\u{62C}\u{645}\u{649}"),
+    ('\u{FDA8}', "\u{633}\u{62E}\u{649}"),
+    ('\u{FDA9}', "\u{635}\u{62D}\u{649}"),
+    ('\u{FDAA}', "\u{633}\u{6DB}\u{62D}\u{649}"),
+    ('\u{FDAB}', "\u{636}\u{62D}\u{649}"),
+    ('\u{FDAC}', "\u{644}\u{62C}\u{649}"),
+    ('\u{FDAD}', "\u{644}\u{645}\u{649}"),
+    ('\u{FDAE}', "\u{649}\u{62D}\u{649}"),
+    ('\u{FDAF}', "\u{649}\u{62C}\u{649}"),
+    ('\u{FDB0}', "\u{649}\u{645}\u{649}"),
+    ('\u{FDB1}', "\u{645}\u{645}\u{649}"),
+    ('\u{FDB2}', "\u{642}\u{645}\u{649}"),
+    ('\u{FDB3}', "\u{646}\u{62D}\u{649}"),
+    ('\u{FDB4}', "\u{642}\u{645}\u{62D}"),
+    ('\u{FDB5}', "\u{644}\u{62D}\u{645}"),
+    ('\u{FDB6}', "\u{639}\u{645}\u{649}"),
+    ('\u{FDB7}', "\u{643}\u{645}\u{649}"),
+    ('\u{FDB8}', "\u{646}\u{62C}\u{62D}"),
+    ('\u{FDB9}', "\u{645}\u{62E}\u{649}"),
+    ('\u{FDBA}', "\u{644}\u{62C}\u{645}"),
+    ('\u{FDBB}', "\u{643}\u{645}\u{645}"),
+    ('\u{FDBC}', "\u{644}\u{62C}\u{645}"),
+    ('\u{FDBD}', "\u{646}\u{62C}\u{62D}"),
+    ('\u{FDBE}', "\u{62C}\u{62D}\u{649}"),
+    ('\u{FDBF}', "\u{62D}\u{62C}\u{649}"),
+    ('\u{FDC0}', "\u{645}\u{62C}\u{649}"),
+    ('\u{FDC1}', "\u{641}\u{645}\u{649}"),
+    ('\u{FDC2}', "\u{628}\u{62D}\u{649}"),
+    ('\u{FDC3}', "\u{643}\u{645}\u{645}"),
+    ('\u{FDC4}', "\u{639}\u{62C}\u{645}"),
+    ('\u{FDC5}', "\u{635}\u{645}\u{645}"),
+    ('\u{FDC6}', "\u{633}\u{62E}\u{649}"),
+    ('\u{FDC7}', "\u{646}\u{62C}\u{649}"),
+    ('\u{FDF0}', "\u{635}\u{644}\u{649}"),
+    ('\u{FDF1}', "\u{642}\u{644}\u{649}"),
+    ('\u{FDF2}', "l\u{644}\u{644}\u{651}\u{670}o"),
+    ('\u{FDF3}', "l\u{643}\u{628}\u{631}"),
+    ('\u{FDF4}', "\u{645}\u{62D}\u{645}\u{62F}"),
+    ('\u{FDF5}', "\u{635}\u{644}\u{639}\u{645}"),
+    ('\u{FDF6}', "\u{631}\u{633}\u{648}\u{644}"),
+    ('\u{FDF7}', "\u{639}\u{644}\u{649}o"),
+    ('\u{FDF8}', "\u{648}\u{633}\u{644}\u{645}"),
+    ('\u{FDF9}', "\u{635}\u{644}\u{649}"),
+    ('\u{FDFA}', "\u{635}\u{644}\u{649} l\u{644}\u{644}o \u{639}\u{644}\u{649}o \u{648}\u{633}\u{644}\u{645}"),
+    ('\u{FDFB}', "\u{62C}\u{644} \u{62C}\u{644}l\u{644}o"),
+    ('\u{FDFC}', "\u{631}\u{649}l\u{644}"),
+    ('\u{FE19}', "\u{2D57}"),
+    ('\u{FE30}', ":"),
+    ('\u{FE31}', "\u{2502}"),
+    ('\u{FE34}', "\u{2307}"),
+    ('\u{FE35}', "\u{23DC}"),
+    ('\u{FE36}', "\u{23DD}"),
+    ('\u{FE37}', "\u{23DE}"),
+    ('\u{FE38}', "\u{23DF}"),
+    ('\u{FE39}', "\u{23E0}"),
+    ('\u{FE3A}', "\u{23E1}"),
+    ('\u{FE49}', "\u{2C9}"),
+    ('\u{FE4A}', "\u{2C9}"),
+    ('\u{FE4B}', "\u{2C9}"),
+    ('\u{FE4C}', "\u{2C9}"),
+    ('\u{FE4D}', "_"),
+    ('\u{FE4E}', "_"),
+    ('\u{FE4F}', "_"),
+    ('\u{FE58}', "-"),
+    ('\u{FE68}', "\u{5C}"),
+    ('\u{FE80}', "\u{621}"),
+    ('\u{FE81}', "l\u{303}"),
+    ('\u{FE82}', "l\u{303}"),
+    ('\u{FE83}', "l\u{674}"),
+    ('\u{FE84}', "l\u{674}"),
+    ('\u{FE85}', "\u{648}\u{674}"),
+    ('\u{FE86}', "\u{648}\u{674}"),
+    ('\u{FE87}', "l\u{655}"),
+    ('\u{FE88}', "l\u{655}"),
+    ('\u{FE89}', "\u{649}\u{674}"),
+    ('\u{FE8A}', "\u{649}\u{674}"),
+    ('\u{FE8B}', "\u{649}\u{674}"),
+    ('\u{FE8C}', "\u{649}\u{674}"),
+    ('\u{FE8D}', "l"),
+    ('\u{FE8E}', "l"),
+    ('\u{FE8F}', "\u{628}"),
+    ('\u{FE90}', "\u{628}"),
+    ('\u{FE91}', "\u{628}"),
+    ('\u{FE92}', "\u{628}"),
+    ('\u{FE93}', "\u{629}"),
+    ('\u{FE94}', "\u{629}"),
+    ('\u{FE95}', "\u{62A}"),
+    ('\u{FE96}', "\u{62A}"),
+    ('\u{FE97}', "\u{62A}"),
+    ('\u{FE98}', "\u{62A}"),
+    ('\u{FE99}', "\u{649}\u{6DB}"),
+    ('\u{FE9A}', "\u{649}\u{6DB}"),
+    ('\u{FE9B}', "\u{649}\u{6DB}"),
+    ('\u{FE9C}', "\u{649}\u{6DB}"),
+    ('\u{FE9D}', "\u{62C}"),
+    ('\u{FE9E}', "\u{62C}"),
+    ('\u{FE9F}', "\u{62C}"),
+    ('\u{FEA0}', "\u{62C}"),
+    ('\u{FEA1}', "\u{62D}"),
+    ('\u{FEA2}', "\u{62D}"),
+    ('\u{FEA3}', "\u{62D}"),
+    ('\u{FEA4}', "\u{62D}"),
+    ('\u{FEA5}', "\u{62E}"),
+    ('\u{FEA6}', "\u{62E}"),
+    ('\u{FEA7}', "\u{62E}"),
+    ('\u{FEA8}', "\u{62E}"),
+    ('\u{FEA9}', "\u{62F}"),
+    ('\u{FEAA}', "\u{62F}"),
+    ('\u{FEAB}', "\u{630}"),
+    ('\u{FEAC}', "\u{630}"),
+    ('\u{FEAD}', "\u{631}"),
+    ('\u{FEAE}', "\u{631}"),
+    ('\u{FEAF}', "\u{632}"),
+    ('\u{FEB0}', "\u{632}"),
+    ('\u{FEB1}', "\u{633}"),
+    ('\u{FEB2}', "\u{633}"),
+    ('\u{FEB3}', "\u{633}"),
+    ('\u{FEB4}', "\u{633}"),
+    ('\u{FEB5}', "\u{633}\u{6DB}"),
+    ('\u{FEB6}', "\u{633}\u{6DB}"),
+    ('\u{FEB7}', "\u{633}\u{6DB}"),
+    ('\u{FEB8}', "\u{633}\u{6DB}"),
+    ('\u{FEB9}', "\u{635}"),
+    ('\u{FEBA}', "\u{635}"),
+    ('\u{FEBB}', "\u{635}"),
+    ('\u{FEBC}', "\u{635}"),
+    ('\u{FEBD}', "\u{636}"),
+    ('\u{FEBE}', "\u{636}"),
+    ('\u{FEBF}', "\u{636}"),
+    ('\u{FEC0}', "\u{636}"),
+    ('\u{FEC1}', "\u{637}"),
+    ('\u{FEC2}', "\u{637}"),
+    ('\u{FEC3}', "\u{637}"),
+    ('\u{FEC4}', "\u{637}"),
+    ('\u{FEC5}', "\u{638}"),
+    ('\u{FEC6}', "\u{638}"),
+    ('\u{FEC7}', "\u{638}"),
+    ('\u{FEC8}', "\u{638}"),
+    ('\u{FEC9}', "\u{639}"),
+    ('\u{FECA}', "\u{639}"),
+    ('\u{FECB}', "\u{639}"),
+    ('\u{FECC}', "\u{639}"),
+    ('\u{FECD}', "\u{63A}"),
+    ('\u{FECE}', "\u{63A}"),
+    ('\u{FECF}', "\u{63A}"),
+    ('\u{FED0}', "\u{63A}"),
+    ('\u{FED1}', "\u{641}"),
+    ('\u{FED2}', "\u{641}"),
+    ('\u{FED3}', "\u{641}"),
+    ('\u{FED4}', "\u{641}"),
+    ('\u{FED5}', "\u{642}"),
+    ('\u{FED6}', "\u{642}"),
+    ('\u{FED7}', "\u{642}"),
+    ('\u{FED8}', "\u{642}"),
+    ('\u{FED9}', "\u{643}"),
+    ('\u{FEDA}', "\u{643}"),
+    ('\u{FEDB}', "\u{643}"),
+    ('\u{FEDC}', "\u{643}"),
+    ('\u{FEDD}', "\u{644}"),
+    ('\u{FEDE}', "\u{644}"),
+    ('\u{FEDF}', "\u{644}"),
+    ('\u{FEE0}', "\u{644}"),
+    ('\u{FEE1}', "\u{645}"),
+    ('\u{FEE2}', "\u{645}"),
+    ('\u{FEE3}', "\u{645}"),
+    ('\u{FEE4}', "\u{645}"),
+    ('\u{FEE5}', "\u{646}"),
+    ('\u{FEE6}', "\u{646}"),
+    ('\u{FEE7}', "\u{646}"),
+    ('\u{FEE8}', "\u{646}"),
+    ('\u{FEE9}', "o"),
+    ('\u{FEEA}', "o"),
+    ('\u{FEEB}', "o"),
+    ('\u{FEEC}', "o"),
+    ('\u{FEED}', "\u{648}"),
+    ('\u{FEEE}', "\u{648}"),
+    ('\u{FEEF}', "\u{649}"),
+    ('\u{FEF0}', "\u{649}"),
+    ('\u{FEF1}', "\u{649}"),
+    ('\u{FEF2}', "\u{649}"),
+    ('\u{FEF3}', "\u{649}"),
+    ('\u{FEF4}', "\u{649}"),
+    ('\u{FEF5}', "\u{644}l\u{303}"),
+    ('\u{FEF6}', "\u{644}l\u{303}"),
+    ('\u{FEF7}', "\u{644}l\u{674}"),
+    ('\u{FEF8}', "\u{644}l\u{674}"),
+    ('\u{FEF9}', "\u{644}l\u{655}"),
+    ('\u{FEFA}', "\u{644}l\u{655}"),
+    ('\u{FEFB}', "\u{644}l"),
+    ('\u{FEFC}', "\u{644}l"),
+    ('\u{FF01}', "!"),
+    ('\u{FF02}', "''"),
+    ('\u{FF07}', "'"),
+    ('\u{FF0D}', "\u{30FC}"),
+    ('\u{FF1A}', ":"),
+    ('\u{FF21}', "A"),
+    ('\u{FF22}', "B"),
+    ('\u{FF23}', "C"),
+    ('\u{FF25}', "E"),
+    ('\u{FF28}', "H"),
+    ('\u{FF29}', "l"),
+    ('\u{FF2A}', "J"),
+    ('\u{FF2B}', "K"),
+    ('\u{FF2D}', "M"),
+    ('\u{FF2E}', "N"),
+    ('\u{FF2F}', "O"),
+    ('\u{FF30}', "P"),
+    ('\u{FF33}', "S"),
+    ('\u{FF34}', "T"),
+    ('\u{FF38}', "X"),
+    ('\u{FF39}', "Y"),
+    ('\u{FF3A}', "Z"),
+    ('\u{FF3B}', "("),
+    ('\u{FF3C}', "\u{5C}"),
+    ('\u{FF3D}', ")"),
+    ('\u{FF3E}', "\u{FE3F}"),
+    ('\u{FF40}', "'"),
+    ('\u{FF41}', "a"),
+    ('\u{FF43}', "c"),
+    ('\u{FF45}', "e"),
+    ('\u{FF47}', "g"),
+    ('\u{FF48}', "h"),
+    ('\u{FF49}', "i"),
+    ('\u{FF4A}', "j"),
+    ('\u{FF4C}', "l"),
+    ('\u{FF4F}', "o"),
+    ('\u{FF50}', "p"),
+    ('\u{FF53}', "s"),
+    ('\u{FF56}', "v"),
+    ('\u{FF58}', "x"),
+    ('\u{FF59}', "y"),
+    ('\u{FF5C}', "\u{2502}"),
+    ('\u{FF5E}', "\u{301C}"),
+    ('\u{FF65}', "\u{B7}"),
+    ('\u{FFE3}', "\u{2C9}"),
+    ('\u{FFE8}', "l"),
+    ('\u{FFED}', "\u{25AA}"),
+    ('\u{10101}', "\u{B7}"),
+    ('\u{1018E}', "N\u{30A}"),
+    ('\u{10196}', "X\u{335}"),
+    ('\u{10197}', "V\u{335}"),
+    ('\u{10198}', "l\u{335}l\u{335}S\u{335}"),
+    ('\u{10199}', "l\u{335}l\u{335}"),
+    ('\u{101A0}', "\u{2CE8}"),
+    ('\u{10282}', "B"),
+    ('\u{10285}', "\u{394}"),
+    ('\u{10286}', "E"),
+    ('\u{10287}', "F"),
+    ('\u{1028A}', "l"),
+    ('\u{1028D}', "\u{245}"),
+    ('\u{10290}', "X"),
+    ('\u{10292}', "O"),
+    ('\u{10294}', "\u{16DC}"),
+    ('\u{10295}', "P"),
+    ('\u{10296}', "S"),
+    ('\u{10297}', "T"),
+    ('\u{1029B}', "+"),
+    ('\u{102A0}', "A"),
+    ('\u{102A1}', "B"),
+    ('\u{102A2}', "C"),
+    ('\u{102A3}', "\u{394}"),
+    ('\u{102A5}', "F"),
+    ('\u{102AB}', "O"),
+    ('\u{102AD}', "\u{3D8}"),
+    ('\u{102B0}', "M"),
+    ('\u{102B1}', "T"),
+    ('\u{102B2}', "Y"),
+    ('\u{102B3}', "\u{3A6}"),
+    ('\u{102B4}', "X"),
+    ('\u{102B5}', "\u{3A8}"),
+    ('\u{102B6}', "\u{3A9}"),
+    ('\u{102B8}', "\u{2D40}"),
+    ('\u{102CF}', "H"),
+    ('\u{102E1}', "\u{62F}"),
+    ('\u{102E4}', "\u{648}"),
+    ('\u{102E8}', "\u{637}"),
+    ('\u{102F2}', "\u{635}"),
+    ('\u{102F5}', "Z"),
+    ('\u{10301}', "B"),
+    ('\u{10302}', "C"),
+    ('\u{10309}', "l"),
+    ('\u{10311}', "M"),
+    ('\u{10312}', "\u{3D8}"),
+    ('\u{10315}', "T"),
+    ('\u{10317}', "X"),
+    ('\u{1031A}', "8"),
+    ('\u{1031F}', "*"),
+    ('\u{10320}', "l"),
+    ('\u{10322}', "X"),
+    ('\u{103D1}', "\u{10382}"),
+    ('\u{103D3}', "\u{10393}"),
+    ('\u{10401}', "\u{190}"),
+    ('\u{10404}', "O"),
+    ('\u{10411}', "\u{A4F6}"),
+    ('\u{10415}', "C"),
+    ('\u{1041B}', "L"),
+    ('\u{1041F}', "\u{2C70}"),
+    ('\u{10420}', "S"),
+    ('\u{10423}', "\u{186}"),
+    ('\u{10425}', "\u{418}"),
+    ('\u{10429}', "\u{A793}"),
+    ('\u{1042A}', "\u{29A}"),
+    ('\u{1042C}', "o"),
+    ('\u{1043D}', "c"),
+    ('\u{1043F}', "\u{277}"),
+    ('\u{10442}', "\u{25E}"),
+    ('\u{10443}', "\u{29F}"),
+    ('\u{10448}', "s"),
+    ('\u{1044B}', "\u{254}"),
+    ('\u{1044D}', "\u{1D0E}"),
+    ('\u{104A0}', "\u{10486}"),
+    ('\u{104B0}', "\u{245}"),
+    ('\u{104B4}', "R"),
+    ('\u{104BC}', "\u{4C3}"),
+    ('\u{104C2}', "O"),
+    ('\u{104C3}', "\u{298}"),
+    ('\u{104C4}', "\u{DE}"),
+    ('\u{104CD}', "\u{40B}"),
+    ('\u{104CE}', "U"),
+    ('\u{104D0}', "\u{16E6}"),
+    ('\u{104D1}', "\u{3A8}"),
+    ('\u{104D2}', "7"),
+    ('\u{104D8}', "\u{28C}"),
+    ('\u{104DB}', "\u{3BB}"),
+    ('\u{104EA}', "o"),
+    ('\u{104EB}', "\u{A669}"),
+    ('\u{104F6}', "u"),
+    ('\u{104F9}', "\u{3C8}"),
+    ('\u{10513}', "N"),
+    ('\u{10516}', "O"),
+    ('\u{10518}', "K"),
+    ('\u{1051C}', "C"),
+    ('\u{1051D}', "V"),
+    ('\u{10525}', "F"),
+    ('\u{10526}', "L"),
+    ('\u{10527}', "X"),
+    ('\u{10A3A}', "\u{323}"),
+    ('\u{10A50}', "."),
+    ('\u{10A57}', "\u{10A56}\u{10A56}"),
+    ('\u{10CFA}', "\u{10CA5}"),
+    ('\u{10CFC}', "\u{10C82}"),
+    ('\u{110BB}', "\u{970}"),
+    ('\u{111C7}', "\u{970}"),
+    ('\u{111CA}', "\u{323}"),
+    ('\u{111CB}', "\u{93A}"),
+    ('\u{111DB}', "\u{A8FC}"),
+    ('\u{111DC}', "\u{A8FB}"),
+    ('\u{111DE}', "\u{2248}"),
+    ('\u{11300}', "\u{30A}"),
+    ('\u{11413}', "\u{11434}\u{11442}\u{11412}"),
+    ('\u{11419}', "\u{11434}\u{11442}\u{11418}"),
+    ('\u{11424}', "\u{11434}\u{11442}\u{11423}"),
+    ('\u{1142A}', "\u{11434}\u{11442}\u{11429}"),
+    ('\u{1142D}', "\u{11434}\u{11442}\u{1142C}"),
+    ('\u{1142F}', "\u{11434}\u{11442}\u{1142E}"),
+    ('\u{1144C}', "\u{1144B}\u{1144B}"),
+    ('\u{11492}', "\u{998}"),
+    ('\u{11494}', "\u{99A}"),
+    ('\u{11496}', "\u{99C}"),
+    ('\u{11498}', "\u{99E}"),
+    ('\u{11499}', "\u{99F}"),
+    ('\u{1149B}', "\u{9A1}"),
+    ('\u{1149D}', "\u{9B2}"),
+    ('\u{1149E}', "\u{9A4}"),
+    ('\u{1149F}', "\u{9A5}"),
+    ('\u{114A0}', "\u{9A6}"),
+    ('\u{114A1}', "\u{9A7}"),
+    ('\u{114A2}', "\u{9A8}"),
+    ('\u{114A3}', "\u{9AA}"),
+    ('\u{114A7}', "\u{9AE}"),
+    ('\u{114A8}', "\u{9AF}"),
+    ('\u{114A9}', "\u{9AC}"),
+    ('\u{114AA}', "\u{9A3}"),
+    ('\u{114AB}', "\u{9B0}"),
+    ('\u{114AD}', "\u{9B7}"),
+    ('\u{114AE}', "\u{9B8}"),
+    ('\u{114B0}', "\u{9BE}"),
+    ('\u{114B1}', "\u{9BF}"),
+    ('\u{114B9}', "\u{9C7}"),
+    ('\u{114BD}', "\u{9D7}"),
+    ('\u{114BF}', "\u{306}\u{307}"),
+    ('\u{114C1}', "\u{983}"),
+    ('\u{114C2}', "\u{9CD}"),
+    ('\u{114C3}', "\u{323}"),
+    ('\u{114C4}', "\u{9BD}"),
+    ('\u{114C5}', "w\u{307}"),
+    ('\u{114D0}', "O"),
+    ('\u{114D1}', "\u{9E7}"),
+    ('\u{114D2}', "\u{9E8}"),
+    ('\u{114D6}', "\u{9EC}"),
+    ('\u{115D8}', "\u{11582}"),
+    ('\u{115D9}', "\u{11582}"),
+    ('\u{115DA}', "\u{11583}"),
+    ('\u{115DB}', "\u{11584}"),
+    ('\u{115DC}', "\u{115B2}"),
+    ('\u{115DD}', "\u{115B3}"),
+    ('\u{11642}', "\u{11641}\u{11641}"),
+    ('\u{11700}', "rn"),
+    ('\u{11706}', "v"),
+    ('\u{1170A}', "w"),
+    ('\u{1170E}', "w"),
+    ('\u{1170F}', "w"),
+    ('\u{118A0}', "V"),
+    ('\u{118A2}', "F"),
+    ('\u{118A3}', "L"),
+    ('\u{118A4}', "Y"),
+    ('\u{118A6}', "E"),
+    ('\u{118A8}', "\u{2207}"),
+    ('\u{118A9}', "Z"),
+    ('\u{118AC}', "9"),
+    ('\u{118AE}', "E"),
+    ('\u{118AF}', "4"),
+    ('\u{118B2}', "L"),
+    ('\u{118B5}', "O"),
+    ('\u{118B7}', "\u{16DC}"),
+    ('\u{118B8}', "U"),
+    ('\u{118BB}', "5"),
+    ('\u{118BC}', "T"),
+    ('\u{118C0}', "v"),
+    ('\u{118C1}', "s"),
+    ('\u{118C2}', "F"),
+    ('\u{118C3}', "i"),
+    ('\u{118C4}', "z"),
+    ('\u{118C6}', "7"),
+    ('\u{118C8}', "o"),
+    ('\u{118CA}', "3"),
+    ('\u{118CC}', "9"),
+    ('\u{118CE}', "\u{A793}"),
+    ('\u{118D5}', "6"),
+    ('\u{118D6}', "9"),
+    ('\u{118D7}', "o"),
+    ('\u{118D8}', "u"),
+    ('\u{118DC}', "y"),
+    ('\u{118E0}', "O"),
+    ('\u{118E3}', "rn"),
+    ('\u{118E4}', "\u{669}"),
+    ('\u{118E5}', "Z"),
+    ('\u{118E6}', "W"),
+    ('\u{118E9}', "C"),
+    ('\u{118EC}', "X"),
+    ('\u{118EF}', "W"),
+    ('\u{118F2}', "C"),
+    ('\u{11AE6}', "\u{11AE5}\u{11AEF}"),
+    ('\u{11AE7}', "\u{11AE5}\u{11AF0}"),
+    ('\u{11AE8}', "\u{11AE5}\u{11AE5}"),
+    ('\u{11AE9}', "\u{11AE5}\u{11AE5}\u{11AEF}"),
+    ('\u{11AEA}', "\u{11AE5}\u{11AE5}\u{11AF0}"),
+    ('\u{11AEC}', "\u{11AEB}\u{11AEF}"),
+    ('\u{11AED}', "\u{11AEB}\u{11AEB}"),
+    ('\u{11AEE}', "\u{11AEB}\u{11AEB}\u{11AEF}"),
+    ('\u{11AF4}', "\u{11AF3}\u{11AEF}"),
+    ('\u{11AF5}', "\u{11AF3}\u{11AF0}"),
+    ('\u{11AF6}', "\u{11AF3}\u{11AF3}"),
+    ('\u{11AF7}', "\u{11AF3}\u{11AF3}\u{11AEF}"),
+    ('\u{11AF8}', "\u{11AF3}\u{11AF3}\u{11AF0}"),
+    ('\u{11C42}', "\u{11C41}\u{11C41}"),
+    ('\u{11CB2}', "\u{11CAA}"),
+    ('\u{12038}', "\u{1039A}"),
+    ('\u{132F9}', "\u{1099E}"),
+    ('\u{16F07}', "\u{393}"),
+    ('\u{16F08}', "V"),
+    ('\u{16F0A}', "T"),
+    ('\u{16F16}', "L"),
+    ('\u{16F1A}', "\u{394}"),
+    ('\u{16F1C}', "\u{A658}"),
+    ('\u{16F26}', "\u{A4F6}"),
+    ('\u{16F28}', "l"),
+    ('\u{16F2D}', "\u{190}"),
+    ('\u{16F35}', "R"),
+    ('\u{16F3A}', "S"),
+    ('\u{16F3B}', "3"),
+    ('\u{16F3D}', "\u{245}"),
+    ('\u{16F3F}', ">"),
+    ('\u{16F40}', "A"),
+    ('\u{16F42}', "U"),
+    ('\u{16F43}', "Y"),
+    ('\u{16F51}', "'"),
+    ('\u{16F52}', "'"),
+    ('\u{1D114}', "{"),
+    ('\u{1D16D}', "."),
+    ('\u{1D202}', "\u{4FE}"),
+    ('\u{1D206}', "3"),
+    ('\u{1D20B}', "\u{418}"),
+    ('\u{1D20D}', "V"),
+    ('\u{1D20F}', "\u{5C}"),
+    ('\u{1D212}', "7"),
+    ('\u{1D213}', "F"),
+    ('\u{1D214}', "\u{102BC}"),
+    ('\u{1D215}', "\u{A4F6}"),
+    ('\u{1D216}', "R"),
+    ('\u{1D217}', "\u{2C6F}"),
+    ('\u{1D21A}', "O\u{335}"),
+    ('\u{1D21B}', "\u{2144}"),
+    ('\u{1D21C}', "\u{A4D5}"),
+    ('\u{1D221}', "\u{190}"),
+    ('\u{1D222}', "\u{460}"),
+    ('\u{1D22A}', "L"),
+    ('\u{1D22B}', "\u{A4F6}"),
+    ('\u{1D230}', "\u{A7FB}"),
+    ('\u{1D236}', "<"),
+    ('\u{1D237}', ">"),
+    ('\u{1D238}', "\u{228F}"),
+    ('\u{1D239}', "\u{2290}"),
+    ('\u{1D23A}', "/"),
+    ('\u{1D23B}', "\u{5C}"),
+    ('\u{1D23F}', "\u{16CB}"),
+    ('\u{1D245}', "\u{548}"),
+    ('\u{1D400}', "A"),
+    ('\u{1D401}', "B"),
+    ('\u{1D402}', "C"),
+    ('\u{1D403}', "D"),
+    ('\u{1D404}', "E"),
+    ('\u{1D405}', "F"),
+    ('\u{1D406}', "G"),
+    ('\u{1D407}', "H"),
+    ('\u{1D408}', "l"),
+    ('\u{1D409}', "J"),
+    ('\u{1D40A}', "K"),
+    ('\u{1D40B}', "L"),
+    ('\u{1D40C}', "M"),
+    ('\u{1D40D}', "N"),
+    ('\u{1D40E}', "O"),
+    ('\u{1D40F}', "P"),
+    ('\u{1D410}', "Q"),
+    ('\u{1D411}', "R"),
+    ('\u{1D412}', "S"),
+    ('\u{1D413}', "T"),
+    ('\u{1D414}', "U"),
+    ('\u{1D415}', "V"),
+    ('\u{1D416}', "W"),
+    ('\u{1D417}', "X"),
+    ('\u{1D418}', "Y"),
+    ('\u{1D419}', "Z"),
+    ('\u{1D41A}', "a"),
+    ('\u{1D41B}', "b"),
+    ('\u{1D41C}', "c"),
+    ('\u{1D41D}', "d"),
+    ('\u{1D41E}', "e"),
+    ('\u{1D41F}', "f"),
+    ('\u{1D420}', "g"),
+    ('\u{1D421}', "h"),
+    ('\u{1D422}', "i"),
+    ('\u{1D423}', "j"),
+    ('\u{1D424}', "k"),
+    ('\u{1D425}', "l"),
+    ('\u{1D426}', "rn"),
+    ('\u{1D427}', "n"),
+    ('\u{1D428}', "o"),
+    ('\u{1D429}', "p"),
+    ('\u{1D42A}', "q"),
+    ('\u{1D42B}', "r"),
+    ('\u{1D42C}', "s"),
+    ('\u{1D42D}', "t"),
+    ('\u{1D42E}', "u"),
+    ('\u{1D42F}', "v"),
+    ('\u{1D430}', "w"),
+    ('\u{1D431}', "x"),
+    ('\u{1D432}', "y"),
+    ('\u{1D433}', "z"),
+    ('\u{1D434}', "A"),
+    ('\u{1D435}', "B"),
+    ('\u{1D436}', "C"),
+    ('\u{1D437}', "D"),
+    ('\u{1D438}', "E"),
+    ('\u{1D439}', "F"),
+    ('\u{1D43A}', "G"),
+    ('\u{1D43B}', "H"),
+    ('\u{1D43C}', "l"),
+    ('\u{1D43D}', "J"),
+    ('\u{1D43E}', "K"),
+    ('\u{1D43F}', "L"),
+    ('\u{1D440}', "M"),
+    ('\u{1D441}', "N"),
+    ('\u{1D442}', "O"),
+    ('\u{1D443}', "P"),
+    ('\u{1D444}', "Q"),
+    ('\u{1D445}', "R"),
+    ('\u{1D446}', "S"),
+    ('\u{1D447}', "T"),
+    ('\u{1D448}', "U"),
+    ('\u{1D449}', "V"),
+    ('\u{1D44A}', "W"),
+    ('\u{1D44B}', "X"),
+    ('\u{1D44C}', "Y"),
+    ('\u{1D44D}', "Z"),
+    ('\u{1D44E}', "a"),
+    ('\u{1D44F}', "b"),
+    ('\u{1D450}', "c"),
+    ('\u{1D451}', "d"),
+    ('\u{1D452}', "e"),
+    ('\u{1D453}', "f"),
+    ('\u{1D454}', "g"),
+    ('\u{1D456}', "i"),
+    ('\u{1D457}', "j"),
+    ('\u{1D458}', "k"),
+    ('\u{1D459}', "l"),
+    ('\u{1D45A}', "rn"),
+    ('\u{1D45B}', "n"),
+    ('\u{1D45C}', "o"),
+    ('\u{1D45D}', "p"),
+    ('\u{1D45E}', "q"),
+    ('\u{1D45F}', "r"),
+    ('\u{1D460}', "s"),
+    ('\u{1D461}', "t"),
+    ('\u{1D462}', "u"),
+    ('\u{1D463}', "v"),
+    ('\u{1D464}', "w"),
+    ('\u{1D465}', "x"),
+    ('\u{1D466}', "y"),
+    ('\u{1D467}', "z"),
+    ('\u{1D468}', "A"),
+    ('\u{1D469}', "B"),
+    ('\u{1D46A}', "C"),
+    ('\u{1D46B}', "D"),
+    ('\u{1D46C}', "E"),
+    ('\u{1D46D}', "F"),
+    ('\u{1D46E}', "G"),
+    ('\u{1D46F}', "H"),
+    ('\u{1D470}', "l"),
+    ('\u{1D471}', "J"),
+    ('\u{1D472}', "K"),
+    ('\u{1D473}', "L"),
+    ('\u{1D474}', "M"),
+    ('\u{1D475}', "N"),
+    ('\u{1D476}', "O"),
+    ('\u{1D477}', "P"),
+    ('\u{1D478}', "Q"),
+    ('\u{1D479}', "R"),
+    ('\u{1D47A}', "S"),
+    ('\u{1D47B}', "T"),
+    ('\u{1D47C}', "U"),
+    ('\u{1D47D}', "V"),
+    ('\u{1D47E}', "W"),
+    ('\u{1D47F}', "X"),
+    ('\u{1D480}', "Y"),
+    ('\u{1D481}', "Z"),
+    ('\u{1D482}', "a"),
+    ('\u{1D483}', "b"),
+    ('\u{1D484}', "c"),
+    ('\u{1D485}', "d"),
+    ('\u{1D486}', "e"),
+    ('\u{1D487}', "f"),
+    ('\u{1D488}', "g"),
+    ('\u{1D489}', "h"),
+    ('\u{1D48A}', "i"),
+    ('\u{1D48B}', "j"),
+    ('\u{1D48C}', "k"),
+    ('\u{1D48D}', "l"),
+    ('\u{1D48E}', "rn"),
+    ('\u{1D48F}', "n"),
+    ('\u{1D490}', "o"),
+    ('\u{1D491}', "p"),
+    ('\u{1D492}', "q"),
+    ('\u{1D493}', "r"),
+    ('\u{1D494}', "s"),
+    ('\u{1D495}', "t"),
+    ('\u{1D496}', "u"),
+    ('\u{1D497}', "v"),
+    ('\u{1D498}', "w"),
+    ('\u{1D499}', "x"),
+    ('\u{1D49A}', "y"),
+    ('\u{1D49B}', "z"),
+    ('\u{1D49C}', "A"),
+    ('\u{1D49E}', "C"),
+    ('\u{1D49F}', "D"),
+    ('\u{1D4A2}', "G"),
+    ('\u{1D4A5}', "J"),
+    ('\u{1D4A6}', "K"),
+    ('\u{1D4A9}', "N"),
+    ('\u{1D4AA}', "O"),
+    ('\u{1D4AB}', "P"),
+    ('\u{1D4AC}', "Q"),
+    ('\u{1D4AE}', "S"),
+    ('\u{1D4AF}', "T"),
+    ('\u{1D4B0}', "U"),
+    ('\u{1D4B1}', "V"),
+    ('\u{1D4B2}', "W"),
+    ('\u{1D4B3}', "X"),
+    ('\u{1D4B4}', "Y"),
+    ('\u{1D4B5}', "Z"),
+    ('\u{1D4B6}', "a"),
+    ('\u{1D4B7}', "b"),
+    ('\u{1D4B8}', "c"),
+    ('\u{1D4B9}', "d"),
+    ('\u{1D4BB}', "f"),
+    ('\u{1D4BD}', "h"),
+    ('\u{1D4BE}', "i"),
+    ('\u{1D4BF}', "j"),
+    ('\u{1D4C0}', "k"),
+    ('\u{1D4C1}', "l"),
+    ('\u{1D4C2}', "rn"),
+    ('\u{1D4C3}', "n"),
+    ('\u{1D4C5}', "p"),
+    ('\u{1D4C6}', "q"),
+    ('\u{1D4C7}', "r"),
+    ('\u{1D4C8}', "s"),
+    ('\u{1D4C9}', "t"),
+    ('\u{1D4CA}', "u"),
+    ('\u{1D4CB}', "v"),
+    ('\u{1D4CC}', "w"),
+    ('\u{1D4CD}', "x"),
+    ('\u{1D4CE}', "y"),
+    ('\u{1D4CF}', "z"),
+    ('\u{1D4D0}', "A"),
+    ('\u{1D4D1}', "B"),
+    ('\u{1D4D2}', "C"),
+    ('\u{1D4D3}', "D"),
+    ('\u{1D4D4}', "E"),
+    ('\u{1D4D5}', "F"),
+    ('\u{1D4D6}', "G"),
+    ('\u{1D4D7}', "H"),
+    ('\u{1D4D8}', "l"),
+    ('\u{1D4D9}', "J"),
+    ('\u{1D4DA}', "K"),
+    ('\u{1D4DB}', "L"),
+    ('\u{1D4DC}', "M"),
+    ('\u{1D4DD}', "N"),
+    ('\u{1D4DE}', "O"),
+    ('\u{1D4DF}', "P"),
+    ('\u{1D4E0}', "Q"),
+    ('\u{1D4E1}', "R"),
+    ('\u{1D4E2}', "S"),
+    ('\u{1D4E3}', "T"),
+    ('\u{1D4E4}', "U"),
+    ('\u{1D4E5}', "V"),
+    ('\u{1D4E6}', "W"),
+    ('\u{1D4E7}', "X"),
+    ('\u{1D4E8}', "Y"),
+    ('\u{1D4E9}', "Z"),
+    ('\u{1D4EA}', "a"),
+    ('\u{1D4EB}', "b"),
+    ('\u{1D4EC}', "c"),
+    ('\u{1D4ED}', "d"),
+    ('\u{1D4EE}', "e"),
+    ('\u{1D4EF}', "f"),
+    ('\u{1D4F0}', "g"),
+    ('\u{1D4F1}', "h"),
+    ('\u{1D4F2}', "i"),
+    ('\u{1D4F3}', "j"),
+    ('\u{1D4F4}', "k"),
+    ('\u{1D4F5}', "l"),
+    ('\u{1D4F6}', "rn"),
+    ('\u{1D4F7}', "n"),
+    ('\u{1D4F8}', "o"),
+    ('\u{1D4F9}', "p"),
+    ('\u{1D4FA}', "q"),
+    ('\u{1D4FB}', "r"),
+    ('\u{1D4FC}', "s"),
+    ('\u{1D4FD}', "t"),
+    ('\u{1D4FE}', "u"),
+    ('\u{1D4FF}', "v"),
+    ('\u{1D500}', "w"),
+    ('\u{1D501}', "x"),
+    ('\u{1D502}', "y"),
+    ('\u{1D503}', "z"),
+    ('\u{1D504}', "A"),
+    ('\u{1D505}', "B"),
+    ('\u{1D507}', "D"),
+    ('\u{1D508}', "E"),
+    ('\u{1D509}', "F"),
+    ('\u{1D50A}', "G"),
+    ('\u{1D50D}', "J"),
+    ('\u{1D50E}', "K"),
+    ('\u{1D50F}', "L"),
+    ('\u{1D510}', "M"),
+    ('\u{1D511}', "N"),
+    ('\u{1D512}', "O"),
+    ('\u{1D513}', "P"),
+    ('\u{1D514}', "Q"),
+    ('\u{1D516}', "S"),
+    ('\u{1D517}', "T"),
+    ('\u{1D518}', "U"),
+    ('\u{1D519}', "V"),
+    ('\u{1D51A}', "W"),
+    ('\u{1D51B}', "X"),
+    ('\u{1D51C}', "Y"),
+    ('\u{1D51E}', "a"),
+    ('\u{1D51F}', "b"),
+    ('\u{1D520}', "c"),
+    ('\u{1D521}', "d"),
+    ('\u{1D522}', "e"),
+    ('\u{1D523}', "f"),
+    ('\u{1D524}', "g"),
+    ('\u{1D525}', "h"),
+    ('\u{1D526}', "i"),
+    ('\u{1D527}', "j"),
+    ('\u{1D528}', "k"),
+    ('\u{1D529}', "l"),
+    ('\u{1D52A}', "rn"),
+    ('\u{1D52B}', "n"),
+    ('\u{1D52C}', "o"),
+    ('\u{1D52D}', "p"),
+    ('\u{1D52E}', "q"),
+    ('\u{1D52F}', "r"),
+    ('\u{1D530}', "s"),
+    ('\u{1D531}', "t"),
+    ('\u{1D532}', "u"),
+    ('\u{1D533}', "v"),
+    ('\u{1D534}', "w"),
+    ('\u{1D535}', "x"),
+    ('\u{1D536}', "y"),
+    ('\u{1D537}', "z"),
+    ('\u{1D538}', "A"),
+    ('\u{1D539}', "B"),
+    ('\u{1D53B}', "D"),
+    ('\u{1D53C}', "E"),
+    ('\u{1D53D}', "F"),
+    ('\u{1D53E}', "G"),
+    ('\u{1D540}', "l"),
+    ('\u{1D541}', "J"),
+    ('\u{1D542}', "K"),
+    ('\u{1D543}', "L"),
+    ('\u{1D544}', "M"),
+    ('\u{1D546}', "O"),
+    ('\u{1D54A}', "S"),
+    ('\u{1D54B}', "T"),
+    ('\u{1D54C}', "U"),
+    ('\u{1D54D}', "V"),
+    ('\u{1D54E}', "W"),
+    ('\u{1D54F}', "X"),
+    ('\u{1D550}', "Y"),
+    ('\u{1D552}', "a"),
+    ('\u{1D553}', "b"),
+    ('\u{1D554}', "c"),
+    ('\u{1D555}', "d"),
+    ('\u{1D556}', "e"),
+    ('\u{1D557}', "f"),
+    ('\u{1D558}', "g"),
+    ('\u{1D559}', "h"),
+    ('\u{1D55A}', "i"),
+    ('\u{1D55B}', "j"),
+    ('\u{1D55C}', "k"),
+    ('\u{1D55D}', "l"),
+    ('\u{1D55E}', "rn"),
+    ('\u{1D55F}', "n"),
+    ('\u{1D560}', "o"),
+    ('\u{1D561}', "p"),
+    ('\u{1D562}', "q"),
+    ('\u{1D563}', "r"),
+    ('\u{1D564}', "s"),
+    ('\u{1D565}', "t"),
+    ('\u{1D566}', "u"),
+    ('\u{1D567}', "v"),
+    ('\u{1D568}', "w"),
+    ('\u{1D569}', "x"),
+    ('\u{1D56A}', "y"),
+    ('\u{1D56B}', "z"),
+    ('\u{1D56C}', "A"),
+    ('\u{1D56D}', "B"),
+    ('\u{1D56E}', "C"),
+    ('\u{1D56F}', "D"),
+    ('\u{1D570}', "E"),
+    ('\u{1D571}', "F"),
+    ('\u{1D572}', "G"),
+    ('\u{1D573}', "H"),
+    ('\u{1D574}', "l"),
+    ('\u{1D575}', "J"),
+    ('\u{1D576}', "K"),
+    ('\u{1D577}', "L"),
+    ('\u{1D578}', "M"),
+    ('\u{1D579}', "N"),
+    ('\u{1D57A}', "O"),
+    ('\u{1D57B}', "P"),
+    ('\u{1D57C}', "Q"),
+    ('\u{1D57D}', "R"),
+    ('\u{1D57E}', "S"),
+    ('\u{1D57F}', "T"),
+    ('\u{1D580}', "U"),
+    ('\u{1D581}', "V"),
+    ('\u{1D582}', "W"),
+    ('\u{1D583}', "X"),
+    ('\u{1D584}', "Y"),
+    ('\u{1D585}', "Z"),
+    ('\u{1D586}', "a"),
+    ('\u{1D587}', "b"),
+    ('\u{1D588}', "c"),
+    ('\u{1D589}', "d"),
+    ('\u{1D58A}', "e"),
+    ('\u{1D58B}', "f"),
+    ('\u{1D58C}', "g"),
+    ('\u{1D58D}', "h"),
+    ('\u{1D58E}', "i"),
+    ('\u{1D58F}', "j"),
+    ('\u{1D590}', "k"),
+    ('\u{1D591}', "l"),
+    ('\u{1D592}', "rn"),
+    ('\u{1D593}', "n"),
+    ('\u{1D594}', "o"),
+    ('\u{1D595}', "p"),
+    ('\u{1D596}', "q"),
+    ('\u{1D597}', "r"),
+    ('\u{1D598}', "s"),
+    ('\u{1D599}', "t"),
+    ('\u{1D59A}', "u"),
+    ('\u{1D59B}', "v"),
+    ('\u{1D59C}', "w"),
+    ('\u{1D59D}', "x"),
+    ('\u{1D59E}', "y"),
+    ('\u{1D59F}', "z"),
+    ('\u{1D5A0}', "A"),
+    ('\u{1D5A1}', "B"),
+    ('\u{1D5A2}', "C"),
+    ('\u{1D5A3}', "D"),
+    ('\u{1D5A4}', "E"),
+    ('\u{1D5A5}', "F"),
+    ('\u{1D5A6}', "G"),
+    ('\u{1D5A7}', "H"),
+    ('\u{1D5A8}', "l"),
+    ('\u{1D5A9}', "J"),
+    ('\u{1D5AA}', "K"),
+    ('\u{1D5AB}', "L"),
+    ('\u{1D5AC}', "M"),
+    ('\u{1D5AD}', "N"),
+    ('\u{1D5AE}', "O"),
+    ('\u{1D5AF}', "P"),
+    ('\u{1D5B0}', "Q"),
+    ('\u{1D5B1}', "R"),
+    ('\u{1D5B2}', "S"),
+    ('\u{1D5B3}', "T"),
+    ('\u{1D5B4}', "U"),
+    ('\u{1D5B5}', "V"),
+    ('\u{1D5B6}', "W"),
+    ('\u{1D5B7}', "X"),
+    ('\u{1D5B8}', "Y"),
+    ('\u{1D5B9}', "Z"),
+    ('\u{1D5BA}', "a"),
+    ('\u{1D5BB}', "b"),
+    ('\u{1D5BC}', "c"),
+    ('\u{1D5BD}', "d"),
+    ('\u{1D5BE}', "e"),
+    ('\u{1D5BF}', "f"),
+    ('\u{1D5C0}', "g"),
+    ('\u{1D5C1}', "h"),
+    ('\u{1D5C2}', "i"),
+    ('\u{1D5C3}', "j"),
+    ('\u{1D5C4}', "k"),
+    ('\u{1D5C5}', "l"),
+    ('\u{1D5C6}', "rn"),
+    ('\u{1D5C7}', "n"),
+    ('\u{1D5C8}', "o"),
+    ('\u{1D5C9}', "p"),
+    ('\u{1D5CA}', "q"),
+    ('\u{1D5CB}', "r"),
+    ('\u{1D5CC}', "s"),
+    ('\u{1D5CD}', "t"),
+    ('\u{1D5CE}', "u"),
+    ('\u{1D5CF}', "v"),
+    ('\u{1D5D0}', "w"),
+    ('\u{1D5D1}', "x"),
+    ('\u{1D5D2}', "y"),
+    ('\u{1D5D3}', "z"),
+    ('\u{1D5D4}', "A"),
+    ('\u{1D5D5}', "B"),
+    ('\u{1D5D6}', "C"),
+    ('\u{1D5D7}', "D"),
+    ('\u{1D5D8}', "E"),
+    ('\u{1D5D9}', "F"),
+    ('\u{1D5DA}', "G"),
+    ('\u{1D5DB}', "H"),
+    ('\u{1D5DC}', "l"),
+    ('\u{1D5DD}', "J"),
+    ('\u{1D5DE}', "K"),
+    ('\u{1D5DF}', "L"),
+    ('\u{1D5E0}', "M"),
+    ('\u{1D5E1}', "N"),
+    ('\u{1D5E2}', "O"),
+    ('\u{1D5E3}', "P"),
+    ('\u{1D5E4}', "Q"),
+    ('\u{1D5E5}', "R"),
+    ('\u{1D5E6}', "S"),
+    ('\u{1D5E7}', "T"),
+    ('\u{1D5E8}', "U"),
+    ('\u{1D5E9}', "V"),
+    ('\u{1D5EA}', "W"),
+    ('\u{1D5EB}', "X"),
+    ('\u{1D5EC}', "Y"),
+    ('\u{1D5ED}', "Z"),
+    ('\u{1D5EE}', "a"),
+    ('\u{1D5EF}', "b"),
+    ('\u{1D5F0}', "c"),
+    ('\u{1D5F1}', "d"),
+    ('\u{1D5F2}', "e"),
+    ('\u{1D5F3}', "f"),
+    ('\u{1D5F4}', "g"),
+    ('\u{1D5F5}', "h"),
+    ('\u{1D5F6}', "i"),
+    ('\u{1D5F7}', "j"),
+    ('\u{1D5F8}', "k"),
+    ('\u{1D5F9}', "l"),
+    ('\u{1D5FA}', "rn"),
+    ('\u{1D5FB}', "n"),
+    ('\u{1D5FC}', "o"),
+    ('\u{1D5FD}', "p"),
+    ('\u{1D5FE}', "q"),
+    ('\u{1D5FF}', "r"),
+    ('\u{1D600}', "s"),
+    ('\u{1D601}', "t"),
+    ('\u{1D602}', "u"),
+    ('\u{1D603}', "v"),
+    ('\u{1D604}', "w"),
+    ('\u{1D605}', "x"),
+    ('\u{1D606}', "y"),
+    ('\u{1D607}', "z"),
+    ('\u{1D608}', "A"),
+    ('\u{1D609}', "B"),
+    ('\u{1D60A}', "C"),
+    ('\u{1D60B}', "D"),
+    ('\u{1D60C}', "E"),
+    ('\u{1D60D}', "F"),
+    ('\u{1D60E}', "G"),
+    ('\u{1D60F}', "H"),
+    ('\u{1D610}', "l"),
+    ('\u{1D611}', "J"),
+    ('\u{1D612}', "K"),
+    ('\u{1D613}', "L"),
+    ('\u{1D614}', "M"),
+    ('\u{1D615}', "N"),
+    ('\u{1D616}', "O"),
+    ('\u{1D617}', "P"),
+    ('\u{1D618}', "Q"),
+    ('\u{1D619}', "R"),
+    ('\u{1D61A}', "S"),
+    ('\u{1D61B}', "T"),
+    ('\u{1D61C}', "U"),
+    ('\u{1D61D}', "V"),
+    ('\u{1D61E}', "W"),
+    ('\u{1D61F}', "X"),
+    ('\u{1D620}', "Y"),
+    ('\u{1D621}', "Z"),
+    ('\u{1D622}', "a"),
+    ('\u{1D623}', "b"),
+    ('\u{1D624}', "c"),
+    ('\u{1D625}', "d"),
+    ('\u{1D626}', "e"),
+    ('\u{1D627}', "f"),
+    ('\u{1D628}', "g"),
+    ('\u{1D629}', "h"),
+    ('\u{1D62A}', "i"),
+    ('\u{1D62B}', "j"),
+    ('\u{1D62C}', "k"),
+    ('\u{1D62D}', "l"),
+    ('\u{1D62E}', "rn"),
+    ('\u{1D62F}', "n"),
+    ('\u{1D630}', "o"),
+    ('\u{1D631}', "p"),
+    ('\u{1D632}', "q"),
+    ('\u{1D633}', "r"),
+    ('\u{1D634}', "s"),
+    ('\u{1D635}', "t"),
+    ('\u{1D636}', "u"),
+    ('\u{1D637}', "v"),
+    ('\u{1D638}', "w"),
+    ('\u{1D639}', "x"),
+    ('\u{1D63A}', "y"),
+    ('\u{1D63B}', "z"),
+    ('\u{1D63C}', "A"),
+    ('\u{1D63D}', "B"),
+    ('\u{1D63E}', "C"),
+    ('\u{1D63F}', "D"),
+    ('\u{1D640}', "E"),
+    ('\u{1D641}', "F"),
+    ('\u{1D642}', "G"),
+    ('\u{1D643}', "H"),
+    ('\u{1D644}', "l"),
+    ('\u{1D645}', "J"),
+    ('\u{1D646}', "K"),
+    ('\u{1D647}', "L"),
+    ('\u{1D648}', "M"),
+    ('\u{1D649}', "N"),
+    ('\u{1D64A}', "O"),
+    ('\u{1D64B}', "P"),
+    ('\u{1D64C}', "Q"),
+    ('\u{1D64D}', "R"),
+    ('\u{1D64E}', "S"),
+    ('\u{1D64F}', "T"),
+    ('\u{1D650}', "U"),
+    ('\u{1D651}', "V"),
+    ('\u{1D652}', "W"),
+    ('\u{1D653}', "X"),
+    ('\u{1D654}', "Y"),
+    ('\u{1D655}', "Z"),
+    ('\u{1D656}', "a"),
+    ('\u{1D657}', "b"),
+    ('\u{1D658}', "c"),
+    ('\u{1D659}', "d"),
+    ('\u{1D65A}', "e"),
+    ('\u{1D65B}', "f"),
+    ('\u{1D65C}', "g"),
+    ('\u{1D65D}', "h"),
+    ('\u{1D65E}', "i"),
+    ('\u{1D65F}', "j"),
+    ('\u{1D660}', "k"),
+    ('\u{1D661}', "l"),
+    ('\u{1D662}', "rn"),
+    ('\u{1D663}', "n"),
+    ('\u{1D664}', "o"),
+    ('\u{1D665}', "p"),
+    ('\u{1D666}', "q"),
+    ('\u{1D667}', "r"),
+    ('\u{1D668}', "s"),
+    ('\u{1D669}', "t"),
+    ('\u{1D66A}', "u"),
+    ('\u{1D66B}', "v"),
+    ('\u{1D66C}', "w"),
+    ('\u{1D66D}', "x"),
+    ('\u{1D66E}', "y"),
+    ('\u{1D66F}', "z"),
+    ('\u{1D670}', "A"),
+    ('\u{1D671}', "B"),
+    ('\u{1D672}', "C"),
+    ('\u{1D673}', "D"),
+    ('\u{1D674}', "E"),
+    ('\u{1D675}', "F"),
+    ('\u{1D676}', "G"),
+    ('\u{1D677}', "H"),
+    ('\u{1D678}', "l"),
+    ('\u{1D679}', "J"),
+    ('\u{1D67A}', "K"),
+    ('\u{1D67B}', "L"),
+    ('\u{1D67C}', "M"),
+    ('\u{1D67D}', "N"),
+    ('\u{1D67E}', "O"),
+    ('\u{1D67F}', "P"),
+    ('\u{1D680}', "Q"),
+    ('\u{1D681}', "R"),
+    ('\u{1D682}', "S"),
+    ('\u{1D683}', "T"),
+    ('\u{1D684}', "U"),
+    ('\u{1D685}', "V"),
+    ('\u{1D686}', "W"),
+    ('\u{1D687}', "X"),
+    ('\u{1D688}', "Y"),
+    ('\u{1D689}', "Z"),
+    ('\u{1D68A}', "a"),
+    ('\u{1D68B}', "b"),
+    ('\u{1D68C}', "c"),
+    ('\u{1D68D}', "d"),
+    ('\u{1D68E}', "e"),
+    ('\u{1D68F}', "f"),
+    ('\u{1D690}', "g"),
+    ('\u{1D691}', "h"),
+    ('\u{1D692}', "i"),
+    ('\u{1D693}', "j"),
+    ('\u{1D694}', "k"),
+    ('\u{1D695}', "l"),
+    ('\u{1D696}', "rn"),
+    ('\u{1D697}', "n"),
+    ('\u{1D698}', "o"),
+    ('\u{1D699}', "p"),
+    ('\u{1D69A}', "q"),
+    ('\u{1D69B}', "r"),
+    ('\u{1D69C}', "s"),
+    ('\u{1D69D}', "t"),
+    ('\u{1D69E}', "u"),
+    ('\u{1D69F}', "v"),
+    ('\u{1D6A0}', "w"),
+    ('\u{1D6A1}', "x"),
+    ('\u{1D6A2}', "y"),
+    ('\u{1D6A3}', "z"),
+    ('\u{1D6A4}', "i"),
+    ('\u{1D6A5}', "\u{237}"),
+    ('\u{1D6A8}', "A"),
+    ('\u{1D6A9}', "B"),
+    ('\u{1D6AA}', "\u{393}"),
+    ('\u{1D6AB}', "\u{394}"),
+    ('\u{1D6AC}', "E"),
+    ('\u{1D6AD}', "Z"),
+    ('\u{1D6AE}', "H"),
+    ('\u{1D6AF}', "O\u{335}"),
+    ('\u{1D6B0}', "l"),
+    ('\u{1D6B1}', "K"),
+    ('\u{1D6B2}', "\u{245}"),
+    ('\u{1D6B3}', "M"),
+    ('\u{1D6B4}', "N"),
+    ('\u{1D6B5}', "\u{39E}"),
+    ('\u{1D6B6}', "O"),
+    ('\u{1D6B7}', "\u{3A0}"),
+    ('\u{1D6B8}', "P"),
+    ('\u{1D6B9}', "O\u{335}"),
+    ('\u{1D6BA}', "\u{1A9}"),
+    ('\u{1D6BB}', "T"),
+    ('\u{1D6BC}', "Y"),
+    ('\u{1D6BD}', "\u{3A6}"),
+    ('\u{1D6BE}', "X"),
+    ('\u{1D6BF}', "\u{3A8}"),
+    ('\u{1D6C0}', "\u{3A9}"),
+    ('\u{1D6C1}', "\u{2207}"),
+    ('\u{1D6C2}', "a"),
+    ('\u{1D6C3}', "\u{DF}"),
+    ('\u{1D6C4}', "y"),
+    ('\u{1D6C5}', "\u{1E9F}"),
+    ('\u{1D6C6}', "\u{A793}"),
+    ('\u{1D6C7}', "\u{3B6}"),
+    ('\u{1D6C8}', "n\u{329}"),
+    ('\u{1D6C9}', "O\u{335}"),
+    ('\u{1D6CA}', "i"),
+    ('\u{1D6CB}', "\u{138}"),
+    ('\u{1D6CC}', "\u{3BB}"),
+    ('\u{1D6CD}', "\u{3BC}"),
+    ('\u{1D6CE}', "v"),
+    ('\u{1D6CF}', "\u{3BE}"),
+    ('\u{1D6D0}', "o"),
+    ('\u{1D6D1}', "\u{3C0}"),
+    ('\u{1D6D2}', "p"),
+    ('\u{1D6D3}', "\u{3C2}"),
+    ('\u{1D6D4}', "o"),
+    ('\u{1D6D5}', "\u{1D1B}"),
+    ('\u{1D6D6}', "u"),
+    ('\u{1D6D7}', "\u{278}"),
+    ('\u{1D6D8}', "\u{3C7}"),
+    ('\u{1D6D9}', "\u{3C8}"),
+    ('\u{1D6DA}', "\u{3C9}"),
+    ('\u{1D6DB}', "\u{2202}"),
+    ('\u{1D6DC}', "\u{A793}"),
+    ('\u{1D6DD}', "O\u{335}"),
+    ('\u{1D6DE}', "\u{138}"),
+    ('\u{1D6DF}', "\u{278}"),
+    ('\u{1D6E0}', "p"),
+    ('\u{1D6E1}', "\u{3C0}"),
+    ('\u{1D6E2}', "A"),
+    ('\u{1D6E3}', "B"),
+    ('\u{1D6E4}', "\u{393}"),
+    ('\u{1D6E5}', "\u{394}"),
+    ('\u{1D6E6}', "E"),
+    ('\u{1D6E7}', "Z"),
+    ('\u{1D6E8}', "H"),
+    ('\u{1D6E9}', "O\u{335}"),
+    ('\u{1D6EA}', "l"),
+    ('\u{1D6EB}', "K"),
+    ('\u{1D6EC}', "\u{245}"),
+    ('\u{1D6ED}', "M"),
+    ('\u{1D6EE}', "N"),
+    ('\u{1D6EF}', "\u{39E}"),
+    ('\u{1D6F0}', "O"),
+    ('\u{1D6F1}', "\u{3A0}"),
+    ('\u{1D6F2}', "P"),
+    ('\u{1D6F3}', "O\u{335}"),
+    ('\u{1D6F4}', "\u{1A9}"),
+    ('\u{1D6F5}', "T"),
+    ('\u{1D6F6}', "Y"),
+    ('\u{1D6F7}', "\u{3A6}"),
+    ('\u{1D6F8}', "X"),
+    ('\u{1D6F9}', "\u{3A8}"),
+    ('\u{1D6FA}', "\u{3A9}"),
+    ('\u{1D6FB}', "\u{2207}"),
+    ('\u{1D6FC}', "a"),
+    ('\u{1D6FD}', "\u{DF}"),
+    ('\u{1D6FE}', "y"),
+    ('\u{1D6FF}', "\u{1E9F}"),
+    ('\u{1D700}', "\u{A793}"),
+    ('\u{1D701}', "\u{3B6}"),
+    ('\u{1D702}', "n\u{329}"),
+    ('\u{1D703}', "O\u{335}"),
+    ('\u{1D704}', "i"),
+    ('\u{1D705}', "\u{138}"),
+    ('\u{1D706}', "\u{3BB}"),
+    ('\u{1D707}', "\u{3BC}"),
+    ('\u{1D708}', "v"),
+    ('\u{1D709}', "\u{3BE}"),
+    ('\u{1D70A}', "o"),
+    ('\u{1D70B}', "\u{3C0}"),
+    ('\u{1D70C}', "p"),
+    ('\u{1D70D}', "\u{3C2}"),
+    ('\u{1D70E}', "o"),
+    ('\u{1D70F}', "\u{1D1B}"),
+    ('\u{1D710}', "u"),
+    ('\u{1D711}', "\u{278}"),
+    ('\u{1D712}', "\u{3C7}"),
+    ('\u{1D713}', "\u{3C8}"),
+    ('\u{1D714}', "\u{3C9}"),
+    ('\u{1D715}', "\u{2202}"),
+    ('\u{1D716}', "\u{A793}"),
+    ('\u{1D717}', "O\u{335}"),
+    ('\u{1D718}', "\u{138}"),
+    ('\u{1D719}', "\u{278}"),
+    ('\u{1D71A}', "p"),
+    ('\u{1D71B}', "\u{3C0}"),
+    ('\u{1D71C}', "A"),
+    ('\u{1D71D}', "B"),
+    ('\u{1D71E}', "\u{393}"),
+    ('\u{1D71F}', "\u{394}"),
+    ('\u{1D720}', "E"),
+    ('\u{1D721}', "Z"),
+    ('\u{1D722}', "H"),
+    ('\u{1D723}', "O\u{335}"),
+    ('\u{1D724}', "l"),
+    ('\u{1D725}', "K"),
+    ('\u{1D726}', "\u{245}"),
+    ('\u{1D727}', "M"),
+    ('\u{1D728}', "N"),
+    ('\u{1D729}', "\u{39E}"),
+    ('\u{1D72A}', "O"),
+    ('\u{1D72B}', "\u{3A0}"),
+    ('\u{1D72C}', "P"),
+    ('\u{1D72D}', "O\u{335}"),
+    ('\u{1D72E}', "\u{1A9}"),
+    ('\u{1D72F}', "T"),
+    ('\u{1D730}', "Y"),
+    ('\u{1D731}', "\u{3A6}"),
+    ('\u{1D732}', "X"),
+    ('\u{1D733}', "\u{3A8}"),
+    ('\u{1D734}', "\u{3A9}"),
+    ('\u{1D735}', "\u{2207}"),
+    ('\u{1D736}', "a"),
+    ('\u{1D737}', "\u{DF}"),
+    ('\u{1D738}', "y"),
+    ('\u{1D739}', "\u{1E9F}"),
+    ('\u{1D73A}', "\u{A793}"),
+    ('\u{1D73B}', "\u{3B6}"),
+    ('\u{1D73C}', "n\u{329}"),
+    ('\u{1D73D}', "O\u{335}"),
+    ('\u{1D73E}', "i"),
+    ('\u{1D73F}', "\u{138}"),
+    ('\u{1D740}', "\u{3BB}"),
+    ('\u{1D741}', "\u{3BC}"),
+    ('\u{1D742}', "v"),
+    ('\u{1D743}', "\u{3BE}"),
+    ('\u{1D744}', "o"),
+    ('\u{1D745}', "\u{3C0}"),
+    ('\u{1D746}', "p"),
+    ('\u{1D747}', "\u{3C2}"),
+    ('\u{1D748}', "o"),
+    ('\u{1D749}', "\u{1D1B}"),
+    ('\u{1D74A}', "u"),
+    ('\u{1D74B}', "\u{278}"),
+    ('\u{1D74C}', "\u{3C7}"),
+    ('\u{1D74D}', "\u{3C8}"),
+    ('\u{1D74E}', "\u{3C9}"),
+    ('\u{1D74F}', "\u{2202}"),
+    ('\u{1D750}', "\u{A793}"),
+    ('\u{1D751}', "O\u{335}"),
+    ('\u{1D752}', "\u{138}"),
+    ('\u{1D753}', "\u{278}"),
+    ('\u{1D754}', "p"),
+    ('\u{1D755}', "\u{3C0}"),
+    ('\u{1D756}', "A"),
+    ('\u{1D757}', "B"),
+    ('\u{1D758}', "\u{393}"),
+    ('\u{1D759}', "\u{394}"),
+    ('\u{1D75A}', "E"),
+    ('\u{1D75B}', "Z"),
+    ('\u{1D75C}', "H"),
+    ('\u{1D75D}', "O\u{335}"),
+    ('\u{1D75E}', "l"),
+    ('\u{1D75F}', "K"),
+    ('\u{1D760}', "\u{245}"),
+    ('\u{1D761}', "M"),
+    ('\u{1D762}', "N"),
+    ('\u{1D763}', "\u{39E}"),
+    ('\u{1D764}', "O"),
+    ('\u{1D765}', "\u{3A0}"),
+    ('\u{1D766}', "P"),
+    ('\u{1D767}', "O\u{335}"),
+    ('\u{1D768}', "\u{1A9}"),
+    ('\u{1D769}', "T"),
+    ('\u{1D76A}', "Y"),
+    ('\u{1D76B}', "\u{3A6}"),
+    ('\u{1D76C}', "X"),
+    ('\u{1D76D}', "\u{3A8}"),
+    ('\u{1D76E}', "\u{3A9}"),
+    ('\u{1D76F}', "\u{2207}"),
+    ('\u{1D770}', "a"),
+    ('\u{1D771}', "\u{DF}"),
+    ('\u{1D772}', "y"),
+    ('\u{1D773}', "\u{1E9F}"),
+    ('\u{1D774}', "\u{A793}"),
+    ('\u{1D775}', "\u{3B6}"),
+    ('\u{1D776}', "n\u{329}"),
+    ('\u{1D777}', "O\u{335}"),
+    ('\u{1D778}', "i"),
+    ('\u{1D779}', "\u{138}"),
+    ('\u{1D77A}', "\u{3BB}"),
+    ('\u{1D77B}', "\u{3BC}"),
+    ('\u{1D77C}', "v"),
+    ('\u{1D77D}', "\u{3BE}"),
+    ('\u{1D77E}', "o"),
+    ('\u{1D77F}', "\u{3C0}"),
+    ('\u{1D780}', "p"),
+    ('\u{1D781}', "\u{3C2}"),
+    ('\u{1D782}', "o"),
+    ('\u{1D783}', "\u{1D1B}"),
+    ('\u{1D784}', "u"),
+    ('\u{1D785}', "\u{278}"),
+    ('\u{1D786}', "\u{3C7}"),
+    ('\u{1D787}', "\u{3C8}"),
+    ('\u{1D788}', "\u{3C9}"),
+    ('\u{1D789}', "\u{2202}"),
+    ('\u{1D78A}', "\u{A793}"),
+    ('\u{1D78B}', "O\u{335}"),
+    ('\u{1D78C}', "\u{138}"),
+    ('\u{1D78D}', "\u{278}"),
+    ('\u{1D78E}', "p"),
+    ('\u{1D78F}', "\u{3C0}"),
+    ('\u{1D790}', "A"),
+    ('\u{1D791}', "B"),
+    ('\u{1D792}', "\u{393}"),
+    ('\u{1D793}', "\u{394}"),
+    ('\u{1D794}', "E"),
+    ('\u{1D795}', "Z"),
+    ('\u{1D796}', "H"),
+    ('\u{1D797}', "O\u{335}"),
+    ('\u{1D798}', "l"),
+    ('\u{1D799}', "K"),
+    ('\u{1D79A}', "\u{245}"),
+    ('\u{1D79B}', "M"),
+    ('\u{1D79C}', "N"),
+    ('\u{1D79D}', "\u{39E}"),
+    ('\u{1D79E}', "O"),
+    ('\u{1D79F}', "\u{3A0}"),
+    ('\u{1D7A0}', "P"),
+    ('\u{1D7A1}', "O\u{335}"),
+    ('\u{1D7A2}', "\u{1A9}"),
+    ('\u{1D7A3}', "T"),
+    ('\u{1D7A4}', "Y"),
+    ('\u{1D7A5}', "\u{3A6}"),
+    ('\u{1D7A6}', "X"),
+    ('\u{1D7A7}', "\u{3A8}"),
+    ('\u{1D7A8}', "\u{3A9}"),
+    ('\u{1D7A9}', "\u{2207}"),
+    ('\u{1D7AA}', "a"),
+    ('\u{1D7AB}', "\u{DF}"),
+    ('\u{1D7AC}', "y"),
+    ('\u{1D7AD}', "\u{1E9F}"),
+    ('\u{1D7AE}', "\u{A793}"),
+    ('\u{1D7AF}', "\u{3B6}"),
+    ('\u{1D7B0}', "n\u{329}"),
+    ('\u{1D7B1}', "O\u{335}"),
+    ('\u{1D7B2}', "i"),
+    ('\u{1D7B3}', "\u{138}"),
+    ('\u{1D7B4}', "\u{3BB}"),
+    ('\u{1D7B5}', "\u{3BC}"),
+    ('\u{1D7B6}', "v"),
+    ('\u{1D7B7}', "\u{3BE}"),
+    ('\u{1D7B8}', "o"),
+    ('\u{1D7B9}', "\u{3C0}"),
+    ('\u{1D7BA}', "p"),
+    ('\u{1D7BB}', "\u{3C2}"),
+    ('\u{1D7BC}', "o"),
+    ('\u{1D7BD}', "\u{1D1B}"),
+    ('\u{1D7BE}', "u"),
+    ('\u{1D7BF}', "\u{278}"),
+    ('\u{1D7C0}', "\u{3C7}"),
+    ('\u{1D7C1}', "\u{3C8}"),
+    ('\u{1D7C2}', "\u{3C9}"),
+    ('\u{1D7C3}', "\u{2202}"),
+    ('\u{1D7C4}', "\u{A793}"),
+    ('\u{1D7C5}', "O\u{335}"),
+    ('\u{1D7C6}', "\u{138}"),
+    ('\u{1D7C7}', "\u{278}"),
+    ('\u{1D7C8}', "p"),
+    ('\u{1D7C9}', "\u{3C0}"),
+    ('\u{1D7CA}', "F"),
+    ('\u{1D7CB}', "\u{3DD}"),
+    ('\u{1D7CE}', "O"),
+    ('\u{1D7CF}', "l"),
+    ('\u{1D7D0}', "2"),
+    ('\u{1D7D1}', "3"),
+    ('\u{1D7D2}', "4"),
+    ('\u{1D7D3}', "5"),
+    ('\u{1D7D4}', "6"),
+    ('\u{1D7D5}', "7"),
+    ('\u{1D7D6}', "8"),
+    ('\u{1D7D7}', "9"),
+    ('\u{1D7D8}', "O"),
+    ('\u{1D7D9}', "l"),
+    ('\u{1D7DA}', "2"),
+    ('\u{1D7DB}', "3"),
+    ('\u{1D7DC}', "4"),
+    ('\u{1D7DD}', "5"),
+    ('\u{1D7DE}', "6"),
+    ('\u{1D7DF}', "7"),
+    ('\u{1D7E0}', "8"),
+    ('\u{1D7E1}', "9"),
+    ('\u{1D7E2}', "O"),
+    ('\u{1D7E3}', "l"),
+    ('\u{1D7E4}', "2"),
+    ('\u{1D7E5}', "3"),
+    ('\u{1D7E6}', "4"),
+    ('\u{1D7E7}', "5"),
+    ('\u{1D7E8}', "6"),
+    ('\u{1D7E9}', "7"),
+    ('\u{1D7EA}', "8"),
+    ('\u{1D7EB}', "9"),
+    ('\u{1D7EC}', "O"),
+    ('\u{1D7ED}', "l"),
+    ('\u{1D7EE}', "2"),
+    ('\u{1D7EF}', "3"),
+    ('\u{1D7F0}', "4"),
+    ('\u{1D7F1}', "5"),
+    ('\u{1D7F2}', "6"),
+    ('\u{1D7F3}', "7"),
+    ('\u{1D7F4}', "8"),
+    ('\u{1D7F5}', "9"),
+    ('\u{1D7F6}', "O"),
+    ('\u{1D7F7}', "l"),
+    ('\u{1D7F8}', "2"),
+    ('\u{1D7F9}', "3"),
+    ('\u{1D7FA}', "4"),
+    ('\u{1D7FB}', "5"),
+    ('\u{1D7FC}', "6"),
+    ('\u{1D7FD}', "7"),
+    ('\u{1D7FE}', "8"),
+    ('\u{1D7FF}', "9"),
+    ('\u{1E8C7}', "l"),
+    ('\u{1E8C8}', "\u{2220}"),
+    ('\u{1E8C9}', "\u{663}"),
+    ('\u{1E8CB}', "8"),
+    ('\u{1E8CC}', "\u{2202}"),
+    ('\u{1E8CD}', "\u{2202}\u{335}"),
+    ('\u{1EE00}', "l"),
+    ('\u{1EE01}', "\u{628}"),
+    ('\u{1EE02}', "\u{62C}"),
+    ('\u{1EE03}', "\u{62F}"),
+    ('\u{1EE05}', "\u{648}"),
+    ('\u{1EE06}', "\u{632}"),
+    ('\u{1EE07}', "\u{62D}"),
+    ('\u{1EE08}', "\u{637}"),
+    ('\u{1EE09}', "\u{649}"),
+    ('\u{1EE0A}', "\u{643}"),
+    ('\u{1EE0B}', "\u{644}"),
+    ('\u{1EE0C}', "\u{645}"),
+    ('\u{1EE0D}', "\u{646}"),
+    ('\u{1EE0E}', "\u{633}"),
+    ('\u{1EE0F}', "\u{639}"),
+    ('\u{1EE10}', "\u{641}"),
+    ('\u{1EE11}', "\u{635}"),
+    ('\u{1EE12}', "\u{642}"),
+    ('\u{1EE13}', "\u{631}"),
+    ('\u{1EE14}', "\u{633}\u{6DB}"),
+    ('\u{1EE15}', "\u{62A}"),
+    ('\u{1EE16}', "\u{649}\u{6DB}"),
+    ('\u{1EE17}', "\u{62E}"),
+    ('\u{1EE18}', "\u{630}"),
+    ('\u{1EE19}', "\u{636}"),
+    ('\u{1EE1A}', "\u{638}"),
+    ('\u{1EE1B}', "\u{63A}"),
+    ('\u{1EE1C}', "\u{649}"),
+    ('\u{1EE1D}', "\u{649}"),
+    ('\u{1EE1E}', "\u{6A1}"),
+    ('\u{1EE1F}', "\u{6A1}"),
+    ('\u{1EE21}', "\u{628}"),
+    ('\u{1EE22}', "\u{62C}"),
+    ('\u{1EE24}', "o"),
+    ('\u{1EE27}', "\u{62D}"),
+    ('\u{1EE29}', "\u{649}"),
+    ('\u{1EE2A}', "\u{643}"),
+    ('\u{1EE2B}', "\u{644}"),
+    ('\u{1EE2C}', "\u{645}"),
+    ('\u{1EE2D}', "\u{646}"),
+    ('\u{1EE2E}', "\u{633}"),
+    ('\u{1EE2F}', "\u{639}"),
+    ('\u{1EE30}', "\u{641}"),
+    ('\u{1EE31}', "\u{635}"),
+    ('\u{1EE32}', "\u{642}"),
+    ('\u{1EE34}', "\u{633}\u{6DB}"),
+    ('\u{1EE35}', "\u{62A}"),
+    ('\u{1EE36}', "\u{649}\u{6DB}"),
+    ('\u{1EE37}', "\u{62E}"),
+    ('\u{1EE39}', "\u{636}"),
+    ('\u{1EE3B}', "\u{63A}"),
+    ('\u{1EE42}', "\u{62C}"),
+    ('\u{1EE47}', "\u{62D}"),
+    ('\u{1EE49}', "\u{649}"),
+    ('\u{1EE4B}', "\u{644}"),
+    ('\u{1EE4D}', "\u{646}"),
+    ('\u{1EE4E}', "\u{633}"),
+    ('\u{1EE4F}', "\u{639}"),
+    ('\u{1EE51}', "\u{635}"),
+    ('\u{1EE52}', "\u{642}"),
+    ('\u{1EE54}', "\u{633}\u{6DB}"),
+    ('\u{1EE57}', "\u{62E}"),
+    ('\u{1EE59}', "\u{636}"),
+    ('\u{1EE5B}', "\u{63A}"),
+    ('\u{1EE5D}', "\u{649}"),
+    ('\u{1EE5F}', "\u{6A1}"),
+    ('\u{1EE61}', "\u{628}"),
+    ('\u{1EE62}', "\u{62C}"),
+    ('\u{1EE64}', "o"),
+    ('\u{1EE67}', "\u{62D}"),
+    ('\u{1EE68}', "\u{637}"),
+    ('\u{1EE69}', "\u{649}"),
+    ('\u{1EE6A}', "\u{643}"),
+    ('\u{1EE6C}', "\u{645}"),
+    ('\u{1EE6D}', "\u{646}"),
+    ('\u{1EE6E}', "\u{633}"),
+    ('\u{1EE6F}', "\u{639}"),
+    ('\u{1EE70}', "\u{641}"),
+    ('\u{1EE71}', "\u{635}"),
+    ('\u{1EE72}', "\u{642}"),
+    ('\u{1EE74}', "\u{633}\u{6DB}"),
+    ('\u{1EE75}', "\u{62A}"),
+    ('\u{1EE76}', "\u{649}\u{6DB}"),
+    ('\u{1EE77}', "\u{62E}"),
+    ('\u{1EE79}', "\u{636}"),
+    ('\u{1EE7A}', "\u{638}"),
+    ('\u{1EE7B}', "\u{63A}"),
+    ('\u{1EE7C}', "\u{649}"),
+    ('\u{1EE7E}', "\u{6A1}"),
+    ('\u{1EE80}', "l"),
+    ('\u{1EE81}', "\u{628}"),
+    ('\u{1EE82}', "\u{62C}"),
+    ('\u{1EE83}', "\u{62F}"),
+    ('\u{1EE84}', "o"),
+    ('\u{1EE85}', "\u{648}"),
+    ('\u{1EE86}', "\u{632}"),
+    ('\u{1EE87}', "\u{62D}"),
+    ('\u{1EE88}', "\u{637}"),
+    ('\u{1EE89}', "\u{649}"),
+    ('\u{1EE8B}', "\u{644}"),
+    ('\u{1EE8C}', "\u{645}"),
+    ('\u{1EE8D}', "\u{646}"),
+    ('\u{1EE8E}', "\u{633}"),
+    ('\u{1EE8F}', "\u{639}"),
+    ('\u{1EE90}', "\u{641}"),
+    ('\u{1EE91}', "\u{635}"),
+    ('\u{1EE92}', "\u{642}"),
+    ('\u{1EE93}', "\u{631}"),
+    ('\u{1EE94}', "\u{633}\u{6DB}"),
+    ('\u{1EE95}', "\u{62A}"),
+    ('\u{1EE96}', "\u{649}\u{6DB}"),
+    ('\u{1EE97}', "\u{62E}"),
+    ('\u{1EE98}', "\u{630}"),
+    ('\u{1EE99}', "\u{636}"),
+    ('\u{1EE9A}', "\u{638}"),
+    ('\u{1EE9B}', "\u{63A}"),
+    ('\u{1EEA1}', "\u{628}"),
+    ('\u{1EEA2}', "\u{62C}"),
+    ('\u{1EEA3}', "\u{62F}"),
+    ('\u{1EEA5}', "\u{648}"),
+    ('\u{1EEA6}', "\u{632}"),
+    ('\u{1EEA7}', "\u{62D}"),
+    ('\u{1EEA8}', "\u{637}"),
+    ('\u{1EEA9}', "\u{649}"),
+    ('\u{1EEAB}', "\u{644}"),
+    ('\u{1EEAC}', "\u{645}"),
+    ('\u{1EEAD}', "\u{646}"),
+    ('\u{1EEAE}', "\u{633}"),
+    ('\u{1EEAF}', "\u{639}"),
+    ('\u{1EEB0}', "\u{641}"),
+    ('\u{1EEB1}', "\u{635}"),
+    ('\u{1EEB2}', "\u{642}"),
+    ('\u{1EEB3}', "\u{631}"),
+    ('\u{1EEB4}', "\u{633}\u{6DB}"),
+    ('\u{1EEB5}', "\u{62A}"),
+    ('\u{1EEB6}', "\u{649}\u{6DB}"),
+    ('\u{1EEB7}', "\u{62E}"),
+    ('\u{1EEB8}', "\u{630}"),
+    ('\u{1EEB9}', "\u{636}"),
+    ('\u{1EEBA}', "\u{638}"),
+    ('\u{1EEBB}', "\u{63A}"),
+    ('\u{1F100}', "O."),
+    ('\u{1F101}', "O,"),
+    ('\u{1F102}', "l,"),
+    ('\u{1F103}', "2,"),
+    ('\u{1F104}', "3,"),
+    ('\u{1F105}', "4,"),
+    ('\u{1F106}', "5,"),
+    ('\u{1F107}', "6,"),
+    ('\u{1F108}', "7,"),
+    ('\u{1F109}', "8,"),
+    ('\u{1F10A}', "9,"),
+    ('\u{1F10F}', "$\u{20E0}"),
+    ('\u{1F110}', "(A)"),
+    ('\u{1F111}', "(B)"),
+    ('\u{1F112}', "(C)"),
+    ('\u{1F113}', "(D)"),
+    ('\u{1F114}', "(E)"),
+    ('\u{1F115}', "(F)"),
+    ('\u{1F116}', "(G)"),
+    ('\u{1F117}', "(H)"),
+    ('\u{1F118}', "(l)"),
+    ('\u{1F119}', "(J)"),
+    ('\u{1F11A}', "(K)"),
+    ('\u{1F11B}', "(L)"),
+    ('\u{1F11C}', "(M)"),
+    ('\u{1F11D}', "(N)"),
+    ('\u{1F11E}', "(O)"),
+    ('\u{1F11F}', "(P)"),
+    ('\u{1F120}', "(Q)"),
+    ('\u{1F121}', "(R)"),
+    ('\u{1F122}', "(S)"),
+    ('\u{1F123}', "(T)"),
+    ('\u{1F124}', "(U)"),
+    ('\u{1F125}', "(V)"),
+    ('\u{1F126}', "(W)"),
+    ('\u{1F127}', "(X)"),
+    ('\u{1F128}', "(Y)"),
+    ('\u{1F129}', "(Z)"),
+    ('\u{1F12A}', "(S)"),
+    ('\u{1F16D}', "\u{33C4}\u{9}\u{20DD}"),
+    ('\u{1F16E}', "C\u{20E0}"),
+    ('\u{1F240}', "(\u{672C})"),
+    ('\u{1F241}', "(\u{4E09})"),
+    ('\u{1F242}', "(\u{4E8C})"),
+    ('\u{1F243}', "(\u{5B89})"),
+    ('\u{1F244}', "(\u{70B9})"),
+    ('\u{1F245}', "(\u{6253})"),
+    ('\u{1F246}', "(\u{76D7})"),
+    ('\u{1F247}', "(\u{52DD})"),
+    ('\u{1F248}', "(\u{6557})"),
+    ('\u{1F312}', "\u{263D}"),
+    ('\u{1F318}', "\u{263E}"),
+    ('\u{1F319}', "\u{263D}"),
+    ('\u{1F700}', "QE"),
+    ('\u{1F701}', "\u{A658}"),
+    ('\u{1F702}', "\u{394}"),
+    ('\u{1F704}', "\u{102BC}"),
+    ('\u{1F707}', "AR"),
+    ('\u{1F708}', "V\u{1DE4}"),
+    ('\u{1F70A}', "\u{2629}"),
+    ('\u{1F714}', "O\u{335}"),
+    ('\u{1F728}', "\u{102A8}"),
+    ('\u{1F73A}', "\u{29DF}"),
+    ('\u{1F74C}', "C"),
+    ('\u{1F754}', "\u{16DC}"),
+    ('\u{1F755}', "\u{22A1}"),
+    ('\u{1F75C}', "sss"),
+    ('\u{1F75E}', "\u{224F}"),
+    ('\u{1F768}', "T"),
+    ('\u{1F76B}', "MB"),
+    ('\u{1F76C}', "VB"),
+    ('\u{1F771}', "\u{22A0}"),
+    ('\u{1FBF0}', "O"),
+    ('\u{1FBF1}', "l"),
+    ('\u{1FBF2}', "2"),
+    ('\u{1FBF3}', "3"),
+    ('\u{1FBF4}', "4"),
+    ('\u{1FBF5}', "5"),
+    ('\u{1FBF6}', "6"),
+    ('\u{1FBF7}', "7"),
+    ('\u{1FBF8}', "8"),
+    ('\u{1FBF9}', "9"),
+    ('\u{21FE8}', "\u{276C}"),
+];
@@ -1,4 +1,10 @@
+pub mod ascii_fold;
+mod ascii_fold_table;
 pub mod bert;
+pub mod case;
+mod case_tables;
+pub mod confusables;
+mod confusables_table;
 pub mod precompiled;
 pub mod prepend;
 pub mod replace;
@@ -6,7 +12,10 @@ pub mod strip;
 pub mod unicode;
 pub mod utils;
 
+pub use crate::normalizers::ascii_fold::{AsciiFold, AsciiFoldFallback};
 pub use crate::normalizers::bert::BertNormalizer;
+pub use crate::normalizers::case::{CaseFold, CaseFoldMode, CaseLocale, LocaleLowercase};
+pub use crate::normalizers::confusables::ConfusableSkeleton;
 pub use crate::normalizers::precompiled::Precompiled;
 pub use crate::normalizers::prepend::Prepend;
 pub use crate::normalizers::replace::Replace;
@@ -16,6 +25,7 @@ pub use crate::normalizers::utils::{Lowercase, Sequence};
 
 use serde::{Deserialize, Serialize};
 
+use crate::tokenizer::component_type;
 use crate::{NormalizedString, Normalizer};
 
 /// Wrapper for known Normalizers.
@@ -35,6 +45,10 @@ pub enum NormalizerWrapper {
     Precompiled(Precompiled),
     Replace(Replace),
     Prepend(Prepend),
+    CaseFold(CaseFold),
+    LocaleLowercase(LocaleLowercase),
+    AsciiFold(AsciiFold),
+    ConfusableSkeleton(ConfusableSkeleton),
 }
 
 impl Normalizer for NormalizerWrapper {
@@ -53,6 +67,25 @@ impl Normalizer for NormalizerWrapper {
             Self::Precompiled(lc) => lc.normalize(normalized),
             Self::Replace(lc) => lc.normalize(normalized),
             Self::Prepend(lc) => lc.normalize(normalized),
+            Self::CaseFold(cf) => cf.normalize(normalized),
+            Self::LocaleLowercase(lc) => lc.normalize(normalized),
+            Self::AsciiFold(af) => af.normalize(normalized),
+            Self::ConfusableSkeleton(cs) => cs.normalize(normalized),
+        }
+    }
+
+    fn normalize_steps(
+        &self,
+        normalized: &mut NormalizedString,
+        on_step: &mut dyn FnMut(Option<&str>, &NormalizedString),
+    ) -> crate::Result<()> {
+        match self {
+            Self::Sequence(sequence) => sequence.normalize_steps(normalized, on_step),
+            _ => {
+                self.normalize(normalized)?;
+                on_step(component_type(self).as_deref(), normalized);
+                Ok(())
+            }
         }
     }
 }
@@ -70,3 +103,7 @@ impl_enum_from!(Nmt, NormalizerWrapper, Nmt);
 impl_enum_from!(Precompiled, NormalizerWrapper, Precompiled);
 impl_enum_from!(Replace, NormalizerWrapper, Replace);
 impl_enum_from!(Prepend, NormalizerWrapper, Prepend);
+impl_enum_from!(CaseFold, NormalizerWrapper, CaseFold);
+impl_enum_from!(LocaleLowercase, NormalizerWrapper, LocaleLowercase);
+impl_enum_from!(AsciiFold, NormalizerWrapper, AsciiFold);
+impl_enum_from!(ConfusableSkeleton, NormalizerWrapper, ConfusableSkeleton);
@@ -1,8 +1,436 @@
 use crate::tokenizer::{NormalizedString, Normalizer, Result};
-pub use spm_precompiled::Precompiled;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+pub use spm_precompiled::PrecompiledError;
 use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::convert::TryFrom;
 use unicode_segmentation::UnicodeSegmentation;
 
+#[derive(thiserror::Error, Debug)]
+pub enum CharsMapError {
+    #[error("Line {0} should be `source codepoints<TAB>target codepoints`: {1:?}")]
+    InvalidLine(usize, String),
+    #[error("Line {0} has an invalid hexadecimal codepoint: {1:?}")]
+    InvalidCodepoint(usize, String),
+    #[error("Cannot compile an empty or NUL containing source {0:?}")]
+    InvalidSource(String),
+    #[error("Cannot compile a NUL containing target {0:?}")]
+    InvalidTarget(String),
+    #[error("The charsmap is truncated or its trie is malformed")]
+    Malformed,
+    #[error("The rules don't fit in a charsmap, its trie would be too large")]
+    TooLarge,
+}
+
+/// The normalizer of SentencePiece, running a charsmap. It wraps the one of `spm_precompiled`
+/// to keep its charsmap, which isn't exposed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", try_from = "PrecompiledDeserializer")]
+pub struct Precompiled {
+    #[serde(serialize_with = "as_base64")]
+    precompiled_charsmap: Vec<u8>,
+    #[serde(skip)]
+    precompiled: spm_precompiled::Precompiled,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+struct PrecompiledDeserializer {
+    #[serde(deserialize_with = "from_base64")]
+    precompiled_charsmap: Vec<u8>,
+}
+
+fn as_base64<S: Serializer>(bytes: &[u8], serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_str(&STANDARD.encode(bytes))
+}
+
+fn from_base64<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Vec<u8>, D::Error> {
+    let encoded = String::deserialize(deserializer)?;
+    STANDARD.decode(encoded).map_err(serde::de::Error::custom)
+}
+
+impl TryFrom<PrecompiledDeserializer> for Precompiled {
+    type Error = PrecompiledError;
+
+    fn try_from(deserializer: PrecompiledDeserializer) -> std::result::Result<Self, Self::Error> {
+        Self::from(&deserializer.precompiled_charsmap)
+    }
+}
+
+impl Default for Precompiled {
+    /// A `Precompiled` without any rule. The default of `spm_precompiled` can't be used, its
+    /// empty trie can't normalize anything.
+    fn default() -> Self {
+        PrecompiledBuilder::new()
+            .build()
+            .expect("The empty charsmap is valid")
+    }
+}
+
+impl Precompiled {
+    pub fn from(precompiled_charsmap: &[u8]) -> std::result::Result<Self, PrecompiledError> {
+        Ok(Self {
+            precompiled_charsmap: precompiled_charsmap.to_vec(),
+            precompiled: spm_precompiled::Precompiled::from(precompiled_charsmap)?,
+        })
+    }
+
+    /// The charsmap compiled in this normalizer
+    pub fn charsmap(&self) -> &[u8] {
+        &self.precompiled_charsmap
+    }
+
+    /// The replacement of `chunk`, if any
+    pub fn transform(&self, chunk: &str) -> Option<&str> {
+        self.precompiled.transform(chunk)
+    }
+
+    /// Normalize a whole string, without keeping track of the alignments
+    pub fn normalize_string(&self, original: &str) -> String {
+        self.precompiled.normalize_string(original)
+    }
+}
+
+/// Compiles normalization rules into the charsmap used by [`Precompiled`], the format of
+/// SentencePiece: the size in bytes of a double-array trie as a little endian `u32`, the units
+/// of this trie, then the targets separated by `\0`. The trie maps each source to the offset
+/// of its target.
+///
+/// The rules can be read from the TSV files used by SentencePiece, where each line holds the
+/// source and target as space separated hexadecimal codepoints, and an optional comment:
+/// ```
+/// # use tokenizers::normalizers::precompiled::PrecompiledBuilder;
+/// let precompiled = PrecompiledBuilder::from_tsv("FB01\t66 69\t# ﬁ => fi\nD\t\n")
+///     .unwrap()
+///     .rule("A", "a")
+///     .build()
+///     .unwrap();
+/// assert_eq!(precompiled.normalize_string("ﬁ A\rB"), "fi aB");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PrecompiledBuilder {
+    rules: BTreeMap<String, String>,
+}
+
+impl PrecompiledBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse the rules of a TSV file. Empty lines and those starting with `#` are ignored.
+    pub fn from_tsv(tsv: &str) -> Result<Self> {
+        let mut builder = Self::new();
+        for (i, line) in tsv.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut columns = line.split('\t');
+            let (source, target) = match (columns.next(), columns.next()) {
+                (Some(source), Some(target)) => (source, target),
+                _ => return Err(CharsMapError::InvalidLine(i + 1, line.into()).into()),
+            };
+            builder = builder.rule(
+                &parse_codepoints(i + 1, source)?,
+                &parse_codepoints(i + 1, target)?,
+            );
+        }
+        Ok(builder)
+    }
+
+    /// Add a rule replacing `source` by `target`, overriding any previous rule for `source`
+    #[must_use]
+    pub fn rule(mut self, source: &str, target: &str) -> Self {
+        self.rules.insert(source.into(), target.into());
+        self
+    }
+
+    /// Compile the rules into a charsmap
+    pub fn build_charsmap(&self) -> Result<Vec<u8>> {
+        let mut normalized = String::new();
+        let mut offsets: HashMap<&str, u32> = HashMap::new();
+        let mut trie = TrieBuilder::default();
+        for (source, target) in &self.rules {
+            if source.is_empty() || source.contains('\0') {
+                return Err(CharsMapError::InvalidSource(source.clone()).into());
+            }
+            if target.contains('\0') {
+                return Err(CharsMapError::InvalidTarget(target.clone()).into());
+            }
+            let offset = *offsets.entry(target).or_insert_with(|| {
+                let offset = normalized.len() as u32;
+                normalized.push_str(target);
+                normalized.push('\0');
+                offset
+            });
+            trie.insert(source.as_bytes(), offset);
+        }
+
+        let units = trie.build()?;
+        let mut charsmap = Vec::with_capacity(4 + units.len() * 4 + normalized.len());
+        charsmap.extend(((units.len() * 4) as u32).to_le_bytes());
+        for unit in units {
+            charsmap.extend(unit.to_le_bytes());
+        }
+        charsmap.extend(normalized.as_bytes());
+        Ok(charsmap)
+    }
+
+    /// Compile the rules into a [`Precompiled`] normalizer
+    pub fn build(&self) -> Result<Precompiled> {
+        Ok(Precompiled::from(&self.build_charsmap()?)?)
+    }
+}
+
+fn parse_codepoints(line: usize, column: &str) -> Result<String> {
+    column
+        .split_whitespace()
+        .map(|codepoint| {
+            u32::from_str_radix(codepoint, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(|| CharsMapError::InvalidCodepoint(line, codepoint.into()).into())
+        })
+        .collect()
+}
+
+/// A darts-clone unit is a `u32` holding either a leaf value, with its highest bit set, or the
+/// label of a node, whether it has a leaf child and the offset to its children.
+const IS_LEAF: u32 = 1 << 31;
+const HAS_LEAF: u32 = 1 << 8;
+const MAX_OFFSET: usize = 1 << 21;
+const SEARCH_BLOCKS: usize = 16;
+
+#[derive(Default)]
+struct TrieNode {
+    children: BTreeMap<u8, usize>,
+    value: Option<u32>,
+}
+
+/// The value of a node along with the label and class of each of its children
+type Subtree = (Option<u32>, Vec<(u8, usize)>);
+
+#[derive(Default)]
+struct TrieBuilder {
+    nodes: Vec<TrieNode>,
+}
+
+impl TrieBuilder {
+    fn insert(&mut self, key: &[u8], value: u32) {
+        if self.nodes.is_empty() {
+            self.nodes.push(TrieNode::default());
+        }
+        let mut node = 0;
+        for byte in key {
+            let next = self.nodes.len();
+            node = *self.nodes[node].children.entry(*byte).or_insert(next);
+            if node == next {
+                self.nodes.push(TrieNode::default());
+            }
+        }
+        self.nodes[node].value = Some(value);
+    }
+
+    /// Give the same class to the nodes having identical subtrees, so that they can share their
+    /// children like in the DAWG built by darts-clone
+    fn classes(&self) -> Vec<usize> {
+        let mut classes = vec![0; self.nodes.len()];
+        let mut known: HashMap<Subtree, usize> = HashMap::new();
+        // The children are always inserted after their parent
+        for (i, node) in self.nodes.iter().enumerate().rev() {
+            let children = node
+                .children
+                .iter()
+                .map(|(label, child)| (*label, classes[*child]))
+                .collect();
+            let next = known.len();
+            classes[i] = *known.entry((node.value, children)).or_insert(next);
+        }
+        classes
+    }
+
+    /// Lay the nodes out in a double array: the children of a node are at `base ^ label`, with
+    /// `base = position ^ offset`, and its value at `base`. Each base is used by a single class
+    /// of nodes, so a lookup can't end up in the children of another node.
+    fn build(&self) -> Result<Vec<u32>> {
+        let mut units = vec![0u32; 256];
+        let mut used = vec![false; 256];
+        let mut used_bases = HashSet::new();
+        let mut class_bases: HashMap<usize, usize> = HashMap::new();
+        let classes = self.classes();
+        used[0] = true;
+        let mut first_free = 1;
+
+        let mut queue = VecDeque::new();
+        if !self.nodes.is_empty() {
+            queue.push_back((0, 0));
+        }
+        while let Some((i, position)) = queue.pop_front() {
+            let node = &self.nodes[i];
+            let labels = node
+                .value
+                .map(|_| 0)
+                .into_iter()
+                .chain(node.children.keys().copied())
+                .collect::<Vec<_>>();
+            if labels.is_empty() {
+                continue;
+            }
+            if let Some(base) = class_bases.get(&classes[i]) {
+                if (base ^ position) < MAX_OFFSET {
+                    units[position] |= ((base ^ position) as u32) << 10;
+                    if node.value.is_some() {
+                        units[position] |= HAS_LEAF;
+                    }
+                    continue;
+                }
+            }
+
+            // Like darts-clone, only the last blocks are searched to keep the build linear
+            first_free = first_free.max(units.len().saturating_sub(SEARCH_BLOCKS * 256));
+            while used.get(first_free) == Some(&true) {
+                first_free += 1;
+            }
+            // An offset must fit in 21 bits, which bounds the bases reachable from `position`
+            let base = (first_free..=position | (MAX_OFFSET - 1))
+                .map(|free| free ^ labels[0] as usize)
+                .find(|base| {
+                    (*base ^ position) < MAX_OFFSET
+                        && !used_bases.contains(base)
+                        && labels
+                            .iter()
+                            .all(|label| used.get(base ^ *label as usize) != Some(&true))
+                })
+                .ok_or(CharsMapError::TooLarge)?;
+            if base | 0xFF >= units.len() {
+                units.resize((base | 0xFF) + 1, 0);
+                used.resize((base | 0xFF) + 1, false);
+            }
+
+            used_bases.insert(base);
+            class_bases.entry(classes[i]).or_insert(base);
+            units[position] |= ((base ^ position) as u32) << 10;
+            if let Some(value) = node.value {
+                units[position] |= HAS_LEAF;
+                units[base] = IS_LEAF | value;
+                used[base] = true;
+            }
+            for (label, child) in &node.children {
+                let child_position = base ^ *label as usize;
+                units[child_position] = *label as u32;
+                used[child_position] = true;
+                queue.push_back((*child, child_position));
+            }
+        }
+        Ok(units)
+    }
+}
+
+/// Retrieve the rules of a charsmap, as `(source, target)` pairs sorted by source
+pub fn charsmap_rules(charsmap: &[u8]) -> Result<Vec<(String, String)>> {
+    let malformed = || Box::new(CharsMapError::Malformed);
+    let trie_size = charsmap
+        .get(..4)
+        .map(|size| u32::from_le_bytes([size[0], size[1], size[2], size[3]]) as usize)
+        .ok_or_else(malformed)?;
+    let trie = charsmap.get(4..4 + trie_size).ok_or_else(malformed)?;
+    let units = trie
+        .chunks_exact(4)
+        .map(|unit| u32::from_le_bytes([unit[0], unit[1], unit[2], unit[3]]))
+        .collect::<Vec<_>>();
+    let normalized = &charsmap[4 + trie_size..];
+    let unit = |position: usize| units.get(position).copied().ok_or_else(malformed);
+    let offset = |unit: u32| ((unit >> 10) << ((unit & (1 << 9)) >> 6)) as usize;
+
+    let mut rules = vec![];
+    if units.is_empty() {
+        return Ok(rules);
+    }
+    // A depth first search, leaving each node once all its children have been visited. The
+    // bases of the nodes being visited are kept to reject the malformed tries having a cycle.
+    // Each node of a trie has its own unit, so there can't be more nodes than units: this
+    // rejects the malformed tries sharing nodes, whose number of paths could be exponential.
+    enum Step {
+        Enter(usize, Option<u8>),
+        Leave(usize),
+    }
+    let mut key = vec![];
+    let mut path = HashSet::new();
+    let mut entered = 0;
+    let mut stack = vec![Step::Enter(offset(unit(0)?), None)];
+    while let Some(step) = stack.pop() {
+        let base = match step {
+            Step::Enter(base, label) => {
+                entered += 1;
+                if entered > units.len() || !path.insert(base) {
+                    return Err(malformed());
+                }
+                key.extend(label);
+                base
+            }
+            Step::Leave(base) => {
+                path.remove(&base);
+                key.pop();
+                continue;
+            }
+        };
+        stack.push(Step::Leave(base));
+        for label in 1..=255u8 {
+            let position = base ^ label as usize;
+            let child = match units.get(position) {
+                Some(child) if child & (IS_LEAF | 0xFF) == label as u32 => *child,
+                _ => continue,
+            };
+            let child_base = position ^ offset(child);
+            if child & HAS_LEAF != 0 {
+                let start = (unit(child_base)? & !IS_LEAF) as usize;
+                let target = normalized.get(start..).ok_or_else(malformed)?;
+                let end = target.iter().position(|b| *b == 0).unwrap_or(target.len());
+                let mut source = key.clone();
+                source.push(label);
+                rules.push((
+                    String::from_utf8(source).map_err(|_| malformed())?,
+                    String::from_utf8(target[..end].to_vec()).map_err(|_| malformed())?,
+                ));
+            }
+            stack.push(Step::Enter(child_base, Some(label)));
+        }
+    }
+    rules.sort();
+    Ok(rules)
+}
+
+/// Retrieve the rules of a `Precompiled` normalizer, as `(source, target)` pairs sorted by
+/// source
+pub fn precompiled_rules(precompiled: &Precompiled) -> Result<Vec<(String, String)>> {
+    charsmap_rules(precompiled.charsmap())
+}
+
+/// Write rules in the TSV format read by [`PrecompiledBuilder::from_tsv`], with the characters
+/// in a comment to keep them readable
+pub fn rules_to_tsv(rules: &[(String, String)]) -> String {
+    let codepoints = |s: &str| {
+        s.chars()
+            .map(|c| format!("{:04X}", c as u32))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    rules
+        .iter()
+        .map(|(source, target)| {
+            format!(
+                "{}\t{}\t# {} => {}\n",
+                codepoints(source),
+                codepoints(target),
+                source.escape_debug(),
+                target.escape_debug()
+            )
+        })
+        .collect()
+}
+
 fn replace(transformations: &mut Vec<(char, isize)>, old_part: &str, new_part: &str) {
     let old_count = old_part.chars().count() as isize;
     let new_count = new_part.chars().count() as isize;
@@ -71,6 +499,7 @@ impl Normalizer for Precompiled {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tokenizer::normalizer::Range;
 
     #[test]
     fn expansion_followed_by_removal() {
@@ -86,4 +515,104 @@ mod tests {
 
         assert_eq!(n.get(), "TMg");
     }
+
+    #[test]
+    fn build_and_dump_rules() {
+        let tsv = "# A comment\n\
+                   41\t61\t# A => a\n\
+                   2122\t54 4D\n\
+                   65 301\tE9\n\
+                   200B\t\n";
+        let builder = PrecompiledBuilder::from_tsv(tsv).unwrap();
+        let precompiled = builder.build().unwrap();
+        assert_eq!(
+            precompiled.normalize_string("ABA™e\u{301}\u{200B}!"),
+            "aBaTMé!"
+        );
+
+        let mut n = NormalizedString::from("A™\u{200B}!");
+        precompiled.normalize(&mut n).unwrap();
+        assert_eq!(n.get(), "aTM!");
+        assert_eq!(
+            n.get_range_original(Range::Normalized(1..3)),
+            Some("™\u{200B}")
+        );
+
+        let rules = precompiled_rules(&precompiled).unwrap();
+        let expected = [("A", "a"), ("e\u{301}", "é"), ("\u{200B}", ""), ("™", "TM")]
+            .iter()
+            .map(|(source, target)| (source.to_string(), target.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(rules, expected);
+
+        // The rules can be written back and compiled to the same charsmap
+        let tsv = rules_to_tsv(&rules);
+        assert!(tsv.starts_with("0041\t0061\t# A => a\n"));
+        let rebuilt = PrecompiledBuilder::from_tsv(&tsv).unwrap();
+        assert_eq!(
+            rebuilt.build_charsmap().unwrap(),
+            builder.build_charsmap().unwrap()
+        );
+
+        // And it serializes like any charsmap
+        let json = serde_json::to_string(&precompiled).unwrap();
+        let charsmap = builder.build_charsmap().unwrap();
+        let original = spm_precompiled::Precompiled::from(&charsmap).unwrap();
+        assert_eq!(json, serde_json::to_string(&original).unwrap());
+        let precompiled: Precompiled = serde_json::from_str(&json).unwrap();
+        assert_eq!(precompiled_rules(&precompiled).unwrap(), expected);
+    }
+
+    #[test]
+    fn build_many_rules() {
+        let builder = (0x100u32..0x2000)
+            .filter_map(char::from_u32)
+            .fold(PrecompiledBuilder::new(), |builder, c| {
+                builder.rule(&c.to_string(), &format!("<{:X}>", c as u32))
+            });
+        let precompiled = builder.build().unwrap();
+        assert_eq!(precompiled.normalize_string("aĀ\u{1FFF}"), "a<100><1FFF>");
+        assert_eq!(precompiled_rules(&precompiled).unwrap().len(), 0x1F00);
+    }
+
+    #[test]
+    fn invalid_rules() {
+        assert!(PrecompiledBuilder::from_tsv("41").is_err());
+        assert!(PrecompiledBuilder::from_tsv("41\tZZ").is_err());
+        assert!(PrecompiledBuilder::new().rule("", "a").build().is_err());
+        assert!(charsmap_rules(&[8, 0, 0, 0, 1]).is_err());
+        // A trie whose node `a` is its own parent
+        let mut units = vec![0u32; 512];
+        units[0] = 0x100 << 10;
+        units[0x161] = 0x61 | (0x61 << 10);
+        let mut charsmap = 2048u32.to_le_bytes().to_vec();
+        charsmap.extend(units.iter().flat_map(|unit| unit.to_le_bytes()));
+        assert!(charsmap_rules(&charsmap).is_err());
+        // A trie whose nodes `a` and `b` share their children, doubling the paths at each level
+        let levels = 16;
+        let mut units = vec![0u32; 0x100 * (levels + 2)];
+        units[0] = 0x100 << 10;
+        for level in 1..=levels {
+            let (base, child_base) = (0x100 * level, 0x100 * (level + 1));
+            for label in [0x61, 0x62].iter() {
+                let position = base ^ label;
+                units[position] = *label as u32 | (((position ^ child_base) as u32) << 10);
+            }
+        }
+        let mut charsmap = (units.len() as u32 * 4).to_le_bytes().to_vec();
+        charsmap.extend(units.iter().flat_map(|unit| unit.to_le_bytes()));
+        assert!(charsmap_rules(&charsmap).is_err());
+        let empty = PrecompiledBuilder::new().build().unwrap();
+        assert_eq!(empty.normalize_string("abc"), "abc");
+    }
+
+    #[test]
+    fn default() {
+        let precompiled = Precompiled::default();
+        assert_eq!(precompiled.normalize_string("abc"), "abc");
+        assert!(precompiled_rules(&precompiled).unwrap().is_empty());
+        let mut n = NormalizedString::from("abc");
+        precompiled.normalize(&mut n).unwrap();
+        assert_eq!(n.get(), "abc");
+    }
 }
@@ -33,6 +33,17 @@ impl Normalizer for Sequence {
         }
         Ok(())
     }
+
+    fn normalize_steps(
+        &self,
+        normalized: &mut NormalizedString,
+        on_step: &mut dyn FnMut(Option<&str>, &NormalizedString),
+    ) -> Result<()> {
+        for normalizer in &self.normalizers {
+            normalizer.normalize_steps(normalized, on_step)?;
+        }
+        Ok(())
+    }
 }
 
 /// Lowercases the input
@@ -0,0 +1,445 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::pre_tokenizers::unicode_scripts::{get_script, Script};
+use crate::tokenizer::normalizer::Range;
+use crate::tokenizer::{NormalizedString, PreTokenizedString, PreTokenizer, Result};
+
+#[derive(thiserror::Error, Debug)]
+pub enum DictionaryError {
+    #[error("Invalid frequency on line {0} of the dictionary: {1}")]
+    InvalidFrequency(usize, String),
+}
+
+/// How a run of unspaced text is split into words
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentationMode {
+    /// Use as few words as possible
+    #[default]
+    MaximalMatching,
+    /// Use the most likely words, according to their frequency. The words without a frequency
+    /// count as seen once.
+    UnigramCost,
+}
+
+/// Splits the text written in scripts that don't use spaces between words (Thai, Lao, Khmer,
+/// Myanmar, and the Chinese and Japanese scripts) into the words of a dictionary. The rest of
+/// the text is left as it is.
+///
+/// In both modes, the segmentation avoids the chars that aren't part of any word as much as
+/// possible, and the consecutive ones are kept together.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+pub struct DictionarySegmenter {
+    words: Vec<(String, Option<u64>)>,
+    mode: SegmentationMode,
+    #[serde(skip)]
+    costs: HashMap<String, f64>,
+    #[serde(skip)]
+    max_word_len: usize,
+}
+
+impl<'de> Deserialize<'de> for DictionarySegmenter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        enum Type {
+            DictionarySegmenter,
+        }
+
+        #[derive(Deserialize)]
+        pub struct DictionarySegmenterHelper {
+            #[serde(rename = "type")]
+            _type: Type,
+            words: Vec<(String, Option<u64>)>,
+            #[serde(default)]
+            mode: SegmentationMode,
+        }
+
+        let helper = DictionarySegmenterHelper::deserialize(deserializer)?;
+        Ok(Self::new(helper.words, helper.mode))
+    }
+}
+
+impl Clone for DictionarySegmenter {
+    fn clone(&self) -> Self {
+        Self::new(self.words.clone(), self.mode)
+    }
+}
+
+impl PartialEq for DictionarySegmenter {
+    fn eq(&self, other: &Self) -> bool {
+        self.words == other.words && self.mode == other.mode
+    }
+}
+
+impl DictionarySegmenter {
+    /// Create a segmenter from the given words, each with an optional frequency. The empty
+    /// words are ignored.
+    pub fn new(words: Vec<(String, Option<u64>)>, mode: SegmentationMode) -> Self {
+        let words = words
+            .into_iter()
+            .filter(|(word, _)| !word.is_empty())
+            .collect::<Vec<_>>();
+        let frequency = |freq: &Option<u64>| freq.unwrap_or(1).max(1) as f64;
+        let total = words.iter().map(|(_, freq)| frequency(freq)).sum::<f64>();
+        let costs = words
+            .iter()
+            .map(|(word, freq)| {
+                let cost = match mode {
+                    SegmentationMode::MaximalMatching => 1.0,
+                    SegmentationMode::UnigramCost => total.ln() - frequency(freq).ln(),
+                };
+                (word.clone(), cost)
+            })
+            .collect();
+        let max_word_len = words
+            .iter()
+            .map(|(word, _)| word.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        Self {
+            words,
+            mode,
+            costs,
+            max_word_len,
+        }
+    }
+
+    /// Load the words from a file with a word per line, optionally followed by a tab and its
+    /// frequency
+    pub fn from_file<P: AsRef<Path>>(path: P, mode: SegmentationMode) -> Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut words = vec![];
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim_end_matches(['\r', '\n']);
+            if line.is_empty() {
+                continue;
+            }
+            let (word, freq) = match line.rsplit_once('\t') {
+                Some((word, freq)) => {
+                    let freq = freq
+                        .trim()
+                        .parse()
+                        .map_err(|_| DictionaryError::InvalidFrequency(index + 1, freq.into()))?;
+                    (word, Some(freq))
+                }
+                None => (line, None),
+            };
+            words.push((word.to_owned(), freq));
+        }
+        Ok(Self::new(words, mode))
+    }
+
+    pub fn words(&self) -> &[(String, Option<u64>)] {
+        &self.words
+    }
+
+    pub fn mode(&self) -> SegmentationMode {
+        self.mode
+    }
+
+    /// Find the best segmentation of the given chars, and return the byte offset where each
+    /// word starts, relative to the first char
+    fn segment(&self, text: &str, chars: &[(usize, char)]) -> Vec<usize> {
+        let start = chars[0].0;
+        let end = chars
+            .last()
+            .map(|(offset, c)| offset + c.len_utf8())
+            .unwrap();
+        let offset = |i: usize| chars.get(i).map_or(end, |(offset, _)| *offset);
+
+        // For each char, the best segmentation ending right before it
+        let mut best: Vec<Option<Step>> = vec![None; chars.len() + 1];
+        best[0] = Some(Step {
+            cost: (0, 0.0),
+            start: 0,
+            unknown: false,
+        });
+        for i in 0..chars.len() {
+            let (unknown, cost) = best[i].unwrap().cost;
+            let mut relax = |j: usize, step: Step| {
+                if best[j].is_none_or(|current| step.cost < current.cost) {
+                    best[j] = Some(step);
+                }
+            };
+            relax(
+                i + 1,
+                Step {
+                    cost: (unknown + 1, cost),
+                    start: i,
+                    unknown: true,
+                },
+            );
+            for j in i + 1..=(i + self.max_word_len).min(chars.len()) {
+                if let Some(word_cost) = self.costs.get(&text[offset(i)..offset(j)]) {
+                    relax(
+                        j,
+                        Step {
+                            cost: (unknown, cost + word_cost),
+                            start: i,
+                            unknown: false,
+                        },
+                    );
+                }
+            }
+        }
+
+        let mut starts = vec![];
+        let mut j = chars.len();
+        while j > 0 {
+            let Step {
+                start: i, unknown, ..
+            } = best[j].unwrap();
+            let merged = unknown && i > 0 && best[i].is_some_and(|previous| previous.unknown);
+            if !merged {
+                starts.push(offset(i) - start);
+            }
+            j = i;
+        }
+        starts.reverse();
+        starts
+    }
+}
+
+/// The last word of a segmentation
+#[derive(Clone, Copy)]
+struct Step {
+    /// The number of unknown chars, then the cost of the words, of the whole segmentation
+    cost: (usize, f64),
+    /// The char where the word starts
+    start: usize,
+    /// Whether the word is a single char that isn't part of the dictionary
+    unknown: bool,
+}
+
+/// The script of the chars to segment, if any. The Hiragana and Katakana are handled along with
+/// the Han chars, since Japanese mixes them.
+fn segmented_script(c: char) -> Option<Script> {
+    match get_script(c) {
+        _ if c as u32 == 0x30FC => Some(Script::Han),
+        Script::Hiragana | Script::Katakana | Script::Han => Some(Script::Han),
+        script @ (Script::Thai | Script::Lao | Script::Khmer | Script::Myanmar) => Some(script),
+        _ => None,
+    }
+}
+
+impl PreTokenizer for DictionarySegmenter {
+    fn pre_tokenize(&self, pretokenized: &mut PreTokenizedString) -> Result<()> {
+        pretokenized.split(|_, normalized| {
+            let text = normalized.get();
+            let chars = text.char_indices().collect::<Vec<_>>();
+
+            let mut offsets = vec![];
+            let mut run_start = 0;
+            for i in 1..=chars.len() {
+                let script = segmented_script(chars[run_start].1);
+                if i < chars.len() && segmented_script(chars[i].1) == script {
+                    continue;
+                }
+                let run = &chars[run_start..i];
+                match script {
+                    Some(_) => {
+                        let start = run[0].0;
+                        offsets.extend(
+                            self.segment(text, run)
+                                .into_iter()
+                                .map(|offset| start + offset),
+                        );
+                    }
+                    None => offsets.push(run[0].0),
+                }
+                run_start = i;
+            }
+            offsets.push(text.len());
+
+            Ok(offsets
+                .windows(2)
+                .map(|item| {
+                    normalized
+                        .slice(Range::Normalized(item[0]..item[1]))
+                        .expect("NormalizedString bad split")
+                })
+                .collect::<Vec<NormalizedString>>())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pre_tokenizers::PreTokenizerWrapper;
+    use crate::{OffsetReferential, OffsetType};
+    use std::io::Write;
+
+    fn segmenter(words: &[(&str, Option<u64>)], mode: SegmentationMode) -> DictionarySegmenter {
+        let words = words
+            .iter()
+            .map(|(word, freq)| (word.to_string(), *freq))
+            .collect();
+        DictionarySegmenter::new(words, mode)
+    }
+
+    fn splits(pretok: &DictionarySegmenter, s: &str) -> Vec<(String, (usize, usize))> {
+        let mut pretokenized = PreTokenizedString::from(s);
+        pretok.pre_tokenize(&mut pretokenized).unwrap();
+        pretokenized
+            .get_splits(OffsetReferential::Original, OffsetType::Char)
+            .into_iter()
+            .map(|(s, o, _)| (s.to_owned(), o))
+            .collect()
+    }
+
+    fn words(splits: Vec<(String, (usize, usize))>) -> Vec<String> {
+        splits.into_iter().map(|(s, _)| s).collect()
+    }
+
+    #[test]
+    fn maximal_matching() {
+        let pretok = segmenter(
+            &[
+                ("ไป", None),
+                ("ตา", None),
+                ("กลม", None),
+                ("ตาก", None),
+                ("ลม", None),
+                ("ตากลม", None),
+            ],
+            SegmentationMode::MaximalMatching,
+        );
+        assert_eq!(
+            splits(&pretok, "ไปตากลม ok"),
+            vec![
+                ("ไป".into(), (0, 2)),
+                ("ตากลม".into(), (2, 7)),
+                (" ok".into(), (7, 10))
+            ]
+        );
+        // The unknown chars are kept together
+        assert_eq!(
+            words(splits(&pretok, "ไปxyzกขลม")),
+            vec!["ไป", "xyz", "กข", "ลม"]
+        );
+    }
+
+    #[test]
+    fn unigram_cost() {
+        let dictionary = [
+            ("ตา", Some(100)),
+            ("กลม", Some(100)),
+            ("ตาก", Some(10)),
+            ("ลม", Some(10)),
+            ("ตากลม", Some(1)),
+        ];
+        let pretok = segmenter(&dictionary, SegmentationMode::UnigramCost);
+        assert_eq!(words(splits(&pretok, "ตากลม")), vec!["ตา", "กลม"]);
+        let pretok = segmenter(&dictionary, SegmentationMode::MaximalMatching);
+        assert_eq!(words(splits(&pretok, "ตากลม")), vec!["ตากลม"]);
+    }
+
+    #[test]
+    fn script_runs() {
+        let pretok = segmenter(
+            &[
+                ("東京", None),
+                ("に", None),
+                ("行く", None),
+                ("ສະບາຍດີ", None),
+            ],
+            SegmentationMode::MaximalMatching,
+        );
+        assert_eq!(
+            splits(&pretok, "東京に行く。ສະບາຍດີ"),
+            vec![
+                ("東京".into(), (0, 2)),
+                ("に".into(), (2, 3)),
+                ("行く".into(), (3, 5)),
+                ("。".into(), (5, 6)),
+                ("ສະບາຍດີ".into(), (6, 13))
+            ]
+        );
+        // A word spanning two scripts can't match
+        let pretok = segmenter(&[("東京ไทย", None)], SegmentationMode::MaximalMatching);
+        assert_eq!(words(splits(&pretok, "東京ไทย")), vec!["東京", "ไทย"]);
+    }
+
+    #[test]
+    fn word_ids() {
+        use crate::models::wordlevel::WordLevel;
+        use crate::Tokenizer;
+
+        let vocab = [("<unk>", 0), ("ไป", 1), ("ตา", 2), ("กลม", 3)]
+            .iter()
+            .map(|(token, id)| (token.to_string(), *id))
+            .collect();
+        let model = WordLevel::builder()
+            .vocab(vocab)
+            .unk_token("<unk>".into())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(model);
+        tokenizer.with_pre_tokenizer(segmenter(
+            &[("ไป", None), ("ตา", None), ("กลม", None)],
+            SegmentationMode::MaximalMatching,
+        ));
+        let encoding = tokenizer.encode("ไปตากลม", false).unwrap();
+        assert_eq!(encoding.get_ids(), &[1, 2, 3]);
+        assert_eq!(encoding.get_word_ids(), &[Some(0), Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn from_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "ตา\t100\nกลม\n\nลม\t3").unwrap();
+        let pretok =
+            DictionarySegmenter::from_file(file.path(), SegmentationMode::UnigramCost).unwrap();
+        assert_eq!(
+            pretok.words(),
+            &[
+                ("ตา".to_string(), Some(100)),
+                ("กลม".to_string(), None),
+                ("ลม".to_string(), Some(3))
+            ]
+        );
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "ตา\tmany").unwrap();
+        assert!(
+            DictionarySegmenter::from_file(file.path(), SegmentationMode::UnigramCost).is_err()
+        );
+    }
+
+    #[test]
+    fn serialization() {
+        let pretok: PreTokenizerWrapper = segmenter(
+            &[("ตา", Some(100)), ("กลม", None)],
+            SegmentationMode::UnigramCost,
+        )
+        .into();
+        let json = serde_json::to_string(&pretok).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"DictionarySegmenter","words":[["ตา",100],["กลม",null]],"mode":"unigram_cost"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<PreTokenizerWrapper>(&json).unwrap(),
+            pretok
+        );
+        let pretok: PreTokenizerWrapper =
+            serde_json::from_str(r#"{"type":"DictionarySegmenter","words":[["ตา",null]]}"#)
+                .unwrap();
+        assert_eq!(
+            pretok,
+            segmenter(&[("ตา", None)], SegmentationMode::MaximalMatching).into()
+        );
+    }
+}
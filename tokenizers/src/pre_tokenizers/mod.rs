@@ -1,6 +1,7 @@
 pub mod bert;
 pub mod byte_level;
 pub mod delimiter;
+pub mod dictionary;
 pub mod digits;
 pub mod metaspace;
 pub mod punctuation;
@@ -14,6 +15,7 @@ use serde::{Deserialize, Serialize};
 use crate::pre_tokenizers::bert::BertPreTokenizer;
 use crate::pre_tokenizers::byte_level::ByteLevel;
 use crate::pre_tokenizers::delimiter::CharDelimiterSplit;
+use crate::pre_tokenizers::dictionary::DictionarySegmenter;
 use crate::pre_tokenizers::digits::Digits;
 use crate::pre_tokenizers::metaspace::Metaspace;
 use crate::pre_tokenizers::punctuation::Punctuation;
@@ -21,6 +23,7 @@ use crate::pre_tokenizers::sequence::Sequence;
 use crate::pre_tokenizers::split::Split;
 use crate::pre_tokenizers::unicode_scripts::UnicodeScripts;
 use crate::pre_tokenizers::whitespace::{Whitespace, WhitespaceSplit};
+use crate::tokenizer::component_type;
 use crate::{PreTokenizedString, PreTokenizer};
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
@@ -37,6 +40,7 @@ pub enum PreTokenizerWrapper {
     WhitespaceSplit(WhitespaceSplit),
     Digits(Digits),
     UnicodeScripts(UnicodeScripts),
+    DictionarySegmenter(DictionarySegmenter),
 }
 
 impl PreTokenizer for PreTokenizerWrapper {
@@ -53,6 +57,22 @@ impl PreTokenizer for PreTokenizerWrapper {
             Self::WhitespaceSplit(wspt) => wspt.pre_tokenize(normalized),
             Self::Digits(wspt) => wspt.pre_tokenize(normalized),
             Self::UnicodeScripts(us) => us.pre_tokenize(normalized),
+            Self::DictionarySegmenter(ds) => ds.pre_tokenize(normalized),
+        }
+    }
+
+    fn pre_tokenize_steps(
+        &self,
+        pretokenized: &mut PreTokenizedString,
+        on_step: &mut dyn FnMut(Option<&str>, &PreTokenizedString),
+    ) -> crate::Result<()> {
+        match self {
+            Self::Sequence(sequence) => sequence.pre_tokenize_steps(pretokenized, on_step),
+            _ => {
+                self.pre_tokenize(pretokenized)?;
+                on_step(component_type(self).as_deref(), pretokenized);
+                Ok(())
+            }
         }
     }
 }
@@ -68,6 +88,11 @@ impl_enum_from!(Metaspace, PreTokenizerWrapper, Metaspace);
 impl_enum_from!(WhitespaceSplit, PreTokenizerWrapper, WhitespaceSplit);
 impl_enum_from!(Digits, PreTokenizerWrapper, Digits);
 impl_enum_from!(UnicodeScripts, PreTokenizerWrapper, UnicodeScripts);
+impl_enum_from!(
+    DictionarySegmenter,
+    PreTokenizerWrapper,
+    DictionarySegmenter
+);
 
 #[cfg(test)]
 mod tests {
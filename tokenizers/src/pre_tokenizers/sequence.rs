@@ -30,6 +30,17 @@ impl PreTokenizer for Sequence {
         }
         Ok(())
     }
+
+    fn pre_tokenize_steps(
+        &self,
+        pretokenized: &mut PreTokenizedString,
+        on_step: &mut dyn FnMut(Option<&str>, &PreTokenizedString),
+    ) -> Result<()> {
+        for pretokenizer in &self.pretokenizers {
+            pretokenizer.pre_tokenize_steps(pretokenized, on_step)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -40,11 +40,35 @@ impl PostProcessor for BertProcessing {
         }
     }
 
+    fn added_tokens_multi(&self, n_sequences: usize) -> Result<usize> {
+        // [CLS] at the beginning, and [SEP] after each sequence
+        Ok(n_sequences + 1)
+    }
+
+    fn get_special_ids(&self) -> Vec<u32> {
+        vec![self.cls.1, self.sep.1]
+    }
+
+    fn remap_ids(&mut self, ids: &HashMap<u32, u32>) {
+        for (_, id) in [&mut self.cls, &mut self.sep] {
+            *id = ids.get(id).copied().unwrap_or(*id);
+        }
+    }
+
     fn process_encodings(
         &self,
         mut encodings: Vec<Encoding>,
         add_special_tokens: bool,
     ) -> Result<Vec<Encoding>> {
+        // BERT only has two token types, shared by all the sequences after the first one
+        encodings.iter_mut().skip(1).for_each(|encoding| {
+            encoding.set_type_ids(vec![1; encoding.len()]);
+            encoding
+                .get_overflowing_mut()
+                .iter_mut()
+                .for_each(|encoding| encoding.set_type_ids(vec![1; encoding.len()]));
+        });
+
         if !add_special_tokens {
             return Ok(encodings);
         }
@@ -131,7 +155,7 @@ impl PostProcessor for BertProcessing {
 
                     // For compatibility with `TemplateProcessing`, the sequence_ranges shouldn't contain
                     // the special tokens.
-                    let pair_sequence_ranges = HashMap::from_iter(vec![(1, 0..pair_ids.len() - 1)]);
+                    let pair_sequence_ranges = HashMap::from_iter(vec![(i, 0..pair_ids.len() - 1)]);
                     Encoding::new(
                         pair_ids,
                         pair_type_ids,
@@ -157,7 +181,7 @@ impl PostProcessor for BertProcessing {
                                 // For compatibility with `TemplateProcessing`, the sequence_ranges
                                 // shouldn't contain the special tokens.
                                 let pair_sequence_ranges =
-                                    HashMap::from_iter(vec![(1, 0..pair_ids.len() - 1)]);
+                                    HashMap::from_iter(vec![(i, 0..pair_ids.len() - 1)]);
                                 Encoding::new(
                                     pair_ids,
                                     pair_type_ids,
@@ -282,4 +306,29 @@ mod tests {
         assert_eq!(pair_encoding.token_to_sequence(1), Some(0));
         assert_eq!(pair_encoding.token_to_sequence(2), Some(1));
     }
+
+    #[test]
+    fn bert_processing_multi() {
+        use crate::Token;
+        let processor = BertProcessing::default();
+        assert_eq!(processor.added_tokens_multi(3).unwrap(), 4);
+
+        let encodings = ["query", "first", "second"]
+            .iter()
+            .enumerate()
+            .map(|(i, token)| {
+                Encoding::from_tokens(
+                    vec![Token::new(20 + i as u32, token.to_string(), (0, 5))],
+                    0,
+                )
+            })
+            .collect::<Vec<_>>();
+        // Every sequence after the first one gets the type id 1
+        let encoding = processor.process_multi(encodings.clone(), true).unwrap();
+        assert_eq!(encoding.get_ids(), &[101, 20, 102, 21, 102, 22, 102]);
+        assert_eq!(encoding.get_type_ids(), &[0, 0, 0, 1, 1, 1, 1]);
+        assert_eq!(encoding.token_to_sequence(5), Some(2));
+        let encoding = processor.process_multi(encodings, false).unwrap();
+        assert_eq!(encoding.get_type_ids(), &[0, 1, 1]);
+    }
 }
@@ -0,0 +1,422 @@
+//! # Chat Template Processing
+//!
+//! Provides a way to encode a conversation, made of multiple messages written by different
+//! roles (`system`, `user`, `assistant`, ...), as a single `Encoding`. The special tokens to add
+//! around the messages of each role are specified in a [`ChatTemplate`]:
+//! ```
+//! # use tokenizers::processors::chat::{ChatTemplate, RoleTemplate};
+//! let template = ChatTemplate::builder()
+//!     .prefix(vec!["<s>".into()])
+//!     .roles(vec![
+//!         ("user", RoleTemplate::new(vec!["[INST]".into()], vec!["[/INST]".into()])),
+//!         ("assistant", RoleTemplate::new(vec![], vec!["</s>".into()]).assistant(true)),
+//!     ])
+//!     .special_tokens(vec![("<s>", 1), ("</s>", 2), ("[INST]", 3), ("[/INST]", 4)])
+//!     .build()
+//!     .unwrap();
+//! ```
+//!
+//! The content of each message keeps its own sequence id, while the special tokens have none,
+//! and the offsets of its tokens are relative to it. Tokens of the messages written by an
+//! `assistant` role are marked in the `assistant_mask` of the resulting [`ChatEncoding`], which
+//! allows to compute a loss on these spans only.
+use super::template::Tokens;
+use crate::{Encoding, PostProcessor, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+/// A message of a conversation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: String,
+    pub content: String,
+}
+
+impl ChatMessage {
+    pub fn new<R: Into<String>, C: Into<String>>(role: R, content: C) -> Self {
+        Self {
+            role: role.into(),
+            content: content.into(),
+        }
+    }
+}
+
+/// The encoding of a whole conversation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatEncoding {
+    /// The `Encoding` of all the messages, with one sequence id per message
+    pub encoding: Encoding,
+    /// 1 for the tokens of the messages written by an assistant role, including the suffix
+    /// of these messages, and 0 everywhere else
+    pub assistant_mask: Vec<u32>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ChatTemplateError {
+    #[error("No template for role `{0}`")]
+    UnknownRole(String),
+    #[error("Missing SpecialToken with id `{0}`")]
+    UnknownSpecialToken(String),
+}
+
+/// The special tokens added around each message of a role
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RoleTemplate {
+    /// The special tokens added before the content of each message
+    #[serde(default)]
+    pub prefix: Vec<String>,
+    /// The special tokens added after the content of each message
+    #[serde(default)]
+    pub suffix: Vec<String>,
+    /// The type id of the tokens of each message
+    #[serde(default)]
+    pub type_id: u32,
+    /// Whether this role is an assistant, whose messages are in the `assistant_mask`
+    #[serde(default)]
+    pub assistant: bool,
+}
+
+impl RoleTemplate {
+    pub fn new(prefix: Vec<String>, suffix: Vec<String>) -> Self {
+        Self {
+            prefix,
+            suffix,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn type_id(mut self, type_id: u32) -> Self {
+        self.type_id = type_id;
+        self
+    }
+
+    #[must_use]
+    pub fn assistant(mut self, assistant: bool) -> Self {
+        self.assistant = assistant;
+        self
+    }
+}
+
+/// Wrapper for the roles, to convert them from a list of pairs
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Roles(
+    #[serde(serialize_with = "crate::utils::ordered_map")] pub HashMap<String, RoleTemplate>,
+);
+
+impl<S: Into<String>> From<Vec<(S, RoleTemplate)>> for Roles {
+    fn from(v: Vec<(S, RoleTemplate)>) -> Self {
+        Self(v.into_iter().map(|(r, t)| (r.into(), t)).collect())
+    }
+}
+
+impl From<HashMap<String, RoleTemplate>> for Roles {
+    fn from(roles: HashMap<String, RoleTemplate>) -> Self {
+        Self(roles)
+    }
+}
+
+/// A post-processor formatting conversations, using the template of the role of each message.
+/// When used to process regular sequences, only the special tokens around the whole
+/// conversation are added.
+#[derive(Debug, Clone, PartialEq, Eq, Builder, Serialize, Deserialize)]
+#[serde(tag = "type", try_from = "ChatTemplateDeserializer")]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ChatTemplate {
+    /// The special tokens added at the beginning of the conversation
+    #[builder(default)]
+    #[serde(default)]
+    prefix: Vec<String>,
+    /// The special tokens added at the end of the conversation
+    #[builder(default)]
+    #[serde(default)]
+    suffix: Vec<String>,
+    #[builder(setter(into))]
+    roles: Roles,
+    #[builder(setter(into), default)]
+    special_tokens: Tokens,
+}
+
+impl From<&str> for ChatTemplateBuilderError {
+    fn from(e: &str) -> Self {
+        e.to_string().into()
+    }
+}
+
+/// We use this custom deserializer to validate the templates like the builder does
+#[doc(hidden)]
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+struct ChatTemplateDeserializer {
+    #[serde(default)]
+    prefix: Vec<String>,
+    #[serde(default)]
+    suffix: Vec<String>,
+    roles: Roles,
+    #[serde(default)]
+    special_tokens: Tokens,
+}
+
+impl TryFrom<ChatTemplateDeserializer> for ChatTemplate {
+    type Error = ChatTemplateBuilderError;
+
+    fn try_from(t: ChatTemplateDeserializer) -> std::result::Result<Self, Self::Error> {
+        ChatTemplate::builder()
+            .prefix(t.prefix)
+            .suffix(t.suffix)
+            .roles(t.roles)
+            .special_tokens(t.special_tokens)
+            .build()
+    }
+}
+
+impl ChatTemplateBuilder {
+    fn validate(&self) -> std::result::Result<(), String> {
+        let empty = Tokens::default();
+        let special_tokens = self.special_tokens.as_ref().unwrap_or(&empty);
+        let roles = self.roles.iter().flat_map(|roles| roles.0.values());
+        let missing = self
+            .prefix
+            .iter()
+            .chain(self.suffix.iter())
+            .flatten()
+            .chain(roles.flat_map(|role| role.prefix.iter().chain(&role.suffix)))
+            .find(|token| !special_tokens.0.contains_key(*token));
+        match missing {
+            Some(token) => Err(format!(
+                "Missing SpecialToken(s) with id(s) `{}`",
+                token.as_str()
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ChatTemplate {
+    pub fn builder() -> ChatTemplateBuilder {
+        ChatTemplateBuilder::default()
+    }
+
+    pub fn get_roles(&self) -> &HashMap<String, RoleTemplate> {
+        &self.roles.0
+    }
+
+    /// The `Encoding` of the given special tokens
+    fn special_tokens(&self, tokens: &[String], type_id: u32) -> Result<Encoding> {
+        let encodings = tokens
+            .iter()
+            .map(|token| {
+                self.special_tokens
+                    .0
+                    .get(token)
+                    .map(|token| token.encoding(type_id))
+                    .ok_or_else(|| ChatTemplateError::UnknownSpecialToken(token.clone()))
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(Encoding::merge(encodings, false))
+    }
+}
+
+impl PostProcessor for ChatTemplate {
+    fn added_tokens(&self, _is_pair: bool) -> usize {
+        // The special tokens are all known once the template is built
+        [&self.prefix, &self.suffix]
+            .iter()
+            .flat_map(|tokens| tokens.iter())
+            .filter_map(|token| self.special_tokens.0.get(token))
+            .map(|token| token.encoding(0).len())
+            .sum()
+    }
+
+    fn process_encodings(
+        &self,
+        encodings: Vec<Encoding>,
+        add_special_tokens: bool,
+    ) -> Result<Vec<Encoding>> {
+        if !add_special_tokens {
+            return Ok(encodings);
+        }
+        let mut processed = vec![self.special_tokens(&self.prefix, 0)?];
+        processed.extend(encodings);
+        processed.push(self.special_tokens(&self.suffix, 0)?);
+        Ok(processed)
+    }
+
+    fn process_chat(
+        &self,
+        messages: Vec<(String, Encoding)>,
+        add_special_tokens: bool,
+    ) -> Result<ChatEncoding> {
+        let mut encodings = vec![];
+        let mut assistant_mask = vec![];
+        if add_special_tokens {
+            let prefix = self.special_tokens(&self.prefix, 0)?;
+            assistant_mask.extend(vec![0; prefix.len()]);
+            encodings.push(prefix);
+        }
+
+        for (i, (role, mut encoding)) in messages.into_iter().enumerate() {
+            let template = self
+                .roles
+                .0
+                .get(&role)
+                .ok_or(ChatTemplateError::UnknownRole(role))?;
+            encoding.set_type_ids(vec![template.type_id; encoding.len()]);
+            // Like with `TemplateProcessing`, the special tokens don't get a sequence id
+            encoding.set_sequence_id(i);
+            let message = if add_special_tokens {
+                let prefix = self.special_tokens(&template.prefix, template.type_id)?;
+                let suffix = self.special_tokens(&template.suffix, template.type_id)?;
+                assistant_mask.extend(vec![0; prefix.len()]);
+                assistant_mask.extend(vec![
+                    u32::from(template.assistant);
+                    encoding.len() + suffix.len()
+                ]);
+                Encoding::merge(vec![prefix, encoding, suffix], false)
+            } else {
+                assistant_mask.extend(vec![u32::from(template.assistant); encoding.len()]);
+                encoding
+            };
+            encodings.push(message);
+        }
+
+        if add_special_tokens {
+            let suffix = self.special_tokens(&self.suffix, 0)?;
+            assistant_mask.extend(vec![0; suffix.len()]);
+            encodings.push(suffix);
+        }
+
+        Ok(ChatEncoding {
+            encoding: Encoding::merge(encodings, false),
+            assistant_mask,
+        })
+    }
+
+    fn get_special_ids(&self) -> Vec<u32> {
+        self.special_tokens.ids()
+    }
+
+    fn remap_ids(&mut self, ids: &HashMap<u32, u32>) {
+        self.special_tokens.remap_ids(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template() -> ChatTemplate {
+        ChatTemplate::builder()
+            .prefix(vec!["<s>".into()])
+            .roles(vec![
+                (
+                    "user",
+                    RoleTemplate::new(vec!["[INST]".into()], vec!["[/INST]".into()]),
+                ),
+                (
+                    "assistant",
+                    RoleTemplate::new(vec!["[ASST]".into()], vec!["</s>".into()])
+                        .type_id(1)
+                        .assistant(true),
+                ),
+            ])
+            .special_tokens(vec![
+                ("<s>", 1),
+                ("</s>", 2),
+                ("[INST]", 3),
+                ("[/INST]", 4),
+                ("[ASST]", 5),
+            ])
+            .build()
+            .unwrap()
+    }
+
+    fn content(ids: &[u32]) -> Encoding {
+        Encoding::new(
+            ids.to_vec(),
+            vec![0; ids.len()],
+            ids.iter().map(|id| id.to_string()).collect(),
+            vec![None; ids.len()],
+            (0..ids.len()).map(|i| (i, i + 1)).collect(),
+            vec![0; ids.len()],
+            vec![1; ids.len()],
+            vec![],
+            HashMap::new(),
+        )
+    }
+
+    #[test]
+    fn chat_serde() {
+        let template = template();
+        let serialized = serde_json::to_string(&template).unwrap();
+        assert!(serialized.starts_with(r#"{"type":"ChatTemplate","prefix":["<s>"],"suffix":[],"roles":{"assistant":{"prefix":["[ASST]"],"suffix":["</s>"],"type_id":1,"assistant":true},"user""#));
+        assert_eq!(
+            serde_json::from_str::<ChatTemplate>(&serialized).unwrap(),
+            template
+        );
+
+        let missing = ChatTemplate::builder()
+            .roles(vec![(
+                "user",
+                RoleTemplate::new(vec!["[INST]".into()], vec![]),
+            )])
+            .build();
+        assert!(missing.is_err());
+
+        // Deserialized templates are validated too
+        let missing = r#"{"type":"ChatTemplate","prefix":["<s>"],"roles":{}}"#;
+        assert!(serde_json::from_str::<ChatTemplate>(missing).is_err());
+    }
+
+    #[test]
+    fn chat_process() {
+        let template = template();
+        let messages = vec![
+            ("user".to_string(), content(&[10, 11])),
+            ("assistant".to_string(), content(&[12])),
+            ("user".to_string(), content(&[13])),
+        ];
+
+        let chat = template.process_chat(messages.clone(), true).unwrap();
+        let encoding = &chat.encoding;
+        assert_eq!(encoding.get_ids(), &[1, 3, 10, 11, 4, 5, 12, 2, 3, 13, 4]);
+        assert_eq!(chat.assistant_mask, vec![0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0]);
+        assert_eq!(
+            encoding.get_sequence_ids(),
+            vec![
+                None,
+                None,
+                Some(0),
+                Some(0),
+                None,
+                None,
+                Some(1),
+                None,
+                None,
+                Some(2),
+                None
+            ]
+        );
+        assert_eq!(encoding.get_type_ids(), &[0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0]);
+        assert_eq!(
+            encoding.get_special_tokens_mask(),
+            &[1, 1, 0, 0, 1, 1, 0, 1, 1, 0, 1]
+        );
+        // The offsets are relative to each message
+        assert_eq!(encoding.get_offsets()[3], (1, 2));
+        assert_eq!(encoding.get_offsets()[6], (0, 1));
+
+        let chat = template.process_chat(messages, false).unwrap();
+        assert_eq!(chat.encoding.get_ids(), &[10, 11, 12, 13]);
+        assert_eq!(
+            chat.encoding.get_sequence_ids(),
+            vec![Some(0), Some(0), Some(1), Some(2)]
+        );
+        assert_eq!(chat.assistant_mask, vec![0, 0, 1, 0]);
+
+        let unknown = vec![("tool".to_string(), content(&[10]))];
+        assert!(template.process_chat(unknown, true).is_err());
+    }
+}
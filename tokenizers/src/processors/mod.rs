@@ -1,4 +1,5 @@
 pub mod bert;
+pub mod chat;
 pub mod roberta;
 pub mod sequence;
 pub mod template;
@@ -7,9 +8,11 @@ pub mod template;
 pub use super::pre_tokenizers::byte_level;
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use crate::pre_tokenizers::byte_level::ByteLevel;
 use crate::processors::bert::BertProcessing;
+use crate::processors::chat::{ChatEncoding, ChatTemplate};
 use crate::processors::roberta::RobertaProcessing;
 use crate::processors::sequence::Sequence;
 use crate::processors::template::TemplateProcessing;
@@ -18,6 +21,9 @@ use crate::{Encoding, PostProcessor, Result};
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Eq)]
 #[serde(untagged)]
 pub enum PostProcessorWrapper {
+    // ChatTemplate must be first: its required `roles` make it unambiguous, while the
+    // others would accept its fields
+    ChatTemplate(ChatTemplate),
     // Roberta must be before Bert for deserialization (serde does not validate tags)
     Roberta(RobertaProcessing),
     Bert(BertProcessing),
@@ -29,6 +35,7 @@ pub enum PostProcessorWrapper {
 impl PostProcessor for PostProcessorWrapper {
     fn added_tokens(&self, is_pair: bool) -> usize {
         match self {
+            Self::ChatTemplate(chat) => chat.added_tokens(is_pair),
             Self::Bert(bert) => bert.added_tokens(is_pair),
             Self::ByteLevel(bl) => bl.added_tokens(is_pair),
             Self::Roberta(roberta) => roberta.added_tokens(is_pair),
@@ -37,12 +44,24 @@ impl PostProcessor for PostProcessorWrapper {
         }
     }
 
+    fn added_tokens_multi(&self, n_sequences: usize) -> Result<usize> {
+        match self {
+            Self::ChatTemplate(chat) => chat.added_tokens_multi(n_sequences),
+            Self::Bert(bert) => bert.added_tokens_multi(n_sequences),
+            Self::ByteLevel(bl) => bl.added_tokens_multi(n_sequences),
+            Self::Roberta(roberta) => roberta.added_tokens_multi(n_sequences),
+            Self::Template(template) => template.added_tokens_multi(n_sequences),
+            Self::Sequence(bl) => bl.added_tokens_multi(n_sequences),
+        }
+    }
+
     fn process_encodings(
         &self,
         encodings: Vec<Encoding>,
         add_special_tokens: bool,
     ) -> Result<Vec<Encoding>> {
         match self {
+            Self::ChatTemplate(chat) => chat.process_encodings(encodings, add_special_tokens),
             Self::Bert(bert) => bert.process_encodings(encodings, add_special_tokens),
             Self::ByteLevel(bl) => bl.process_encodings(encodings, add_special_tokens),
             Self::Roberta(roberta) => roberta.process_encodings(encodings, add_special_tokens),
@@ -50,8 +69,46 @@ impl PostProcessor for PostProcessorWrapper {
             Self::Sequence(bl) => bl.process_encodings(encodings, add_special_tokens),
         }
     }
+
+    fn process_chat(
+        &self,
+        messages: Vec<(String, Encoding)>,
+        add_special_tokens: bool,
+    ) -> Result<ChatEncoding> {
+        match self {
+            Self::ChatTemplate(chat) => chat.process_chat(messages, add_special_tokens),
+            Self::Bert(bert) => bert.process_chat(messages, add_special_tokens),
+            Self::ByteLevel(bl) => bl.process_chat(messages, add_special_tokens),
+            Self::Roberta(roberta) => roberta.process_chat(messages, add_special_tokens),
+            Self::Template(template) => template.process_chat(messages, add_special_tokens),
+            Self::Sequence(bl) => bl.process_chat(messages, add_special_tokens),
+        }
+    }
+
+    fn get_special_ids(&self) -> Vec<u32> {
+        match self {
+            Self::ChatTemplate(chat) => chat.get_special_ids(),
+            Self::Bert(bert) => bert.get_special_ids(),
+            Self::ByteLevel(bl) => bl.get_special_ids(),
+            Self::Roberta(roberta) => roberta.get_special_ids(),
+            Self::Template(template) => template.get_special_ids(),
+            Self::Sequence(bl) => bl.get_special_ids(),
+        }
+    }
+
+    fn remap_ids(&mut self, ids: &HashMap<u32, u32>) {
+        match self {
+            Self::ChatTemplate(chat) => chat.remap_ids(ids),
+            Self::Bert(bert) => bert.remap_ids(ids),
+            Self::ByteLevel(bl) => bl.remap_ids(ids),
+            Self::Roberta(roberta) => roberta.remap_ids(ids),
+            Self::Template(template) => template.remap_ids(ids),
+            Self::Sequence(bl) => bl.remap_ids(ids),
+        }
+    }
 }
 
+impl_enum_from!(ChatTemplate, PostProcessorWrapper, ChatTemplate);
 impl_enum_from!(BertProcessing, PostProcessorWrapper, Bert);
 impl_enum_from!(ByteLevel, PostProcessorWrapper, ByteLevel);
 impl_enum_from!(RobertaProcessing, PostProcessorWrapper, Roberta);
@@ -55,6 +55,21 @@ impl PostProcessor for RobertaProcessing {
         }
     }
 
+    fn added_tokens_multi(&self, n_sequences: usize) -> Result<usize> {
+        // <s> A </s>, and </s> B </s> for each other sequence
+        Ok(2 * n_sequences)
+    }
+
+    fn get_special_ids(&self) -> Vec<u32> {
+        vec![self.cls.1, self.sep.1]
+    }
+
+    fn remap_ids(&mut self, ids: &HashMap<u32, u32>) {
+        for (_, id) in [&mut self.cls, &mut self.sep] {
+            *id = ids.get(id).copied().unwrap_or(*id);
+        }
+    }
+
     fn process_encodings(
         &self,
         mut encodings: Vec<Encoding>,
@@ -166,7 +181,7 @@ impl PostProcessor for RobertaProcessing {
 
                     // For compatibility with `TemplateProcessing`, the sequence_ranges shouldn't contain
                     // the special tokens.
-                    let pair_sequence_ranges = HashMap::from_iter(vec![(1, 1..pair_ids.len() - 1)]);
+                    let pair_sequence_ranges = HashMap::from_iter(vec![(i, 1..pair_ids.len() - 1)]);
                     Encoding::new(
                         pair_ids,
                         pair_type_ids,
@@ -200,7 +215,7 @@ impl PostProcessor for RobertaProcessing {
                                 // For compatibility with `TemplateProcessing`, the sequence_ranges
                                 // shouldn't contain the special tokens.
                                 let pair_sequence_ranges =
-                                    HashMap::from_iter(vec![(1, 1..pair_ids.len() - 1)]);
+                                    HashMap::from_iter(vec![(i, 1..pair_ids.len() - 1)]);
                                 Encoding::new(
                                     pair_ids,
                                     pair_type_ids,
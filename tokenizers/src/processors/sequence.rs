@@ -1,7 +1,9 @@
+use crate::processors::chat::ChatEncoding;
 use crate::processors::PostProcessorWrapper;
-use crate::tokenizer::{Encoding, PostProcessor, Result};
+use crate::tokenizer::{Encoding, PostProcessor, ProcessorError, Result};
 use crate::utils::macro_rules_attribute;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[macro_rules_attribute(impl_serde_type!)]
@@ -15,6 +17,15 @@ impl Sequence {
     }
 }
 
+/// Whether the given processor can format a conversation
+fn supports_chat(processor: &PostProcessorWrapper) -> bool {
+    match processor {
+        PostProcessorWrapper::ChatTemplate(_) => true,
+        PostProcessorWrapper::Sequence(sequence) => sequence.processors.iter().any(supports_chat),
+        _ => false,
+    }
+}
+
 impl PostProcessor for Sequence {
     fn added_tokens(&self, is_pair: bool) -> usize {
         self.processors
@@ -23,6 +34,24 @@ impl PostProcessor for Sequence {
             .sum::<usize>()
     }
 
+    fn added_tokens_multi(&self, n_sequences: usize) -> Result<usize> {
+        self.processors
+            .iter()
+            .map(|p| p.added_tokens_multi(n_sequences))
+            .sum::<Result<usize>>()
+    }
+
+    fn get_special_ids(&self) -> Vec<u32> {
+        self.processors
+            .iter()
+            .flat_map(|p| p.get_special_ids())
+            .collect()
+    }
+
+    fn remap_ids(&mut self, ids: &HashMap<u32, u32>) {
+        self.processors.iter_mut().for_each(|p| p.remap_ids(ids))
+    }
+
     fn process_encodings(
         &self,
         mut encodings: Vec<Encoding>,
@@ -33,11 +62,41 @@ impl PostProcessor for Sequence {
         }
         Ok(encodings)
     }
+
+    /// The conversation is formatted by the first processor supporting chats, while every other
+    /// processor processes each message on its own, since the offsets of a conversation are
+    /// relative to each message
+    fn process_chat(
+        &self,
+        messages: Vec<(String, Encoding)>,
+        add_special_tokens: bool,
+    ) -> Result<ChatEncoding> {
+        let chat = self
+            .processors
+            .iter()
+            .position(supports_chat)
+            .ok_or(ProcessorError::ChatNotSupported)?;
+
+        let messages = messages
+            .into_iter()
+            .map(|(role, encoding)| {
+                let mut encodings = vec![encoding];
+                for (i, processor) in self.processors.iter().enumerate() {
+                    if i != chat {
+                        encodings = processor.process_encodings(encodings, add_special_tokens)?;
+                    }
+                }
+                Ok((role, Encoding::merge(encodings, false)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.processors[chat].process_chat(messages, add_special_tokens)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::processors::chat::{ChatTemplate, RoleTemplate};
     use crate::processors::{ByteLevel, PostProcessorWrapper};
     use crate::tokenizer::{Encoding, PostProcessor};
     use std::collections::HashMap;
@@ -136,4 +195,42 @@ mod tests {
             sequence.process(start.clone(), Some(start), false).unwrap()
         );
     }
+
+    #[test]
+    fn process_chat() {
+        let message = Encoding::new(
+            vec![10, 11],
+            vec![0, 0],
+            vec!["ĠHello".into(), "Ġthere".into()],
+            vec![],
+            vec![(0, 6), (6, 12)],
+            vec![0, 0],
+            vec![1, 1],
+            vec![],
+            HashMap::new(),
+        );
+        let bytelevel = PostProcessorWrapper::ByteLevel(ByteLevel::default().trim_offsets(true));
+        let chat = ChatTemplate::builder()
+            .roles(vec![(
+                "user",
+                RoleTemplate::new(vec!["[INST]".into()], vec!["[/INST]".into()]),
+            )])
+            .special_tokens(vec![("[INST]", 1), ("[/INST]", 2)])
+            .build()
+            .unwrap();
+        let sequence = Sequence::new(vec![bytelevel.clone(), chat.into()]);
+
+        let messages = vec![("user".to_string(), message)];
+        let processed = sequence.process_chat(messages.clone(), true).unwrap();
+        assert_eq!(processed.encoding.get_ids(), &[1, 10, 11, 2]);
+        // Each message is processed by the other processors
+        assert_eq!(
+            processed.encoding.get_offsets(),
+            &[(0, 0), (0, 6), (7, 12), (0, 0)]
+        );
+        assert_eq!(processed.assistant_mask, vec![0, 0, 0, 0]);
+
+        let sequence = Sequence::new(vec![bytelevel]);
+        assert!(sequence.process_chat(messages, true).is_err());
+    }
 }
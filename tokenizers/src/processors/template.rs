@@ -48,6 +48,22 @@
 //!
 //! The same construct is used for special tokens: `<identifier>(:<type_id>)?`.
 //!
+//! Inputs with more than two sequences, like a query along with multiple passages, use the
+//! `multi` template, in which the next sequences are identified as `$C`, `$D`, ... Inputs with
+//! more sequences than the template repeat its last sequence, along with the special tokens
+//! following it, for each extra sequence. So for 4 sequences, this template gives
+//! `[CLS] $A [SEP] $B:1 [SEP]:1 $C:2 [SEP]:2 $D:2 [SEP]:2`:
+//! ```
+//! # use tokenizers::processors::template::TemplateProcessing;
+//! let template = TemplateProcessing::builder()
+//!     .try_single("[CLS] $A [SEP]").unwrap()
+//!     .try_pair("[CLS] $A [SEP] $B:1 [SEP]:1").unwrap()
+//!     .try_multi("[CLS] $A [SEP] $B:1 [SEP]:1 $C:2 [SEP]:2").unwrap()
+//!     .special_tokens(vec![("[CLS]", 1), ("[SEP]", 0)])
+//!     .build()
+//!     .unwrap();
+//! ```
+//!
 //! **Warning**: You must ensure that you are giving the correct tokens/ids as these will
 //! be added to the `Encoding` without any further check. If the given ids correspond to
 //! something totally different in a `Tokenizer` using this `PostProcessor`, it might lead
@@ -55,20 +71,87 @@
 //!
 //! [`TemplateProcessing`]: struct.TemplateProcessing.html
 //!
-use crate::{Encoding, PostProcessor, Result};
+use crate::{Encoding, PostProcessor, ProcessorError, Result};
 use itertools::Itertools;
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 use std::collections::{HashMap, HashSet};
 use std::convert::{TryFrom, TryInto};
+use std::fmt;
 use std::result::Result as StdResult;
 
-/// Represents any sequences received as input of the PostProcessor
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Eq)]
-pub enum Sequence {
+/// Represents any sequences received as input of the PostProcessor, by its index.
+/// In templates, sequences are identified by a letter: `A` for the first one, `B` for the
+/// pair, then `C`, `D`, ... for the next ones. Sequences past `Z` are identified by their index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sequence(pub usize);
+
+impl Sequence {
     /// This is the first sequence, the one that is always specified
-    A,
+    pub const A: Self = Self(0);
     /// This is the pair sequence, that is optional
-    B,
+    pub const B: Self = Self(1);
+
+    fn from_letter(s: &str) -> Option<Self> {
+        match s.as_bytes() {
+            [c] if c.is_ascii_alphabetic() => Some(Self((c.to_ascii_uppercase() - b'A') as usize)),
+            _ => None,
+        }
+    }
+
+    fn letter(&self) -> Option<char> {
+        if self.0 < 26 {
+            Some((b'A' + self.0 as u8) as char)
+        } else {
+            None
+        }
+    }
+}
+
+impl Serialize for Sequence {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.letter() {
+            Some(letter) => serializer.serialize_char(letter),
+            None => serializer.serialize_u64(self.0 as u64),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Sequence {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(SequenceVisitor)
+    }
+}
+
+struct SequenceVisitor;
+impl<'de> Visitor<'de> for SequenceVisitor {
+    type Value = Sequence;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "a sequence letter or index")
+    }
+
+    fn visit_str<E>(self, v: &str) -> StdResult<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Sequence::from_letter(v).ok_or_else(|| E::custom(format!("Unknown sequence `{}`", v)))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> StdResult<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Sequence(v as usize))
+    }
 }
 
 /// Represents the different kind of pieces that constitute a template.
@@ -108,16 +191,10 @@ impl Piece {
                     id: Sequence::A,
                     type_id: 0,
                 }),
-                "A" | "a" => Some(Self::Sequence {
-                    id: Sequence::A,
-                    type_id: 0,
-                }),
-                "B" | "b" => Some(Self::Sequence {
-                    id: Sequence::B,
-                    type_id: 0,
-                }),
                 n => {
-                    if let Ok(type_id) = n.parse::<u32>() {
+                    if let Some(id) = Sequence::from_letter(n) {
+                        Some(Self::Sequence { id, type_id: 0 })
+                    } else if let Ok(type_id) = n.parse::<u32>() {
                         Some(Self::Sequence {
                             id: Sequence::A,
                             type_id,
@@ -231,6 +308,28 @@ impl SpecialToken {
             Ok(Self { id, ids, tokens })
         }
     }
+
+    /// The `Encoding` of this SpecialToken, with the given type id
+    pub(crate) fn encoding(&self, type_id: u32) -> Encoding {
+        let len = self.ids.len();
+        Encoding::new(
+            self.ids.clone(),
+            vec![type_id; len],
+            self.tokens.clone(),
+            // words
+            vec![None; len],
+            // offsets
+            vec![(0, 0); len],
+            // special_tokens_mask
+            vec![1; len],
+            // attention_mask
+            vec![1; len],
+            // overflowing
+            vec![],
+            // sequence_range
+            HashMap::new(),
+        )
+    }
 }
 
 /// A Template represents a Vec<[`Piece`]>.
@@ -253,6 +352,50 @@ impl SpecialToken {
 #[serde(transparent)]
 pub struct Template(Vec<Piece>);
 
+impl Template {
+    /// The number of sequences used by this template, assuming they are all used
+    fn n_sequences(&self) -> usize {
+        self.0
+            .iter()
+            .filter_map(|piece| match piece {
+                Piece::Sequence { id, .. } => Some(id.0 + 1),
+                Piece::SpecialToken { .. } => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The pieces repeated for each extra sequence: the last sequence, along with everything
+    /// following it
+    fn repeated(&self) -> &[Piece] {
+        let last = Sequence(self.n_sequences().saturating_sub(1));
+        let start = self
+            .0
+            .iter()
+            .position(|piece| matches!(piece, Piece::Sequence { id, .. } if *id == last))
+            .unwrap_or(self.0.len());
+        &self.0[start..]
+    }
+
+    /// The pieces of this template, with the repeated ones expanded up to the given number of
+    /// sequences
+    fn expand(&self, n_sequences: usize) -> Vec<Piece> {
+        let last = Sequence(self.n_sequences().saturating_sub(1));
+        let repeated = self.repeated();
+        let mut pieces = self.0.clone();
+        for i in self.n_sequences()..n_sequences {
+            pieces.extend(repeated.iter().map(|piece| match piece {
+                Piece::Sequence { id, type_id } if *id == last => Piece::Sequence {
+                    id: Sequence(i),
+                    type_id: *type_id,
+                },
+                piece => piece.clone(),
+            }));
+        }
+        pieces
+    }
+}
+
 impl<T> TryFrom<Vec<T>> for Template
 where
     T: TryInto<Piece, Error = String>,
@@ -314,6 +457,24 @@ impl From<HashMap<String, SpecialToken>> for Tokens {
     }
 }
 
+impl Tokens {
+    /// The ids of all the special tokens
+    pub(crate) fn ids(&self) -> Vec<u32> {
+        self.0
+            .values()
+            .flat_map(|token| token.ids.iter().copied())
+            .collect()
+    }
+
+    /// Update the ids of the special tokens, leaving the ones missing from `ids` unchanged
+    pub(crate) fn remap_ids(&mut self, ids: &HashMap<u32, u32>) {
+        self.0
+            .values_mut()
+            .flat_map(|token| token.ids.iter_mut())
+            .for_each(|id| *id = ids.get(id).copied().unwrap_or(*id));
+    }
+}
+
 /// This PostProcessor takes care of processing each input `Encoding` by applying
 /// the corresponding template, before merging them in the final Encoding.
 ///
@@ -332,6 +493,11 @@ impl From<HashMap<String, SpecialToken>> for Tokens {
 ///     .unwrap();
 /// ```
 ///
+/// The optional `multi` template is used for inputs with more than two sequences, and must use
+/// all of them: `$A`, `$B`, `$C`, ... Inputs with more sequences than this template repeat its
+/// last sequence, along with the special tokens following it, for each extra sequence, while
+/// inputs with fewer sequences are an error.
+///
 #[derive(Debug, Clone, PartialEq, Builder, Serialize, Deserialize, Eq)]
 #[serde(tag = "type", from = "TemplateProcessingDeserializer")]
 #[builder(build_fn(validate = "Self::validate"))]
@@ -340,12 +506,21 @@ pub struct TemplateProcessing {
     single: Template,
     #[builder(try_setter, default = "\"$A:0 $B:1\".try_into().unwrap()")]
     pair: Template,
-    #[builder(setter(skip), default = "self.default_added(true)")]
+    #[builder(setter(custom), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    multi: Option<Template>,
+    #[builder(setter(skip), default = "self.default_added(self.single.as_ref())")]
     #[serde(skip)]
     added_single: usize,
-    #[builder(setter(skip), default = "self.default_added(false)")]
+    #[builder(setter(skip), default = "self.default_added(self.pair.as_ref())")]
     #[serde(skip)]
     added_pair: usize,
+    #[builder(
+        setter(skip),
+        default = "self.default_added(self.multi.as_ref().and_then(|m| m.as_ref()))"
+    )]
+    #[serde(skip)]
+    added_multi: usize,
     #[builder(setter(into), default)]
     special_tokens: Tokens,
 }
@@ -362,34 +537,41 @@ impl PartialEq for TemplateProcessingBuilderError {
     }
 }
 
-/// We use this custom deserializer to provided the values for `added_single`
-/// and `added_pair` during deserialization, while not having to serialize them
+/// We use this custom deserializer to provided the values for `added_single`,
+/// `added_pair` and `added_multi` during deserialization, while not having to serialize them
 #[doc(hidden)]
 #[derive(Deserialize)]
 #[serde(tag = "type")]
 struct TemplateProcessingDeserializer {
     single: Template,
     pair: Template,
+    #[serde(default)]
+    multi: Option<Template>,
     special_tokens: Tokens,
 }
 impl From<TemplateProcessingDeserializer> for TemplateProcessing {
     fn from(t: TemplateProcessingDeserializer) -> Self {
-        let added_single = count_added(&t.single, Some(&t.special_tokens));
-        let added_pair = count_added(&t.pair, Some(&t.special_tokens));
+        let added_single = count_added(&t.single.0, Some(&t.special_tokens));
+        let added_pair = count_added(&t.pair.0, Some(&t.special_tokens));
+        let added_multi = t
+            .multi
+            .as_ref()
+            .map_or(0, |multi| count_added(&multi.0, Some(&t.special_tokens)));
         Self {
             single: t.single,
             pair: t.pair,
+            multi: t.multi,
             added_single,
             added_pair,
+            added_multi,
             special_tokens: t.special_tokens,
         }
     }
 }
 
 /// Count the number of added tokens in the given template
-fn count_added(container: &Template, special_tokens: Option<&Tokens>) -> usize {
+fn count_added(container: &[Piece], special_tokens: Option<&Tokens>) -> usize {
     container
-        .0
         .iter()
         .map(|p| match p {
             Piece::Sequence { .. } => 0,
@@ -401,14 +583,20 @@ fn count_added(container: &Template, special_tokens: Option<&Tokens>) -> usize {
 }
 
 impl TemplateProcessingBuilder {
-    fn default_added(&self, is_single: bool) -> usize {
-        let container = if is_single {
-            self.single.as_ref()
-        } else {
-            self.pair.as_ref()
-        };
+    /// Set the template used for inputs with more than two sequences
+    pub fn multi(&mut self, multi: Template) -> &mut Self {
+        self.multi = Some(Some(multi));
+        self
+    }
+
+    /// Try to set the template used for inputs with more than two sequences
+    pub fn try_multi<T: TryInto<Template>>(&mut self, multi: T) -> StdResult<&mut Self, T::Error> {
+        Ok(self.multi(multi.try_into()?))
+    }
+
+    fn default_added(&self, container: Option<&Template>) -> usize {
         container.map_or(0, |pieces| {
-            count_added(pieces, self.special_tokens.as_ref())
+            count_added(&pieces.0, self.special_tokens.as_ref())
         })
     }
 
@@ -436,6 +624,23 @@ impl TemplateProcessingBuilder {
             return Err("Template for `pair` must use both sequences".into());
         }
 
+        if let Some(Some(multi)) = &self.multi {
+            let n_sequences = multi.n_sequences();
+            let used = multi
+                .0
+                .iter()
+                .filter_map(|piece| match piece {
+                    Piece::Sequence { id, .. } => Some(id.0),
+                    Piece::SpecialToken { .. } => None,
+                })
+                .collect::<HashSet<_>>();
+            if n_sequences < 3 || used.len() != n_sequences {
+                return Err(
+                    "Template for `multi` must use at least three sequences, all of them".into(),
+                );
+            }
+        }
+
         let check = |sp| {
             let exist = self
                 .special_tokens
@@ -454,6 +659,12 @@ impl TemplateProcessingBuilder {
             .as_ref()
             .map_or(empty.iter(), |s| s.0.iter())
             .chain(self.pair.as_ref().map_or(empty.iter(), |s| s.0.iter()))
+            .chain(
+                self.multi
+                    .as_ref()
+                    .and_then(|m| m.as_ref())
+                    .map_or(empty.iter(), |s| s.0.iter()),
+            )
             .filter_map(|piece| match piece {
                 Piece::Sequence { .. } => None,
                 Piece::SpecialToken { id, .. } => check(id.as_ref()),
@@ -476,8 +687,10 @@ impl Default for TemplateProcessing {
         Self {
             single: "$0".try_into().unwrap(),
             pair: "$1".try_into().unwrap(),
+            multi: None,
             added_single: 0,
             added_pair: 0,
+            added_multi: 0,
             special_tokens: Tokens::default(),
         }
     }
@@ -499,7 +712,7 @@ impl TemplateProcessing {
             .flat_map(|piece| {
                 match piece {
                     Piece::Sequence { id, type_id } => {
-                        let i = usize::from(*id != Sequence::A);
+                        let i = id.0;
                         let encoding = &mut encodings[i];
                         encoding.set_type_ids(vec![*type_id; encoding.len()]);
                         encoding.set_sequence_id(i);
@@ -508,26 +721,7 @@ impl TemplateProcessing {
                     Piece::SpecialToken { id, type_id } => {
                         if add_special_tokens {
                             let tok = &self.special_tokens.0[id]; // We already checked existance above
-                            let len = tok.ids.len();
-
-                            let encoding = Encoding::new(
-                                tok.ids.clone(),
-                                std::iter::repeat(*type_id).take(len).collect(),
-                                tok.tokens.clone(),
-                                // words
-                                std::iter::repeat(None).take(len).collect(),
-                                // offsets
-                                std::iter::repeat((0, 0)).take(len).collect(),
-                                // special_tokens_mask
-                                std::iter::repeat(1).take(len).collect(),
-                                // attention_mask
-                                std::iter::repeat(1).take(len).collect(),
-                                // overflowing
-                                vec![],
-                                // sequence_range
-                                HashMap::new(),
-                            );
-                            Some(encoding)
+                            Some(tok.encoding(*type_id))
                         } else {
                             None
                         }
@@ -599,6 +793,31 @@ impl PostProcessor for TemplateProcessing {
         }
     }
 
+    fn added_tokens_multi(&self, n_sequences: usize) -> Result<usize> {
+        match (n_sequences, &self.multi) {
+            (2, _) => Ok(self.added_pair),
+            (1, _) => Ok(self.added_single),
+            (n, Some(multi)) if n >= multi.n_sequences() => {
+                let extra = n - multi.n_sequences();
+                let repeated = count_added(multi.repeated(), Some(&self.special_tokens));
+                Ok(self.added_multi + extra * repeated)
+            }
+            (n, Some(multi)) => Err(Box::new(ProcessorError::NotEnoughSequences(
+                multi.n_sequences(),
+                n,
+            ))),
+            _ => Err(Box::new(ProcessorError::InvalidEncodingsVecLength)),
+        }
+    }
+
+    fn get_special_ids(&self) -> Vec<u32> {
+        self.special_tokens.ids()
+    }
+
+    fn remap_ids(&mut self, ids: &HashMap<u32, u32>) {
+        self.special_tokens.remap_ids(ids)
+    }
+
     fn process_encodings(
         &self,
         encodings: Vec<Encoding>,
@@ -622,10 +841,21 @@ impl PostProcessor for TemplateProcessing {
         //     }
         //     _ => return Err(Box::new(ProcessorError::InvalidEncodingsVecLength)),
         // };
-        let template = match encodings.len() {
-            2 => &self.pair.0,
-            1 => &self.single.0,
-            _ => todo!(),
+        let multi;
+        let template = match (encodings.len(), &self.multi) {
+            (2, _) => &self.pair.0,
+            (1, _) => &self.single.0,
+            (n, Some(template)) if n >= template.n_sequences() => {
+                multi = template.expand(n);
+                &multi
+            }
+            (n, Some(template)) => {
+                return Err(Box::new(ProcessorError::NotEnoughSequences(
+                    template.n_sequences(),
+                    n,
+                )))
+            }
+            _ => return Err(Box::new(ProcessorError::InvalidEncodingsVecLength)),
         };
         let encodings = self.apply_template(template, encodings, add_special_tokens)?;
         Ok(encodings)
@@ -703,7 +933,14 @@ mod tests {
             }),
             "$:1".try_into()
         );
-        assert!(Piece::try_from("$C:1").is_err());
+        assert_eq!(
+            Ok(Piece::Sequence {
+                id: Sequence(2),
+                type_id: 1
+            }),
+            "$C:1".try_into()
+        );
+        assert!(Piece::try_from("$CD:1").is_err());
         assert!(Piece::try_from("$A:").is_err());
     }
 
@@ -1087,6 +1324,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn multi_must_use_all_sequences() {
+        let processor = TemplateProcessing::builder()
+            .try_multi("$A $B:1 $D:3")
+            .unwrap()
+            .build();
+        assert_eq!(
+            processor,
+            Err("Template for `multi` must use at least three sequences, all of them".into())
+        );
+    }
+
+    #[test]
+    fn template_processing_multi() {
+        let processor = TemplateProcessing::builder()
+            .try_single("[CLS] $A [SEP]")
+            .unwrap()
+            .try_pair("[CLS] $A [SEP] $B:1 [SEP]:1")
+            .unwrap()
+            .try_multi("[CLS] $A [SEP] $b:1 [SEP]:1 $c:2 [SEP]:2")
+            .unwrap()
+            .special_tokens(vec![("[CLS]", 1), ("[SEP]", 0)])
+            .build()
+            .unwrap();
+        assert_eq!(processor.added_tokens_multi(2).unwrap(), 3);
+        assert_eq!(processor.added_tokens_multi(3).unwrap(), 4);
+
+        let serialized = serde_json::to_string(&processor).unwrap();
+        assert!(serialized.contains(r#"{"Sequence":{"id":"C","type_id":2}}"#));
+        assert_eq!(
+            serde_json::from_str::<TemplateProcessing>(&serialized).unwrap(),
+            processor
+        );
+
+        use crate::Token;
+        let encodings = vec![
+            Encoding::from_tokens(vec![Token::new(12, "query".into(), (0, 5))], 0),
+            Encoding::from_tokens(vec![Token::new(13, "first".into(), (0, 5))], 0),
+            Encoding::from_tokens(vec![Token::new(14, "second".into(), (0, 6))], 0),
+        ];
+        let encoding = processor.process_multi(encodings.clone(), true).unwrap();
+        assert_eq!(encoding.get_ids(), &[1, 12, 0, 13, 0, 14, 0]);
+        assert_eq!(encoding.get_type_ids(), &[0, 0, 0, 1, 1, 2, 2]);
+        assert_eq!(encoding.n_sequences(), 3);
+        assert_eq!(encoding.token_to_sequence(5), Some(2));
+
+        // The last sequence is repeated for the extra ones
+        let mut more = encodings.clone();
+        more.push(Encoding::from_tokens(
+            vec![Token::new(15, "third".into(), (0, 5))],
+            0,
+        ));
+        more.push(Encoding::from_tokens(
+            vec![Token::new(16, "fourth".into(), (0, 6))],
+            0,
+        ));
+        assert_eq!(processor.added_tokens_multi(5).unwrap(), 6);
+        let encoding = processor.process_multi(more, true).unwrap();
+        assert_eq!(encoding.get_ids(), &[1, 12, 0, 13, 0, 14, 0, 15, 0, 16, 0]);
+        assert_eq!(encoding.get_type_ids(), &[0, 0, 0, 1, 1, 2, 2, 2, 2, 2, 2]);
+        assert_eq!(encoding.n_sequences(), 5);
+        assert_eq!(encoding.token_to_sequence(9), Some(4));
+
+        // Fewer sequences than the template are an error
+        let processor = TemplateProcessing::builder()
+            .try_multi("$A $B:1 $C:2 $D:3")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            processor
+                .process_multi(encodings.clone(), true)
+                .unwrap_err()
+                .to_string(),
+            "post-processor needs at least 4 sequences, got 3"
+        );
+        assert_eq!(
+            processor.added_tokens_multi(3).unwrap_err().to_string(),
+            "post-processor needs at least 4 sequences, got 3"
+        );
+
+        // As are more than two sequences without a `multi` template
+        let processor = TemplateProcessing::builder()
+            .try_single("$A")
+            .unwrap()
+            .try_pair("$A $B:1")
+            .unwrap()
+            .build()
+            .unwrap();
+        let error = processor.process_multi(encodings, true).unwrap_err();
+        assert_eq!(
+            processor.added_tokens_multi(3).unwrap_err().to_string(),
+            error.to_string()
+        );
+    }
+
     #[test]
     fn expect_wrong_error_message() {
         let processor = TemplateProcessing::builder()
@@ -285,7 +285,7 @@ impl AddedVocabulary {
             let new_id = if let Some(new_id) = self.token_to_id(&token.content, model) {
                 new_id
             } else {
-                self.added_tokens_map.values().cloned().max().map_or(
+                let mut new_id = self.added_tokens_map.values().cloned().max().map_or(
                     model.get_vocab_size() as u32,
                     |max| {
                         if (max >= model.get_vocab_size() as u32) || model.get_vocab_size() == 0 {
@@ -294,7 +294,13 @@ impl AddedVocabulary {
                             model.get_vocab_size() as u32
                         }
                     },
-                )
+                );
+                // The vocabulary of the model can have holes, like the ids of the added tokens
+                // it was merged with, so its size doesn't always follow its last id
+                while model.id_to_token(new_id).is_some() {
+                    new_id += 1;
+                }
+                new_id
             };
             // Make sure we modify the previous entry
             self.added_tokens_map
@@ -320,6 +326,35 @@ impl AddedVocabulary {
         tokens.len() - ignored
     }
 
+    /// Update the ids of the tokens once the vocabulary has been pruned, using the new id of
+    /// each kept token. The tokens that were not kept are removed.
+    pub(crate) fn remap_ids<N: Normalizer>(
+        &mut self,
+        ids: &HashMap<u32, u32>,
+        model: &impl Model,
+        normalizer: Option<&N>,
+    ) {
+        self.added_tokens_map_r = self
+            .added_tokens_map_r
+            .drain()
+            .filter_map(|(id, token)| Some((*ids.get(&id)?, token)))
+            .collect();
+        self.added_tokens_map = self
+            .added_tokens_map_r
+            .iter()
+            .map(|(id, token)| (token.content.clone(), *id))
+            .collect();
+
+        let kept = &self.added_tokens_map;
+        self.added_tokens
+            .retain(|token| kept.contains_key(&token.content));
+        self.special_tokens
+            .retain(|token| kept.contains_key(&token.content));
+        self.special_tokens_set
+            .retain(|content| kept.contains_key(content));
+        self.refresh_added_tokens(model, normalizer);
+    }
+
     /// Reconstruct our internal RegexSet when new tokens are added to the vocabulary.
     ///
     /// We keep two different RegexSet, one that will take care of matching against the
@@ -461,6 +496,24 @@ impl AddedVocabulary {
         normalizer: Option<&N>,
         sequence: &str,
     ) -> PreTokenizedString {
+        self.extract_and_normalize_with(
+            |sequence| {
+                normalizer.map(|n| n.normalize(sequence));
+            },
+            sequence,
+        )
+    }
+
+    /// Same as `extract_and_normalize`, normalizing each part of the sequence that isn't a
+    /// non-normalized added token with the given function.
+    pub(crate) fn extract_and_normalize_with<F>(
+        &self,
+        mut normalize: F,
+        sequence: &str,
+    ) -> PreTokenizedString
+    where
+        F: FnMut(&mut NormalizedString),
+    {
         let mut pretokenized: PreTokenizedString = sequence.into();
 
         // 1. We extract all the non-normalized tokens from the non-normalized string
@@ -483,7 +536,7 @@ impl AddedVocabulary {
         // 2. Then extract the normalized tokens from the normalized pieces of the string
         pretokenized
             .split(|_, mut sequence| {
-                normalizer.map(|n| n.normalize(&mut sequence));
+                normalize(&mut sequence);
                 Ok(self.split_with_indices(sequence, &self.split_normalized_trie))
             })
             .expect("AddedVocabulary bad split");
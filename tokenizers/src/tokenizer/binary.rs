@@ -0,0 +1,940 @@
+//! A compact binary format for tokenizers, which can be loaded from a memory-mapped file.
+//!
+//! All the integers are stored in little-endian. A file is made of:
+//!   - The magic bytes `TKZB`, followed by the version of the format (`u32`).
+//!   - The skeleton: the JSON representation of the tokenizer without the vocab and merges of
+//!     its model, stored as a tagged binary value.
+//!   - The vocab table (`u8` kind: `0` for none, `1` for a map of tokens to ids, `2` for a list
+//!     of tokens with scores), followed for a non-empty kind by the number of tokens (`u32`), the
+//!     `count + 1` offsets of the tokens in the blob (`u32`), the blob of the tokens ordered by id,
+//!     the ids (`u32`) or scores (`f64`) of the tokens, and the index of the tokens sorted in byte
+//!     order (`u32`), used to look tokens up without deserializing anything.
+//!   - The merges table (`u8` flag), followed when present by the number of merges (`u32`), and
+//!     the pairs of indices in the vocab table (`u32`) of each merge.
+//!
+//! The vocab and merges are only moved out of the skeleton when they can be represented
+//! exactly in these tables, which makes the conversion to and from JSON lossless.
+
+use super::Result;
+use memmap2::Mmap;
+use serde::de::{
+    self,
+    value::{MapDeserializer, SeqDeserializer},
+    DeserializeOwned, IntoDeserializer, Visitor,
+};
+use serde::forward_to_deserialize_any;
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+use std::fs::File;
+use std::ops::Deref;
+use std::path::Path;
+
+/// The magic bytes at the beginning of every binary tokenizer
+pub const BINARY_MAGIC: &[u8; 4] = b"TKZB";
+/// The current version of the binary format
+pub const BINARY_VERSION: u32 = 1;
+
+#[derive(thiserror::Error, Debug)]
+pub enum BinaryError {
+    #[error("Not a binary tokenizer: invalid magic bytes")]
+    InvalidMagic,
+    #[error(
+        "Unsupported binary tokenizer version {0}, expected {}",
+        BINARY_VERSION
+    )]
+    UnsupportedVersion(u32),
+    #[error("Truncated or corrupted binary tokenizer")]
+    Corrupted,
+}
+
+const NULL: u8 = 0;
+const FALSE: u8 = 1;
+const TRUE: u8 = 2;
+const UNSIGNED: u8 = 3;
+const SIGNED: u8 = 4;
+const FLOAT: u8 = 5;
+const STRING: u8 = 6;
+const ARRAY: u8 = 7;
+const OBJECT: u8 = 8;
+
+/// The maximum nesting of the arrays and objects, like in `serde_json`
+const MAX_DEPTH: usize = 128;
+
+const VOCAB_NONE: u8 = 0;
+const VOCAB_IDS: u8 = 1;
+const VOCAB_SCORES: u8 = 2;
+
+/// Encode the given JSON representation of a tokenizer in the binary format
+pub(crate) fn encode(mut value: Value) -> Vec<u8> {
+    let (vocab, merges) = match value.get_mut("model") {
+        Some(Value::Object(model)) => extract_tables(model),
+        _ => (None, None),
+    };
+
+    let mut out = BINARY_MAGIC.to_vec();
+    out.extend(&BINARY_VERSION.to_le_bytes());
+    write_value(&mut out, &value);
+    match &vocab {
+        None => out.push(VOCAB_NONE),
+        Some(vocab) => vocab.write(&mut out),
+    }
+    match merges {
+        None => out.push(0),
+        Some(merges) => {
+            out.push(1);
+            out.extend(&(merges.len() as u32).to_le_bytes());
+            for (a, b) in merges {
+                out.extend(&a.to_le_bytes());
+                out.extend(&b.to_le_bytes());
+            }
+        }
+    }
+    out
+}
+
+fn write_str(out: &mut Vec<u8>, s: &str) {
+    out.extend(&(s.len() as u32).to_le_bytes());
+    out.extend(s.as_bytes());
+}
+
+fn write_value(out: &mut Vec<u8>, value: &Value) {
+    match value {
+        Value::Null => out.push(NULL),
+        Value::Bool(false) => out.push(FALSE),
+        Value::Bool(true) => out.push(TRUE),
+        Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                out.push(UNSIGNED);
+                out.extend(&n.to_le_bytes());
+            } else if let Some(n) = n.as_i64() {
+                out.push(SIGNED);
+                out.extend(&n.to_le_bytes());
+            } else {
+                out.push(FLOAT);
+                out.extend(&n.as_f64().unwrap_or(0.0).to_le_bytes());
+            }
+        }
+        Value::String(s) => {
+            out.push(STRING);
+            write_str(out, s);
+        }
+        Value::Array(values) => {
+            out.push(ARRAY);
+            out.extend(&(values.len() as u32).to_le_bytes());
+            for value in values {
+                write_value(out, value);
+            }
+        }
+        Value::Object(map) => {
+            out.push(OBJECT);
+            out.extend(&(map.len() as u32).to_le_bytes());
+            for (key, value) in map {
+                write_str(out, key);
+                write_value(out, value);
+            }
+        }
+    }
+}
+
+/// The vocab of a model, moved out of the skeleton
+enum Entries {
+    Ids(Vec<(String, u32)>),
+    Scores(Vec<(String, f64)>),
+}
+
+impl Entries {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Object(map) => {
+                let mut entries = map
+                    .iter()
+                    .map(|(token, id)| {
+                        let id = id.as_u64().filter(|id| *id <= u64::from(u32::MAX))?;
+                        Some((token.to_owned(), id as u32))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                entries.sort_by(|a, b| (a.1, &a.0).cmp(&(b.1, &b.0)));
+                Some(Entries::Ids(entries))
+            }
+            Value::Array(pieces) => pieces
+                .iter()
+                .map(|piece| match piece.as_array().map(|p| p.as_slice()) {
+                    // Only actual floats are kept, to serialize them back the same way
+                    Some([Value::String(token), Value::Number(score)]) if score.is_f64() => {
+                        Some((token.to_owned(), score.as_f64()?))
+                    }
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(Entries::Scores),
+            _ => None,
+        }
+    }
+
+    fn tokens(&self) -> Vec<&str> {
+        match self {
+            Entries::Ids(entries) => entries.iter().map(|(t, _)| t.as_str()).collect(),
+            Entries::Scores(entries) => entries.iter().map(|(t, _)| t.as_str()).collect(),
+        }
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        let tokens = self.tokens();
+        out.push(match self {
+            Entries::Ids(_) => VOCAB_IDS,
+            Entries::Scores(_) => VOCAB_SCORES,
+        });
+        out.extend(&(tokens.len() as u32).to_le_bytes());
+
+        let mut offset = 0u32;
+        out.extend(&offset.to_le_bytes());
+        for token in &tokens {
+            offset += token.len() as u32;
+            out.extend(&offset.to_le_bytes());
+        }
+        for token in &tokens {
+            out.extend(token.as_bytes());
+        }
+        match self {
+            Entries::Ids(entries) => {
+                for (_, id) in entries {
+                    out.extend(&id.to_le_bytes());
+                }
+            }
+            Entries::Scores(entries) => {
+                for (_, score) in entries {
+                    out.extend(&score.to_le_bytes());
+                }
+            }
+        }
+
+        let mut sorted = (0..tokens.len() as u32).collect::<Vec<_>>();
+        sorted.sort_by(|a, b| tokens[*a as usize].cmp(tokens[*b as usize]));
+        for index in sorted {
+            out.extend(&index.to_le_bytes());
+        }
+    }
+}
+
+/// Move the vocab and merges of the given model out of it, when they can be stored in tables
+fn extract_tables(model: &mut Map<String, Value>) -> (Option<Entries>, Option<Vec<(u32, u32)>>) {
+    let vocab = match model.get("vocab").and_then(Entries::from_value) {
+        Some(vocab) => vocab,
+        None => return (None, None),
+    };
+    model.remove("vocab");
+
+    let merges = match (&vocab, model.get("merges")) {
+        (Entries::Ids(entries), Some(Value::Array(merges))) => {
+            let indices = entries
+                .iter()
+                .enumerate()
+                .map(|(i, (token, _))| (token.as_str(), i as u32))
+                .collect::<HashMap<_, _>>();
+            merges
+                .iter()
+                .map(|merge| {
+                    let parts = merge.as_str()?.split(' ').collect::<Vec<_>>();
+                    match parts.as_slice() {
+                        [a, b] => Some((*indices.get(a)?, *indices.get(b)?)),
+                        _ => None,
+                    }
+                })
+                .collect::<Option<Vec<_>>>()
+        }
+        _ => None,
+    };
+    if merges.is_some() {
+        model.remove("merges");
+    }
+    (Some(vocab), merges)
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> std::result::Result<&'a [u8], BinaryError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(BinaryError::Corrupted)?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Skip `count` items of `size` bytes, returning the position of the first one
+    fn skip(&mut self, count: usize, size: usize) -> std::result::Result<usize, BinaryError> {
+        let pos = self.pos;
+        self.bytes(count.checked_mul(size).ok_or(BinaryError::Corrupted)?)?;
+        Ok(pos)
+    }
+
+    fn u8(&mut self) -> std::result::Result<u8, BinaryError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u32(&mut self) -> std::result::Result<u32, BinaryError> {
+        let b = self.bytes(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn u64(&mut self) -> std::result::Result<u64, BinaryError> {
+        let b = self.bytes(8)?;
+        Ok(u64::from_le_bytes([
+            b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7],
+        ]))
+    }
+
+    fn str(&mut self) -> std::result::Result<&'a str, BinaryError> {
+        let len = self.u32()? as usize;
+        std::str::from_utf8(self.bytes(len)?).map_err(|_| BinaryError::Corrupted)
+    }
+
+    /// Read a value, nested in `depth` arrays or objects
+    fn value(&mut self, depth: usize) -> std::result::Result<Value, BinaryError> {
+        if depth > MAX_DEPTH {
+            return Err(BinaryError::Corrupted);
+        }
+        Ok(match self.u8()? {
+            NULL => Value::Null,
+            FALSE => Value::Bool(false),
+            TRUE => Value::Bool(true),
+            UNSIGNED => Value::Number(self.u64()?.into()),
+            SIGNED => Value::Number((self.u64()? as i64).into()),
+            FLOAT => Number::from_f64(f64::from_bits(self.u64()?))
+                .map(Value::Number)
+                .ok_or(BinaryError::Corrupted)?,
+            STRING => Value::String(self.str()?.to_owned()),
+            ARRAY => {
+                let len = self.u32()? as usize;
+                // Every value takes at least one byte
+                let mut values = Vec::with_capacity(len.min(self.data.len() - self.pos));
+                for _ in 0..len {
+                    values.push(self.value(depth + 1)?);
+                }
+                Value::Array(values)
+            }
+            OBJECT => {
+                let len = self.u32()?;
+                let mut map = Map::new();
+                for _ in 0..len {
+                    let key = self.str()?.to_owned();
+                    map.insert(key, self.value(depth + 1)?);
+                }
+                Value::Object(map)
+            }
+            _ => return Err(BinaryError::Corrupted),
+        })
+    }
+}
+
+fn u32_at(data: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]])
+}
+
+/// The position of the vocab table in the binary data
+#[derive(Debug, Clone, Copy)]
+struct VocabTable {
+    scored: bool,
+    len: usize,
+    offsets: usize,
+    blob: usize,
+    values: usize,
+    sorted: usize,
+}
+
+impl VocabTable {
+    fn parse(reader: &mut Reader) -> std::result::Result<Option<Self>, BinaryError> {
+        let scored = match reader.u8()? {
+            VOCAB_NONE => return Ok(None),
+            VOCAB_IDS => false,
+            VOCAB_SCORES => true,
+            _ => return Err(BinaryError::Corrupted),
+        };
+        let len = reader.u32()? as usize;
+        let offsets = reader.skip(len + 1, 4)?;
+        let blob_len = u32_at(reader.data, offsets + len * 4) as usize;
+        let blob = reader.skip(blob_len, 1)?;
+        let values = reader.skip(len, if scored { 8 } else { 4 })?;
+        let sorted = reader.skip(len, 4)?;
+        let table = Self {
+            scored,
+            len,
+            offsets,
+            blob,
+            values,
+            sorted,
+        };
+
+        // Validate everything once, so that lookups don't need to
+        let data = reader.data;
+        let mut previous = 0;
+        for i in 0..len {
+            let (start, end) = (table.offset(data, i), table.offset(data, i + 1));
+            if start != previous || end < start || end > blob_len {
+                return Err(BinaryError::Corrupted);
+            }
+            std::str::from_utf8(&data[blob + start..blob + end])
+                .map_err(|_| BinaryError::Corrupted)?;
+            previous = end;
+            if table.sorted_index(data, i) >= len
+                || (!scored && i > 0 && table.id(data, i) < table.id(data, i - 1))
+            {
+                return Err(BinaryError::Corrupted);
+            }
+        }
+        Ok(Some(table))
+    }
+
+    fn offset(&self, data: &[u8], i: usize) -> usize {
+        u32_at(data, self.offsets + i * 4) as usize
+    }
+
+    fn token<'a>(&self, data: &'a [u8], i: usize) -> &'a str {
+        let (start, end) = (self.offset(data, i), self.offset(data, i + 1));
+        // Validated when parsing the table
+        std::str::from_utf8(&data[self.blob + start..self.blob + end]).unwrap_or_default()
+    }
+
+    fn id(&self, data: &[u8], i: usize) -> u32 {
+        if self.scored {
+            i as u32
+        } else {
+            u32_at(data, self.values + i * 4)
+        }
+    }
+
+    fn score(&self, data: &[u8], i: usize) -> f64 {
+        let pos = self.values + i * 8;
+        let b = &data[pos..pos + 8];
+        f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]])
+    }
+
+    fn sorted_index(&self, data: &[u8], i: usize) -> usize {
+        u32_at(data, self.sorted + i * 4) as usize
+    }
+
+    /// Find the index of the given token, with a binary search over the sorted tokens
+    fn find(&self, data: &[u8], token: &str) -> Option<usize> {
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let mid = (low + high) / 2;
+            let index = self.sorted_index(data, mid);
+            match self.token(data, index).cmp(token) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => return Some(index),
+            }
+        }
+        None
+    }
+
+    /// Find the index of the token with the given id
+    fn find_id(&self, data: &[u8], id: u32) -> Option<usize> {
+        if self.scored {
+            return Some(id as usize).filter(|i| *i < self.len);
+        }
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let mid = (low + high) / 2;
+            match self.id(data, mid).cmp(&id) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => return Some(mid),
+            }
+        }
+        None
+    }
+}
+
+/// The position of the merges table in the binary data
+#[derive(Debug, Clone, Copy)]
+struct MergesTable {
+    len: usize,
+    pairs: usize,
+}
+
+impl MergesTable {
+    fn parse(
+        reader: &mut Reader,
+        vocab: Option<&VocabTable>,
+    ) -> std::result::Result<Option<Self>, BinaryError> {
+        if reader.u8()? == 0 {
+            return Ok(None);
+        }
+        let vocab_len = match vocab {
+            Some(vocab) if !vocab.scored => vocab.len,
+            _ => return Err(BinaryError::Corrupted),
+        };
+        let len = reader.u32()? as usize;
+        let pairs = reader.skip(len, 8)?;
+        let table = Self { len, pairs };
+        for i in 0..len {
+            let (a, b) = table.pair(reader.data, i);
+            if a >= vocab_len || b >= vocab_len {
+                return Err(BinaryError::Corrupted);
+            }
+        }
+        Ok(Some(table))
+    }
+
+    fn pair(&self, data: &[u8], i: usize) -> (usize, usize) {
+        let pos = self.pairs + i * 8;
+        (u32_at(data, pos) as usize, u32_at(data, pos + 4) as usize)
+    }
+}
+
+/// The parsed header of a binary tokenizer
+#[derive(Debug, Clone)]
+struct Layout {
+    skeleton: Value,
+    vocab: Option<VocabTable>,
+    merges: Option<MergesTable>,
+}
+
+impl Layout {
+    fn parse(data: &[u8]) -> std::result::Result<Self, BinaryError> {
+        let mut reader = Reader { data, pos: 0 };
+        if reader.bytes(4).ok() != Some(&BINARY_MAGIC[..]) {
+            return Err(BinaryError::InvalidMagic);
+        }
+        let version = reader.u32()?;
+        if version != BINARY_VERSION {
+            return Err(BinaryError::UnsupportedVersion(version));
+        }
+        let skeleton = reader.value(0)?;
+        let vocab = VocabTable::parse(&mut reader)?;
+        let merges = MergesTable::parse(&mut reader, vocab.as_ref())?;
+        Ok(Self {
+            skeleton,
+            vocab,
+            merges,
+        })
+    }
+
+    fn to_json_value(&self, data: &[u8]) -> Value {
+        let mut value = self.skeleton.clone();
+        if let (Some(Value::Object(model)), Some(vocab)) = (value.get_mut("model"), &self.vocab) {
+            let tokens = (0..vocab.len).map(|i| vocab.token(data, i).to_owned());
+            let entries = if vocab.scored {
+                Value::Array(
+                    tokens
+                        .enumerate()
+                        .map(|(i, token)| {
+                            let score = Number::from_f64(vocab.score(data, i))
+                                .map_or(Value::Null, Value::Number);
+                            Value::Array(vec![Value::String(token), score])
+                        })
+                        .collect(),
+                )
+            } else {
+                Value::Object(
+                    tokens
+                        .enumerate()
+                        .map(|(i, token)| (token, Value::from(vocab.id(data, i))))
+                        .collect(),
+                )
+            };
+            model.insert("vocab".into(), entries);
+            if let Some(merges) = &self.merges {
+                let merges = (0..merges.len)
+                    .map(|i| Value::String(merge_string(data, vocab, merges, i)))
+                    .collect();
+                model.insert("merges".into(), Value::Array(merges));
+            }
+        }
+        value
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, data: &[u8]) -> Result<T> {
+        let skeleton = match &self.skeleton {
+            Value::Object(skeleton) => skeleton,
+            other => return Ok(T::deserialize(other)?),
+        };
+        let fields = match (skeleton.get("model"), &self.vocab) {
+            (Some(Value::Object(model)), Some(vocab)) => {
+                let mut tables = vec![("vocab", Field::Vocab(data, *vocab))];
+                if let Some(merges) = self.merges {
+                    tables.push(("merges", Field::Merges(data, *vocab, merges)));
+                }
+                vec![("model", Field::Object(model, tables))]
+            }
+            _ => vec![],
+        };
+        Ok(T::deserialize(Field::Object(skeleton, fields))?)
+    }
+}
+
+fn merge_string(data: &[u8], vocab: &VocabTable, merges: &MergesTable, i: usize) -> String {
+    let (a, b) = merges.pair(data, i);
+    format!("{} {}", vocab.token(data, a), vocab.token(data, b))
+}
+
+/// A value given to the `Deserialize` implementations, which reads the tables directly from
+/// the binary data instead of going through their JSON representation. Strings are borrowed
+/// from the skeleton and the tables, as some implementations expect to borrow them.
+enum Field<'a> {
+    Json(&'a Value),
+    /// An object of the skeleton, with some of its fields replaced by the given ones
+    Object(&'a Map<String, Value>, Vec<(&'static str, Field<'a>)>),
+    Str(&'a str),
+    F64(f64),
+    Piece(&'a str, f64),
+    Vocab(&'a [u8], VocabTable),
+    Merges(&'a [u8], VocabTable, MergesTable),
+}
+
+impl<'de> IntoDeserializer<'de, serde_json::Error> for Field<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Field<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Field::Json(value) => value.deserialize_any(visitor),
+            Field::Object(map, fields) => {
+                let skeleton = map
+                    .iter()
+                    .filter(|(key, _)| fields.iter().all(|(field, _)| field != key))
+                    .map(|(key, value)| (Field::Str(key), Field::Json(value)))
+                    .collect::<Vec<_>>();
+                visitor.visit_map(MapDeserializer::new(
+                    skeleton.into_iter().chain(
+                        fields
+                            .into_iter()
+                            .map(|(key, field)| (Field::Str(key), field)),
+                    ),
+                ))
+            }
+            Field::Str(s) => visitor.visit_borrowed_str(s),
+            Field::F64(f) => visitor.visit_f64(f),
+            Field::Piece(token, score) => visitor.visit_seq(SeqDeserializer::new(
+                vec![Field::Str(token), Field::F64(score)].into_iter(),
+            )),
+            Field::Vocab(data, vocab) if vocab.scored => visitor.visit_seq(SeqDeserializer::new(
+                (0..vocab.len).map(|i| Field::Piece(vocab.token(data, i), vocab.score(data, i))),
+            )),
+            Field::Vocab(data, vocab) => visitor.visit_map(MapDeserializer::new(
+                (0..vocab.len).map(|i| (Field::Str(vocab.token(data, i)), vocab.id(data, i))),
+            )),
+            Field::Merges(data, vocab, merges) => visitor.visit_seq(SeqDeserializer::new(
+                (0..merges.len).map(|i| merge_string(data, &vocab, &merges, i)),
+            )),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Field::Json(value) => value.deserialize_option(visitor),
+            field => visitor.visit_some(field),
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Field::Json(value) => value.deserialize_enum(name, variants, visitor),
+            field => field.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Field::Json(value) => value.deserialize_newtype_struct(name, visitor),
+            field => visitor.visit_newtype_struct(field),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+enum Data {
+    Mapped(Mmap),
+    Owned(Vec<u8>),
+}
+
+impl Deref for Data {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Data::Mapped(mmap) => mmap,
+            Data::Owned(bytes) => bytes,
+        }
+    }
+}
+
+/// A tokenizer stored in the binary format, usually memory-mapped from a file.
+///
+/// The vocab can be queried directly from the binary data, without deserializing the
+/// tokenizer, and the whole tokenizer can be converted back to JSON without any loss.
+pub struct BinaryTokenizer {
+    data: Data,
+    layout: Layout,
+}
+
+impl BinaryTokenizer {
+    /// Memory-map the binary tokenizer at the given path
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = File::open(path)?;
+        // Safety: the file is only ever read, and is expected not to be modified while mapped
+        let mmap = unsafe { Mmap::map(&file)? };
+        let layout = Layout::parse(&mmap)?;
+        Ok(Self {
+            data: Data::Mapped(mmap),
+            layout,
+        })
+    }
+
+    /// Load a binary tokenizer from the given bytes
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self> {
+        let layout = Layout::parse(&bytes)?;
+        Ok(Self {
+            data: Data::Owned(bytes),
+            layout,
+        })
+    }
+
+    /// Convert the given JSON tokenizer in the binary format, without instantiating it
+    pub fn from_json(json: &str) -> Result<Self> {
+        Self::from_bytes(encode(serde_json::from_str(json)?))
+    }
+
+    /// The binary representation of this tokenizer
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Save this tokenizer in the binary format at the given path
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        std::fs::write(path, self.as_bytes())?;
+        Ok(())
+    }
+
+    /// The size of the vocab of the model, without the added tokens
+    pub fn get_vocab_size(&self) -> usize {
+        self.layout.vocab.map_or(0, |vocab| vocab.len)
+    }
+
+    /// Find the id of the given token in the vocab of the model
+    pub fn token_to_id(&self, token: &str) -> Option<u32> {
+        let vocab = self.layout.vocab.as_ref()?;
+        let index = vocab.find(&self.data, token)?;
+        Some(vocab.id(&self.data, index))
+    }
+
+    /// Find the token with the given id in the vocab of the model
+    pub fn id_to_token(&self, id: u32) -> Option<&str> {
+        let vocab = self.layout.vocab.as_ref()?;
+        let index = vocab.find_id(&self.data, id)?;
+        Some(vocab.token(&self.data, index))
+    }
+
+    /// The JSON representation of this tokenizer
+    pub fn to_json_value(&self) -> Value {
+        self.layout.to_json_value(&self.data)
+    }
+
+    /// Serialize this tokenizer as a JSON String
+    pub fn to_json(&self, pretty: bool) -> Result<String> {
+        let value = self.to_json_value();
+        Ok(if pretty {
+            serde_json::to_string_pretty(&value)?
+        } else {
+            serde_json::to_string(&value)?
+        })
+    }
+
+    /// Instantiate the tokenizer, like a `Tokenizer` or a `TokenizerImpl`
+    pub fn to_tokenizer<T: DeserializeOwned>(&self) -> Result<T> {
+        self.layout.deserialize(&self.data)
+    }
+}
+
+/// Deserialize a tokenizer from the given bytes in the binary format
+pub(crate) fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T> {
+    let layout = Layout::parse(data)?;
+    layout.deserialize(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::bpe::BPE;
+    use crate::tokenizer::{AddedToken, Tokenizer};
+    use std::str::FromStr;
+
+    fn bpe_tokenizer() -> Tokenizer {
+        let vocab = [
+            ("a", 0),
+            ("b", 1),
+            ("c", 2),
+            ("ab", 3),
+            ("abc", 4),
+            ("a b", 5),
+        ]
+        .iter()
+        .map(|(t, i)| (t.to_string(), *i))
+        .collect();
+        let merges = vec![
+            ("a".to_string(), "b".to_string()),
+            ("ab".to_string(), "c".to_string()),
+        ];
+        let bpe = BPE::builder()
+            .vocab_and_merges(vocab, merges)
+            .unk_token("c".into())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(bpe);
+        tokenizer.add_special_tokens(&[AddedToken::from("[CLS]", true)]);
+        tokenizer
+    }
+
+    #[test]
+    fn binary_roundtrip() {
+        let tokenizer = bpe_tokenizer();
+        let json = tokenizer.to_string(false).unwrap();
+        let bytes = tokenizer.to_binary().unwrap();
+
+        let binary = BinaryTokenizer::from_bytes(bytes.clone()).unwrap();
+        assert_eq!(binary.get_vocab_size(), 6);
+        assert_eq!(binary.token_to_id("abc"), Some(4));
+        assert_eq!(binary.token_to_id("a b"), Some(5));
+        assert_eq!(binary.token_to_id("d"), None);
+        assert_eq!(binary.id_to_token(3), Some("ab"));
+        assert_eq!(binary.id_to_token(6), None);
+        // Objects are not kept in the same order, but hold the same values
+        let value = serde_json::from_str::<Value>(&json).unwrap();
+        assert_eq!(binary.to_json_value(), value);
+
+        let decoded = Tokenizer::from_binary(&bytes).unwrap();
+        assert_eq!(decoded.to_string(false).unwrap(), json);
+        assert_eq!(
+            decoded.encode("abc[CLS]", false).unwrap().get_ids(),
+            tokenizer.encode("abc[CLS]", false).unwrap().get_ids()
+        );
+        // Converting from JSON directly gives the same bytes
+        assert_eq!(
+            BinaryTokenizer::from_json(&json).unwrap().as_bytes(),
+            &bytes[..]
+        );
+    }
+
+    #[test]
+    fn binary_roundtrip_unigram() {
+        let json = r#"{"version":"1.0","truncation":null,"padding":null,"added_tokens":[],"normalizer":{"type":"Lowercase"},"pre_tokenizer":{"type":"Metaspace","replacement":"▁","prepend_scheme":"always","split":true},"post_processor":null,"decoder":null,"model":{"type":"Unigram","unk_id":0,"vocab":[["<unk>",0.0],["▁a",-1.5],["b",-2.0]],"byte_fallback":false}}"#;
+        let tokenizer = Tokenizer::from_str(json).unwrap();
+        let json = tokenizer.to_string(false).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("unigram.bin");
+        tokenizer.save_binary(&path).unwrap();
+
+        let binary = BinaryTokenizer::from_file(&path).unwrap();
+        assert_eq!(binary.token_to_id("▁a"), Some(1));
+        assert_eq!(binary.id_to_token(2), Some("b"));
+        let decoded: Tokenizer = binary.to_tokenizer().unwrap();
+        assert_eq!(decoded.to_string(false).unwrap(), json);
+        assert_eq!(
+            Tokenizer::from_binary_file(&path)
+                .unwrap()
+                .to_string(false)
+                .unwrap(),
+            json
+        );
+    }
+
+    #[test]
+    fn binary_invalid() {
+        let mut bytes = bpe_tokenizer().to_binary().unwrap();
+        let len = bytes.len();
+        assert!(matches!(
+            Layout::parse(&bytes[..len - 3]),
+            Err(BinaryError::Corrupted)
+        ));
+        bytes[4] = 2;
+        assert!(matches!(
+            Layout::parse(&bytes),
+            Err(BinaryError::UnsupportedVersion(2))
+        ));
+        bytes[0] = b'{';
+        assert!(matches!(
+            Layout::parse(&bytes),
+            Err(BinaryError::InvalidMagic)
+        ));
+    }
+
+    #[test]
+    fn binary_nesting_limit() {
+        let nested = |depth: usize| {
+            let mut data = vec![];
+            for _ in 0..depth {
+                data.push(ARRAY);
+                data.extend(&1u32.to_le_bytes());
+            }
+            data.push(NULL);
+            data
+        };
+        let data = nested(MAX_DEPTH);
+        assert!(Reader {
+            data: &data,
+            pos: 0
+        }
+        .value(0)
+        .is_ok());
+        let data = nested(MAX_DEPTH + 1);
+        assert!(matches!(
+            Reader {
+                data: &data,
+                pos: 0
+            }
+            .value(0),
+            Err(BinaryError::Corrupted)
+        ));
+        // Without the limit, this would overflow the stack
+        let data = nested(1_000_000);
+        assert!(matches!(
+            Reader {
+                data: &data,
+                pos: 0
+            }
+            .value(0),
+            Err(BinaryError::Corrupted)
+        ));
+    }
+}
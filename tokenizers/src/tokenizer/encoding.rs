@@ -3,6 +3,7 @@ use crate::tokenizer::{Offsets, Token};
 use crate::utils::padding::PaddingDirection;
 use crate::utils::truncation::TruncationDirection;
 use serde::{Deserialize, Serialize};
+use std::cmp;
 use std::collections::HashMap;
 use std::ops::Range;
 
@@ -305,6 +306,27 @@ impl Encoding {
     ///
     /// Panics if `stride >= max_len`
     pub fn truncate(&mut self, max_len: usize, stride: usize, direction: TruncationDirection) {
+        self.truncate_at(max_len, stride, direction, |_| true)
+    }
+
+    /// Truncate the current `Encoding`, cutting it only before the tokens for which `is_boundary`
+    /// returns `true`, like the first token of each word. Each part, including the overflowing
+    /// ones, is as long as possible without exceeding `max_len`, and overlaps the previous one by
+    /// at most `stride` tokens, starting on a boundary: when there is no boundary among the last
+    /// `stride` tokens of a part, the next part does not overlap it. When no boundary can be
+    /// found in a window of `max_len` tokens, it is cut anywhere, and the next part then
+    /// overlaps it by `stride` tokens.
+    ///
+    /// Panics if `stride >= max_len`
+    pub fn truncate_at<F>(
+        &mut self,
+        max_len: usize,
+        stride: usize,
+        direction: TruncationDirection,
+        is_boundary: F,
+    ) where
+        F: Fn(usize) -> bool,
+    {
         let encoding_len = self.ids.len();
         if max_len >= encoding_len {
             return;
@@ -321,40 +343,72 @@ impl Encoding {
         // When truncating, we lose the `sequence_ranges` information.
         self.sequence_ranges.clear();
 
-        let offset = max_len - stride;
-        let mut end = false;
-        let parts_ranges: Vec<(usize, usize)> = match direction {
-            TruncationDirection::Right => (0..encoding_len)
-                .step_by(offset)
-                .filter_map(|start| {
-                    if !end {
-                        let stop = std::cmp::min(start + max_len, encoding_len);
-                        end = stop == encoding_len;
-                        Some((start, stop))
-                    } else {
-                        None
+        let mut parts_ranges: Vec<(usize, usize)> = vec![];
+        match direction {
+            TruncationDirection::Right => {
+                let mut start = 0;
+                loop {
+                    if start + max_len >= encoding_len {
+                        parts_ranges.push((start, encoding_len));
+                        break;
                     }
-                })
-                .collect(),
-            TruncationDirection::Left => (0..encoding_len)
-                .rev()
-                .step_by(offset)
-                .filter_map(|stop| {
-                    let stop = stop + 1;
-                    let start = if stop < max_len { 0 } else { stop - max_len };
-                    if start < stop && !end {
-                        end = start == 0;
-                        Some((start, stop))
-                    } else {
-                        None
+                    let boundary = (start + 1..=start + max_len)
+                        .rev()
+                        .find(|i| is_boundary(*i));
+                    let stop = boundary.unwrap_or(start + max_len);
+                    parts_ranges.push((start, stop));
+                    // The next part starts on the first boundary at most `stride` tokens back, or
+                    // anywhere when this part was already cut anywhere
+                    let first = cmp::max(stop.saturating_sub(stride), start + 1);
+                    start = match boundary {
+                        Some(_) => (first..stop).find(|i| is_boundary(*i)).unwrap_or(stop),
+                        None => first,
+                    };
+                }
+            }
+            TruncationDirection::Left => {
+                let mut stop = encoding_len;
+                loop {
+                    if stop <= max_len {
+                        parts_ranges.push((0, stop));
+                        break;
                     }
-                })
-                .collect(),
-        };
+                    let boundary = (stop - max_len..stop).find(|i| is_boundary(*i));
+                    let start = boundary.unwrap_or(stop - max_len);
+                    parts_ranges.push((start, stop));
+                    // The next part stops on the last boundary at most `stride` tokens ahead, or
+                    // anywhere when this part was already cut anywhere
+                    let last = cmp::min(start + stride, stop - 1);
+                    stop = match boundary {
+                        Some(_) => (start + 1..=last)
+                            .rev()
+                            .find(|i| is_boundary(*i))
+                            .unwrap_or(start),
+                        None => last,
+                    };
+                }
+            }
+        }
 
         let mut i = 0;
         let (start, stop) = parts_ranges[i];
-        let mut new_encoding = Encoding {
+        let mut new_encoding = self.slice(start, stop);
+
+        loop {
+            if i == parts_ranges.len() - 1 {
+                break;
+            }
+            i += 1;
+            let (start, stop) = parts_ranges[i];
+            new_encoding.overflowing.push(self.slice(start, stop));
+        }
+        *self = new_encoding;
+    }
+
+    /// Get a new `Encoding` with the tokens between `start` and `stop`. The sequence ranges and
+    /// the overflowing parts are not kept.
+    pub(crate) fn slice(&self, start: usize, stop: usize) -> Self {
+        Encoding {
             ids: self.ids[start..stop].to_vec(),
             type_ids: self.type_ids[start..stop].to_vec(),
             tokens: self.tokens[start..stop].to_vec(),
@@ -364,27 +418,7 @@ impl Encoding {
             attention_mask: self.attention_mask[start..stop].to_vec(),
             overflowing: vec![],
             sequence_ranges: HashMap::new(),
-        };
-
-        loop {
-            if i == parts_ranges.len() - 1 {
-                break;
-            }
-            i += 1;
-            let (start, stop) = parts_ranges[i];
-            new_encoding.overflowing.push(Encoding {
-                ids: self.ids[start..stop].to_vec(),
-                type_ids: self.type_ids[start..stop].to_vec(),
-                tokens: self.tokens[start..stop].to_vec(),
-                words: self.words[start..stop].to_vec(),
-                offsets: self.offsets[start..stop].to_vec(),
-                special_tokens_mask: self.special_tokens_mask[start..stop].to_vec(),
-                attention_mask: self.attention_mask[start..stop].to_vec(),
-                overflowing: vec![],
-                sequence_ranges: HashMap::new(),
-            });
         }
-        *self = new_encoding;
     }
 
     /// Merge all Encodings together
@@ -788,6 +822,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn truncate_at_boundaries() {
+        let words = [0, 1, 1, 2, 3, 3, 4];
+        let encoding = Encoding::new(
+            (0..7).collect(),
+            vec![0; 7],
+            words.iter().map(|w| w.to_string()).collect(),
+            words.iter().map(|w| Some(*w)).collect(),
+            (0..7).map(|i| (i, i + 1)).collect(),
+            vec![0; 7],
+            vec![1; 7],
+            vec![],
+            HashMap::new(),
+        );
+        let is_word_start = |i: usize| i == 0 || i == words.len() || words[i] != words[i - 1];
+        let parts = |encoding: &Encoding| {
+            std::iter::once(encoding)
+                .chain(encoding.get_overflowing())
+                .map(|e| e.get_ids().to_vec())
+                .collect::<Vec<_>>()
+        };
+
+        // The stride snaps to the beginning of a word
+        let mut enc = encoding.clone();
+        enc.truncate_at(4, 2, TruncationDirection::Right, is_word_start);
+        assert_eq!(parts(&enc), vec![vec![0, 1, 2, 3], vec![3, 4, 5, 6]]);
+        let mut enc = encoding.clone();
+        enc.truncate_at(4, 2, TruncationDirection::Left, is_word_start);
+        assert_eq!(parts(&enc), vec![vec![3, 4, 5, 6], vec![0, 1, 2, 3]]);
+
+        let mut enc = encoding.clone();
+        enc.truncate_at(3, 1, TruncationDirection::Right, is_word_start);
+        assert_eq!(parts(&enc), vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+
+        // Without any boundary, the windows are cut anywhere, and keep their stride
+        let mut enc = encoding.clone();
+        enc.truncate_at(4, 2, TruncationDirection::Right, |_| false);
+        assert_eq!(
+            parts(&enc),
+            vec![vec![0, 1, 2, 3], vec![2, 3, 4, 5], vec![4, 5, 6]]
+        );
+        let mut enc = encoding;
+        enc.truncate_at(4, 2, TruncationDirection::Left, |_| false);
+        assert_eq!(
+            parts(&enc),
+            vec![vec![3, 4, 5, 6], vec![1, 2, 3, 4], vec![0, 1, 2]]
+        );
+    }
+
     #[test]
     fn mappings() {
         let encoding = Encoding {
@@ -0,0 +1,312 @@
+use super::{
+    EncodeInput, Encoding, InputSequence, Model, NormalizedString, Normalizer, OffsetReferential,
+    OffsetType, Offsets, PostProcessor, PreTokenizedString, PreTokenizer, Result, Token,
+    TokenizerImpl,
+};
+use crate::utils::rng::in_sample_stream;
+use serde::Serialize;
+
+/// The `type` of the given component, as found in its serialization
+pub(crate) fn component_type<T: Serialize>(component: &T) -> Option<String> {
+    serde_json::to_value(component)
+        .ok()?
+        .get("type")?
+        .as_str()
+        .map(|t| t.to_owned())
+}
+
+/// The state of a string after a normalization step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NormalizationStep {
+    /// The type of the normalizer applied during this step, when known
+    pub normalizer: Option<String>,
+    pub normalized: String,
+    /// The offsets in the original sequence of each byte of `normalized`
+    pub alignments: Vec<Offsets>,
+}
+
+/// The normalization of a part of the sequence
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NormalizedPart {
+    pub original: String,
+    /// The offsets of this part in the original sequence
+    pub offsets: Offsets,
+    pub steps: Vec<NormalizationStep>,
+}
+
+/// A split of a `PreTokenizedString`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SplitExplanation {
+    pub normalized: String,
+    /// The offsets of this split in the original sequence
+    pub offsets: Offsets,
+    /// The tokens of this split, once it has been tokenized
+    pub tokens: Option<Vec<Token>>,
+}
+
+/// The state of the splits after a pre-tokenization step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PreTokenizationStep {
+    /// The type of the pre-tokenizer applied during this step, when known
+    pub pre_tokenizer: Option<String>,
+    pub splits: Vec<SplitExplanation>,
+}
+
+/// The intermediate states of the encoding of a sequence
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SequenceExplanation {
+    pub sequence: String,
+    /// The normalization of each part of the sequence that wasn't extracted as a
+    /// non-normalized added token
+    pub normalization: Vec<NormalizedPart>,
+    /// The splits once the added tokens have been extracted, with the tokens of the added ones
+    pub added_tokens: Vec<SplitExplanation>,
+    pub pre_tokenization: Vec<PreTokenizationStep>,
+    /// The splits once tokenized by the model
+    pub tokens: Vec<SplitExplanation>,
+}
+
+/// The intermediate states of the encoding of an input, as returned by
+/// [`TokenizerImpl::explain`]. Pre-tokenized sequences have one explanation for each of their
+/// words.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Explanation {
+    /// The explanation of each sequence of the input
+    pub sequences: Vec<Vec<SequenceExplanation>>,
+    /// The final `Encoding`, once post-processed
+    pub encoding: Encoding,
+}
+
+fn explain_splits(pretokenized: &PreTokenizedString) -> Vec<SplitExplanation> {
+    pretokenized
+        .get_splits(OffsetReferential::Original, OffsetType::Byte)
+        .into_iter()
+        .map(|(normalized, offsets, tokens)| SplitExplanation {
+            normalized: normalized.to_owned(),
+            offsets,
+            tokens: tokens.clone(),
+        })
+        .collect()
+}
+
+impl NormalizationStep {
+    fn new(normalizer: Option<&str>, normalized: &NormalizedString) -> Self {
+        Self {
+            normalizer: normalizer.map(|n| n.to_owned()),
+            normalized: normalized.get().to_owned(),
+            alignments: normalized.shifted_alignments(),
+        }
+    }
+}
+
+impl<M, N, PT, PP, D> TokenizerImpl<M, N, PT, PP, D>
+where
+    M: Model,
+    N: Normalizer,
+    PT: PreTokenizer,
+    PP: PostProcessor,
+{
+    /// Encode the given input like [`encode`](Self::encode), keeping track of the state after
+    /// each stage of the pipeline: each normalizer, the extraction of the added tokens, each
+    /// pre-tokenizer, and the model. The result can be serialized to JSON, to help understand
+    /// where a tokenization comes from.
+    pub fn explain<'s, E>(&self, input: E, add_special_tokens: bool) -> Result<Explanation>
+    where
+        E: Into<EncodeInput<'s>>,
+    {
+        let sequences = input.into().into_sequences();
+        let texts = self.truncation_texts(&sequences);
+        let texts = texts
+            .iter()
+            .map(|text| text.as_deref().map(|text| (text, OffsetType::Byte)))
+            .collect::<Vec<_>>();
+
+        let (sequences, encodings): (Vec<_>, Vec<_>) = in_sample_stream(|| {
+            sequences
+                .into_iter()
+                .enumerate()
+                .map(|(i, sequence)| self.explain_single_sequence(sequence, i as u32))
+                .collect::<Result<Vec<_>>>()
+        })?
+        .into_iter()
+        .unzip();
+
+        Ok(Explanation {
+            sequences,
+            encoding: self.post_process_sequences(encodings, &texts, add_special_tokens)?,
+        })
+    }
+
+    fn explain_single_sequence(
+        &self,
+        sequence: InputSequence,
+        type_id: u32,
+    ) -> Result<(Vec<SequenceExplanation>, Encoding)> {
+        let words: Vec<&str> = match &sequence {
+            InputSequence::Raw(seq) => {
+                let (explanation, encoding) = self.explain_subsequence(seq, type_id, None)?;
+                return Ok((vec![explanation], encoding));
+            }
+            InputSequence::PreTokenized(seq) => seq.to_vec(),
+            InputSequence::PreTokenizedOwned(seq) => seq.iter().map(|s| s.as_str()).collect(),
+            InputSequence::PreTokenizedCow(seq) => seq.iter().map(|s| s.as_ref()).collect(),
+        };
+
+        let (explanations, encodings): (Vec<_>, Vec<_>) = words
+            .into_iter()
+            .enumerate()
+            .map(|(i, word)| self.explain_subsequence(word, type_id, Some(i as u32)))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        Ok((explanations, encodings.into_iter().collect()))
+    }
+
+    fn explain_subsequence(
+        &self,
+        sequence: &str,
+        type_id: u32,
+        word_idx: Option<u32>,
+    ) -> Result<(SequenceExplanation, Encoding)> {
+        let mut normalization = vec![];
+        let mut pretokenized = self.added_vocabulary.extract_and_normalize_with(
+            |normalized| {
+                let mut part = NormalizedPart {
+                    original: normalized.get_original().to_owned(),
+                    offsets: normalized.offsets_original(),
+                    steps: vec![],
+                };
+                if let Some(normalizer) = &self.normalizer {
+                    // Errors are ignored, like when encoding
+                    let _ = normalizer.normalize_steps(normalized, &mut |n, normalized| {
+                        part.steps.push(NormalizationStep::new(n, normalized))
+                    });
+                }
+                normalization.push(part);
+            },
+            sequence,
+        );
+        let added_tokens = explain_splits(&pretokenized);
+
+        let mut pre_tokenization = vec![];
+        if let Some(pre_tokenizer) = &self.pre_tokenizer {
+            pre_tokenizer.pre_tokenize_steps(&mut pretokenized, &mut |p, pretokenized| {
+                pre_tokenization.push(PreTokenizationStep {
+                    pre_tokenizer: p.map(|p| p.to_owned()),
+                    splits: explain_splits(pretokenized),
+                })
+            })?;
+        }
+
+        pretokenized.tokenize(|normalized| self.model.tokenize(normalized.get()))?;
+        let tokens = explain_splits(&pretokenized);
+        let encoding = pretokenized.into_encoding(word_idx, type_id, OffsetType::Byte)?;
+
+        Ok((
+            SequenceExplanation {
+                sequence: sequence.to_owned(),
+                normalization,
+                added_tokens,
+                pre_tokenization,
+                tokens,
+            },
+            encoding,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::wordlevel::WordLevel;
+    use crate::normalizers::{Lowercase, Sequence as NormalizerSequence, Strip};
+    use crate::pre_tokenizers::punctuation::Punctuation;
+    use crate::pre_tokenizers::sequence::Sequence as PreTokenizerSequence;
+    use crate::pre_tokenizers::whitespace::WhitespaceSplit;
+    use crate::tokenizer::{AddedToken, Tokenizer};
+
+    #[test]
+    fn explain() {
+        let vocab = [("[UNK]", 0), ("hey", 1), ("!", 2), ("[SEP]", 3)]
+            .iter()
+            .map(|(t, i)| (t.to_string(), *i))
+            .collect();
+        let model = WordLevel::builder()
+            .vocab(vocab)
+            .unk_token("[UNK]".into())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(model);
+        tokenizer.with_normalizer(NormalizerSequence::new(vec![
+            Strip::new(true, true).into(),
+            Lowercase.into(),
+        ]));
+        tokenizer.with_pre_tokenizer(PreTokenizerSequence::new(vec![
+            WhitespaceSplit.into(),
+            Punctuation::default().into(),
+        ]));
+        tokenizer.add_special_tokens(&[AddedToken::from("[SEP]", true)]);
+
+        let explanation = tokenizer.explain(" HEY! [SEP]you", false).unwrap();
+        assert_eq!(
+            explanation.encoding,
+            tokenizer.encode(" HEY! [SEP]you", false).unwrap()
+        );
+
+        let sequence = &explanation.sequences[0][0];
+        // Each part around the added token is normalized on its own
+        assert_eq!(sequence.normalization.len(), 2);
+        let part = &sequence.normalization[0];
+        assert_eq!((part.original.as_str(), part.offsets), (" HEY! ", (0, 6)));
+        let steps = part
+            .steps
+            .iter()
+            .map(|s| (s.normalizer.as_deref(), s.normalized.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            steps,
+            vec![(Some("Strip"), "HEY!"), (Some("Lowercase"), "hey!")]
+        );
+        assert_eq!(part.steps[1].alignments[0], (1, 2));
+
+        let splits = sequence
+            .added_tokens
+            .iter()
+            .map(|s| (s.normalized.as_str(), s.tokens.is_some()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            splits,
+            vec![("hey!", false), ("[SEP]", true), ("you", false)]
+        );
+
+        let steps = sequence
+            .pre_tokenization
+            .iter()
+            .map(|s| (s.pre_tokenizer.as_deref(), s.splits.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            steps,
+            vec![(Some("WhitespaceSplit"), 3), (Some("Punctuation"), 4)]
+        );
+
+        let tokens = sequence
+            .tokens
+            .iter()
+            .map(|s| (s.offsets, s.tokens.as_ref().unwrap()[0].value.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            vec![
+                ((1, 4), "hey"),
+                ((4, 5), "!"),
+                ((6, 11), "[SEP]"),
+                ((11, 14), "[UNK]")
+            ]
+        );
+
+        let json = serde_json::to_value(&explanation).unwrap();
+        assert_eq!(
+            json["sequences"][0][0]["pre_tokenization"][1]["pre_tokenizer"],
+            "Punctuation"
+        );
+    }
+}
@@ -10,6 +10,7 @@
 //!   ...).
 
 use std::{
+    cmp,
     collections::HashMap,
     fs::{read_to_string, File},
     io::prelude::*,
@@ -21,31 +22,48 @@ use std::{
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::utils::combine_nbest;
 use crate::utils::iter::ResultShunt;
+use crate::utils::padding::get_pad_length;
 use crate::utils::parallelism::*;
 use crate::utils::progress::{ProgressBar, ProgressStyle};
+use crate::utils::rng::{in_sample_stream, with_sample_stream};
+use crate::utils::truncation::{truncate_sequences, truncated_lengths};
 
 mod added_vocabulary;
+mod binary;
 mod encoding;
+mod explain;
 pub mod normalizer;
 pub mod pattern;
 pub mod pre_tokenizer;
+mod prune;
 mod serialization;
 
 // Re-export wrappers
 pub use crate::decoders::DecoderWrapper;
+pub use crate::models::merge::VocabMergeReport;
 pub use crate::models::ModelWrapper;
 pub use crate::normalizers::NormalizerWrapper;
 pub use crate::pre_tokenizers::PreTokenizerWrapper;
+pub use crate::processors::chat::{ChatEncoding, ChatMessage};
 pub use crate::processors::PostProcessorWrapper;
 // And some other types
+pub use crate::utils::cache::CacheStats;
 pub use crate::utils::iter::LinesWithEnding;
+pub use crate::utils::packing::{
+    pack_encodings, PackedEncoding, PackedEncodings, PackingError, PackingParams,
+};
 pub use crate::utils::padding::{pad_encodings, PaddingDirection, PaddingParams, PaddingStrategy};
 pub use crate::utils::truncation::{
-    truncate_encodings, TruncationDirection, TruncationParams, TruncationStrategy,
+    truncate_encodings, truncate_encodings_multi, BoundaryRegex, TruncationBoundary,
+    TruncationDirection, TruncationParams, TruncationStrategy,
 };
 pub use added_vocabulary::*;
+pub use binary::{BinaryError, BinaryTokenizer, BINARY_MAGIC, BINARY_VERSION};
 pub use encoding::*;
+pub(crate) use explain::component_type;
+pub use explain::*;
 pub use normalizer::{NormalizedString, OffsetReferential, SplitDelimiterBehavior};
 pub use pre_tokenizer::*;
 
@@ -56,6 +74,19 @@ pub type Offsets = (usize, usize);
 /// Takes care of pre-processing strings.
 pub trait Normalizer {
     fn normalize(&self, normalized: &mut NormalizedString) -> Result<()>;
+
+    /// Normalize like `normalize`, calling `on_step` with the type of the normalizer of each
+    /// step (when known) and the state of the string after it. Only composite normalizers,
+    /// like a `Sequence`, have more than one step.
+    fn normalize_steps(
+        &self,
+        normalized: &mut NormalizedString,
+        on_step: &mut dyn FnMut(Option<&str>, &NormalizedString),
+    ) -> Result<()> {
+        self.normalize(normalized)?;
+        on_step(None, normalized);
+        Ok(())
+    }
 }
 
 /// The `PreTokenizer` is in charge of doing the pre-segmentation step. It splits the given string
@@ -65,6 +96,19 @@ pub trait Normalizer {
 /// the original string.
 pub trait PreTokenizer {
     fn pre_tokenize(&self, pretokenized: &mut PreTokenizedString) -> Result<()>;
+
+    /// Pre-tokenize like `pre_tokenize`, calling `on_step` with the type of the pre-tokenizer
+    /// of each step (when known) and the state of the splits after it. Only composite
+    /// pre-tokenizers, like a `Sequence`, have more than one step.
+    fn pre_tokenize_steps(
+        &self,
+        pretokenized: &mut PreTokenizedString,
+        on_step: &mut dyn FnMut(Option<&str>, &PreTokenizedString),
+    ) -> Result<()> {
+        self.pre_tokenize(pretokenized)?;
+        on_step(None, pretokenized);
+        Ok(())
+    }
 }
 
 /// Represents a model used during Tokenization (like BPE or Word or Unigram).
@@ -73,6 +117,22 @@ pub trait Model {
     /// Tokenize the given sequence into multiple underlying `Token`. The `offsets` on the `Token`
     /// are expected to be relative to the given sequence.
     fn tokenize(&self, sequence: &str) -> Result<Vec<Token>>;
+    /// Tokenize the given sequence into its `n` best segmentations, each along with its score,
+    /// from the most to the least likely. The scores are only comparable between segmentations
+    /// of the same model: a log-probability for a `Unigram`, but a rank penalty for a `BPE`,
+    /// minus the number of merge ranks skipped. By default, a model only has one way to
+    /// tokenize a sequence, which is returned with a score of `0.0`.
+    fn tokenize_nbest(&self, sequence: &str, n: usize) -> Result<Vec<(Vec<Token>, f64)>> {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        Ok(vec![(self.tokenize(sequence)?, 0.0)])
+    }
+    /// Count the tokens `tokenize` would produce for the given sequence. Models can override
+    /// this to avoid building the `Token`s.
+    fn count_tokens(&self, sequence: &str) -> Result<usize> {
+        Ok(self.tokenize(sequence)?.len())
+    }
     /// Find the ID associated to a string token
     fn token_to_id(&self, token: &str) -> Option<u32>;
     /// Find the string token associated to an ID
@@ -86,6 +146,18 @@ pub trait Model {
     fn save(&self, folder: &Path, prefix: Option<&str>) -> Result<Vec<PathBuf>>;
     /// Get an instance of a Trainer capable of training this Model
     fn get_trainer(&self) -> <Self as Model>::Trainer;
+    /// Remove from the vocabulary the tokens for which `keep` returns `false`, and give
+    /// contiguous ids to the remaining ones, in the same order. The tokens the model can't work
+    /// without, like its unknown token, are always kept. Returns the new id of each kept token.
+    fn prune_vocab(&mut self, _keep: &dyn Fn(u32) -> bool) -> Result<HashMap<u32, u32>> {
+        Err(Box::new(ModelError::PruningNotSupported))
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ModelError {
+    #[error("model does not support pruning its vocabulary")]
+    PruningNotSupported,
 }
 
 /// A `PostProcessor` has the responsibility to post process an encoded output of the `Tokenizer`.
@@ -93,6 +165,11 @@ pub trait Model {
 pub trait PostProcessor {
     /// Returns the number of tokens that will be added during the processing step
     fn added_tokens(&self, is_pair: bool) -> usize;
+    /// Returns the number of tokens that will be added during the processing step of the given
+    /// number of sequences, or the error processing them would give
+    fn added_tokens_multi(&self, n_sequences: usize) -> Result<usize> {
+        Ok(self.added_tokens(n_sequences > 1))
+    }
     /// Process both encodings and returns a new merged one
     fn process(
         &self,
@@ -100,11 +177,18 @@ pub trait PostProcessor {
         pair_encoding: Option<Encoding>,
         add_special_tokens: bool,
     ) -> Result<Encoding> {
-        let mut encodings = if let Some(pair_encoding) = pair_encoding {
-            vec![encoding, pair_encoding]
-        } else {
-            vec![encoding]
-        };
+        let mut encodings = vec![encoding];
+        encodings.extend(pair_encoding);
+        self.process_multi(encodings, add_special_tokens)
+    }
+
+    /// Process any number of encodings and returns a new merged one. Each sequence gets its
+    /// index as sequence id and type id, before being processed.
+    fn process_multi(
+        &self,
+        mut encodings: Vec<Encoding>,
+        add_special_tokens: bool,
+    ) -> Result<Encoding> {
         encodings.iter_mut().enumerate().for_each(|(i, encoding)| {
             encoding.set_sequence_id(i);
             encoding
@@ -124,6 +208,25 @@ pub trait PostProcessor {
         encodings: Vec<Encoding>,
         add_special_tokens: bool,
     ) -> Result<Vec<Encoding>>;
+
+    /// Process the encodings of the messages of a conversation, along with their role, and
+    /// returns a single encoding of the whole conversation
+    fn process_chat(
+        &self,
+        _messages: Vec<(String, Encoding)>,
+        _add_special_tokens: bool,
+    ) -> Result<ChatEncoding> {
+        Err(Box::new(ProcessorError::ChatNotSupported))
+    }
+
+    /// The ids of the special tokens added by this processor
+    fn get_special_ids(&self) -> Vec<u32> {
+        vec![]
+    }
+
+    /// Update the ids of the special tokens added by this processor, once the vocabulary has
+    /// been pruned. `ids` contains the new id of each of the `get_special_ids`.
+    fn remap_ids(&mut self, _ids: &HashMap<u32, u32>) {}
 }
 impl dyn PostProcessor {
     pub fn default_process(
@@ -146,8 +249,12 @@ impl dyn PostProcessor {
 
 #[derive(thiserror::Error, Debug)]
 pub enum ProcessorError {
-    #[error("encodings vector length must be either 1 or 2")]
+    #[error("invalid number of encodings for this post-processor")]
     InvalidEncodingsVecLength,
+    #[error("post-processor does not support chat messages")]
+    ChatNotSupported,
+    #[error("post-processor needs at least {0} sequences, got {1}")]
+    NotEnoughSequences(usize, usize),
 }
 
 /// A `Decoder` changes the raw tokens into its more readable form.
@@ -177,7 +284,7 @@ pub trait Trainer {
         F: Fn(&str) -> Result<Vec<String>> + Sync;
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Token {
     pub id: u32,
     pub value: String,
@@ -256,6 +363,19 @@ impl<'s> From<&'s [Cow<'s, str>]> for InputSequence<'s> {
 pub enum EncodeInput<'s> {
     Single(InputSequence<'s>),
     Dual(InputSequence<'s>, InputSequence<'s>),
+    /// Any number of sequences, like a query along with multiple passages
+    Multi(Vec<InputSequence<'s>>),
+}
+
+impl<'s> EncodeInput<'s> {
+    /// The sequences of this input, in order
+    pub fn into_sequences(self) -> Vec<InputSequence<'s>> {
+        match self {
+            Self::Single(s1) => vec![s1],
+            Self::Dual(s1, s2) => vec![s1, s2],
+            Self::Multi(sequences) => sequences,
+        }
+    }
 }
 
 impl<'s, I: Into<InputSequence<'s>>> From<I> for EncodeInput<'s> {
@@ -434,6 +554,52 @@ impl Tokenizer {
     > {
         self.0
     }
+
+    /// Merge the vocabulary of the other tokenizer, whose model must be of the same type, into
+    /// this one. The ids of this tokenizer don't change: the new tokens of the model come after
+    /// the ones in use, followed by the added tokens of the other tokenizer that are missing.
+    /// The rest of the pipeline is the one of this tokenizer.
+    ///
+    /// The report gives the id of each token of the other tokenizer in the merged one, along
+    /// with the tokens that were added and those that had a different id.
+    pub fn merge_vocab(&self, other: &Tokenizer) -> Result<(Tokenizer, VocabMergeReport)> {
+        let model_vocab = self.get_model().get_vocab();
+        let reserved = self
+            .get_added_tokens_decoder()
+            .into_iter()
+            .filter(|(id, token)| model_vocab.get(&token.content) != Some(id))
+            .map(|(id, token)| (id, token.content))
+            .collect();
+        let (model, mut report) = self.get_model().merge_vocab(other.get_model(), &reserved)?;
+
+        let mut merged = self.clone();
+        merged.with_model(model);
+        let mut added = other
+            .get_added_tokens_decoder()
+            .into_iter()
+            .filter(|(id, _)| !report.ids.contains_key(id))
+            .collect::<Vec<_>>();
+        added.sort_unstable_by_key(|(id, _)| *id);
+        for (other_id, token) in added {
+            let (id, new) = match merged.token_to_id(&token.content) {
+                Some(id) => (id, false),
+                None => {
+                    if token.special {
+                        merged.add_special_tokens(std::slice::from_ref(&token));
+                    } else {
+                        merged.add_tokens(std::slice::from_ref(&token));
+                    }
+                    match merged.token_to_id(&token.content) {
+                        Some(id) => (id, true),
+                        None => continue,
+                    }
+                }
+            };
+            report.register(&token.content, other_id, id, new);
+        }
+        Ok((merged, report))
+    }
+
     pub fn from_file<P: AsRef<Path>>(file: P) -> Result<Self> {
         let content = read_to_string(file)?;
         let tokenizer = serde_json::from_str(&content)?;
@@ -443,6 +609,12 @@ impl Tokenizer {
         let tokenizer = serde_json::from_slice(bytes.as_ref())?;
         Ok(tokenizer)
     }
+    pub fn from_binary<P: AsRef<[u8]>>(bytes: P) -> Result<Self> {
+        binary::decode(bytes.as_ref())
+    }
+    pub fn from_binary_file<P: AsRef<Path>>(file: P) -> Result<Self> {
+        BinaryTokenizer::from_file(file)?.to_tokenizer()
+    }
     #[cfg(feature = "http")]
     pub fn from_pretrained<S: AsRef<str>>(
         identifier: S,
@@ -604,6 +776,11 @@ where
         &self.model
     }
 
+    /// Get a mutable reference to the model
+    pub fn get_model_mut(&mut self) -> &mut M {
+        &mut self.model
+    }
+
     /// Set the added vocabulary.
     pub fn with_added_vocabulary(&mut self, added_vocabulary: AddedVocabulary) -> &mut Self {
         self.added_vocabulary = added_vocabulary;
@@ -620,7 +797,7 @@ where
     /// Fails if `stride` is too high relative to `max_length` and `post_processor.added_tokens()`
     pub fn with_truncation(&mut self, trunc: Option<TruncationParams>) -> Result<&mut Self> {
         if let Some(trunc_params) = &trunc {
-            let n_added_tokens = self.get_n_added_tokens(false);
+            let n_added_tokens = self.get_n_added_tokens(1)?;
             let effective_max_length = trunc_params.max_length - n_added_tokens;
             if effective_max_length < trunc_params.stride {
                 return Err(Box::new(TruncationParamError(format!(
@@ -760,6 +937,55 @@ where
         }
     }
 
+    /// Encode the given sequence into its `n` best segmentations, as scored by the model
+    fn encode_single_sequence_nbest(
+        &self,
+        sequence: InputSequence,
+        type_id: u32,
+        n: usize,
+        offsets_type: OffsetType,
+    ) -> Result<Vec<(Encoding, f64)>> {
+        let encode = |word_idx: Option<u32>, subseq: &str| -> Result<Vec<(Encoding, f64)>> {
+            let normalized = self
+                .added_vocabulary
+                .extract_and_normalize(self.normalizer.as_ref(), subseq);
+            let pre_tokenized = self.do_pre_tokenize(normalized)?;
+            pre_tokenized
+                .tokenize_nbest(n, |normalized| {
+                    self.model.tokenize_nbest(normalized.get(), n)
+                })?
+                .into_iter()
+                .map(|(pre_tokenized, score)| {
+                    Ok((
+                        pre_tokenized.into_encoding(word_idx, type_id, offsets_type)?,
+                        score,
+                    ))
+                })
+                .collect()
+        };
+        let encode_all = |subseqs: Vec<&str>| -> Result<Vec<(Encoding, f64)>> {
+            let mut encodings = vec![(Encoding::default(), 0.0)];
+            for (i, subseq) in subseqs.into_iter().enumerate() {
+                encodings =
+                    combine_nbest(&encodings, &encode(Some(i as u32), subseq)?, n, |a, b| {
+                        Encoding::merge([a.clone(), b.clone()], false)
+                    });
+            }
+            Ok(encodings)
+        };
+
+        match sequence {
+            InputSequence::PreTokenized(seq) => encode_all(seq.to_vec()),
+            InputSequence::PreTokenizedOwned(seq) => {
+                encode_all(seq.iter().map(|s| s.as_str()).collect())
+            }
+            InputSequence::PreTokenizedCow(seq) => {
+                encode_all(seq.iter().map(|s| s.as_ref()).collect())
+            }
+            InputSequence::Raw(seq) => encode(None, seq.as_ref()),
+        }
+    }
+
     /// Encode the given input. This method accepts both single sequences, as well as pair
     /// sequences. Also, a sequence can be a string, or already pre-tokenized input directly:
     ///
@@ -786,20 +1012,102 @@ where
     where
         E: Into<EncodeInput<'s>>,
     {
-        // Extract sequences from the EncodeInput
-        let (sequence, pair) = match input.into() {
-            EncodeInput::Single(s1) => (s1, None),
-            EncodeInput::Dual(s1, s2) => (s1, Some(s2)),
-        };
+        in_sample_stream(|| {
+            self.encode_sequences(
+                input.into().into_sequences(),
+                OffsetType::Byte,
+                add_special_tokens,
+            )
+        })
+    }
+
+    /// Encode each of the given sequences, and post process them together
+    fn encode_sequences(
+        &self,
+        sequences: Vec<InputSequence>,
+        offsets_type: OffsetType,
+        add_special_tokens: bool,
+    ) -> Result<Encoding> {
+        let texts = self.truncation_texts(&sequences);
 
         // Encode each sequence
-        let encoding = self.encode_single_sequence(sequence, 0, OffsetType::Byte)?;
-        let pair_encoding = pair
-            .map(|sequence| self.encode_single_sequence(sequence, 1, OffsetType::Byte))
-            .transpose()?;
+        let encodings = sequences
+            .into_iter()
+            .enumerate()
+            .map(|(i, sequence)| self.encode_single_sequence(sequence, i as u32, offsets_type))
+            .collect::<Result<Vec<_>>>()?;
+
+        // And finally post process
+        let texts = texts
+            .iter()
+            .map(|text| text.as_deref().map(|text| (text, offsets_type)))
+            .collect::<Vec<_>>();
+        self.post_process_sequences(encodings, &texts, add_special_tokens)
+    }
+
+    /// Encode the given input into its `n` best segmentations, from the most to the least
+    /// likely, each along with its score. The score of an `Encoding` is the sum of the scores
+    /// the model gave to the segmentation of each of its words, as described by
+    /// `Model::tokenize_nbest`: log-probabilities with a `Unigram`, but rank penalties with a
+    /// `BPE`.
+    ///
+    /// ```
+    /// # use tokenizers::Tokenizer;
+    /// # use tokenizers::models::unigram::Unigram;
+    /// let vocab = vec![
+    ///     ("<unk>".to_string(), 0.0),
+    ///     ("a".to_string(), -1.0),
+    ///     ("b".to_string(), -1.0),
+    ///     ("ab".to_string(), -1.5),
+    /// ];
+    /// let tokenizer = Tokenizer::new(Unigram::from(vocab, Some(0), false).unwrap());
+    ///
+    /// let nbest = tokenizer.encode_nbest("ab", 2, false).unwrap();
+    /// assert_eq!(nbest[0].0.get_tokens(), &["ab"]);
+    /// assert_eq!(nbest[1].0.get_tokens(), &["a", "b"]);
+    /// assert!(nbest[0].1 > nbest[1].1);
+    /// ```
+    pub fn encode_nbest<'s, E>(
+        &self,
+        input: E,
+        n: usize,
+        add_special_tokens: bool,
+    ) -> Result<Vec<(Encoding, f64)>>
+    where
+        E: Into<EncodeInput<'s>>,
+    {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        let sequences = input.into().into_sequences();
+        let texts = self.truncation_texts(&sequences);
+        let texts = texts
+            .iter()
+            .map(|text| text.as_deref().map(|text| (text, OffsetType::Byte)))
+            .collect::<Vec<_>>();
+
+        // Encode each sequence, and keep the best combinations
+        let mut combinations = vec![(vec![], 0.0)];
+        for (i, sequence) in sequences.into_iter().enumerate() {
+            let encodings =
+                self.encode_single_sequence_nbest(sequence, i as u32, n, OffsetType::Byte)?;
+            combinations = combine_nbest(&combinations, &encodings, n, |previous, encoding| {
+                let mut combination: Vec<Encoding> = previous.clone();
+                combination.push(encoding.clone());
+                combination
+            });
+        }
 
         // And finally post process
-        self.post_process(encoding, pair_encoding, add_special_tokens)
+        combinations
+            .into_iter()
+            .map(|(encodings, score)| {
+                Ok((
+                    self.post_process_sequences(encodings, &texts, add_special_tokens)?,
+                    score,
+                ))
+            })
+            .collect()
     }
 
     /// Encode the given input, using offsets relative to chars instead of bytes.
@@ -829,20 +1137,109 @@ where
     where
         E: Into<EncodeInput<'s>>,
     {
-        // Extract sequences from the EncodeInput
-        let (sequence, pair) = match input.into() {
-            EncodeInput::Single(s1) => (s1, None),
-            EncodeInput::Dual(s1, s2) => (s1, Some(s2)),
+        in_sample_stream(|| {
+            self.encode_sequences(
+                input.into().into_sequences(),
+                OffsetType::Char,
+                add_special_tokens,
+            )
+        })
+    }
+
+    /// Count the tokens of the given input, exactly as `encode(input, add_special_tokens)`
+    /// would produce them, but without building the `Encoding`. When truncating at boundaries
+    /// other than tokens, the input is encoded since the length depends on these boundaries.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use tokenizers::Tokenizer;
+    /// # use tokenizers::models::wordlevel::WordLevel;
+    /// # use tokenizers::pre_tokenizers::whitespace::Whitespace;
+    /// let vocab: HashMap<String, u32> = [("[UNK]".into(), 0), ("hello".into(), 1)]
+    ///     .iter()
+    ///     .cloned()
+    ///     .collect();
+    /// let model = WordLevel::builder()
+    ///     .vocab(vocab)
+    ///     .unk_token("[UNK]".into())
+    ///     .build()
+    ///     .unwrap();
+    /// let mut tokenizer = Tokenizer::new(model);
+    /// tokenizer.with_pre_tokenizer(Whitespace {});
+    ///
+    /// assert_eq!(tokenizer.count_tokens("hello world", false).unwrap(), 2);
+    /// ```
+    pub fn count_tokens<'s, E>(&self, input: E, add_special_tokens: bool) -> Result<usize>
+    where
+        E: Into<EncodeInput<'s>>,
+    {
+        in_sample_stream(|| {
+            let input = input.into();
+            if matches!(&self.truncation, Some(trunc) if trunc.boundary != TruncationBoundary::Token)
+            {
+                // The length depends on where the boundaries are
+                return Ok(self.encode(input, add_special_tokens)?.len());
+            }
+
+            // Count the tokens of each sequence
+            let lens = input
+                .into_sequences()
+                .into_iter()
+                .map(|sequence| self.count_single_sequence(sequence))
+                .collect::<Result<Vec<_>>>()?;
+
+            // And finally count what post processing does
+            self.count_post_processed(lens, add_special_tokens)
+        })
+    }
+
+    /// Count the tokens of a single sequence, as `encode_single_sequence` would produce them
+    fn count_single_sequence(&self, sequence: InputSequence) -> Result<usize> {
+        let count = |subseq: &str| -> Result<usize> {
+            let normalized = self
+                .added_vocabulary
+                .extract_and_normalize(self.normalizer.as_ref(), subseq);
+            let pre_tokenized = self.do_pre_tokenize(normalized)?;
+            pre_tokenized.count_tokens(|normalized| self.model.count_tokens(normalized.get()))
         };
 
-        // Encode each sequence
-        let encoding = self.encode_single_sequence(sequence, 0, OffsetType::Char)?;
-        let pair_encoding = pair
-            .map(|sequence| self.encode_single_sequence(sequence, 1, OffsetType::Char))
-            .transpose()?;
+        match sequence {
+            InputSequence::PreTokenized(seq) => seq.iter().map(|sequence| count(sequence)).sum(),
+            InputSequence::PreTokenizedOwned(seq) => {
+                seq.iter().map(|sequence| count(sequence)).sum()
+            }
+            InputSequence::PreTokenizedCow(seq) => seq.iter().map(|sequence| count(sequence)).sum(),
+            InputSequence::Raw(seq) => count(seq.as_ref()),
+        }
+    }
 
-        // And finally post process
-        self.post_process(encoding, pair_encoding, add_special_tokens)
+    /// Encode a conversation made of role-tagged messages in a single `Encoding`, using a
+    /// post-processor that supports chats, like [`ChatTemplate`](crate::processors::chat::ChatTemplate).
+    /// Each message has its own sequence id, and its offsets are relative to its content.
+    /// No truncation or padding is applied.
+    pub fn encode_chat(
+        &self,
+        messages: &[ChatMessage],
+        add_special_tokens: bool,
+    ) -> Result<ChatEncoding> {
+        let encodings = in_sample_stream(|| {
+            messages
+                .iter()
+                .map(|message| {
+                    let encoding = self.encode_single_sequence(
+                        message.content.as_str().into(),
+                        0,
+                        OffsetType::Byte,
+                    )?;
+                    Ok((message.role.clone(), encoding))
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        match &self.post_processor {
+            Some(processor) => processor.process_chat(encodings, add_special_tokens),
+            None => Err(Box::new(ProcessorError::ChatNotSupported)),
+        }
     }
 
     /// Decode the given ids, back to a String
@@ -877,6 +1274,115 @@ where
         result.push_str(&text_chunk);
         Ok(result)
     }
+
+    /// Get a [`DecodeStream`] to decode ids one at a time, as they are generated.
+    ///
+    /// ```
+    /// # use tokenizers::Tokenizer;
+    /// # use tokenizers::models::bpe::BPE;
+    /// # let tokenizer = Tokenizer::new(BPE::default());
+    /// let mut decode_stream = tokenizer.decode_stream(false);
+    /// for id in [0, 1, 2] {
+    ///     if let Some(chunk) = decode_stream.step(id).unwrap() {
+    ///         print!("{chunk}");
+    ///     }
+    /// }
+    /// ```
+    pub fn decode_stream(&self, skip_special_tokens: bool) -> DecodeStream<'_, M, N, PT, PP, D> {
+        DecodeStream::new(self, skip_special_tokens)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum DecodeStreamError {
+    #[error("Invalid prefix encountered")]
+    InvalidPrefix,
+}
+
+/// A `DecodeStream` decodes ids one at a time, returning only the newly finalized text.
+///
+/// Decoders like `ByteLevel`, `Metaspace` or `ByteFallback` depend on the surrounding tokens
+/// (leading space stripping, multi-byte characters spread across several tokens, ...), so a
+/// single id can't be decoded on its own. Instead, we keep a small window of ids that were
+/// already emitted and decode it along with the new ones. The difference between both decoded
+/// strings is the new text. Any text ending with an incomplete UTF-8 sequence (decoded as `�`)
+/// is held back until the following ids complete it, or until the stream is flushed.
+///
+/// Concatenating all the chunks returned by `step`, followed by the one returned by `flush`,
+/// gives the same result as a single call to `TokenizerImpl::decode` with all the ids.
+pub struct DecodeStream<'tok, M, N, PT, PP, D> {
+    /// The tokenizer used to decode the ids
+    tokenizer: &'tok TokenizerImpl<M, N, PT, PP, D>,
+    /// The ids kept around as context, followed by the ids not yet emitted
+    ids: Vec<u32>,
+    /// The decoded string of the context ids, already emitted
+    prefix: String,
+    /// The index in `ids` of the first id that has not been emitted yet
+    prefix_index: usize,
+    /// Whether special tokens should be skipped while decoding
+    skip_special_tokens: bool,
+}
+
+impl<'tok, M, N, PT, PP, D> DecodeStream<'tok, M, N, PT, PP, D>
+where
+    M: Model,
+    N: Normalizer,
+    PT: PreTokenizer,
+    PP: PostProcessor,
+    D: Decoder,
+{
+    fn new(tokenizer: &'tok TokenizerImpl<M, N, PT, PP, D>, skip_special_tokens: bool) -> Self {
+        Self {
+            tokenizer,
+            ids: vec![],
+            prefix: String::new(),
+            prefix_index: 0,
+            skip_special_tokens,
+        }
+    }
+
+    /// Add the given id to the stream, and returns the newly finalized text if any.
+    pub fn step(&mut self, id: u32) -> Result<Option<String>> {
+        self.ids.push(id);
+        let string = self
+            .tokenizer
+            .decode(self.ids.as_slice(), self.skip_special_tokens)?;
+        if string.len() > self.prefix.len() && !string.ends_with('\u{FFFD}') {
+            if !string.starts_with(&self.prefix) {
+                return Err(Box::new(DecodeStreamError::InvalidPrefix));
+            }
+            let new_text = string[self.prefix.len()..].to_string();
+            let new_prefix_index = self.ids.len() - self.prefix_index;
+            self.ids.drain(..self.prefix_index);
+            self.prefix = self
+                .tokenizer
+                .decode(self.ids.as_slice(), self.skip_special_tokens)?;
+            self.prefix_index = new_prefix_index;
+            Ok(Some(new_text))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Return the text still held back, like an incomplete UTF-8 sequence at the end of the
+    /// stream, and reset the stream so that the next id starts a new one.
+    pub fn flush(&mut self) -> Result<Option<String>> {
+        let string = self
+            .tokenizer
+            .decode(self.ids.as_slice(), self.skip_special_tokens)?;
+        let new_text = if string.len() > self.prefix.len() {
+            if !string.starts_with(&self.prefix) {
+                return Err(Box::new(DecodeStreamError::InvalidPrefix));
+            }
+            Some(string[self.prefix.len()..].to_string())
+        } else {
+            None
+        };
+        self.ids.clear();
+        self.prefix.clear();
+        self.prefix_index = 0;
+        Ok(new_text)
+    }
 }
 
 impl<M, N, PT, PP, D> TokenizerImpl<M, N, PT, PP, D>
@@ -955,6 +1461,20 @@ impl<M, N, PT, PP, D> TokenizerImpl<M, N, PT, PP, D>
 where
     PP: PostProcessor,
 {
+    /// The original text of the raw sequences, when the truncation boundaries depend on it
+    fn truncation_texts<'s>(&self, sequences: &[InputSequence<'s>]) -> Vec<Option<Cow<'s, str>>> {
+        match self.truncation.as_ref().map(|trunc| &trunc.boundary) {
+            Some(TruncationBoundary::Sentence) | Some(TruncationBoundary::Regex(_)) => sequences
+                .iter()
+                .map(|sequence| match sequence {
+                    InputSequence::Raw(text) => Some(text.clone()),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
     /// Post processing logic, handling the case where there is no PostProcessor set
     pub fn post_process(
         &self,
@@ -962,34 +1482,56 @@ where
         pair_encoding: Option<Encoding>,
         add_special_tokens: bool,
     ) -> Result<Encoding> {
+        let mut encodings = vec![encoding];
+        encodings.extend(pair_encoding);
+        self.post_process_multi(encodings, add_special_tokens)
+    }
+
+    /// Post processing logic for any number of encodings, handling the case where there is no
+    /// PostProcessor set
+    pub fn post_process_multi(
+        &self,
+        encodings: Vec<Encoding>,
+        add_special_tokens: bool,
+    ) -> Result<Encoding> {
+        self.post_process_sequences(encodings, &[], add_special_tokens)
+    }
+
+    /// Post process the encodings of the given sequences, whose original text is used to find
+    /// the truncation boundaries when needed
+    fn post_process_sequences(
+        &self,
+        encodings: Vec<Encoding>,
+        texts: &[Option<(&str, OffsetType)>],
+        add_special_tokens: bool,
+    ) -> Result<Encoding> {
+        if encodings.is_empty() {
+            return Err(Box::new(ProcessorError::InvalidEncodingsVecLength));
+        }
+
         // 1. First we truncate if needed
-        let (encoding, pair_encoding) = {
+        let encodings = {
             if let Some(trunc) = &self.truncation {
-                let n_added_tokens = self.get_n_added_tokens(pair_encoding.is_some());
+                let n_added_tokens = self.get_n_added_tokens(encodings.len())?;
 
                 if add_special_tokens && n_added_tokens > 0 {
                     let params = TruncationParams {
                         max_length: trunc.max_length - n_added_tokens,
-                        ..*trunc
+                        ..trunc.clone()
                     };
-                    truncate_encodings(encoding, pair_encoding, &params)?
+                    truncate_sequences(encodings, texts, &params)?
                 } else {
-                    truncate_encodings(encoding, pair_encoding, trunc)?
+                    truncate_sequences(encodings, texts, trunc)?
                 }
             } else {
-                (encoding, pair_encoding)
+                encodings
             }
         };
 
         // 2. Then We post process
         let final_encoding = if let Some(processor) = &self.post_processor {
-            processor.process(encoding, pair_encoding, add_special_tokens)?
+            processor.process_multi(encodings, add_special_tokens)?
         } else {
-            let encodings = if let Some(pair_encoding) = pair_encoding {
-                vec![encoding, pair_encoding]
-            } else {
-                vec![encoding]
-            };
             let mut encodings =
                 <dyn PostProcessor>::default_process(encodings, add_special_tokens)?;
             if encodings.len() != 1 {
@@ -1010,11 +1552,49 @@ where
         Ok(final_encoding)
     }
 
-    fn get_n_added_tokens(&self, is_pair: bool) -> usize {
+    /// Count the tokens `post_process` would produce for encodings of the given lengths
+    fn count_post_processed(&self, lens: Vec<usize>, add_special_tokens: bool) -> Result<usize> {
+        if lens.is_empty() {
+            return Err(Box::new(ProcessorError::InvalidEncodingsVecLength));
+        }
+
+        // The post processor must support this number of sequences, as when encoding
+        let n_added_tokens = self.get_n_added_tokens(lens.len())?;
+
+        // 1. First we truncate if needed
+        let lens = if let Some(trunc) = &self.truncation {
+            if add_special_tokens && n_added_tokens > 0 {
+                let params = TruncationParams {
+                    max_length: trunc.max_length - n_added_tokens,
+                    ..trunc.clone()
+                };
+                truncated_lengths(&lens, &params)?
+            } else {
+                truncated_lengths(&lens, trunc)?
+            }
+        } else {
+            lens
+        };
+
+        // 2. Then the post processor adds its special tokens
+        let mut len = lens.iter().sum::<usize>();
+        if add_special_tokens {
+            len += n_added_tokens;
+        }
+
+        // 3. Then we pad if needed
+        if let Some(params) = &self.padding {
+            len = cmp::max(len, get_pad_length(params, || len));
+        }
+
+        Ok(len)
+    }
+
+    fn get_n_added_tokens(&self, n_sequences: usize) -> Result<usize> {
         if let Some(processor) = &self.post_processor {
-            processor.added_tokens(is_pair)
+            processor.added_tokens_multi(n_sequences)
         } else {
-            0
+            Ok(0)
         }
     }
 }
@@ -1027,6 +1607,35 @@ where
     PP: PostProcessor + Send + Sync,
     D: Decoder + Send + Sync,
 {
+    /// Count the tokens of all the inputs in parallel, exactly as `encode_batch` would produce
+    /// them, but without building any `Encoding`
+    pub fn count_tokens_batch<'s, E>(
+        &self,
+        inputs: Vec<E>,
+        add_special_tokens: bool,
+    ) -> Result<Vec<usize>>
+    where
+        E: Into<EncodeInput<'s>> + Send,
+    {
+        let mut counts = inputs
+            .into_maybe_par_iter()
+            .enumerate()
+            .map(|(i, input)| {
+                with_sample_stream(i as u64, || self.count_tokens(input, add_special_tokens))
+            })
+            .collect::<Result<Vec<usize>>>()?;
+
+        if let Some(params) = &self.padding {
+            // We handle the batch padding here too
+            let pad_length = get_pad_length(params, || counts.iter().copied().max().unwrap_or(0));
+            counts
+                .iter_mut()
+                .for_each(|count| *count = cmp::max(*count, pad_length));
+        }
+
+        Ok(counts)
+    }
+
     /// Encode all the sentences in parallel, using multiple threads
     pub fn encode_batch<'s, E>(
         &self,
@@ -1038,7 +1647,10 @@ where
     {
         let mut encodings = inputs
             .into_maybe_par_iter()
-            .map(|input| self.encode(input, add_special_tokens))
+            .enumerate()
+            .map(|(i, input)| {
+                with_sample_stream(i as u64, || self.encode(input, add_special_tokens))
+            })
             .collect::<Result<Vec<Encoding>>>()?;
 
         if let Some(params) = &self.padding {
@@ -1061,7 +1673,12 @@ where
     {
         let mut encodings = inputs
             .into_maybe_par_iter()
-            .map(|input| self.encode_char_offsets(input, add_special_tokens))
+            .enumerate()
+            .map(|(i, input)| {
+                with_sample_stream(i as u64, || {
+                    self.encode_char_offsets(input, add_special_tokens)
+                })
+            })
             .collect::<Result<Vec<Encoding>>>()?;
 
         if let Some(params) = &self.padding {
@@ -1253,6 +1870,17 @@ where
         let tokenizer = serde_json::from_slice(bytes.as_ref())?;
         Ok(tokenizer)
     }
+
+    /// Instantiate a new Tokenizer from bytes in the binary format
+    pub fn from_binary<P: AsRef<[u8]>>(bytes: P) -> Result<Self> {
+        binary::decode(bytes.as_ref())
+    }
+
+    /// Instantiate a new Tokenizer from the given file in the binary format, which is
+    /// memory-mapped while loading
+    pub fn from_binary_file<P: AsRef<Path>>(file: P) -> Result<Self> {
+        BinaryTokenizer::from_file(file)?.to_tokenizer()
+    }
 }
 
 impl<M, N, PT, PP, D> TokenizerImpl<M, N, PT, PP, D>
@@ -1305,4 +1933,370 @@ where
 
         Ok(())
     }
+
+    /// Serialize the current tokenizer in the binary format
+    pub fn to_binary(&self) -> Result<Vec<u8>> {
+        Ok(binary::encode(serde_json::to_value(self)?))
+    }
+
+    /// Save the current tokenizer in the binary format at the given path
+    pub fn save_binary<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let serialized = self.to_binary()?;
+
+        let mut file = File::create(path)?;
+        file.write_all(&serialized)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoders::byte_fallback::ByteFallback;
+    use crate::decoders::sequence::Sequence;
+    use crate::models::bpe::BPE;
+    use crate::pre_tokenizers::metaspace::{Metaspace, PrependScheme};
+
+    fn byte_fallback_tokenizer() -> Tokenizer {
+        let vocab: HashMap<String, u32> = [
+            ("<unk>", 0),
+            ("<0xE2>", 1),
+            ("<0x82>", 2),
+            ("<0xAC>", 3),
+            ("▁Hey", 4),
+            ("▁friend", 5),
+            ("!", 6),
+        ]
+        .iter()
+        .map(|(token, id)| (token.to_string(), *id))
+        .collect();
+        let bpe = BPE::builder()
+            .vocab_and_merges(vocab, vec![])
+            .unk_token("<unk>".into())
+            .byte_fallback(true)
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(bpe);
+        tokenizer.with_decoder(Sequence::new(vec![
+            ByteFallback::new().into(),
+            Metaspace::new('▁', PrependScheme::Always, true).into(),
+        ]));
+        tokenizer
+    }
+
+    #[test]
+    fn decode_stream() {
+        let tokenizer = byte_fallback_tokenizer();
+        let ids = [4, 5, 6, 1, 2, 3, 5];
+
+        let mut decode_stream = tokenizer.decode_stream(false);
+        let chunks = ids
+            .iter()
+            .map(|id| decode_stream.step(*id).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            chunks,
+            vec![
+                Some("Hey".to_string()),
+                Some(" friend".to_string()),
+                Some("!".to_string()),
+                None,
+                None,
+                Some("€".to_string()),
+                Some(" friend".to_string()),
+            ]
+        );
+
+        let streamed = chunks.into_iter().flatten().collect::<String>();
+        assert_eq!(streamed, tokenizer.decode(&ids, false).unwrap());
+    }
+
+    #[test]
+    fn decode_stream_split_char() {
+        use crate::decoders::byte_level::ByteLevel;
+
+        // `é` is encoded as 0xC3 0xA9, each byte having its own token
+        let vocab = [("H", 0), ("Ã", 1), ("©", 2)]
+            .iter()
+            .map(|(token, id)| (token.to_string(), *id))
+            .collect();
+        let bpe = BPE::builder()
+            .vocab_and_merges(vocab, vec![])
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(bpe);
+        tokenizer.with_decoder(ByteLevel::default());
+
+        let mut decode_stream = tokenizer.decode_stream(false);
+        assert_eq!(decode_stream.step(0).unwrap(), Some("H".to_string()));
+        assert_eq!(decode_stream.step(1).unwrap(), None);
+        assert_eq!(decode_stream.step(2).unwrap(), Some("é".to_string()));
+        assert_eq!(decode_stream.flush().unwrap(), None);
+
+        // The incomplete sequence is only emitted when flushing
+        assert_eq!(decode_stream.step(0).unwrap(), Some("H".to_string()));
+        assert_eq!(decode_stream.step(1).unwrap(), None);
+        assert_eq!(decode_stream.flush().unwrap(), Some("\u{FFFD}".to_string()));
+        assert_eq!(decode_stream.step(2).unwrap(), None);
+        assert_eq!(decode_stream.flush().unwrap(), Some("\u{FFFD}".to_string()));
+    }
+
+    #[test]
+    fn decode_stream_skip_special_tokens() {
+        let mut tokenizer = byte_fallback_tokenizer();
+        tokenizer.add_special_tokens(&[AddedToken::from("</s>", true)]);
+        let eos = tokenizer.token_to_id("</s>").unwrap();
+
+        let mut decode_stream = tokenizer.decode_stream(true);
+        assert_eq!(decode_stream.step(4).unwrap(), Some("Hey".to_string()));
+        assert_eq!(decode_stream.step(eos).unwrap(), None);
+        assert_eq!(decode_stream.step(5).unwrap(), Some(" friend".to_string()));
+    }
+
+    #[test]
+    fn encode_nbest() {
+        use crate::models::unigram::Unigram;
+        use crate::pre_tokenizers::whitespace::WhitespaceSplit;
+
+        let vocab = [("<unk>", 0.0), ("a", -1.0), ("b", -1.0), ("ab", -1.5)]
+            .iter()
+            .map(|(piece, score)| (piece.to_string(), *score))
+            .collect();
+        let mut tokenizer = Tokenizer::new(Unigram::from(vocab, Some(0), false).unwrap());
+        tokenizer.with_pre_tokenizer(WhitespaceSplit);
+
+        let nbest = tokenizer.encode_nbest("ab ab", 3, false).unwrap();
+        let tokens = nbest
+            .iter()
+            .map(|(encoding, _)| encoding.get_tokens().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            vec![vec!["ab", "ab"], vec!["ab", "a", "b"], vec!["a", "b", "ab"],]
+        );
+        assert_eq!(nbest[1].0.get_offsets(), &[(0, 2), (3, 4), (4, 5)]);
+        assert_eq!(nbest[1].0.get_word_ids(), &[Some(0), Some(1), Some(1)]);
+        assert!(nbest[0].1 > nbest[1].1);
+        assert_eq!(nbest[1].1, nbest[2].1);
+        assert_eq!(
+            nbest[0].0.get_ids(),
+            tokenizer.encode("ab ab", false).unwrap().get_ids()
+        );
+
+        let nbest = tokenizer
+            .encode_nbest(("ab", &["b", "b"][..]), 2, false)
+            .unwrap();
+        assert_eq!(nbest[0].0.get_tokens(), &["ab", "b", "b"]);
+        assert_eq!(nbest[0].0.get_sequence_ids(), &[Some(0), Some(1), Some(1)]);
+        assert_eq!(nbest[1].0.get_tokens(), &["a", "b", "b", "b"]);
+        assert!(tokenizer.encode_nbest("ab", 0, false).unwrap().is_empty());
+    }
+
+    #[test]
+    fn encode_multi() {
+        use crate::processors::bert::BertProcessing;
+
+        let mut tokenizer = byte_fallback_tokenizer();
+        tokenizer.with_post_processor(BertProcessing::new(("</s>".into(), 7), ("<s>".into(), 8)));
+        let input = || EncodeInput::Multi(vec!["!!!!".into(), "€€".into(), "!!".into()]);
+
+        let encoding = tokenizer.encode(input(), true).unwrap();
+        assert_eq!(
+            encoding.get_ids(),
+            &[8, 6, 6, 6, 6, 7, 1, 2, 3, 1, 2, 3, 7, 6, 6, 7]
+        );
+        assert_eq!(
+            encoding.get_type_ids(),
+            &[0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1]
+        );
+        assert_eq!(encoding.token_to_sequence(14), Some(2));
+
+        // 4 special tokens, and each sequence keeps half of its tokens
+        tokenizer
+            .with_truncation(Some(TruncationParams {
+                max_length: 10,
+                strategy: TruncationStrategy::Proportional,
+                ..Default::default()
+            }))
+            .unwrap();
+        let encoding = tokenizer.encode(input(), true).unwrap();
+        assert_eq!(encoding.get_ids(), &[8, 6, 6, 7, 1, 2, 3, 7, 6, 7]);
+        assert_eq!(tokenizer.count_tokens(input(), true).unwrap(), 10);
+    }
+
+    #[test]
+    fn truncation_boundaries() {
+        use crate::models::wordlevel::WordLevel;
+        use crate::pre_tokenizers::whitespace::Whitespace;
+
+        let vocab: HashMap<String, u32> = ["[UNK]", "A", "b", "C", "d", "e", "F", "g", "."]
+            .iter()
+            .enumerate()
+            .map(|(id, token)| (token.to_string(), id as u32))
+            .collect();
+        let model = WordLevel::builder()
+            .vocab(vocab)
+            .unk_token("[UNK]".into())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(model);
+        tokenizer.with_pre_tokenizer(Whitespace {});
+        let text = "A b. C d e. F g.";
+        let parts = |encoding: &Encoding| {
+            std::iter::once(encoding)
+                .chain(encoding.get_overflowing())
+                .map(|e| e.get_tokens().join(" "))
+                .collect::<Vec<_>>()
+        };
+
+        let mut truncate = |boundary| {
+            tokenizer
+                .with_truncation(Some(TruncationParams {
+                    max_length: 5,
+                    boundary,
+                    ..Default::default()
+                }))
+                .unwrap();
+            let encoding = tokenizer.encode(text, false).unwrap();
+            assert_eq!(
+                parts(&encoding),
+                parts(&tokenizer.encode_char_offsets(text, false).unwrap())
+            );
+            assert_eq!(tokenizer.count_tokens(text, false).unwrap(), encoding.len());
+            parts(&encoding)
+        };
+
+        assert_eq!(
+            truncate(TruncationBoundary::Token),
+            vec!["A b . C d", "e . F g ."]
+        );
+        assert_eq!(
+            truncate(TruncationBoundary::Sentence),
+            vec!["A b .", "C d e .", "F g ."]
+        );
+        assert_eq!(
+            truncate(TruncationBoundary::Regex(BoundaryRegex::new("b").unwrap())),
+            vec!["A b", ". C d e .", "F g ."]
+        );
+    }
+
+    #[test]
+    fn count_tokens() {
+        use crate::pre_tokenizers::whitespace::Whitespace;
+        use crate::processors::template::TemplateProcessing;
+
+        let mut tokenizer = byte_fallback_tokenizer();
+        tokenizer.with_pre_tokenizer(Whitespace {});
+        tokenizer.add_special_tokens(&[AddedToken::from("</s>", true)]);
+        tokenizer.with_post_processor(
+            TemplateProcessing::builder()
+                .try_single("$A </s>")
+                .unwrap()
+                .try_pair("$A </s> $B:1 </s>:1")
+                .unwrap()
+                .try_multi("$A </s> $B:1 </s>:1 $C:2 </s>:2")
+                .unwrap()
+                .special_tokens(vec![("</s>", 7)])
+                .build()
+                .unwrap(),
+        );
+
+        let inputs: Vec<EncodeInput> = vec![
+            "▁Hey ▁friend!".into(),
+            "".into(),
+            "▁Hey€ ▁friend </s>▁Hey".into(),
+            ("▁Hey", "▁friend! ▁friend ▁friend").into(),
+            (&["▁Hey", "€"][..], "!").into(),
+            EncodeInput::Multi(vec!["▁Hey".into(), "▁friend!".into(), "€!".into()]),
+        ];
+        let check = |tokenizer: &Tokenizer| {
+            for add_special_tokens in [false, true] {
+                for input in &inputs {
+                    assert_eq!(
+                        tokenizer
+                            .count_tokens(input.clone(), add_special_tokens)
+                            .unwrap(),
+                        tokenizer
+                            .encode(input.clone(), add_special_tokens)
+                            .unwrap()
+                            .len()
+                    );
+                }
+                let encodings = tokenizer
+                    .encode_batch(inputs.clone(), add_special_tokens)
+                    .unwrap();
+                assert_eq!(
+                    tokenizer
+                        .count_tokens_batch(inputs.clone(), add_special_tokens)
+                        .unwrap(),
+                    encodings.iter().map(|e| e.len()).collect::<Vec<_>>()
+                );
+            }
+        };
+        check(&tokenizer);
+        // "▁", "H", "e", "y" are unknown, "€" falls back to its 3 bytes, followed by "</s>"
+        assert_eq!(tokenizer.count_tokens("▁Hey€", true).unwrap(), 8);
+
+        tokenizer
+            .with_truncation(Some(TruncationParams {
+                max_length: 4,
+                ..Default::default()
+            }))
+            .unwrap();
+        check(&tokenizer);
+
+        tokenizer.with_padding(Some(PaddingParams {
+            strategy: PaddingStrategy::Fixed(6),
+            ..Default::default()
+        }));
+        check(&tokenizer);
+
+        tokenizer.with_truncation(None).unwrap();
+        tokenizer.with_padding(Some(PaddingParams {
+            pad_to_multiple_of: Some(4),
+            ..Default::default()
+        }));
+        check(&tokenizer);
+    }
+
+    #[test]
+    fn count_tokens_unsupported_sequences() {
+        use crate::processors::template::TemplateProcessing;
+
+        let mut tokenizer = byte_fallback_tokenizer();
+        let three: EncodeInput = EncodeInput::Multi(vec!["!".into(), "!".into(), "!".into()]);
+        let check = |tokenizer: &Tokenizer| {
+            for add_special_tokens in [false, true] {
+                let encoded = tokenizer.encode(three.clone(), add_special_tokens);
+                let counted = tokenizer.count_tokens(three.clone(), add_special_tokens);
+                assert_eq!(
+                    counted.unwrap_err().to_string(),
+                    encoded.unwrap_err().to_string()
+                );
+            }
+        };
+
+        // Without a `multi` template
+        tokenizer.with_post_processor(
+            TemplateProcessing::builder()
+                .try_single("$A")
+                .unwrap()
+                .try_pair("$A $B:1")
+                .unwrap()
+                .build()
+                .unwrap(),
+        );
+        check(&tokenizer);
+
+        // With a `multi` template needing more sequences
+        tokenizer.with_post_processor(
+            TemplateProcessing::builder()
+                .try_multi("$A $B:1 $C:2 $D:3")
+                .unwrap()
+                .build()
+                .unwrap(),
+        );
+        check(&tokenizer);
+    }
 }
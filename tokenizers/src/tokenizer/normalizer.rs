@@ -135,6 +135,14 @@ impl NormalizedString {
         &self.original
     }
 
+    /// Return the offsets in the original string of each byte of the normalized one
+    pub(crate) fn shifted_alignments(&self) -> Vec<Offsets> {
+        self.alignments
+            .iter()
+            .map(|(start, end)| (start + self.original_shift, end + self.original_shift))
+            .collect()
+    }
+
     /// Return the original offsets
     pub fn offsets_original(&self) -> Offsets {
         (
@@ -1,3 +1,4 @@
+use crate::utils::combine_nbest;
 use crate::{
     normalizer::Range, Encoding, NormalizedString, OffsetReferential, Offsets, Result, Token,
 };
@@ -125,6 +126,69 @@ impl PreTokenizedString {
         Ok(())
     }
 
+    /// Count the tokens of all the splits, using the provided `count_tokens` function for those
+    /// that do not have attached `Tokens`.
+    pub fn count_tokens<F>(&self, count_tokens: F) -> Result<usize>
+    where
+        F: Fn(&NormalizedString) -> Result<usize>,
+    {
+        self.splits
+            .iter()
+            .map(|split| match &split.tokens {
+                Some(tokens) => Ok(tokens.len()),
+                None => count_tokens(&split.normalized),
+            })
+            .sum()
+    }
+
+    /// Tokenize all the splits that do not have attached `Tokens` into their `n` best
+    /// segmentations, using the provided `tokenize_nbest` function. Returns the `n` best
+    /// combinations of these segmentations, as tokenized copies of this `PreTokenizedString`
+    /// along with their score, the sum of the scores of each split.
+    pub fn tokenize_nbest<F>(&self, n: usize, tokenize_nbest: F) -> Result<Vec<(Self, f64)>>
+    where
+        F: Fn(&NormalizedString) -> Result<Vec<(Vec<Token>, f64)>>,
+    {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        let nbests = self
+            .splits
+            .iter()
+            .filter(|s| s.tokens.is_none())
+            .map(|split| tokenize_nbest(&split.normalized))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Each candidate keeps the index of the segmentation used for each split
+        let mut candidates: Vec<(Vec<usize>, f64)> = vec![(vec![], 0.0)];
+        for nbest in &nbests {
+            let indices = nbest
+                .iter()
+                .enumerate()
+                .map(|(i, (_, score))| (i, *score))
+                .collect::<Vec<_>>();
+            candidates = combine_nbest(&candidates, &indices, n, |candidate, i| {
+                let mut candidate = candidate.clone();
+                candidate.push(*i);
+                candidate
+            });
+        }
+
+        Ok(candidates
+            .into_iter()
+            .map(|(candidate, score)| {
+                let mut pretokenized = self.clone();
+                pretokenized
+                    .splits
+                    .iter_mut()
+                    .filter(|s| s.tokens.is_none())
+                    .zip(nbests.iter().zip(candidate))
+                    .for_each(|(split, (nbest, i))| split.tokens = Some(nbest[i].0.clone()));
+                (pretokenized, score)
+            })
+            .collect())
+    }
+
     /// Transform the current `PreTokenizedString` into an `Encoding`.
     ///
     /// If a `word_idx` is provided, any word in the generated `Encoding`
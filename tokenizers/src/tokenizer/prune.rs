@@ -0,0 +1,263 @@
+use super::{Decoder, Model, Normalizer, PostProcessor, PreTokenizer, Result, TokenizerImpl};
+use std::collections::{HashMap, HashSet};
+
+impl<M, N, PT, PP, D> TokenizerImpl<M, N, PT, PP, D>
+where
+    M: Model,
+    N: Normalizer,
+    PT: PreTokenizer,
+    PP: PostProcessor,
+    D: Decoder,
+{
+    /// Remove from the vocabulary the tokens for which `keep(id, token)` returns `false`, and
+    /// give contiguous ids to the remaining ones, in the same order: the tokens of the model
+    /// first, then the added tokens that are not part of it. Returns the new id of each kept
+    /// token, which can be used to slice the embeddings of a model.
+    ///
+    /// The special tokens, those added by the post-processor, the padding token and the tokens
+    /// the model can't work without are always kept. The ids used by the added vocabulary, the
+    /// post-processor and the padding are updated.
+    ///
+    /// ```
+    /// # use std::collections::{HashMap, HashSet};
+    /// # use tokenizers::Tokenizer;
+    /// # use tokenizers::models::wordlevel::WordLevel;
+    /// let vocab: HashMap<String, u32> = [("[UNK]", 0), ("hello", 1), ("world", 2), ("!", 3)]
+    ///     .iter()
+    ///     .map(|(token, id)| (token.to_string(), *id))
+    ///     .collect();
+    /// let model = WordLevel::builder()
+    ///     .vocab(vocab)
+    ///     .unk_token("[UNK]".into())
+    ///     .build()
+    ///     .unwrap();
+    /// let mut tokenizer = Tokenizer::new(model);
+    ///
+    /// let allowed: HashSet<&str> = ["world", "!"].iter().copied().collect();
+    /// let ids = tokenizer
+    ///     .prune_vocab(|_, token| allowed.contains(token))
+    ///     .unwrap();
+    /// assert_eq!(ids, [(0, 0), (2, 1), (3, 2)].iter().copied().collect());
+    /// assert_eq!(tokenizer.token_to_id("!"), Some(2));
+    /// ```
+    pub fn prune_vocab<F>(&mut self, keep: F) -> Result<HashMap<u32, u32>>
+    where
+        F: Fn(u32, &str) -> bool,
+    {
+        let added = self.added_vocabulary.get_added_tokens_decoder();
+        let mut forced: HashSet<u32> = added
+            .iter()
+            .filter(|(_, token)| token.special)
+            .map(|(id, _)| *id)
+            .collect();
+        forced.extend(
+            self.post_processor
+                .iter()
+                .flat_map(|pp| pp.get_special_ids()),
+        );
+        forced.extend(self.padding.as_ref().map(|padding| padding.pad_id));
+
+        let model_vocab = self.model.get_vocab();
+        let kept_model: HashSet<u32> = model_vocab
+            .iter()
+            .filter(|(token, id)| match added.get(id) {
+                // The added tokens being part of the model are kept like the other added tokens
+                Some(token) => forced.contains(id) || keep(**id, &token.content),
+                None => forced.contains(id) || keep(**id, token),
+            })
+            .map(|(_, id)| *id)
+            .collect();
+        let mut kept_added: Vec<u32> = added
+            .iter()
+            .filter(|(id, token)| {
+                model_vocab.get(&token.content) != Some(id)
+                    && (forced.contains(id) || keep(**id, &token.content))
+            })
+            .map(|(id, _)| *id)
+            .collect();
+        kept_added.sort_unstable();
+
+        let mut ids = self.model.prune_vocab(&|id| kept_model.contains(&id))?;
+        let model_size = ids.len() as u32;
+        ids.extend(
+            kept_added
+                .into_iter()
+                .enumerate()
+                .map(|(i, id)| (id, model_size + i as u32)),
+        );
+
+        self.added_vocabulary
+            .remap_ids(&ids, &self.model, self.normalizer.as_ref());
+        if let Some(post_processor) = self.post_processor.as_mut() {
+            post_processor.remap_ids(&ids);
+        }
+        if let Some(padding) = self.padding.as_mut() {
+            padding.pad_id = ids.get(&padding.pad_id).copied().unwrap_or(padding.pad_id);
+        }
+        Ok(ids)
+    }
+
+    /// Remove from the vocabulary the tokens that are not used to encode the given corpus, as
+    /// explained in [`prune_vocab`](Self::prune_vocab). Returns the new id of each kept token.
+    pub fn prune_vocab_with_corpus<I, S>(&mut self, corpus: I) -> Result<HashMap<u32, u32>>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut used: HashSet<u32> = HashSet::new();
+        for text in corpus {
+            let encoding = self.encode(text.as_ref(), false)?;
+            used.extend(encoding.get_ids());
+            for overflowing in encoding.get_overflowing() {
+                used.extend(overflowing.get_ids());
+            }
+        }
+        self.prune_vocab(|id, _| used.contains(&id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::bpe::BPE;
+    use crate::models::wordlevel::WordLevel;
+    use crate::models::wordpiece::WordPiece;
+    use crate::pre_tokenizers::whitespace::Whitespace;
+    use crate::processors::template::TemplateProcessing;
+    use crate::tokenizer::{AddedToken, PaddingParams, Tokenizer};
+    use std::collections::HashMap;
+
+    #[test]
+    fn prune_bpe_with_corpus() {
+        let vocab: HashMap<String, u32> = [
+            ("<unk>", 0),
+            ("a", 1),
+            ("b", 2),
+            ("c", 3),
+            ("d", 4),
+            ("ab", 5),
+            ("cd", 6),
+            ("abc", 7),
+            ("abcd", 8),
+        ]
+        .iter()
+        .map(|(token, id)| (token.to_string(), *id))
+        .collect();
+        let merges = vec![
+            ("a".to_string(), "b".to_string()),
+            ("c".to_string(), "d".to_string()),
+            ("ab".to_string(), "c".to_string()),
+            ("ab".to_string(), "cd".to_string()),
+        ];
+        let bpe = BPE::builder()
+            .vocab_and_merges(vocab, merges)
+            .unk_token("<unk>".into())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(bpe);
+        tokenizer.with_pre_tokenizer(Whitespace {});
+        tokenizer.add_special_tokens(&[AddedToken::from("[CLS]", true)]);
+        tokenizer.add_tokens(&[AddedToken::from("unused", false)]);
+        tokenizer.with_post_processor(
+            TemplateProcessing::builder()
+                .try_single("[CLS] $A")
+                .unwrap()
+                .special_tokens(vec![("[CLS]", 9)])
+                .build()
+                .unwrap(),
+        );
+        tokenizer.with_padding(Some(PaddingParams {
+            pad_id: 9,
+            ..Default::default()
+        }));
+        let before = tokenizer.encode("abcd ab", true).unwrap();
+
+        let ids = tokenizer.prune_vocab_with_corpus(["abcd ab"]).unwrap();
+        // "abcd" needs "ab" and "cd", which need the chars, but "abc" isn't used
+        let mut kept = ids
+            .iter()
+            .map(|(old, new)| (*old, *new))
+            .collect::<Vec<_>>();
+        kept.sort_unstable();
+        assert_eq!(
+            kept,
+            vec![
+                (0, 0),
+                (1, 1),
+                (2, 2),
+                (3, 3),
+                (4, 4),
+                (5, 5),
+                (6, 6),
+                (8, 7),
+                (9, 8)
+            ]
+        );
+        assert_eq!(tokenizer.get_vocab_size(true), 9);
+        assert_eq!(tokenizer.token_to_id("unused"), None);
+        assert_eq!(tokenizer.token_to_id("[CLS]"), Some(8));
+        assert_eq!(tokenizer.get_padding().unwrap().pad_id, 8);
+
+        let after = tokenizer.encode("abcd ab", true).unwrap();
+        assert_eq!(after.get_tokens(), before.get_tokens());
+        assert_eq!(
+            after.get_ids(),
+            before
+                .get_ids()
+                .iter()
+                .map(|id| ids[id])
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn prune_wordpiece_with_corpus() {
+        let vocab: HashMap<String, u32> = [
+            ("[UNK]", 0),
+            ("hug", 1),
+            ("##s", 2),
+            ("pug", 3),
+            ("##gy", 4),
+        ]
+        .iter()
+        .map(|(token, id)| (token.to_string(), *id))
+        .collect();
+        let wordpiece = WordPiece::builder().vocab(vocab).build().unwrap();
+        let mut tokenizer = Tokenizer::new(wordpiece);
+        tokenizer.with_pre_tokenizer(Whitespace {});
+
+        let ids = tokenizer.prune_vocab_with_corpus(["hugs hug"]).unwrap();
+        // The unknown token is always kept
+        assert_eq!(ids, [(0, 0), (1, 1), (2, 2)].iter().copied().collect());
+        assert_eq!(tokenizer.get_vocab_size(true), 3);
+        assert_eq!(tokenizer.token_to_id("##gy"), None);
+
+        let encoding = tokenizer.encode("hugs pug", false).unwrap();
+        assert_eq!(encoding.get_tokens(), &["hug", "##s", "[UNK]"]);
+        assert_eq!(encoding.get_ids(), &[1, 2, 0]);
+    }
+
+    #[test]
+    fn prune_wordlevel() {
+        let vocab: HashMap<String, u32> = [("hello", 0), ("[UNK]", 1), ("world", 2), ("!", 3)]
+            .iter()
+            .map(|(token, id)| (token.to_string(), *id))
+            .collect();
+        let wordlevel = WordLevel::builder()
+            .vocab(vocab)
+            .unk_token("[UNK]".into())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(wordlevel);
+        tokenizer.with_pre_tokenizer(Whitespace {});
+
+        let ids = tokenizer.prune_vocab(|_, token| token == "!").unwrap();
+        // The unknown token is always kept
+        assert_eq!(ids, [(1, 0), (3, 1)].iter().copied().collect());
+        assert_eq!(tokenizer.get_vocab_size(true), 2);
+        assert_eq!(tokenizer.id_to_token(0), Some("[UNK]".into()));
+
+        let encoding = tokenizer.encode("hello !", false).unwrap();
+        assert_eq!(encoding.get_tokens(), &["[UNK]", "!"]);
+        assert_eq!(encoding.get_ids(), &[0, 1]);
+    }
+}
@@ -1,22 +1,128 @@
 use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::RwLock;
 
 /// The default capacity for a `BPE`'s internal cache.
 pub static DEFAULT_CACHE_CAPACITY: usize = 10_000;
 
-/// Provides a simple multithread cache to speed up BPE tokenization that will try to read values
-/// concurrently but won't block if another thread is writing.
-/// The goal is clearly not the accuracy of the content, both get and set
-/// are not guaranteed to actually get or set.
+/// The maximum number of shards of a `Cache`.
+const MAX_SHARDS: usize = 16;
+/// The minimum capacity of a shard, so that small caches don't get split too much.
+const MIN_SHARD_CAPACITY: usize = 64;
+
+/// Statistics about the use of a model's cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of lookups that found their value in the cache
+    pub hits: u64,
+    /// The number of lookups that didn't find their value in the cache
+    pub misses: u64,
+    /// The number of values currently in the cache
+    pub len: usize,
+    /// The maximum number of values in the cache, 0 when caching is disabled
+    pub capacity: usize,
+}
+
+#[derive(Debug)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    /// Whether the entry has been read since the clock hand last went over it
+    referenced: AtomicBool,
+}
+
+/// A bounded part of the cache, evicting its entries with the CLOCK algorithm: the hand goes
+/// over the entries in a circle, evicting the first one that wasn't read since its last pass.
+#[derive(Debug)]
+struct Shard<K, V> {
+    /// The index of each key in `entries`
+    map: HashMap<K, usize>,
+    entries: Vec<Entry<K, V>>,
+    capacity: usize,
+    /// The index of the next entry considered for eviction
+    hand: usize,
+}
+
+impl<K, V> Shard<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    fn new(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+            entries: Vec::with_capacity(capacity),
+            capacity,
+            hand: 0,
+        }
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = &self.entries[*self.map.get(key)?];
+        entry.referenced.store(true, Ordering::Relaxed);
+        Some(entry.value.clone())
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        if let Some(index) = self.map.get(&key) {
+            self.entries[*index].value = value;
+            return;
+        }
+
+        let entry = Entry {
+            key: key.clone(),
+            value,
+            referenced: AtomicBool::new(false),
+        };
+        if self.entries.len() < self.capacity {
+            self.map.insert(key, self.entries.len());
+            self.entries.push(entry);
+            return;
+        }
+
+        // Every referenced entry gets a second chance, so this stops after at most one turn
+        while self.entries[self.hand]
+            .referenced
+            .swap(false, Ordering::Relaxed)
+        {
+            self.hand = (self.hand + 1) % self.capacity;
+        }
+        let evicted = std::mem::replace(&mut self.entries[self.hand], entry);
+        self.map.remove(&evicted.key);
+        self.map.insert(key, self.hand);
+        self.hand = (self.hand + 1) % self.capacity;
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+        self.entries.clear();
+        self.hand = 0;
+    }
+}
+
+/// Provides a bounded multithread cache to speed up tokenization. The entries are spread
+/// over multiple shards to limit the contention. Once a shard is full, its entries are evicted
+/// with the CLOCK algorithm, an approximation of LRU where reads only need a read lock: each
+/// read marks its entry, and each insertion evicts the first unmarked entry found after the
+/// last evicted one, unmarking the entries it skips. Entries that are read keep being cached,
+/// so the cache keeps up with the words seen by long-running processes.
 #[derive(Debug)]
 pub(crate) struct Cache<K, V>
 where
     K: Eq + Hash + Clone,
     V: Clone,
 {
-    map: RwLock<HashMap<K, V>>,
+    shards: Vec<RwLock<Shard<K, V>>>,
+    hasher: RandomState,
+    hits: AtomicU64,
+    misses: AtomicU64,
     pub capacity: usize,
 }
 
@@ -46,10 +152,27 @@ where
     K: Eq + Hash + Clone,
     V: Clone,
 {
-    /// Create new `Cache` with the given capacity.
+    /// Create new `Cache` with the given capacity. A capacity of 0 disables the cache.
     pub(crate) fn new(capacity: usize) -> Self {
-        let map = RwLock::new(HashMap::with_capacity(capacity));
-        Cache { map, capacity }
+        let n_shards = (capacity / MIN_SHARD_CAPACITY).clamp(1, MAX_SHARDS);
+        let shards = if capacity == 0 {
+            vec![]
+        } else {
+            // The capacity is split as evenly as possible between the shards
+            (0..n_shards)
+                .map(|i| {
+                    let extra = usize::from(i < capacity % n_shards);
+                    RwLock::new(Shard::new(capacity / n_shards + extra))
+                })
+                .collect()
+        };
+        Cache {
+            shards,
+            hasher: RandomState::new(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            capacity,
+        }
     }
 
     /// Create a fresh `Cache` with the same configuration.
@@ -59,7 +182,34 @@ where
 
     /// Clear the cache.
     pub(crate) fn clear(&self) {
-        self.map.write().unwrap().clear();
+        for shard in &self.shards {
+            shard.write().unwrap().clear();
+        }
+    }
+
+    /// Get the statistics of this cache.
+    pub(crate) fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            len: self
+                .shards
+                .iter()
+                .map(|shard| shard.read().unwrap().entries.len())
+                .sum(),
+            capacity: self.capacity,
+        }
+    }
+
+    fn shard<Q>(&self, key: &Q) -> Option<&RwLock<Shard<K, V>>>
+    where
+        Q: Hash + ?Sized,
+    {
+        if self.shards.is_empty() {
+            return None;
+        }
+        let hash = self.hasher.hash_one(key);
+        Some(&self.shards[hash as usize % self.shards.len()])
     }
 
     #[allow(dead_code)]
@@ -69,11 +219,10 @@ where
         K: Borrow<Q>,
         Q: Hash + Eq + ?Sized + 'a,
     {
-        if let Ok(ref mut cache) = self.map.try_read() {
-            Some(keys_iter.map(|k| cache.get(k).cloned()).collect())
-        } else {
-            None
+        if self.shards.is_empty() {
+            return None;
         }
+        Some(keys_iter.map(|k| self.get(k)).collect())
     }
 
     pub(crate) fn get<Q>(&self, key: &Q) -> Option<V>
@@ -81,34 +230,24 @@ where
         K: Borrow<Q>,
         Q: Hash + Eq + ?Sized,
     {
-        if let Ok(ref mut cache) = self.map.try_read() {
-            cache.get(key).cloned()
+        let value = self.shard(key)?.read().unwrap().get(key);
+        let counter = if value.is_some() {
+            &self.hits
         } else {
-            None
-        }
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
     }
 
     pub(crate) fn set_values<I>(&self, entries: I)
     where
         I: IntoIterator<Item = (K, V)>,
     {
-        // Before trying to acquire a write lock, we check if we are already at
-        // capacity with a read handler.
-        if let Ok(cache) = self.map.try_read() {
-            if cache.len() >= self.capacity {
-                // At capacity, so do nothing.
-                return;
+        for (key, value) in entries {
+            if let Some(shard) = self.shard(&key) {
+                shard.write().unwrap().insert(key, value);
             }
-        } else {
-            // If we couldn't acquire a read handle then we probably won't be able to acquire
-            // a write handle one quadrillionth of a second later.
-            return;
-        }
-
-        // Not at capacity, so try acquiring a write handle.
-        if let Ok(mut cache) = self.map.try_write() {
-            let free = self.capacity - cache.len();
-            cache.extend(entries.into_iter().take(free));
         }
     }
 
@@ -116,3 +255,49 @@ where
         self.set_values(std::iter::once((key, value)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_unused_entries() {
+        let cache = Cache::new(3);
+        for i in 0..3 {
+            cache.set(i, i);
+        }
+        assert_eq!(cache.get(&0), Some(0));
+        assert_eq!(cache.get(&2), Some(2));
+
+        // 1 is the only entry that wasn't read
+        cache.set(3, 3);
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.get(&3), Some(3));
+        assert_eq!(cache.get(&0), Some(0));
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 4);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.len, 3);
+        assert_eq!(stats.capacity, 3);
+    }
+
+    #[test]
+    fn bounded_shards() {
+        let cache = Cache::new(DEFAULT_CACHE_CAPACITY);
+        assert_eq!(cache.shards.len(), MAX_SHARDS);
+        for i in 0..3 * DEFAULT_CACHE_CAPACITY {
+            cache.set(i, i);
+        }
+        assert_eq!(cache.stats().len, DEFAULT_CACHE_CAPACITY);
+        assert_eq!(
+            cache.get(&(3 * DEFAULT_CACHE_CAPACITY - 1)),
+            Some(3 * DEFAULT_CACHE_CAPACITY - 1)
+        );
+
+        let disabled = Cache::new(0);
+        disabled.set(0, 0);
+        assert_eq!(disabled.get(&0), None);
+        assert_eq!(disabled.stats(), CacheStats::default());
+    }
+}
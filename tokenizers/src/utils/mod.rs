@@ -12,9 +12,12 @@ mod onig;
 pub use crate::utils::onig::SysRegex;
 
 pub mod iter;
+pub mod packing;
 pub mod padding;
 pub mod parallelism;
 pub(crate) mod progress;
+pub(crate) mod rng;
+pub mod sentencepiece;
 pub mod truncation;
 
 use serde::{Serialize, Serializer};
@@ -33,6 +36,35 @@ where
     ordered.serialize(serializer)
 }
 
+/// Combines two lists of scored candidates into the `n` best pairs, from the highest to the
+/// lowest score. The score of a pair is the sum of the scores of its parts.
+pub(crate) fn combine_nbest<L, R, T, F>(
+    left: &[(L, f64)],
+    right: &[(R, f64)],
+    n: usize,
+    combine: F,
+) -> Vec<(T, f64)>
+where
+    F: Fn(&L, &R) -> T,
+{
+    let mut pairs = left
+        .iter()
+        .enumerate()
+        .flat_map(|(i, (_, l))| {
+            right
+                .iter()
+                .enumerate()
+                .map(move |(j, (_, r))| (i, j, l + r))
+        })
+        .collect::<Vec<_>>();
+    pairs.sort_by(|a, b| b.2.total_cmp(&a.2));
+    pairs
+        .into_iter()
+        .take(n)
+        .map(|(i, j, score)| (combine(&left[i].0, &right[j].0), score))
+        .collect()
+}
+
 macro_rules! impl_enum_from (
     ($from_ty:ty, $enum:ty, $variant:ident) => {
         impl From<$from_ty> for $enum {
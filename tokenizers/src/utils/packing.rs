@@ -0,0 +1,298 @@
+use crate::tokenizer::{Encoding, PaddingDirection, PaddingParams, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackingParams {
+    /// The number of tokens in each packed row
+    pub max_length: usize,
+    /// Whether documents are split at the end of a row to fill it entirely. Otherwise, a
+    /// document that doesn't fit in the space left starts a new row, and only documents longer
+    /// than `max_length` are split.
+    pub split_documents: bool,
+    /// The number of tokens at the end of a split part of a document, repeated at the
+    /// beginning of the next part
+    pub stride: usize,
+    /// Rows shorter than `max_length` are padded up to `max_length` with these parameters
+    /// when provided. Only the padding tokens and direction are used.
+    pub padding: Option<PaddingParams>,
+}
+
+impl Default for PackingParams {
+    fn default() -> Self {
+        Self {
+            max_length: 2048,
+            split_documents: true,
+            stride: 0,
+            padding: None,
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PackingError {
+    /// Each part of a split document must include tokens that were not in the previous one.
+    #[error("Packing error: `stride` must be strictly less than `max_length`")]
+    StrideTooLarge,
+}
+
+/// A row of packed documents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PackedEncoding {
+    /// The `Encoding` of all the tokens in the row
+    pub encoding: Encoding,
+    /// The index of the document of each token in the packed stream, `None` for padding
+    pub document_ids: Vec<Option<usize>>,
+    /// The position of each token, restarting from 0 at the beginning of each document in the
+    /// row (including the continuation of a split document), and 0 for padding
+    pub position_ids: Vec<u32>,
+    /// The cumulative sequence lengths, as expected by variable-length attention: the offset at
+    /// which each document in the row starts, followed by the length of the row. Padding is
+    /// kept in its own sequence, so that the offsets always cover the whole row.
+    pub cu_seqlens: Vec<u32>,
+}
+
+impl PackedEncoding {
+    fn new(parts: Vec<(usize, Encoding)>) -> Self {
+        let mut document_ids = vec![];
+        let mut position_ids = vec![];
+        let mut cu_seqlens = vec![0];
+        for (document_id, part) in &parts {
+            document_ids.extend(vec![Some(*document_id); part.len()]);
+            position_ids.extend(0..part.len() as u32);
+            cu_seqlens.push(document_ids.len() as u32);
+        }
+
+        Self {
+            encoding: Encoding::merge(parts.into_iter().map(|(_, part)| part), false),
+            document_ids,
+            position_ids,
+            cu_seqlens,
+        }
+    }
+
+    fn pad(&mut self, target_length: usize, params: &PaddingParams) {
+        let pad_length = target_length.saturating_sub(self.encoding.len());
+        if pad_length == 0 {
+            return;
+        }
+
+        self.encoding.pad(
+            target_length,
+            params.pad_id,
+            params.pad_type_id,
+            &params.pad_token,
+            params.direction,
+        );
+        let document_ids = vec![None; pad_length];
+        let position_ids = vec![0; pad_length];
+        match params.direction {
+            PaddingDirection::Left => {
+                self.document_ids.splice(0..0, document_ids);
+                self.position_ids.splice(0..0, position_ids);
+                self.cu_seqlens = std::iter::once(0)
+                    .chain(self.cu_seqlens.iter().map(|o| o + pad_length as u32))
+                    .collect();
+            }
+            PaddingDirection::Right => {
+                self.document_ids.extend(document_ids);
+                self.position_ids.extend(position_ids);
+                self.cu_seqlens.push(target_length as u32);
+            }
+        }
+    }
+}
+
+/// An iterator packing a stream of `Encoding`s in rows of `max_length` tokens. Created
+/// with [`pack_encodings`].
+pub struct PackedEncodings<I> {
+    encodings: I,
+    params: PackingParams,
+    /// The index of the next document in the stream
+    next_document: usize,
+    /// The document being packed, along with its index and the position of the first token
+    /// left to pack
+    pending: Option<(usize, Encoding, usize)>,
+}
+
+impl<I> PackedEncodings<I>
+where
+    I: Iterator<Item = Encoding>,
+{
+    fn next_pending(&mut self) -> Option<(usize, Encoding, usize)> {
+        if let Some(pending) = self.pending.take() {
+            return Some(pending);
+        }
+        for encoding in self.encodings.by_ref() {
+            let document = self.next_document;
+            self.next_document += 1;
+            // Empty documents take no space
+            if !encoding.is_empty() {
+                return Some((document, encoding, 0));
+            }
+        }
+        None
+    }
+}
+
+impl<I> Iterator for PackedEncodings<I>
+where
+    I: Iterator<Item = Encoding>,
+{
+    type Item = PackedEncoding;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let max_length = self.params.max_length;
+        let stride = self.params.stride;
+
+        let mut parts = vec![];
+        let mut len = 0;
+        while len < max_length {
+            let (document, encoding, start) = match self.next_pending() {
+                Some(pending) => pending,
+                None => break,
+            };
+            let remaining = encoding.len() - start;
+            let space = max_length - len;
+            if remaining <= space {
+                parts.push((document, encoding.slice(start, encoding.len())));
+                len += remaining;
+                continue;
+            }
+
+            // The document doesn't fit in this row. We split it only if we are allowed to, or
+            // if it wouldn't fit in any row, and if the next part starts after this one.
+            if len > 0 && (!self.params.split_documents || space <= stride) {
+                self.pending = Some((document, encoding, start));
+                break;
+            }
+            parts.push((document, encoding.slice(start, start + space)));
+            self.pending = Some((document, encoding, start + space - stride));
+            len = max_length;
+        }
+
+        if parts.is_empty() {
+            return None;
+        }
+        let mut packed = PackedEncoding::new(parts);
+        if let Some(padding) = &self.params.padding {
+            packed.pad(max_length, padding);
+        }
+        Some(packed)
+    }
+}
+
+/// Pack the given stream of `Encoding`s in rows of `max_length` tokens, keeping track of the
+/// document each token comes from. Documents are identified by their index in the stream.
+pub fn pack_encodings<I>(
+    encodings: I,
+    params: &PackingParams,
+) -> Result<PackedEncodings<I::IntoIter>>
+where
+    I: IntoIterator<Item = Encoding>,
+{
+    if params.stride >= params.max_length {
+        return Err(Box::new(PackingError::StrideTooLarge));
+    }
+
+    Ok(PackedEncodings {
+        encodings: encodings.into_iter(),
+        params: params.clone(),
+        next_document: 0,
+        pending: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Token;
+
+    fn document(first_id: u32, len: u32) -> Encoding {
+        Encoding::from_tokens(
+            (first_id..first_id + len)
+                .enumerate()
+                .map(|(i, id)| Token::new(id, id.to_string(), (i, i + 1)))
+                .collect(),
+            0,
+        )
+    }
+
+    #[test]
+    fn pack_split_documents() {
+        let params = PackingParams {
+            max_length: 4,
+            ..Default::default()
+        };
+        let documents = vec![document(0, 3), document(10, 0), document(20, 6)];
+        let rows = pack_encodings(documents, &params)
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].encoding.get_ids(), &[0, 1, 2, 20]);
+        assert_eq!(
+            rows[0].document_ids,
+            vec![Some(0), Some(0), Some(0), Some(2)]
+        );
+        assert_eq!(rows[0].position_ids, vec![0, 1, 2, 0]);
+        assert_eq!(rows[0].cu_seqlens, vec![0, 3, 4]);
+        assert_eq!(rows[1].encoding.get_ids(), &[21, 22, 23, 24]);
+        assert_eq!(rows[1].position_ids, vec![0, 1, 2, 3]);
+        assert_eq!(rows[1].cu_seqlens, vec![0, 4]);
+        // The final row is not padded by default
+        assert_eq!(rows[2].encoding.get_ids(), &[25]);
+        assert_eq!(rows[2].document_ids, vec![Some(2)]);
+        assert_eq!(rows[2].cu_seqlens, vec![0, 1]);
+    }
+
+    #[test]
+    fn pack_with_stride_and_padding() {
+        let params = PackingParams {
+            max_length: 4,
+            split_documents: false,
+            stride: 1,
+            padding: Some(PaddingParams {
+                pad_id: 99,
+                ..Default::default()
+            }),
+        };
+        let documents = vec![document(0, 2), document(10, 3), document(20, 6)];
+        let rows = pack_encodings(documents, &params)
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        // Documents are not split unless they are longer than a row, in which case the parts
+        // overlap with `stride` tokens
+        let ids = rows
+            .iter()
+            .map(|r| r.encoding.get_ids().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            vec![
+                vec![0, 1, 99, 99],
+                vec![10, 11, 12, 99],
+                vec![20, 21, 22, 23],
+                vec![23, 24, 25, 99],
+            ]
+        );
+        assert_eq!(rows[0].document_ids, vec![Some(0), Some(0), None, None]);
+        assert_eq!(rows[0].position_ids, vec![0, 1, 0, 0]);
+        assert_eq!(rows[0].cu_seqlens, vec![0, 2, 4]);
+        assert_eq!(rows[0].encoding.get_attention_mask(), &[1, 1, 0, 0]);
+        assert_eq!(rows[3].document_ids, vec![Some(2), Some(2), Some(2), None]);
+
+        // Padding on the left shifts the sequences
+        let mut params = params;
+        params.padding.as_mut().unwrap().direction = PaddingDirection::Left;
+        let rows = pack_encodings(vec![document(0, 2)], &params)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(rows[0].encoding.get_ids(), &[99, 99, 0, 1]);
+        assert_eq!(rows[0].document_ids, vec![None, None, Some(0), Some(0)]);
+        assert_eq!(rows[0].cu_seqlens, vec![0, 2, 4]);
+
+        params.stride = 4;
+        assert!(pack_encodings(vec![document(0, 2)], &params).is_err());
+    }
+}
@@ -52,20 +52,13 @@ pub fn pad_encodings(encodings: &mut [Encoding], params: &PaddingParams) -> Resu
         return Ok(());
     }
 
-    let mut pad_length = match params.strategy {
-        PaddingStrategy::Fixed(size) => size,
-        PaddingStrategy::BatchLongest => encodings
+    let pad_length = get_pad_length(params, || {
+        encodings
             .maybe_par_iter()
             .map(|e| e.get_ids().len())
             .max()
-            .unwrap(),
-    };
-
-    if let Some(multiple) = params.pad_to_multiple_of {
-        if multiple > 0 && pad_length % multiple > 0 {
-            pad_length += multiple - pad_length % multiple;
-        }
-    }
+            .unwrap()
+    });
 
     encodings.maybe_par_iter_mut().for_each(|encoding| {
         encoding.pad(
@@ -80,6 +73,25 @@ pub fn pad_encodings(encodings: &mut [Encoding], params: &PaddingParams) -> Resu
     Ok(())
 }
 
+/// Get the length to pad a batch to, given a function computing the length of its longest
+/// encoding
+pub(crate) fn get_pad_length<F>(params: &PaddingParams, longest: F) -> usize
+where
+    F: FnOnce() -> usize,
+{
+    let mut pad_length = match params.strategy {
+        PaddingStrategy::Fixed(size) => size,
+        PaddingStrategy::BatchLongest => longest(),
+    };
+
+    if let Some(multiple) = params.pad_to_multiple_of {
+        if multiple > 0 && pad_length % multiple > 0 {
+            pad_length += multiple - pad_length % multiple;
+        }
+    }
+    pad_length
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
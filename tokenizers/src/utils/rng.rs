@@ -0,0 +1,119 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::cell::Cell;
+
+/// A stream of samples: its id, and the position of the next word sampled in it
+#[derive(Clone, Copy)]
+struct SampleStream {
+    id: u64,
+    position: u64,
+}
+
+thread_local! {
+    static SAMPLE_STREAM: Cell<Option<SampleStream>> = const { Cell::new(None) };
+}
+
+/// Restores the sample stream that was current before a new one got opened
+struct RestoreStream(Option<SampleStream>);
+
+impl Drop for RestoreStream {
+    fn drop(&mut self) {
+        SAMPLE_STREAM.with(|stream| stream.set(self.0));
+    }
+}
+
+/// Runs `f` in the sample stream `id`. The words sampled by `f` get their positions in this
+/// stream, in the order they are sampled, starting from 0. The tokenizer opens one stream for
+/// each input it encodes, whose id is the index of the input in its batch, so the samples of
+/// an input never depend on the other inputs, or on the thread encoding it.
+pub(crate) fn with_sample_stream<T>(id: u64, f: impl FnOnce() -> T) -> T {
+    let previous =
+        SAMPLE_STREAM.with(|stream| stream.replace(Some(SampleStream { id, position: 0 })));
+    let _restore = RestoreStream(previous);
+    f()
+}
+
+/// Runs `f` in the current sample stream, or in the stream 0 when none is open
+pub(crate) fn in_sample_stream<T>(f: impl FnOnce() -> T) -> T {
+    if SAMPLE_STREAM.with(|stream| stream.get().is_some()) {
+        f()
+    } else {
+        with_sample_stream(0, f)
+    }
+}
+
+/// Hashes the given bytes with FNV-1a, which unlike the std hashers is guaranteed to stay stable
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Creates the RNG used to sample `word`. Its state only depends on the `seed`, on the `word`,
+/// and on the position of the word in the current sample stream, which this call takes. The
+/// same word thus gets a new sample at each of its positions, while encoding the same inputs
+/// always gives the same samples. Outside of any stream, the word is at the position 0 of the
+/// stream 0.
+///
+/// The RNG is a ChaCha8 seeded with these values, whose output is specified, so the samples
+/// stay the same across versions of `rand`, unlike with its `StdRng`.
+pub(crate) fn sample_rng(seed: u64, word: &str) -> ChaCha8Rng {
+    let stream = SAMPLE_STREAM.with(|stream| {
+        let current = stream.get();
+        if let Some(current) = current {
+            stream.set(Some(SampleStream {
+                position: current.position + 1,
+                ..current
+            }));
+        }
+        current.unwrap_or(SampleStream { id: 0, position: 0 })
+    });
+    let mut rng_seed = [0u8; 32];
+    rng_seed[..8].copy_from_slice(&seed.to_le_bytes());
+    rng_seed[8..16].copy_from_slice(&stream.id.to_le_bytes());
+    rng_seed[16..24].copy_from_slice(&stream.position.to_le_bytes());
+    rng_seed[24..].copy_from_slice(&fnv1a(0xcbf2_9ce4_8422_2325, word.as_bytes()).to_le_bytes());
+    ChaCha8Rng::from_seed(rng_seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn sample_rng_reproducible() {
+        let sample = |seed, word| sample_rng(seed, word).gen::<u64>();
+        assert_eq!(sample(42, "hello"), sample(42, "hello"));
+        assert_ne!(sample(42, "hello"), sample(43, "hello"));
+        assert_ne!(sample(42, "hello"), sample(42, "world"));
+        // The samples never change across versions
+        assert_eq!(sample(42, "hello"), 9879187955932220425);
+    }
+
+    #[test]
+    fn sample_streams() {
+        let sample = |id| {
+            with_sample_stream(id, || {
+                (0..3)
+                    .map(|_| sample_rng(42, "hello").gen::<u64>())
+                    .collect::<Vec<_>>()
+            })
+        };
+        let first = sample(0);
+        // Each position gets a new sample, reproduced by the same stream
+        assert_ne!(first[0], first[1]);
+        assert_eq!(first, sample(0));
+        assert_ne!(first, sample(1));
+        assert_eq!(first[0], sample_rng(42, "hello").gen::<u64>());
+
+        // A nested stream doesn't move the position of the current one
+        let nested = with_sample_stream(0, || {
+            let a = sample_rng(42, "hello").gen::<u64>();
+            sample(1);
+            let b = in_sample_stream(|| sample_rng(42, "hello").gen::<u64>());
+            vec![a, b]
+        });
+        assert_eq!(nested, first[..2]);
+    }
+}
@@ -0,0 +1,977 @@
+//! Reading and writing of SentencePiece `.model` files.
+//!
+//! A SentencePiece model is a serialized `ModelProto` protobuf message. Only the parts that are
+//! relevant to the tokenization are handled here: the pieces, along with the trainer and
+//! normalizer specs. Any other field is skipped while reading.
+use crate::decoders::byte_fallback::ByteFallback;
+use crate::decoders::sequence::Sequence as DecoderSequence;
+use crate::decoders::DecoderWrapper;
+use crate::models::bpe::BPE;
+use crate::models::unigram::Unigram;
+use crate::models::ModelWrapper;
+use crate::normalizers::replace::ReplacePattern;
+use crate::normalizers::{NormalizerWrapper, Precompiled, Replace, Sequence, Strip};
+use crate::pre_tokenizers::metaspace::{Metaspace, PrependScheme};
+use crate::pre_tokenizers::PreTokenizerWrapper;
+use crate::tokenizer::{AddedToken, Result, Tokenizer};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+#[derive(thiserror::Error, Debug)]
+pub enum SentencePieceError {
+    #[error("Unexpected end of the SentencePiece model")]
+    UnexpectedEof,
+    #[error("Unsupported protobuf wire type {0}")]
+    UnsupportedWireType(u64),
+    #[error("Invalid UTF-8 string in the SentencePiece model")]
+    InvalidUtf8,
+    #[error("Unsupported SentencePiece model type {0:?}")]
+    UnsupportedModelType(ModelType),
+    #[error("Only Unigram models can be exported to SentencePiece")]
+    UnsupportedModel,
+    #[error("SentencePiece models require an unknown token")]
+    MissingUnkToken,
+    #[error("Added token `{0}` with id {1} doesn't follow the model vocabulary")]
+    AddedTokenOutOfRange(String, u32),
+}
+
+/// The type of a `SentencePiece`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PieceType {
+    Normal,
+    Unknown,
+    Control,
+    UserDefined,
+    Unused,
+    Byte,
+}
+
+impl PieceType {
+    fn from_u64(value: u64) -> Self {
+        match value {
+            2 => Self::Unknown,
+            3 => Self::Control,
+            4 => Self::UserDefined,
+            5 => Self::Unused,
+            6 => Self::Byte,
+            _ => Self::Normal,
+        }
+    }
+
+    fn to_u64(self) -> u64 {
+        match self {
+            Self::Normal => 1,
+            Self::Unknown => 2,
+            Self::Control => 3,
+            Self::UserDefined => 4,
+            Self::Unused => 5,
+            Self::Byte => 6,
+        }
+    }
+}
+
+/// The algorithm used by a SentencePiece model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelType {
+    Unigram,
+    Bpe,
+    Word,
+    Char,
+}
+
+impl ModelType {
+    fn from_u64(value: u64) -> Self {
+        match value {
+            2 => Self::Bpe,
+            3 => Self::Word,
+            4 => Self::Char,
+            _ => Self::Unigram,
+        }
+    }
+
+    fn to_u64(self) -> u64 {
+        match self {
+            Self::Unigram => 1,
+            Self::Bpe => 2,
+            Self::Word => 3,
+            Self::Char => 4,
+        }
+    }
+}
+
+/// A single entry of the SentencePiece vocabulary.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentencePiece {
+    pub piece: String,
+    pub score: f32,
+    pub kind: PieceType,
+}
+
+/// The subset of the SentencePiece `TrainerSpec` used for tokenization.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrainerSpec {
+    pub model_type: ModelType,
+    pub vocab_size: i32,
+    pub split_by_whitespace: bool,
+    pub byte_fallback: bool,
+    pub unk_id: i32,
+    pub bos_id: i32,
+    pub eos_id: i32,
+    pub pad_id: i32,
+    pub unk_piece: String,
+    pub bos_piece: String,
+    pub eos_piece: String,
+    pub pad_piece: String,
+}
+
+impl Default for TrainerSpec {
+    fn default() -> Self {
+        Self {
+            model_type: ModelType::Unigram,
+            vocab_size: 8000,
+            split_by_whitespace: true,
+            byte_fallback: false,
+            unk_id: 0,
+            bos_id: 1,
+            eos_id: 2,
+            pad_id: -1,
+            unk_piece: "<unk>".into(),
+            bos_piece: "<s>".into(),
+            eos_piece: "</s>".into(),
+            pad_piece: "<pad>".into(),
+        }
+    }
+}
+
+/// The subset of the SentencePiece `NormalizerSpec` used for tokenization.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizerSpec {
+    pub name: String,
+    pub precompiled_charsmap: Vec<u8>,
+    pub add_dummy_prefix: bool,
+    pub remove_extra_whitespaces: bool,
+    pub escape_whitespaces: bool,
+}
+
+impl Default for NormalizerSpec {
+    fn default() -> Self {
+        Self {
+            name: "identity".into(),
+            precompiled_charsmap: vec![],
+            add_dummy_prefix: true,
+            remove_extra_whitespaces: true,
+            escape_whitespaces: true,
+        }
+    }
+}
+
+/// A SentencePiece `ModelProto`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModelProto {
+    pub pieces: Vec<SentencePiece>,
+    pub trainer_spec: TrainerSpec,
+    pub normalizer_spec: NormalizerSpec,
+}
+
+/// The value of a protobuf field, as found on the wire.
+enum Value<'a> {
+    Varint(u64),
+    Fixed64,
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+/// Iterates over the (field number, value) pairs of a protobuf message.
+struct FieldReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> FieldReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let (byte, rest) = self
+                .data
+                .split_first()
+                .ok_or(SentencePieceError::UnexpectedEof)?;
+            self.data = rest;
+            value |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Ok(value)
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.data.len() < n {
+            return Err(SentencePieceError::UnexpectedEof.into());
+        }
+        let (taken, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    fn next_field(&mut self) -> Result<Option<(u64, Value<'a>)>> {
+        if self.data.is_empty() {
+            return Ok(None);
+        }
+        let key = self.varint()?;
+        let value = match key & 0x7 {
+            0 => Value::Varint(self.varint()?),
+            1 => {
+                self.take(8)?;
+                Value::Fixed64
+            }
+            2 => {
+                let len = self.varint()? as usize;
+                Value::Bytes(self.take(len)?)
+            }
+            5 => {
+                let bytes = self.take(4)?;
+                Value::Fixed32(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            }
+            wire_type => return Err(SentencePieceError::UnsupportedWireType(wire_type).into()),
+        };
+        Ok(Some((key >> 3, value)))
+    }
+}
+
+/// Writes the fields of a protobuf message.
+#[derive(Default)]
+struct FieldWriter {
+    data: Vec<u8>,
+}
+
+impl FieldWriter {
+    fn raw_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.data.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.data.push(value as u8);
+    }
+
+    fn varint(&mut self, field: u64, value: u64) {
+        self.raw_varint(field << 3);
+        self.raw_varint(value);
+    }
+
+    fn int32(&mut self, field: u64, value: i32) {
+        // Negative values are sign extended to 64 bits, as specified by protobuf
+        self.varint(field, value as i64 as u64);
+    }
+
+    fn bool(&mut self, field: u64, value: bool) {
+        self.varint(field, value as u64);
+    }
+
+    fn float(&mut self, field: u64, value: f32) {
+        self.raw_varint(field << 3 | 5);
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bytes(&mut self, field: u64, value: &[u8]) {
+        self.raw_varint(field << 3 | 2);
+        self.raw_varint(value.len() as u64);
+        self.data.extend_from_slice(value);
+    }
+}
+
+fn utf8(bytes: &[u8]) -> Result<String> {
+    Ok(std::str::from_utf8(bytes)
+        .map_err(|_| SentencePieceError::InvalidUtf8)?
+        .to_owned())
+}
+
+impl SentencePiece {
+    fn decode(data: &[u8]) -> Result<Self> {
+        let mut piece = Self {
+            piece: String::new(),
+            score: 0.0,
+            kind: PieceType::Normal,
+        };
+        let mut reader = FieldReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match (field, value) {
+                (1, Value::Bytes(b)) => piece.piece = utf8(b)?,
+                (2, Value::Fixed32(v)) => piece.score = f32::from_bits(v),
+                (3, Value::Varint(v)) => piece.kind = PieceType::from_u64(v),
+                _ => {}
+            }
+        }
+        Ok(piece)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut writer = FieldWriter::default();
+        writer.bytes(1, self.piece.as_bytes());
+        writer.float(2, self.score);
+        if self.kind != PieceType::Normal {
+            writer.varint(3, self.kind.to_u64());
+        }
+        writer.data
+    }
+}
+
+impl TrainerSpec {
+    fn decode(data: &[u8]) -> Result<Self> {
+        let mut spec = Self::default();
+        let mut reader = FieldReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match (field, value) {
+                (3, Value::Varint(v)) => spec.model_type = ModelType::from_u64(v),
+                (4, Value::Varint(v)) => spec.vocab_size = v as i32,
+                (19, Value::Varint(v)) => spec.split_by_whitespace = v != 0,
+                (35, Value::Varint(v)) => spec.byte_fallback = v != 0,
+                (40, Value::Varint(v)) => spec.unk_id = v as i32,
+                (41, Value::Varint(v)) => spec.bos_id = v as i32,
+                (42, Value::Varint(v)) => spec.eos_id = v as i32,
+                (43, Value::Varint(v)) => spec.pad_id = v as i32,
+                (45, Value::Bytes(b)) => spec.unk_piece = utf8(b)?,
+                (46, Value::Bytes(b)) => spec.bos_piece = utf8(b)?,
+                (47, Value::Bytes(b)) => spec.eos_piece = utf8(b)?,
+                (48, Value::Bytes(b)) => spec.pad_piece = utf8(b)?,
+                _ => {}
+            }
+        }
+        Ok(spec)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut writer = FieldWriter::default();
+        writer.varint(3, self.model_type.to_u64());
+        writer.int32(4, self.vocab_size);
+        writer.bool(19, self.split_by_whitespace);
+        writer.bool(35, self.byte_fallback);
+        writer.int32(40, self.unk_id);
+        writer.int32(41, self.bos_id);
+        writer.int32(42, self.eos_id);
+        writer.int32(43, self.pad_id);
+        writer.bytes(45, self.unk_piece.as_bytes());
+        writer.bytes(46, self.bos_piece.as_bytes());
+        writer.bytes(47, self.eos_piece.as_bytes());
+        writer.bytes(48, self.pad_piece.as_bytes());
+        writer.data
+    }
+}
+
+impl NormalizerSpec {
+    fn decode(data: &[u8]) -> Result<Self> {
+        let mut spec = Self::default();
+        let mut reader = FieldReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match (field, value) {
+                (1, Value::Bytes(b)) => spec.name = utf8(b)?,
+                (2, Value::Bytes(b)) => spec.precompiled_charsmap = b.to_vec(),
+                (3, Value::Varint(v)) => spec.add_dummy_prefix = v != 0,
+                (4, Value::Varint(v)) => spec.remove_extra_whitespaces = v != 0,
+                (5, Value::Varint(v)) => spec.escape_whitespaces = v != 0,
+                _ => {}
+            }
+        }
+        Ok(spec)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut writer = FieldWriter::default();
+        writer.bytes(1, self.name.as_bytes());
+        if !self.precompiled_charsmap.is_empty() {
+            writer.bytes(2, &self.precompiled_charsmap);
+        }
+        writer.bool(3, self.add_dummy_prefix);
+        writer.bool(4, self.remove_extra_whitespaces);
+        writer.bool(5, self.escape_whitespaces);
+        writer.data
+    }
+}
+
+impl ModelProto {
+    /// Parse a serialized `ModelProto`
+    pub fn from_bytes<P: AsRef<[u8]>>(bytes: P) -> Result<Self> {
+        let mut proto = Self::default();
+        let mut reader = FieldReader::new(bytes.as_ref());
+        while let Some((field, value)) = reader.next_field()? {
+            match (field, value) {
+                (1, Value::Bytes(b)) => proto.pieces.push(SentencePiece::decode(b)?),
+                (2, Value::Bytes(b)) => proto.trainer_spec = TrainerSpec::decode(b)?,
+                (3, Value::Bytes(b)) => proto.normalizer_spec = NormalizerSpec::decode(b)?,
+                _ => {}
+            }
+        }
+        Ok(proto)
+    }
+
+    /// Read the given `.model` file
+    pub fn from_file<P: AsRef<Path>>(file: P) -> Result<Self> {
+        let mut bytes = vec![];
+        File::open(file)?.read_to_end(&mut bytes)?;
+        Self::from_bytes(bytes)
+    }
+
+    /// Serialize this `ModelProto`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = FieldWriter::default();
+        for piece in &self.pieces {
+            writer.bytes(1, &piece.encode());
+        }
+        writer.bytes(2, &self.trainer_spec.encode());
+        writer.bytes(3, &self.normalizer_spec.encode());
+        writer.data
+    }
+
+    /// Save this `ModelProto` as a `.model` file
+    pub fn save<P: AsRef<Path>>(&self, file: P) -> Result<()> {
+        File::create(file)?.write_all(&self.to_bytes())?;
+        Ok(())
+    }
+
+    fn unk_id(&self) -> Option<usize> {
+        self.pieces
+            .iter()
+            .position(|p| p.kind == PieceType::Unknown)
+    }
+
+    fn unused_ids(&self) -> impl Iterator<Item = usize> + '_ {
+        self.pieces
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.kind == PieceType::Unused)
+            .map(|(id, _)| id)
+    }
+
+    /// Build the `Unigram` or `BPE` model described by this proto. The unused pieces keep their
+    /// id, but are never produced.
+    pub fn model(&self) -> Result<ModelWrapper> {
+        let byte_fallback = self.trainer_spec.byte_fallback;
+        match self.trainer_spec.model_type {
+            ModelType::Unigram => {
+                let vocab = self
+                    .pieces
+                    .iter()
+                    .map(|p| (p.piece.clone(), p.score as f64))
+                    .collect();
+                let mut unigram = Unigram::from(vocab, self.unk_id(), byte_fallback)?;
+                unigram.set_unused(self.unused_ids().collect());
+                Ok(unigram.into())
+            }
+            ModelType::Bpe => {
+                let vocab: HashMap<String, u32> = self
+                    .pieces
+                    .iter()
+                    .enumerate()
+                    .map(|(id, p)| (p.piece.clone(), id as u32))
+                    .collect();
+                // The unused pieces keep their id, but no merge produces them
+                let mut reachable = vocab.clone();
+                for id in self.unused_ids() {
+                    reachable.remove(&self.pieces[id].piece);
+                }
+                let mut builder = BPE::builder()
+                    .vocab_and_merges(vocab, bpe_merges(&reachable))
+                    .fuse_unk(true)
+                    .byte_fallback(byte_fallback);
+                if let Some(unk_id) = self.unk_id() {
+                    builder = builder.unk_token(self.pieces[unk_id].piece.clone());
+                }
+                Ok(builder.build()?.into())
+            }
+            model_type => Err(SentencePieceError::UnsupportedModelType(model_type).into()),
+        }
+    }
+
+    /// Build the normalizer matching the `NormalizerSpec`
+    pub fn normalizer(&self) -> Result<Option<NormalizerWrapper>> {
+        let spec = &self.normalizer_spec;
+        let mut normalizers: Vec<NormalizerWrapper> = vec![];
+        if !spec.precompiled_charsmap.is_empty() {
+            normalizers.push(Precompiled::from(&spec.precompiled_charsmap)?.into());
+        }
+        if spec.remove_extra_whitespaces {
+            normalizers.push(Strip::new(false, true).into());
+            normalizers.push(extra_whitespaces()?.into());
+        }
+        Ok(match normalizers.len() {
+            0 => None,
+            1 => normalizers.pop(),
+            _ => Some(Sequence::new(normalizers).into()),
+        })
+    }
+
+    fn metaspace(&self) -> Metaspace {
+        let prepend_scheme = if self.normalizer_spec.add_dummy_prefix {
+            PrependScheme::First
+        } else {
+            PrependScheme::Never
+        };
+        // Without escaping, the pieces contain the whitespaces themselves
+        let replacement = if self.normalizer_spec.escape_whitespaces {
+            '▁'
+        } else {
+            ' '
+        };
+        Metaspace::new(
+            replacement,
+            prepend_scheme,
+            self.trainer_spec.split_by_whitespace,
+        )
+    }
+
+    /// Build the pre-tokenizer matching this proto
+    pub fn pre_tokenizer(&self) -> PreTokenizerWrapper {
+        self.metaspace().into()
+    }
+
+    /// Build the decoder matching this proto
+    pub fn decoder(&self) -> DecoderWrapper {
+        if self.trainer_spec.byte_fallback {
+            DecoderSequence::new(vec![ByteFallback::new().into(), self.metaspace().into()]).into()
+        } else {
+            self.metaspace().into()
+        }
+    }
+
+    /// Build a complete `Tokenizer` from this proto. Control and unknown pieces are added as
+    /// special tokens, while user defined pieces are added as regular `AddedToken`s.
+    pub fn tokenizer(&self) -> Result<Tokenizer> {
+        let mut tokenizer = Tokenizer::new(self.model()?);
+        if let Some(normalizer) = self.normalizer()? {
+            tokenizer.with_normalizer(normalizer);
+        }
+        tokenizer.with_pre_tokenizer(self.pre_tokenizer());
+        tokenizer.with_decoder(self.decoder());
+
+        let special_tokens = self
+            .pieces
+            .iter()
+            .filter(|p| matches!(p.kind, PieceType::Control | PieceType::Unknown))
+            .map(|p| AddedToken::from(p.piece.clone(), true))
+            .collect::<Vec<_>>();
+        tokenizer.add_special_tokens(&special_tokens);
+        let user_defined = self
+            .pieces
+            .iter()
+            .filter(|p| p.kind == PieceType::UserDefined)
+            .map(|p| AddedToken::from(p.piece.clone(), false).normalized(false))
+            .collect::<Vec<_>>();
+        tokenizer.add_tokens(&user_defined);
+
+        Ok(tokenizer)
+    }
+
+    /// Build a `ModelProto` from a `Tokenizer` using a `Unigram` model. Special added tokens
+    /// are exported as control pieces, and the other added tokens as user defined pieces.
+    pub fn from_tokenizer(tokenizer: &Tokenizer) -> Result<Self> {
+        let unigram = match tokenizer.get_model() {
+            ModelWrapper::Unigram(unigram) => unigram,
+            _ => return Err(SentencePieceError::UnsupportedModel.into()),
+        };
+        let unk_id = unigram
+            .unk_id()
+            .ok_or(SentencePieceError::MissingUnkToken)?;
+        let added_tokens = tokenizer.get_added_tokens_decoder();
+
+        let mut pieces = unigram
+            .vocab
+            .iter()
+            .enumerate()
+            .map(|(id, (piece, score))| {
+                let kind = if id == unk_id {
+                    PieceType::Unknown
+                } else if let Some(token) = added_tokens.get(&(id as u32)) {
+                    if token.special {
+                        PieceType::Control
+                    } else {
+                        PieceType::UserDefined
+                    }
+                } else if unigram.get_unused().contains(&id) {
+                    PieceType::Unused
+                } else if unigram.byte_fallback() && is_byte_piece(piece) {
+                    PieceType::Byte
+                } else {
+                    PieceType::Normal
+                };
+                SentencePiece {
+                    piece: piece.clone(),
+                    score: *score as f32,
+                    kind,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Added tokens that are not part of the model must directly follow its vocabulary
+        let mut extra_tokens = added_tokens
+            .iter()
+            .filter(|(id, _)| **id as usize >= pieces.len())
+            .collect::<Vec<_>>();
+        extra_tokens.sort_unstable_by_key(|(id, _)| **id);
+        for (id, token) in extra_tokens {
+            if *id as usize != pieces.len() {
+                return Err(
+                    SentencePieceError::AddedTokenOutOfRange(token.content.clone(), *id).into(),
+                );
+            }
+            pieces.push(SentencePiece {
+                piece: token.content.clone(),
+                score: 0.0,
+                kind: if token.special {
+                    PieceType::Control
+                } else {
+                    PieceType::UserDefined
+                },
+            });
+        }
+
+        let piece_id = |piece: &str| -> i32 {
+            pieces
+                .iter()
+                .position(|p| p.piece == piece)
+                .map_or(-1, |id| id as i32)
+        };
+        let defaults = TrainerSpec::default();
+        let trainer_spec = TrainerSpec {
+            model_type: ModelType::Unigram,
+            vocab_size: pieces.len() as i32,
+            byte_fallback: unigram.byte_fallback(),
+            unk_id: unk_id as i32,
+            bos_id: piece_id(&defaults.bos_piece),
+            eos_id: piece_id(&defaults.eos_piece),
+            pad_id: piece_id(&defaults.pad_piece),
+            unk_piece: pieces[unk_id].piece.clone(),
+            ..defaults
+        };
+
+        let mut normalizer_spec = NormalizerSpec {
+            remove_extra_whitespaces: false,
+            ..Default::default()
+        };
+        if let Some(normalizer) = tokenizer.get_normalizer() {
+            let normalizers = match normalizer {
+                NormalizerWrapper::Sequence(sequence) => sequence.get_normalizers(),
+                normalizer => std::slice::from_ref(normalizer),
+            };
+            for normalizer in normalizers {
+                match normalizer {
+                    NormalizerWrapper::Precompiled(precompiled) => {
+                        // The rules of the charsmap are unknown, so they can't be named
+                        normalizer_spec.name = "user_defined".into();
+                        normalizer_spec.precompiled_charsmap = precompiled.charsmap().to_vec();
+                    }
+                    NormalizerWrapper::Replace(replace) if *replace == extra_whitespaces()? => {
+                        normalizer_spec.remove_extra_whitespaces = true;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut split_by_whitespace = true;
+        if let Some(pre_tokenizer) = tokenizer.get_pre_tokenizer() {
+            let pre_tokenizers = match pre_tokenizer {
+                PreTokenizerWrapper::Sequence(sequence) => sequence.get_pre_tokenizers(),
+                pre_tokenizer => std::slice::from_ref(pre_tokenizer),
+            };
+            for pre_tokenizer in pre_tokenizers {
+                if let PreTokenizerWrapper::Metaspace(metaspace) = pre_tokenizer {
+                    normalizer_spec.add_dummy_prefix =
+                        metaspace.get_prepend_scheme() != PrependScheme::Never;
+                    normalizer_spec.escape_whitespaces = metaspace.get_replacement() != ' ';
+                    split_by_whitespace = metaspace.get_split();
+                }
+            }
+        }
+
+        Ok(Self {
+            pieces,
+            trainer_spec: TrainerSpec {
+                split_by_whitespace,
+                ..trainer_spec
+            },
+            normalizer_spec,
+        })
+    }
+}
+
+/// Rebuilds the BPE merges from a SentencePiece vocabulary, where the pieces are ordered by
+/// priority: each piece is produced by merging any two pieces composing it.
+fn bpe_merges(vocab: &HashMap<String, u32>) -> Vec<(String, String)> {
+    let mut pieces = vocab.iter().collect::<Vec<_>>();
+    pieces.sort_unstable_by_key(|(_, id)| **id);
+
+    let mut merges = vec![];
+    for (piece, _) in pieces {
+        let mut local = piece
+            .char_indices()
+            .skip(1)
+            .filter_map(|(i, _)| {
+                let (left, right) = piece.split_at(i);
+                Some(((vocab.get(left)?, vocab.get(right)?), left, right))
+            })
+            .collect::<Vec<_>>();
+        local.sort_unstable_by_key(|(ids, _, _)| *ids);
+        merges.extend(
+            local
+                .into_iter()
+                .map(|(_, left, right)| (left.to_owned(), right.to_owned())),
+        );
+    }
+    merges
+}
+
+/// SentencePiece removes the extra whitespaces of the whole input: the leading and trailing
+/// ones, and the consecutive ones. Since added tokens are extracted before normalizing, each
+/// part of the input is right stripped, and its consecutive whitespaces are collapsed. A single
+/// leading whitespace is left, to be merged with the dummy prefix by the `Metaspace`.
+fn extra_whitespaces() -> Result<Replace> {
+    Replace::new(ReplacePattern::Regex(" {2,}".into()), " ")
+}
+
+fn is_byte_piece(piece: &str) -> bool {
+    piece.len() == 6
+        && piece.starts_with("<0x")
+        && piece.ends_with('>')
+        && u8::from_str_radix(&piece[3..5], 16).is_ok()
+}
+
+impl Tokenizer {
+    /// Instantiate a new Tokenizer from the given SentencePiece `.model` file
+    pub fn from_sentencepiece_file<P: AsRef<Path>>(file: P) -> Result<Self> {
+        ModelProto::from_file(file)?.tokenizer()
+    }
+
+    /// Instantiate a new Tokenizer from the bytes of a SentencePiece model
+    pub fn from_sentencepiece_bytes<P: AsRef<[u8]>>(bytes: P) -> Result<Self> {
+        ModelProto::from_bytes(bytes)?.tokenizer()
+    }
+
+    /// Save the current tokenizer as a SentencePiece `.model` file. Only `Unigram` models
+    /// are supported.
+    pub fn save_sentencepiece<P: AsRef<Path>>(&self, file: P) -> Result<()> {
+        ModelProto::from_tokenizer(self)?.save(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn piece(piece: &str, score: f32, kind: PieceType) -> SentencePiece {
+        SentencePiece {
+            piece: piece.into(),
+            score,
+            kind,
+        }
+    }
+
+    fn unigram_proto() -> ModelProto {
+        ModelProto {
+            pieces: vec![
+                piece("<unk>", 0.0, PieceType::Unknown),
+                piece("<s>", 0.0, PieceType::Control),
+                piece("</s>", 0.0, PieceType::Control),
+                piece("<sep>", 0.0, PieceType::UserDefined),
+                piece("▁", -1.0, PieceType::Normal),
+                piece("▁hello", -2.0, PieceType::Normal),
+                piece("▁world", -2.5, PieceType::Normal),
+                piece("h", -3.0, PieceType::Normal),
+                piece("e", -3.0, PieceType::Normal),
+                piece("l", -3.0, PieceType::Normal),
+                piece("o", -3.0, PieceType::Normal),
+            ],
+            trainer_spec: TrainerSpec {
+                vocab_size: 11,
+                ..Default::default()
+            },
+            normalizer_spec: NormalizerSpec::default(),
+        }
+    }
+
+    #[test]
+    fn proto_roundtrip() {
+        let proto = unigram_proto();
+        let bytes = proto.to_bytes();
+        assert_eq!(ModelProto::from_bytes(bytes).unwrap(), proto);
+    }
+
+    #[test]
+    fn proto_skips_unknown_fields() {
+        let proto = unigram_proto();
+        let mut writer = FieldWriter::default();
+        writer.varint(100, 42);
+        writer.bytes(5, b"denormalizer");
+        let mut bytes = writer.data;
+        bytes.extend(proto.to_bytes());
+        assert_eq!(ModelProto::from_bytes(bytes).unwrap(), proto);
+    }
+
+    #[test]
+    fn proto_truncated() {
+        let bytes = unigram_proto().to_bytes();
+        assert!(ModelProto::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn import_unigram() {
+        let tokenizer = Tokenizer::from_sentencepiece_bytes(unigram_proto().to_bytes()).unwrap();
+        let encoding = tokenizer.encode("hello <sep>  world", false).unwrap();
+        assert_eq!(encoding.get_tokens(), &["▁hello", "<sep>", "▁world"]);
+        assert_eq!(encoding.get_ids(), &[5, 3, 6]);
+        assert_eq!(encoding.get_offsets(), &[(0, 5), (6, 11), (12, 18)]);
+
+        let encoding = tokenizer.encode("hello world</s>", false).unwrap();
+        assert_eq!(encoding.get_ids(), &[5, 6, 2]);
+        assert_eq!(
+            tokenizer.decode(encoding.get_ids(), true).unwrap(),
+            "hello world"
+        );
+    }
+
+    #[test]
+    fn import_remove_extra_whitespaces() {
+        let tokenizer = Tokenizer::from_sentencepiece_bytes(unigram_proto().to_bytes()).unwrap();
+        let encoding = tokenizer.encode("  hello   world  ", false).unwrap();
+        assert_eq!(encoding.get_tokens(), &["▁hello", "▁world"]);
+        assert_eq!(encoding.get_offsets(), &[(1, 7), (9, 15)]);
+
+        let mut proto = unigram_proto();
+        proto.normalizer_spec.remove_extra_whitespaces = false;
+        let tokenizer = Tokenizer::from_sentencepiece_bytes(proto.to_bytes()).unwrap();
+        let encoding = tokenizer.encode("hello  world ", false).unwrap();
+        assert_eq!(encoding.get_tokens(), &["▁hello", "▁", "▁world", "▁"]);
+    }
+
+    #[test]
+    fn import_without_escaping_whitespaces() {
+        let mut proto = unigram_proto();
+        proto.normalizer_spec.escape_whitespaces = false;
+        for piece in proto.pieces.iter_mut() {
+            piece.piece = piece.piece.replace('▁', " ");
+        }
+
+        let tokenizer = Tokenizer::from_sentencepiece_bytes(proto.to_bytes()).unwrap();
+        let encoding = tokenizer.encode("hello world", false).unwrap();
+        assert_eq!(encoding.get_tokens(), &[" hello", " world"]);
+        assert_eq!(encoding.get_ids(), &[5, 6]);
+        assert_eq!(
+            tokenizer.decode(encoding.get_ids(), false).unwrap(),
+            "hello world"
+        );
+
+        let exported = ModelProto::from_tokenizer(&tokenizer).unwrap();
+        assert!(!exported.normalizer_spec.escape_whitespaces);
+        assert_eq!(exported.pieces, proto.pieces);
+    }
+
+    #[test]
+    fn import_bpe() {
+        let mut proto = ModelProto {
+            pieces: vec![
+                piece("<unk>", 0.0, PieceType::Unknown),
+                piece("<0x21>", 0.0, PieceType::Byte),
+                piece("▁h", -0.0, PieceType::Normal),
+                piece("ll", -1.0, PieceType::Normal),
+                piece("▁he", -2.0, PieceType::Normal),
+                piece("llo", -3.0, PieceType::Normal),
+                piece("▁hello", -4.0, PieceType::Normal),
+                piece("▁", -5.0, PieceType::Normal),
+                piece("h", -6.0, PieceType::Normal),
+                piece("e", -7.0, PieceType::Normal),
+                piece("l", -8.0, PieceType::Normal),
+                piece("o", -9.0, PieceType::Normal),
+            ],
+            ..Default::default()
+        };
+        proto.trainer_spec.model_type = ModelType::Bpe;
+        proto.trainer_spec.byte_fallback = true;
+
+        let tokenizer = Tokenizer::from_sentencepiece_bytes(proto.to_bytes()).unwrap();
+        let encoding = tokenizer.encode("hello hell!", false).unwrap();
+        assert_eq!(encoding.get_tokens(), &["▁hello", "▁he", "ll", "<0x21>"]);
+        assert_eq!(
+            tokenizer.decode(encoding.get_ids(), false).unwrap(),
+            "hello hell!"
+        );
+    }
+
+    #[test]
+    fn export_unigram() {
+        let tokenizer = Tokenizer::from_sentencepiece_bytes(unigram_proto().to_bytes()).unwrap();
+        let proto = ModelProto::from_tokenizer(&tokenizer).unwrap();
+        assert_eq!(proto.pieces, unigram_proto().pieces);
+        assert_eq!(proto.trainer_spec.unk_id, 0);
+        assert_eq!(proto.trainer_spec.bos_id, 1);
+        assert_eq!(proto.trainer_spec.eos_id, 2);
+        assert_eq!(proto.trainer_spec.pad_id, -1);
+        assert_eq!(proto.normalizer_spec, NormalizerSpec::default());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("spm.model");
+        tokenizer.save_sentencepiece(&path).unwrap();
+        let reloaded = Tokenizer::from_sentencepiece_file(&path).unwrap();
+        assert_eq!(
+            reloaded.encode("hello world", false).unwrap().get_ids(),
+            tokenizer.encode("hello world", false).unwrap().get_ids()
+        );
+    }
+
+    #[test]
+    fn unused_pieces() {
+        let mut proto = unigram_proto();
+        proto
+            .pieces
+            .push(piece("▁hello▁world", 0.0, PieceType::Unused));
+        proto.pieces.push(piece("ld", 0.0, PieceType::Unused));
+
+        let tokenizer = Tokenizer::from_sentencepiece_bytes(proto.to_bytes()).unwrap();
+        let encoding = tokenizer.encode("hello world", false).unwrap();
+        assert_eq!(encoding.get_tokens(), &["▁hello", "▁world"]);
+        assert_eq!(tokenizer.token_to_id("ld"), Some(12));
+
+        let exported = ModelProto::from_tokenizer(&tokenizer).unwrap();
+        assert_eq!(exported.pieces, proto.pieces);
+
+        let mut proto = ModelProto {
+            pieces: vec![
+                piece("<unk>", 0.0, PieceType::Unknown),
+                piece("▁h", -0.0, PieceType::Normal),
+                piece("▁he", -1.0, PieceType::Unused),
+                piece("▁", -2.0, PieceType::Normal),
+                piece("h", -3.0, PieceType::Normal),
+                piece("e", -4.0, PieceType::Normal),
+            ],
+            ..Default::default()
+        };
+        proto.trainer_spec.model_type = ModelType::Bpe;
+        let tokenizer = Tokenizer::from_sentencepiece_bytes(proto.to_bytes()).unwrap();
+        let encoding = tokenizer.encode("he", false).unwrap();
+        assert_eq!(encoding.get_tokens(), &["▁h", "e"]);
+    }
+
+    #[test]
+    fn export_precompiled_name() {
+        use crate::normalizers::precompiled::PrecompiledBuilder;
+        let precompiled = PrecompiledBuilder::new().rule("ｈ", "h").build().unwrap();
+        let mut tokenizer =
+            Tokenizer::from_sentencepiece_bytes(unigram_proto().to_bytes()).unwrap();
+        tokenizer.with_normalizer(precompiled.clone());
+
+        let proto = ModelProto::from_tokenizer(&tokenizer).unwrap();
+        assert_eq!(proto.normalizer_spec.name, "user_defined");
+        assert_eq!(
+            proto.normalizer_spec.precompiled_charsmap,
+            precompiled.charsmap()
+        );
+    }
+
+    #[test]
+    fn export_requires_unigram() {
+        let tokenizer = Tokenizer::new(BPE::default());
+        assert!(ModelProto::from_tokenizer(&tokenizer).is_err());
+    }
+}
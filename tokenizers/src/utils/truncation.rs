@@ -1,7 +1,10 @@
-use crate::tokenizer::{Encoding, Result};
+use crate::tokenizer::{Encoding, OffsetType, Result};
+use crate::utils::SysRegex;
 use serde::{Deserialize, Serialize};
 use std::cmp;
-use std::mem;
+use std::convert::TryFrom;
+use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Eq, Default)]
 pub enum TruncationDirection {
@@ -19,6 +22,137 @@ impl std::convert::AsRef<str> for TruncationDirection {
     }
 }
 
+/// Where a sequence can be cut when truncating it, which is also where its overflowing parts
+/// can start.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Eq, Default)]
+pub enum TruncationBoundary {
+    /// Between any two tokens
+    #[default]
+    Token,
+    /// Between two words, as identified by the word ids of the `Encoding`
+    Word,
+    /// At the beginning of each sentence of the original text
+    Sentence,
+    /// At the end of each match of the given regex in the original text
+    Regex(BoundaryRegex),
+}
+
+/// The regex of a `TruncationBoundary`, compiled once when created or deserialized
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct BoundaryRegex {
+    pattern: String,
+    regex: Arc<SysRegex>,
+}
+
+impl BoundaryRegex {
+    pub fn new<P: Into<String>>(pattern: P) -> Result<Self> {
+        let pattern = pattern.into();
+        let regex = Arc::new(SysRegex::new(&pattern)?);
+        Ok(Self { pattern, regex })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+}
+
+impl PartialEq for BoundaryRegex {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for BoundaryRegex {}
+
+impl TryFrom<String> for BoundaryRegex {
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    fn try_from(pattern: String) -> Result<Self> {
+        Self::new(pattern)
+    }
+}
+
+impl From<BoundaryRegex> for String {
+    fn from(regex: BoundaryRegex) -> Self {
+        regex.pattern
+    }
+}
+
+impl std::convert::AsRef<str> for TruncationBoundary {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Token => "token",
+            Self::Word => "word",
+            Self::Sentence => "sentence",
+            Self::Regex(_) => "regex",
+        }
+    }
+}
+
+impl TruncationBoundary {
+    fn is_token(&self) -> bool {
+        *self == Self::Token
+    }
+
+    /// Whether the given `Encoding` can be cut before each of its tokens, or at its end. The
+    /// sentences and the regex matches are found in the original text of the sequence, with the
+    /// type of its offsets. Without it, they fall back to words.
+    pub(crate) fn cuts(
+        &self,
+        encoding: &Encoding,
+        text: Option<(&str, OffsetType)>,
+    ) -> Result<Vec<bool>> {
+        let len = encoding.len();
+        let positions: Vec<usize> = match (self, text) {
+            (Self::Token, _) => return Ok(vec![true; len + 1]),
+            (Self::Sentence, Some((text, _))) => text
+                .split_sentence_bound_indices()
+                .map(|(start, _)| start)
+                .collect(),
+            (Self::Regex(regex), Some((text, _))) => {
+                regex.regex.find_iter(text).map(|(_, end)| end).collect()
+            }
+            _ => {
+                let words = encoding.get_word_ids();
+                return Ok((0..=len)
+                    .map(|i| i == 0 || i == len || words[i].is_none() || words[i] != words[i - 1])
+                    .collect());
+            }
+        };
+        let positions = match text {
+            // The positions are sorted, so the chars can be counted incrementally
+            Some((text, OffsetType::Char)) => {
+                let (mut chars, mut last) = (0, 0);
+                positions
+                    .into_iter()
+                    .map(|position| {
+                        chars += text[last..position].chars().count();
+                        last = position;
+                        chars
+                    })
+                    .collect()
+            }
+            _ => positions,
+        };
+
+        // A token starts a new part when one of the positions falls between its start and the
+        // start of the previous token. Tokens starting at the same offset, like the bytes of
+        // the same character, are never separated.
+        let offsets = encoding.get_offsets();
+        Ok((0..=len)
+            .map(|i| {
+                if i == 0 || i == len {
+                    return true;
+                }
+                let (previous, current) = (offsets[i - 1].0, offsets[i].0);
+                let next = positions.partition_point(|position| *position <= previous);
+                next < positions.len() && positions[next] <= current
+            })
+            .collect())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TruncationParams {
     #[serde(default)]
@@ -26,6 +160,8 @@ pub struct TruncationParams {
     pub max_length: usize,
     pub strategy: TruncationStrategy,
     pub stride: usize,
+    #[serde(default, skip_serializing_if = "TruncationBoundary::is_token")]
+    pub boundary: TruncationBoundary,
 }
 
 impl Default for TruncationParams {
@@ -35,6 +171,7 @@ impl Default for TruncationParams {
             strategy: TruncationStrategy::default(),
             stride: 0,
             direction: TruncationDirection::default(),
+            boundary: TruncationBoundary::default(),
         }
     }
 }
@@ -51,9 +188,12 @@ pub enum TruncationError {
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Eq)]
 pub enum TruncationStrategy {
+    /// Truncate the longest sequences first, until they all fit
     LongestFirst,
     OnlyFirst,
     OnlySecond,
+    /// Truncate each sequence proportionally to its length
+    Proportional,
 }
 
 impl Default for TruncationStrategy {
@@ -68,108 +208,126 @@ impl std::convert::AsRef<str> for TruncationStrategy {
             Self::LongestFirst => "longest_first",
             Self::OnlyFirst => "only_first",
             Self::OnlySecond => "only_second",
+            Self::Proportional => "proportional",
         }
     }
 }
 
 pub fn truncate_encodings(
-    mut encoding: Encoding,
-    mut pair_encoding: Option<Encoding>,
+    encoding: Encoding,
+    pair_encoding: Option<Encoding>,
     params: &TruncationParams,
 ) -> Result<(Encoding, Option<Encoding>)> {
-    if params.max_length == 0 {
-        encoding.truncate(0, params.stride, params.direction);
-        if let Some(other_encoding) = pair_encoding.as_mut() {
-            other_encoding.truncate(0, params.stride, params.direction);
+    let mut encodings = vec![encoding];
+    encodings.extend(pair_encoding);
+    let mut encodings = truncate_encodings_multi(encodings, params)?.into_iter();
+    let encoding = encodings.next().unwrap();
+    Ok((encoding, encodings.next()))
+}
+
+/// Truncate any number of encodings so that, together, they respect the given parameters.
+/// The original texts being unknown, sentence and regex boundaries fall back to words.
+pub fn truncate_encodings_multi(
+    encodings: Vec<Encoding>,
+    params: &TruncationParams,
+) -> Result<Vec<Encoding>> {
+    truncate_sequences(encodings, &[], params)
+}
+
+/// Truncate any number of encodings, using the original text of each sequence, along with the
+/// type of its offsets, when known.
+pub(crate) fn truncate_sequences(
+    mut encodings: Vec<Encoding>,
+    texts: &[Option<(&str, OffsetType)>],
+    params: &TruncationParams,
+) -> Result<Vec<Encoding>> {
+    let lens = encodings.iter().map(|e| e.len()).collect::<Vec<_>>();
+    let lens = truncated_lengths(&lens, params)?;
+    for (i, (encoding, len)) in encodings.iter_mut().zip(lens).enumerate() {
+        if params.boundary.is_token() {
+            encoding.truncate(len, params.stride, params.direction);
+        } else if len < encoding.len() {
+            let cuts = params
+                .boundary
+                .cuts(encoding, texts.get(i).copied().flatten())?;
+            encoding.truncate_at(len, params.stride, params.direction, |token| cuts[token]);
         }
-        return Ok((encoding, pair_encoding));
     }
+    Ok(encodings)
+}
 
-    let total_length = encoding.get_ids().len()
-        + pair_encoding
-            .as_ref()
-            .map(|e| e.get_ids().len())
-            .unwrap_or(0);
+/// Compute the lengths of any number of sequences, once truncated with the given parameters.
+pub(crate) fn truncated_lengths(lens: &[usize], params: &TruncationParams) -> Result<Vec<usize>> {
+    if params.max_length == 0 {
+        return Ok(vec![0; lens.len()]);
+    }
+
+    let total_length = lens.iter().sum::<usize>();
     let to_remove = if total_length > params.max_length {
         total_length - params.max_length
     } else {
-        return Ok((encoding, pair_encoding));
+        return Ok(lens.to_vec());
     };
 
+    let mut truncated = lens.to_vec();
     match params.strategy {
         TruncationStrategy::LongestFirst => {
-            if let Some(other_encoding) = pair_encoding.as_mut() {
-                // Assuming n1 <= n2, there are 3 cases
-                // Case 1:
-                //   No truncation needs to be performed.
-                //   This scenario is handled before the match.
-                // Case 2:
-                //   Only the longer input needs to be truncated.
-                //   n1 = n1
-                //   n2 = max_length - n1
-                // Case 3:
-                //   Both inputs must be truncated.
-                //   n1 = max_length / 2
-                //   n2 = n1 + max_length % 2
-
-                let mut n1 = encoding.get_ids().len();
-                let mut n2 = other_encoding.get_ids().len();
-                let mut swap = false;
-
-                // Ensure n1 is the length of the shortest input
-                if n1 > n2 {
-                    swap = true;
-                    mem::swap(&mut n1, &mut n2);
-                }
-
-                if n1 > params.max_length {
-                    // This needs to be a special case
-                    // to avoid max_length - n1 < 0
-                    // since n1 and n2 are unsigned
-                    n2 = n1;
-                } else {
-                    n2 = cmp::max(n1, params.max_length - n1);
-                }
-
-                if n1 + n2 > params.max_length {
-                    n1 = params.max_length / 2;
-                    n2 = n1 + params.max_length % 2;
+            // We go over the sequences from the shortest to the longest. Sequences shorter than
+            // an even share of the remaining budget are kept whole, and once one is longer, all
+            // the remaining ones get the same share. What can't be shared evenly goes to the
+            // longest sequences, or to the last ones in case of a tie.
+            let mut order = (0..lens.len()).collect::<Vec<_>>();
+            order.sort_by_key(|i| lens[*i]);
+
+            let mut budget = params.max_length;
+            for (pos, i) in order.iter().enumerate() {
+                let remaining = lens.len() - pos;
+                let share = budget / remaining;
+                if lens[*i] <= share {
+                    budget -= lens[*i];
+                    continue;
                 }
 
-                // Swap lengths if we swapped previosuly
-                if swap {
-                    mem::swap(&mut n1, &mut n2);
+                let extra = budget % remaining;
+                for (j, i) in order[pos..].iter().enumerate() {
+                    truncated[*i] = share + usize::from(j >= remaining - extra);
                 }
-                encoding.truncate(n1, params.stride, params.direction);
-                other_encoding.truncate(n2, params.stride, params.direction);
-            } else {
-                encoding.truncate(total_length - to_remove, params.stride, params.direction);
+                break;
+            }
+        }
+        TruncationStrategy::Proportional => {
+            // Each sequence keeps its share of the max length, and what is left after rounding
+            // down goes to the longest sequences
+            for (truncated, len) in truncated.iter_mut().zip(lens) {
+                *truncated = len * params.max_length / total_length;
+            }
+            let mut order = (0..lens.len()).collect::<Vec<_>>();
+            order.sort_by_key(|i| cmp::Reverse(lens[*i]));
+            let left = params.max_length - truncated.iter().sum::<usize>();
+            for i in order.into_iter().take(left) {
+                truncated[i] += 1;
             }
         }
         TruncationStrategy::OnlyFirst | TruncationStrategy::OnlySecond => {
-            let target = if params.strategy == TruncationStrategy::OnlyFirst {
-                Ok(&mut encoding)
-            } else if let Some(encoding) = pair_encoding.as_mut() {
-                Ok(encoding)
-            } else {
-                Err(Box::new(TruncationError::SecondSequenceNotProvided))
-            }?;
+            let index = usize::from(params.strategy == TruncationStrategy::OnlySecond);
+            let target = truncated
+                .get_mut(index)
+                .ok_or(TruncationError::SecondSequenceNotProvided)?;
 
-            let target_len = target.get_ids().len();
-            if target_len > to_remove {
-                target.truncate(target_len - to_remove, params.stride, params.direction);
+            if *target > to_remove {
+                *target -= to_remove;
             } else {
                 return Err(Box::new(TruncationError::SequenceTooShort));
             }
         }
     }
-    Ok((encoding, pair_encoding))
+    Ok(truncated)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tokenizer::Encoding;
+    use crate::tokenizer::{Encoding, Offsets};
     use std::collections::HashMap;
 
     fn get_empty() -> Encoding {
@@ -283,6 +441,7 @@ mod tests {
             strategy: TruncationStrategy::LongestFirst,
             stride: 0,
             direction: TruncationDirection::Right,
+            boundary: TruncationBoundary::Token,
         };
 
         truncate_and_assert(get_empty(), get_empty(), &params, 0, 0);
@@ -313,6 +472,7 @@ mod tests {
             strategy: TruncationStrategy::LongestFirst,
             stride: 0,
             direction: TruncationDirection::Right,
+            boundary: TruncationBoundary::Token,
         };
 
         truncate_and_assert(get_empty(), get_short(), &params, 0, 0);
@@ -320,6 +480,109 @@ mod tests {
         truncate_and_assert(get_long(), get_long(), &params, 0, 0);
     }
 
+    #[test]
+    fn truncate_multi() {
+        let lengths = |strategy, max_length| {
+            let params = TruncationParams {
+                max_length,
+                strategy,
+                ..Default::default()
+            };
+            let encodings = vec![get_short(), get_long(), get_medium()];
+            truncate_encodings_multi(encodings, &params)
+                .map(|encodings| encodings.iter().map(|e| e.len()).collect::<Vec<_>>())
+        };
+
+        assert_eq!(
+            lengths(TruncationStrategy::LongestFirst, 14).unwrap(),
+            vec![2, 8, 4]
+        );
+        assert_eq!(
+            lengths(TruncationStrategy::LongestFirst, 10).unwrap(),
+            vec![2, 4, 4]
+        );
+        assert_eq!(
+            lengths(TruncationStrategy::LongestFirst, 7).unwrap(),
+            vec![2, 3, 2]
+        );
+        assert_eq!(
+            lengths(TruncationStrategy::Proportional, 7).unwrap(),
+            vec![1, 4, 2]
+        );
+        assert_eq!(
+            lengths(TruncationStrategy::OnlySecond, 7).unwrap(),
+            vec![2, 1, 4]
+        );
+        assert!(lengths(TruncationStrategy::OnlyFirst, 7).is_err());
+    }
+
+    #[test]
+    fn boundary_cuts() {
+        let encoding = |tokens: &[(&str, Offsets)]| {
+            Encoding::new(
+                vec![0; tokens.len()],
+                vec![0; tokens.len()],
+                tokens.iter().map(|(t, _)| t.to_string()).collect(),
+                (0..tokens.len() as u32).map(Some).collect(),
+                tokens.iter().map(|(_, o)| *o).collect(),
+                vec![0; tokens.len()],
+                vec![1; tokens.len()],
+                vec![],
+                HashMap::new(),
+            )
+        };
+        let text = "Hi there. Bye now.";
+        let enc = encoding(&[
+            ("Hi", (0, 2)),
+            ("there", (3, 8)),
+            (".", (8, 9)),
+            ("Bye", (10, 13)),
+            ("now", (14, 17)),
+            (".", (17, 18)),
+        ]);
+        let cuts = |boundary: TruncationBoundary, text| boundary.cuts(&enc, text).unwrap();
+
+        assert_eq!(cuts(TruncationBoundary::Word, None), vec![true; 7]);
+        assert_eq!(
+            cuts(TruncationBoundary::Sentence, Some((text, OffsetType::Byte))),
+            vec![true, false, false, true, false, false, true]
+        );
+        assert_eq!(
+            cuts(
+                TruncationBoundary::Regex(BoundaryRegex::new("e").unwrap()),
+                Some((text, OffsetType::Byte))
+            ),
+            vec![true, false, true, false, true, false, true]
+        );
+        // Without the original text, the sentences fall back to words
+        assert_eq!(cuts(TruncationBoundary::Sentence, None), vec![true; 7]);
+
+        // The positions are converted to chars with char offsets
+        let text = "Hé. Ok";
+        let enc = encoding(&[("Hé", (0, 2)), (".", (2, 3)), ("Ok", (4, 6))]);
+        assert_eq!(
+            TruncationBoundary::Sentence
+                .cuts(&enc, Some((text, OffsetType::Char)))
+                .unwrap(),
+            vec![true, false, true, true]
+        );
+
+        let params = TruncationParams {
+            boundary: TruncationBoundary::Regex(BoundaryRegex::new(r"\n").unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&params).unwrap(),
+            r#"{"direction":"Right","max_length":512,"strategy":"LongestFirst","stride":0,"boundary":{"Regex":"\\n"}}"#
+        );
+        // The regex is compiled when deserialized
+        let serialized = serde_json::to_string(&params).unwrap();
+        let deserialized: TruncationParams = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.boundary, params.boundary);
+        let invalid = serialized.replace(r#"\\n"#, "(");
+        assert!(serde_json::from_str::<TruncationParams>(&invalid).is_err());
+    }
+
     #[test]
     fn test_deserialize_defaults() {
         let old_truncation_params = r#"{"max_length":256,"strategy":"LongestFirst","stride":0}"#;